@@ -0,0 +1,307 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::{Field, PrimeField};
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+/// Why a ceremony was abandoned
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum AbortReason {
+    /// The participants at these ordinals did not send their payloads in time
+    Timeout(Vec<usize>),
+    /// The participants at these ordinals sent invalid payloads
+    Blame(Vec<usize>),
+    /// A reason given by the application
+    Other(String),
+}
+
+impl Display for AbortReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout(ordinals) => write!(f, "timed out waiting for {ordinals:?}"),
+            Self::Blame(ordinals) => write!(f, "invalid payloads from {ordinals:?}"),
+            Self::Other(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl AbortReason {
+    fn add_to_transcript(&self, transcript: &mut merlin::Transcript) {
+        let append_ordinals = |transcript: &mut merlin::Transcript, ordinals: &[usize]| {
+            transcript.append_u64(b"ordinals", ordinals.len() as u64);
+            for ordinal in ordinals {
+                transcript.append_u64(b"ordinal", *ordinal as u64);
+            }
+        };
+        match self {
+            Self::Timeout(ordinals) => {
+                transcript.append_message(b"reason", b"timeout");
+                append_ordinals(transcript, ordinals);
+            }
+            Self::Blame(ordinals) => {
+                transcript.append_message(b"reason", b"blame");
+                append_ordinals(transcript, ordinals);
+            }
+            Self::Other(reason) => {
+                transcript.append_message(b"reason", b"other");
+                transcript.append_message(b"text", reason.as_bytes());
+            }
+        }
+    }
+}
+
+/// A signature on an [`AbortCertificate`] by one participant's
+/// long term signing key
+#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
+pub struct AbortSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The public key of the signer
+    #[serde(with = "group")]
+    pub(crate) signer: G,
+    /// The signature
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> AbortSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The public key of the signer
+    pub fn signer(&self) -> G {
+        self.signer
+    }
+}
+
+/// An auditable record that a ceremony was aborted and its key never
+/// became valid.
+///
+/// Created by one participant with [`Participant::abort_certificate`] and
+/// countersigned by the others with [`Participant::countersign_abort`].
+/// Signatures are made with each participant's long term signing key, not
+/// with anything produced by the ceremony.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AbortCertificate<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Why the ceremony was aborted
+    pub(crate) reason: AbortReason,
+    /// The round the ceremony was in when it was aborted
+    pub(crate) round: Round,
+    /// The digest of the ceremony parameters and roster
+    pub(crate) ceremony_digest: [u8; 32],
+    /// The digest of the evidence held by the participant that aborted
+    pub(crate) evidence_digest: [u8; 32],
+    /// The signatures by ordinal index
+    #[serde(bound(
+        serialize = "AbortSignature<G>: Serialize",
+        deserialize = "AbortSignature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signatures: BTreeMap<usize, AbortSignature<G>>,
+}
+
+impl<G> AbortCertificate<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Why the ceremony was aborted
+    pub fn reason(&self) -> &AbortReason {
+        &self.reason
+    }
+
+    /// The round the ceremony was in when it was aborted
+    pub fn round(&self) -> Round {
+        self.round
+    }
+
+    /// The digest of the ceremony parameters and roster
+    pub fn ceremony_digest(&self) -> [u8; 32] {
+        self.ceremony_digest
+    }
+
+    /// The digest of the evidence held by the participant that aborted
+    pub fn evidence_digest(&self) -> [u8; 32] {
+        self.evidence_digest
+    }
+
+    /// The signatures by ordinal index
+    pub fn signatures(&self) -> &BTreeMap<usize, AbortSignature<G>> {
+        &self.signatures
+    }
+
+    /// Check that at least `quorum` of the participants in `signers` signed
+    /// this certificate with the public keys given by ordinal index.
+    ///
+    /// Signatures from ordinals not in `signers` are ignored.
+    pub fn verify(&self, signers: &BTreeMap<usize, G>, quorum: usize) -> DkgResult<()> {
+        let mut valid = 0;
+        for (ordinal, signature) in &self.signatures {
+            let Some(signer) = signers.get(ordinal) else {
+                continue;
+            };
+            if signature.signer != *signer {
+                return Err(Error::Signature(format!(
+                    "Abort certificate signer {ordinal} used an unexpected key"
+                )));
+            }
+            self.verify_signature(*ordinal, signature)?;
+            valid += 1;
+        }
+        if valid < quorum {
+            return Err(Error::Signature(format!(
+                "Abort certificate needs {quorum} signatures but only has {valid}"
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sign(&mut self, ordinal: usize, signing_key: G::Scalar) -> DkgResult<()> {
+        if signing_key.is_zero().into() {
+            return Err(Error::Signature(
+                "Cannot sign an abort certificate with a zero key".to_string(),
+            ));
+        }
+        let signer = G::generator() * signing_key;
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = G::generator() * k;
+        let c = self.challenge(ordinal, &signer, &r);
+        self.signatures.insert(
+            ordinal,
+            AbortSignature {
+                signer,
+                signature: Signature {
+                    r,
+                    s: k + c * signing_key,
+                },
+            },
+        );
+        Ok(())
+    }
+
+    fn verify_signature(&self, ordinal: usize, signature: &AbortSignature<G>) -> DkgResult<()> {
+        if signature.signer.is_identity().into() {
+            return Err(Error::Signature(format!(
+                "Abort certificate signer {ordinal} is the identity"
+            )));
+        }
+        let c = self.challenge(ordinal, &signature.signer, &signature.signature.r);
+        if G::generator() * signature.signature.s != signature.signature.r + signature.signer * c {
+            return Err(Error::Signature(format!(
+                "Invalid abort certificate signature from {ordinal}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn challenge(&self, ordinal: usize, signer: &G, r: &G) -> G::Scalar {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Abort Certificate");
+        self.reason.add_to_transcript(&mut transcript);
+        transcript.append_u64(b"round", u8::from(self.round) as u64);
+        transcript.append_message(b"ceremony", &self.ceremony_digest);
+        transcript.append_message(b"evidence", &self.evidence_digest);
+        let mut statement = [0u8; 32];
+        transcript.challenge_bytes(b"statement", &mut statement);
+
+        let mut input = statement.to_vec();
+        input.extend_from_slice(&(ordinal as u64).to_be_bytes());
+        input.extend_from_slice(signer.to_bytes().as_ref());
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-ABORT");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Record that this ceremony is abandoned and sign the record with
+    /// this participant's long term `signing_key`.
+    ///
+    /// The certificate binds the ceremony parameters and roster and a digest
+    /// of the invalid participants and dispute evidence held by this participant.
+    pub fn abort_certificate(
+        &self,
+        reason: AbortReason,
+        signing_key: G::Scalar,
+    ) -> DkgResult<AbortCertificate<G>> {
+        if self.completed {
+            return Err(Error::Round(
+                "Cannot abort a ceremony that has completed".to_string(),
+            ));
+        }
+        let mut certificate = AbortCertificate {
+            reason,
+            round: self.round,
+            ceremony_digest: self.ceremony_digest(),
+            evidence_digest: self.evidence_digest()?,
+            signatures: BTreeMap::new(),
+        };
+        certificate.sign(self.ordinal, signing_key)?;
+        Ok(certificate)
+    }
+
+    /// Countersign an abort certificate created by another participant of
+    /// this ceremony with this participant's long term `signing_key`
+    pub fn countersign_abort(
+        &self,
+        certificate: &mut AbortCertificate<G>,
+        signing_key: G::Scalar,
+    ) -> DkgResult<()> {
+        if self.completed {
+            return Err(Error::Round(
+                "Cannot countersign an abort of a ceremony that has completed".to_string(),
+            ));
+        }
+        if certificate.ceremony_digest != self.ceremony_digest() {
+            return Err(Error::Signature(
+                "Abort certificate is for a different ceremony".to_string(),
+            ));
+        }
+        certificate.sign(self.ordinal, signing_key)
+    }
+
+    fn ceremony_digest(&self) -> [u8; 32] {
+        let mut transcript = self.new_transcript(b"Frost DKG - Abort Ceremony");
+        transcript.append_u64(b"threshold", self.threshold as u64);
+        transcript.append_u64(b"limit", self.limit as u64);
+        transcript.append_message(b"generator", self.message_generator.to_bytes().as_ref());
+        for (ordinal, id) in &self.all_participant_ids {
+            transcript.append_u64(b"ordinal", *ordinal as u64);
+            transcript.append_message(b"id", id.0.to_repr().as_ref());
+        }
+        let mut digest = [0u8; 32];
+        transcript.challenge_bytes(b"ceremony digest", &mut digest);
+        digest
+    }
+
+    fn evidence_digest(&self) -> DkgResult<[u8; 32]> {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Abort Evidence");
+        for ordinal in self.invalid_participant_ids.keys() {
+            transcript.append_u64(b"invalid", *ordinal as u64);
+        }
+        for (ordinal, evidence) in &self.dispute_evidence {
+            transcript.append_u64(b"accused", *ordinal as u64);
+            transcript.append_message(b"evidence", &postcard::to_stdvec(evidence)?);
+        }
+        let mut digest = [0u8; 32];
+        transcript.challenge_bytes(b"evidence digest", &mut digest);
+        Ok(digest)
+    }
+}
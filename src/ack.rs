@@ -0,0 +1,197 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A recipient's signed statement that it verified the round 2 share from
+/// a dealer against the dealer's round 1 commitments.
+///
+/// The recipient signs with the key its round 1 proof of knowledge was
+/// made with, so anyone holding the [`SignedContext`] and the round 1
+/// broadcasts of both parties can check with [`ShareAck::verify`] that the
+/// share was delivered.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShareAck<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the dealer
+    pub(crate) dealer_ordinal: usize,
+    /// The ordinal index of the recipient
+    pub(crate) recipient_ordinal: usize,
+    /// The digest of the dealer's feldman commitments
+    pub(crate) commitments_digest: [u8; 32],
+    /// The schnorr signature of the recipient
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> ShareAck<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the dealer
+    pub fn dealer_ordinal(&self) -> usize {
+        self.dealer_ordinal
+    }
+
+    /// The ordinal index of the recipient
+    pub fn recipient_ordinal(&self) -> usize {
+        self.recipient_ordinal
+    }
+
+    /// The digest of the dealer's feldman commitments the share was checked against
+    pub fn commitments_digest(&self) -> [u8; 32] {
+        self.commitments_digest
+    }
+
+    /// Check the acknowledgment against the round 1 broadcasts of the
+    /// `dealer` and the `recipient`, both of which are checked against `context`
+    pub fn verify(
+        &self,
+        context: &SignedContext<G>,
+        dealer: &Round1Data<G>,
+        recipient: &Round1Data<G>,
+    ) -> DkgResult<()> {
+        if dealer.sender_ordinal != self.dealer_ordinal
+            || recipient.sender_ordinal != self.recipient_ordinal
+        {
+            return Err(Error::Signature(
+                "Share acknowledgment is for different participants".to_string(),
+            ));
+        }
+        if dealer.commitments_digest() != self.commitments_digest {
+            return Err(Error::Signature(format!(
+                "Share acknowledgment from {} is for different commitments",
+                self.recipient_ordinal
+            )));
+        }
+        context.verify(dealer)?;
+        context.verify(recipient)?;
+        let c = Self::challenge(
+            context,
+            dealer,
+            self.recipient_ordinal,
+            &recipient.verifying_share,
+            &self.signature.r,
+        );
+        if context.message_generator * self.signature.s
+            != self.signature.r + recipient.verifying_share * c
+        {
+            return Err(Error::Signature(format!(
+                "Invalid share acknowledgment from {}",
+                self.recipient_ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    fn challenge(
+        context: &SignedContext<G>,
+        dealer: &Round1Data<G>,
+        recipient_ordinal: usize,
+        recipient_verifying_share: &G,
+        r: &G,
+    ) -> G::Scalar {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Share Acknowledgment");
+        transcript.append_message(b"roster", &context.roster_digest);
+        transcript.append_message(b"parameters", &context.parameters_digest);
+        transcript.append_u64(b"dealer", dealer.sender_ordinal as u64);
+        transcript.append_message(b"commitments", &dealer.commitments_digest());
+        transcript.append_message(b"dealer_r", dealer.signature.r.to_bytes().as_ref());
+        transcript.append_u64(b"recipient", recipient_ordinal as u64);
+        let mut statement = [0u8; 32];
+        transcript.challenge_bytes(b"statement", &mut statement);
+
+        let mut input = statement.to_vec();
+        input.extend_from_slice(recipient_verifying_share.to_bytes().as_ref());
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-ACK");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Acknowledge the verified round 2 share from the dealer at
+    /// `dealer_ordinal`.
+    ///
+    /// Send the acknowledgment back to the dealer which keeps it with
+    /// [`Participant::receive_share_ack`].
+    pub fn acknowledge_share(&self, dealer_ordinal: usize) -> DkgResult<ShareAck<G>> {
+        if dealer_ordinal == self.ordinal
+            || !self.received_round2_data.contains_key(&dealer_ordinal)
+        {
+            return Err(Error::Round(format!(
+                "No verified round 2 share from {dealer_ordinal}"
+            )));
+        }
+        let dealer = self
+            .received_round1_data
+            .get(&dealer_ordinal)
+            .ok_or_else(|| Error::Round(format!("No round 1 data from {dealer_ordinal}")))?;
+        let context = self.signed_context();
+        let (secret, verifying_share) = self.round1_key();
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = self.message_generator * k;
+        let c = ShareAck::challenge(&context, dealer, self.ordinal, &verifying_share, &r);
+        Ok(ShareAck {
+            dealer_ordinal,
+            recipient_ordinal: self.ordinal,
+            commitments_digest: dealer.commitments_digest(),
+            signature: Signature {
+                r,
+                s: k + c * secret,
+            },
+        })
+    }
+
+    /// Check and keep an acknowledgment of the share this participant
+    /// dealt to another participant
+    pub fn receive_share_ack(&mut self, ack: ShareAck<G>) -> DkgResult<()> {
+        if ack.dealer_ordinal != self.ordinal {
+            return Err(Error::Signature(format!(
+                "Share acknowledgment is for dealer {}",
+                ack.dealer_ordinal
+            )));
+        }
+        let dealer = self.received_round1_data.get(&self.ordinal);
+        let recipient = self.received_round1_data.get(&ack.recipient_ordinal);
+        let (Some(dealer), Some(recipient)) = (dealer, recipient) else {
+            return Err(Error::Round(format!(
+                "No round 1 data to check the acknowledgment from {}",
+                ack.recipient_ordinal
+            )));
+        };
+        ack.verify(&self.signed_context(), dealer, recipient)?;
+        self.share_acks.insert(ack.recipient_ordinal, ack);
+        Ok(())
+    }
+
+    /// The acknowledgments of this participant's shares by recipient ordinal index
+    pub fn get_share_acks(&self) -> &BTreeMap<usize, ShareAck<G>> {
+        &self.share_acks
+    }
+
+    /// The valid participants that have not acknowledged their share yet
+    pub fn unacknowledged_recipients(&self) -> Vec<usize> {
+        self.valid_participant_ids
+            .keys()
+            .filter(|ordinal| **ordinal != self.ordinal && !self.share_acks.contains_key(ordinal))
+            .copied()
+            .collect()
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::io;
+use std::marker::PhantomData;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+#[derive(Debug)]
+enum Command<F: ScalarHash> {
+    Deliver(Vec<u8>, oneshot::Sender<DkgResult<()>>),
+    Status(oneshot::Sender<DkgStatus>),
+    Run(oneshot::Sender<DkgResult<Vec<ParticipantRoundOutput<F>>>>),
+}
+
+/// Runs a participant on its own task so it can be driven from many
+/// tasks without sharing a mutable reference
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DkgActor<G>(PhantomData<G>);
+
+impl<G> DkgActor<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Spawn the actor for `participant`.
+    ///
+    /// The task finishes and returns the participant once every handle is dropped.
+    pub fn spawn<P>(mut participant: P) -> (DkgActorHandle<G::Scalar>, JoinHandle<P>)
+    where
+        P: AnyParticipant<G> + 'static,
+    {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let join_handle = tokio::spawn(async move {
+            while let Some(command) = receiver.recv().await {
+                // A dropped reply channel means the caller is no longer interested
+                match command {
+                    Command::Deliver(data, reply) => {
+                        let _ = reply.send(participant.receive(&data));
+                    }
+                    Command::Status(reply) => {
+                        let _ = reply.send(participant.status());
+                    }
+                    Command::Run(reply) => {
+                        let outputs = participant
+                            .run()
+                            .map(|generator| generator.iter().collect::<Vec<_>>());
+                        let _ = reply.send(outputs);
+                    }
+                }
+            }
+            participant
+        });
+        (DkgActorHandle { sender }, join_handle)
+    }
+}
+
+/// A cloneable handle for sending commands to a [`DkgActor`]
+#[derive(Debug, Clone)]
+pub struct DkgActorHandle<F: ScalarHash> {
+    sender: mpsc::UnboundedSender<Command<F>>,
+}
+
+impl<F: ScalarHash> DkgActorHandle<F> {
+    /// Deliver a message received from another participant
+    pub async fn deliver(&self, data: Vec<u8>) -> DkgResult<()> {
+        let (reply, response) = oneshot::channel();
+        self.request(Command::Deliver(data, reply), response)
+            .await?
+    }
+
+    /// Request a snapshot of the protocol progress
+    pub async fn status(&self) -> DkgResult<DkgStatus> {
+        let (reply, response) = oneshot::channel();
+        self.request(Command::Status(reply), response).await
+    }
+
+    /// Request the participant run the next round and return the messages to send
+    pub async fn run(&self) -> DkgResult<Vec<ParticipantRoundOutput<F>>> {
+        let (reply, response) = oneshot::channel();
+        self.request(Command::Run(reply), response).await?
+    }
+
+    async fn request<T>(
+        &self,
+        command: Command<F>,
+        response: oneshot::Receiver<T>,
+    ) -> DkgResult<T> {
+        self.sender
+            .send(command)
+            .map_err(|_| Error::Io(io::Error::from(io::ErrorKind::BrokenPipe)))?;
+        response
+            .await
+            .map_err(|_| Error::Io(io::Error::from(io::ErrorKind::BrokenPipe)))
+    }
+}
@@ -0,0 +1,365 @@
+//! Adapters that let arkworks curves be used with this crate.
+//!
+//! [`ArkScalar`] and [`ArkGroup`] wrap an arkworks scalar field and curve group
+//! and implement the `ff` and `group` traits the protocol needs. The few
+//! constants `ff` requires that arkworks doesn't expose are provided by
+//! [`ArkScalarParams`] and the encoding size by [`ArkCurve`].
+//!
+//! Arkworks arithmetic is not constant time so neither are these adapters.
+
+use super::*;
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::{AdditiveGroup, BigInteger, MontFp, PrimeField as ArkPrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use elliptic_curve::ff::helpers::sqrt_ratio_generic;
+use elliptic_curve::group::{Group, GroupEncoding};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use rand_core::TryRng;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The constants required by [`elliptic_curve::PrimeField`] that arkworks fields do not provide
+pub trait ArkScalarParams: ArkPrimeField {
+    /// The canonical little endian encoding of a scalar
+    type Repr: Copy + Default + Send + Sync + 'static + AsRef<[u8]> + AsMut<[u8]>;
+    /// The modulus as a big endian hex string prefixed with `0x`
+    const MODULUS_HEX: &'static str;
+    /// The inverse of two
+    const TWO_INV: Self;
+    /// The `GENERATOR^t` where `t * 2^TWO_ADICITY + 1` is the modulus
+    const ROOT_OF_UNITY: Self;
+    /// The inverse of [`ArkScalarParams::ROOT_OF_UNITY`]
+    const ROOT_OF_UNITY_INV: Self;
+    /// `GENERATOR^{2^TWO_ADICITY}`
+    const DELTA: Self;
+}
+
+/// An arkworks curve group that can be wrapped by [`ArkGroup`]
+pub trait ArkCurve: CurveGroup<ScalarField: ArkScalarParams> {
+    /// The compressed encoding of a point
+    type Repr: Copy + Default + Send + Sync + 'static + AsRef<[u8]> + AsMut<[u8]>;
+}
+
+/// A scalar of an arkworks prime field
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArkScalar<F: ArkScalarParams>(pub F);
+
+/// A point of an arkworks curve group
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArkGroup<C: ArkCurve>(pub C);
+
+impl<F: ArkScalarParams> From<u64> for ArkScalar<F> {
+    fn from(value: u64) -> Self {
+        Self(F::from(value))
+    }
+}
+
+impl<F: ArkScalarParams> ConditionallySelectable for ArkScalar<F> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        if bool::from(choice) { *b } else { *a }
+    }
+}
+
+impl<F: ArkScalarParams> ConstantTimeEq for ArkScalar<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_repr().as_ref().ct_eq(other.to_repr().as_ref())
+    }
+}
+
+impl<F: ArkScalarParams> Neg for ArkScalar<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<C: ArkCurve> Neg for ArkGroup<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+macro_rules! ark_ops {
+    ($name:ident, $bound:ident, $rhs:ty, $($op:ident $func:ident $op_assign:ident $func_assign:ident),+) => {
+        $(
+            impl<T: $bound> $op<$rhs> for $name<T> {
+                type Output = Self;
+
+                fn $func(self, rhs: $rhs) -> Self {
+                    Self(self.0.$func(rhs.0))
+                }
+            }
+
+            impl<'a, T: $bound> $op<&'a $rhs> for $name<T> {
+                type Output = Self;
+
+                fn $func(self, rhs: &'a $rhs) -> Self {
+                    Self(self.0.$func(rhs.0))
+                }
+            }
+
+            impl<T: $bound> $op_assign<$rhs> for $name<T> {
+                fn $func_assign(&mut self, rhs: $rhs) {
+                    self.0.$func_assign(rhs.0);
+                }
+            }
+
+            impl<'a, T: $bound> $op_assign<&'a $rhs> for $name<T> {
+                fn $func_assign(&mut self, rhs: &'a $rhs) {
+                    self.0.$func_assign(rhs.0);
+                }
+            }
+        )+
+    };
+}
+
+ark_ops!(ArkScalar, ArkScalarParams, Self, Add add AddAssign add_assign, Sub sub SubAssign sub_assign, Mul mul MulAssign mul_assign);
+ark_ops!(ArkGroup, ArkCurve, Self, Add add AddAssign add_assign, Sub sub SubAssign sub_assign);
+ark_ops!(ArkGroup, ArkCurve, ArkScalar<T::ScalarField>, Mul mul MulAssign mul_assign);
+
+impl<F: ArkScalarParams> Sum for ArkScalar<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, s| acc + s)
+    }
+}
+
+impl<'a, F: ArkScalarParams> Sum<&'a Self> for ArkScalar<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, s| acc + s)
+    }
+}
+
+impl<F: ArkScalarParams> Product for ArkScalar<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, s| acc * s)
+    }
+}
+
+impl<'a, F: ArkScalarParams> Product<&'a Self> for ArkScalar<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, s| acc * s)
+    }
+}
+
+impl<C: ArkCurve> Sum for ArkGroup<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<'a, C: ArkCurve> Sum<&'a Self> for ArkGroup<C> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<F: ArkScalarParams> Field for ArkScalar<F> {
+    const ZERO: Self = Self(F::ZERO);
+    const ONE: Self = Self(F::ONE);
+
+    fn try_random<R: TryRng + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        // Sample 128 bits more than the modulus so the reduction bias is negligible
+        let mut bytes = vec![0u8; F::MODULUS_BIT_SIZE.div_ceil(8) as usize + 16];
+        rng.try_fill_bytes(&mut bytes)?;
+        Ok(Self(F::from_le_bytes_mod_order(&bytes)))
+    }
+
+    fn square(&self) -> Self {
+        Self(ark_ff::Field::square(&self.0))
+    }
+
+    fn double(&self) -> Self {
+        Self(AdditiveGroup::double(&self.0))
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let inverse = ark_ff::Field::inverse(&self.0);
+        CtOption::new(
+            Self(inverse.unwrap_or(F::ZERO)),
+            Choice::from(u8::from(inverse.is_some())),
+        )
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        sqrt_ratio_generic(num, div)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        let root = ark_ff::Field::sqrt(&self.0);
+        CtOption::new(
+            Self(root.unwrap_or(F::ZERO)),
+            Choice::from(u8::from(root.is_some())),
+        )
+    }
+}
+
+impl<F: ArkScalarParams> PrimeField for ArkScalar<F> {
+    type Repr = F::Repr;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let scalar = Self(F::from_le_bytes_mod_order(repr.as_ref()));
+        // Only the canonical encoding of a scalar round trips
+        let canonical = scalar.to_repr().as_ref().ct_eq(repr.as_ref());
+        CtOption::new(scalar, canonical)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        let mut repr = F::Repr::default();
+        let bytes = BigInteger::to_bytes_le(&self.0.into_bigint());
+        let len = repr.as_ref().len();
+        repr.as_mut().copy_from_slice(&bytes[..len]);
+        repr
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(u8::from(BigInteger::is_odd(&self.0.into_bigint())))
+    }
+
+    const MODULUS: &'static str = F::MODULUS_HEX;
+    const NUM_BITS: u32 = F::MODULUS_BIT_SIZE;
+    const CAPACITY: u32 = F::MODULUS_BIT_SIZE - 1;
+    const TWO_INV: Self = Self(F::TWO_INV);
+    const MULTIPLICATIVE_GENERATOR: Self = Self(F::GENERATOR);
+    const S: u32 = F::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self(F::ROOT_OF_UNITY);
+    const ROOT_OF_UNITY_INV: Self = Self(F::ROOT_OF_UNITY_INV);
+    const DELTA: Self = Self(F::DELTA);
+}
+
+impl<C: ArkCurve> ConditionallySelectable for ArkGroup<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        if bool::from(choice) { *b } else { *a }
+    }
+}
+
+impl<C: ArkCurve> Group for ArkGroup<C> {
+    type Scalar = ArkScalar<C::ScalarField>;
+
+    fn try_random<R: TryRng + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        let scalar = Self::Scalar::try_random(rng)?;
+        Ok(Self::generator() * scalar)
+    }
+
+    fn identity() -> Self {
+        Self(<C as Zero>::zero())
+    }
+
+    fn generator() -> Self {
+        Self(<C as PrimeGroup>::generator())
+    }
+
+    fn is_identity(&self) -> Choice {
+        Choice::from(u8::from(Zero::is_zero(&self.0)))
+    }
+
+    fn double(&self) -> Self {
+        Self(AdditiveGroup::double(&self.0))
+    }
+}
+
+impl<C: ArkCurve> GroupEncoding for ArkGroup<C> {
+    type Repr = C::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        // Checks the point is on the curve and in the prime order subgroup
+        let point =
+            <C::Affine as CanonicalDeserialize>::deserialize_compressed(bytes.as_ref()).ok();
+        CtOption::new(
+            Self(point.map(Into::into).unwrap_or_else(<C as Zero>::zero)),
+            Choice::from(u8::from(point.is_some())),
+        )
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        let point =
+            <C::Affine as CanonicalDeserialize>::deserialize_compressed_unchecked(bytes.as_ref())
+                .ok();
+        CtOption::new(
+            Self(point.map(Into::into).unwrap_or_else(<C as Zero>::zero)),
+            Choice::from(u8::from(point.is_some())),
+        )
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let mut repr = C::Repr::default();
+        CanonicalSerialize::serialize_compressed(&self.0.into_affine(), repr.as_mut())
+            .expect("the encoding size matches the curve");
+        repr
+    }
+}
+
+#[cfg(feature = "ark-bn254")]
+impl ArkScalarParams for ark_bn254::Fr {
+    type Repr = [u8; 32];
+    const MODULUS_HEX: &'static str =
+        "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+    const TWO_INV: Self =
+        MontFp!("10944121435919637611123202872628637544274182200208017171849102093287904247809");
+    const ROOT_OF_UNITY: Self =
+        MontFp!("19103219067921713944291392827692070036145651957329286315305642004821462161904");
+    const ROOT_OF_UNITY_INV: Self =
+        MontFp!("776454056201908206186590970419435932130236139910903033203789591477115950462");
+    const DELTA: Self =
+        MontFp!("5266228460530200451425464971825753823072228272503274930591399474110020095489");
+}
+
+// Named through the curve configs rather than the `G1Projective` aliases,
+// which coherence can't tell apart when both curves are enabled
+#[cfg(feature = "ark-bn254")]
+impl ArkCurve for ark_ec::short_weierstrass::Projective<ark_bn254::g1::Config> {
+    type Repr = [u8; 32];
+}
+
+#[cfg(feature = "ark-bls12-381")]
+impl ArkScalarParams for ark_bls12_381::Fr {
+    type Repr = [u8; 32];
+    const MODULUS_HEX: &'static str =
+        "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+    const TWO_INV: Self =
+        MontFp!("26217937587563095239723870254092982918845276250263818911301829349969290592257");
+    const ROOT_OF_UNITY: Self =
+        MontFp!("10238227357739495823651030575849232062558860180284477541189508159991286009131");
+    const ROOT_OF_UNITY_INV: Self =
+        MontFp!("2361660012179324004544952053205906600198439069793331036989202475535180077688");
+    const DELTA: Self =
+        MontFp!("3793952369011177517951424454785176000433849974408744014172535497121832470999");
+}
+
+#[cfg(feature = "ark-bls12-381")]
+impl ArkCurve for ark_ec::short_weierstrass::Projective<ark_bls12_381::g1::Config> {
+    type Repr = Bls12381G1Repr;
+}
+
+/// The compressed encoding of a BLS12-381 G1 point.
+///
+/// `[u8; 48]` doesn't implement [`Default`] so it can't be used as a
+/// [`GroupEncoding::Repr`] directly.
+#[cfg(feature = "ark-bls12-381")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bls12381G1Repr(pub [u8; 48]);
+
+#[cfg(feature = "ark-bls12-381")]
+impl Default for Bls12381G1Repr {
+    fn default() -> Self {
+        Self([0u8; 48])
+    }
+}
+
+#[cfg(feature = "ark-bls12-381")]
+impl AsRef<[u8]> for Bls12381G1Repr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "ark-bls12-381")]
+impl AsMut<[u8]> for Bls12381G1Repr {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Encode `value` as canonical JSON in the style of RFC 8785 (JCS).
+///
+/// Object members are sorted by their UTF-16 code units and no
+/// insignificant whitespace is emitted, so the same report always
+/// produces the same bytes regardless of the implementation that wrote it.
+/// Floating point numbers are rejected since their canonical form is
+/// not needed by any report produced by this crate.
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> DkgResult<Vec<u8>> {
+    let value = serde_json::to_value(value)?;
+    let mut out = Vec::with_capacity(256);
+    write_canonical(&value, &mut out)?;
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut Vec<u8>) -> DkgResult<()> {
+    match value {
+        Value::Number(n) if n.is_f64() => {
+            return Err(Error::Json(serde::ser::Error::custom(
+                "floating point numbers are not supported in canonical json",
+            )));
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+            serde_json::to_writer(&mut *out, value)?;
+        }
+        Value::Array(values) => {
+            out.push(b'[');
+            for (i, v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical(v, out)?;
+            }
+            out.push(b']');
+        }
+        Value::Object(members) => {
+            let mut members = members.iter().collect::<Vec<_>>();
+            members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push(b'{');
+            for (i, (k, v)) in members.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, k)?;
+                out.push(b':');
+                write_canonical(v, out)?;
+            }
+            out.push(b'}');
+        }
+    }
+    Ok(())
+}
+
+/// A schnorr signature over a canonical JSON report that is
+/// stored and distributed separately from the report itself
+#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
+pub struct DetachedSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The public key of the signer
+    #[serde(with = "group")]
+    pub(crate) signer: G,
+    /// The signature
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> DetachedSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Sign the canonical JSON encoding of `report` with `secret`
+    pub fn sign<T: Serialize + ?Sized>(
+        report: &T,
+        secret: G::Scalar,
+        generator: G,
+    ) -> DkgResult<Self> {
+        let message = to_canonical_json(report)?;
+        Self::sign_bytes(&message, secret, generator)
+    }
+
+    /// Sign an already canonicalized `message` with `secret`
+    pub fn sign_bytes(message: &[u8], secret: G::Scalar, generator: G) -> DkgResult<Self> {
+        if secret.is_zero().into() || generator.is_identity().into() {
+            return Err(Error::Signature(
+                "Cannot sign with a zero secret or identity generator".to_string(),
+            ));
+        }
+        let signer = generator * secret;
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = generator * k;
+        let challenge = Self::challenge(message, &generator, &signer, &r);
+        Ok(Self {
+            signer,
+            signature: Signature {
+                r,
+                s: k + challenge * secret,
+            },
+        })
+    }
+
+    /// Verify this signature over the canonical JSON encoding of `report`
+    pub fn verify<T: Serialize + ?Sized>(&self, report: &T, generator: G) -> DkgResult<()> {
+        let message = to_canonical_json(report)?;
+        self.verify_bytes(&message, generator)
+    }
+
+    /// Verify this signature over an already canonicalized `message`
+    pub fn verify_bytes(&self, message: &[u8], generator: G) -> DkgResult<()> {
+        if self.signer.is_identity().into() || self.signature.r.is_identity().into() {
+            return Err(Error::Signature(
+                "Signer or commitment is the identity".to_string(),
+            ));
+        }
+        let challenge = Self::challenge(message, &generator, &self.signer, &self.signature.r);
+        let computed_r = generator * self.signature.s - self.signer * challenge;
+        if computed_r != self.signature.r {
+            return Err(Error::Signature("Invalid detached signature".to_string()));
+        }
+        Ok(())
+    }
+
+    /// The public key of the signer
+    pub fn signer(&self) -> G {
+        self.signer
+    }
+
+    /// The signature
+    pub fn signature(&self) -> Signature<G> {
+        self.signature
+    }
+
+    fn challenge(message: &[u8], generator: &G, signer: &G, r: &G) -> G::Scalar {
+        let mut bytes = Vec::with_capacity(message.len() + 256);
+        bytes.extend_from_slice(b"Frost DKG - Detached Signature");
+        bytes.extend_from_slice(generator.to_bytes().as_ref());
+        bytes.extend_from_slice(signer.to_bytes().as_ref());
+        bytes.extend_from_slice(r.to_bytes().as_ref());
+        bytes.extend_from_slice(message);
+        hash_to_group_scalar::<G>(&bytes)
+    }
+}
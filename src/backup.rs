@@ -0,0 +1,79 @@
+use super::*;
+use age::secrecy::SecretString;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::zeroize::Zeroize;
+use elliptic_curve_tools::SumOfProducts;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Encrypt this output to one or more age X25519 `recipients`
+    /// (`age1…`) as a binary `.age` file.
+    pub fn to_age(&self, recipients: &[&str]) -> DkgResult<Vec<u8>> {
+        let recipients = recipients
+            .iter()
+            .map(|r| {
+                age::x25519::Recipient::from_str(r)
+                    .map_err(|e| Error::Encoding(format!("Invalid age recipient '{r}': {e}")))
+            })
+            .collect::<DkgResult<Vec<_>>>()?;
+        let encryptor = age::Encryptor::with_recipients(
+            recipients.iter().map(|r| -> &dyn age::Recipient { r }),
+        )
+        .map_err(|e| Error::Encoding(format!("Cannot encrypt to the age recipients: {e}")))?;
+        self.encrypt_age(encryptor)
+    }
+
+    /// Encrypt this output with an age passphrase as a binary `.age` file
+    pub fn to_age_with_passphrase(&self, passphrase: &str) -> DkgResult<Vec<u8>> {
+        let encryptor =
+            age::Encryptor::with_user_passphrase(SecretString::from(passphrase.to_string()));
+        self.encrypt_age(encryptor)
+    }
+
+    /// Decrypt an output written by [`DkgOutput::to_age`] with the age
+    /// X25519 `identity` (`AGE-SECRET-KEY-1…`)
+    pub fn from_age(blob: &[u8], identity: &str) -> DkgResult<Self> {
+        let identity = age::x25519::Identity::from_str(identity)
+            .map_err(|e| Error::Encoding(format!("Invalid age identity: {e}")))?;
+        Self::decrypt_age(blob, &identity)
+    }
+
+    /// Decrypt an output written by [`DkgOutput::to_age_with_passphrase`]
+    pub fn from_age_with_passphrase(blob: &[u8], passphrase: &str) -> DkgResult<Self> {
+        let identity = age::scrypt::Identity::new(SecretString::from(passphrase.to_string()));
+        Self::decrypt_age(blob, &identity)
+    }
+
+    fn encrypt_age(&self, encryptor: age::Encryptor) -> DkgResult<Vec<u8>> {
+        let mut plaintext = postcard::to_stdvec(self)?;
+        let mut blob = Vec::new();
+        let result = encryptor.wrap_output(&mut blob).and_then(|mut writer| {
+            writer.write_all(&plaintext)?;
+            writer.finish()
+        });
+        plaintext.zeroize();
+        result?;
+        Ok(blob)
+    }
+
+    fn decrypt_age(blob: &[u8], identity: &dyn age::Identity) -> DkgResult<Self> {
+        let decryptor = age::Decryptor::new(blob)
+            .map_err(|e| Error::Encoding(format!("Invalid age file: {e}")))?;
+        let mut reader = decryptor
+            .decrypt(std::iter::once(identity))
+            .map_err(|e| Error::Encoding(format!("Cannot decrypt the age file: {e}")))?;
+        let mut plaintext = Vec::new();
+        let output = reader
+            .read_to_end(&mut plaintext)
+            .map_err(Error::from)
+            .and_then(|_| postcard::from_bytes::<Self>(&plaintext).map_err(Error::from));
+        plaintext.zeroize();
+        output
+    }
+}
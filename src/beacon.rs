@@ -0,0 +1,219 @@
+use super::*;
+use rand_core::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Debug, Formatter};
+use vsss_rs::IdentifierPrimeField;
+
+/// The commitment each participant broadcasts in the first step of the beacon
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BeaconCommitment {
+    /// The ordinal index of the sender
+    pub sender_ordinal: usize,
+    /// The hash of the sender's seed
+    pub commitment: [u8; 32],
+}
+
+/// The seed each participant broadcasts once every commitment was received
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BeaconReveal {
+    /// The ordinal index of the sender
+    pub sender_ordinal: usize,
+    /// The sender's seed
+    pub seed: [u8; 32],
+}
+
+/// An optional commit-reveal coin flip run before the ceremony.
+///
+/// Every participant commits to a random seed and only reveals it after
+/// receiving all commitments, so no single party can choose the output.
+/// A party can still refuse to reveal and force a restart, which the
+/// application should treat as an abort.
+#[derive(Clone)]
+pub struct RandomnessBeacon {
+    ordinal: usize,
+    participants: usize,
+    label: Vec<u8>,
+    seed: [u8; 32],
+    commitments: BTreeMap<usize, [u8; 32]>,
+    reveals: BTreeMap<usize, [u8; 32]>,
+}
+
+impl Debug for RandomnessBeacon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RandomnessBeacon")
+            .field("ordinal", &self.ordinal)
+            .field("participants", &self.participants)
+            .field("commitments", &self.commitments.keys().collect::<Vec<_>>())
+            .field("reveals", &self.reveals.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RandomnessBeacon {
+    /// Start the beacon for the participant at `ordinal` out of `participants`.
+    ///
+    /// `label` should be the transcript label of the ceremony so the
+    /// output cannot be replayed in another one.
+    pub fn new(ordinal: usize, participants: usize, label: &[u8]) -> DkgResult<Self> {
+        if ordinal >= participants {
+            return Err(Error::Initialization(format!(
+                "Beacon ordinal {ordinal} is not less than the {participants} participants"
+            )));
+        }
+        let mut seed = [0u8; 32];
+        rand::rng().fill_bytes(&mut seed);
+        let mut beacon = Self {
+            ordinal,
+            participants,
+            label: label.to_vec(),
+            seed,
+            commitments: BTreeMap::new(),
+            reveals: BTreeMap::new(),
+        };
+        beacon
+            .commitments
+            .insert(ordinal, beacon.commit(ordinal, &seed));
+        beacon.reveals.insert(ordinal, seed);
+        Ok(beacon)
+    }
+
+    /// The commitment to broadcast to the other participants
+    pub fn commitment(&self) -> BeaconCommitment {
+        BeaconCommitment {
+            sender_ordinal: self.ordinal,
+            commitment: self.commitments[&self.ordinal],
+        }
+    }
+
+    /// Receive another participant's commitment
+    pub fn receive_commitment(&mut self, commitment: &BeaconCommitment) -> DkgResult<()> {
+        self.check_sender(commitment.sender_ordinal)?;
+        if self.commitments.contains_key(&commitment.sender_ordinal) {
+            return Err(Error::Round(format!(
+                "Beacon: Participant {} already sent a commitment",
+                commitment.sender_ordinal
+            )));
+        }
+        self.commitments
+            .insert(commitment.sender_ordinal, commitment.commitment);
+        Ok(())
+    }
+
+    /// The seed to broadcast once every commitment was received
+    pub fn reveal(&self) -> DkgResult<BeaconReveal> {
+        if self.commitments.len() < self.participants {
+            return Err(Error::Round(format!(
+                "Beacon: Waiting for {} more commitments",
+                self.participants - self.commitments.len()
+            )));
+        }
+        Ok(BeaconReveal {
+            sender_ordinal: self.ordinal,
+            seed: self.seed,
+        })
+    }
+
+    /// Receive another participant's seed and check it against its commitment
+    pub fn receive_reveal(&mut self, reveal: &BeaconReveal) -> DkgResult<()> {
+        self.check_sender(reveal.sender_ordinal)?;
+        let commitment = self
+            .commitments
+            .get(&reveal.sender_ordinal)
+            .ok_or_else(|| {
+                Error::Round(format!(
+                    "Beacon: Participant {} revealed before committing",
+                    reveal.sender_ordinal
+                ))
+            })?;
+        if self.commit(reveal.sender_ordinal, &reveal.seed) != *commitment {
+            return Err(Error::Round(format!(
+                "Beacon: Participant {} revealed a seed that does not match its commitment",
+                reveal.sender_ordinal
+            )));
+        }
+        self.reveals.insert(reveal.sender_ordinal, reveal.seed);
+        Ok(())
+    }
+
+    /// The joint output once every seed was revealed
+    pub fn output(&self) -> DkgResult<BeaconOutput> {
+        if self.reveals.len() < self.participants {
+            return Err(Error::Round(format!(
+                "Beacon: Waiting for {} more reveals",
+                self.participants - self.reveals.len()
+            )));
+        }
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Beacon Output");
+        transcript.append_message(b"label", &self.label);
+        for (ordinal, seed) in &self.reveals {
+            transcript.append_u64(b"ordinal", *ordinal as u64);
+            transcript.append_message(b"seed", seed);
+        }
+        let mut value = [0u8; 32];
+        transcript.challenge_bytes(b"beacon", &mut value);
+        Ok(BeaconOutput { value })
+    }
+
+    fn check_sender(&self, sender_ordinal: usize) -> DkgResult<()> {
+        if sender_ordinal >= self.participants || sender_ordinal == self.ordinal {
+            return Err(Error::Round(format!(
+                "Beacon: Invalid sender ordinal {sender_ordinal}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn commit(&self, ordinal: usize, seed: &[u8; 32]) -> [u8; 32] {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Beacon Commitment");
+        transcript.append_message(b"label", &self.label);
+        transcript.append_u64(b"ordinal", ordinal as u64);
+        transcript.append_message(b"seed", seed);
+        let mut commitment = [0u8; 32];
+        transcript.challenge_bytes(b"commitment", &mut commitment);
+        commitment
+    }
+}
+
+/// The jointly generated randomness of a [`RandomnessBeacon`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BeaconOutput {
+    pub(crate) value: [u8; 32],
+}
+
+impl BeaconOutput {
+    /// The raw beacon value
+    pub fn value(&self) -> [u8; 32] {
+        self.value
+    }
+
+    /// The session id derived from the beacon.
+    ///
+    /// Added to the transcripts with [`Parameters::with_session_id`].
+    pub fn session_id(&self) -> [u8; 32] {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Beacon Session");
+        transcript.append_message(b"beacon", &self.value);
+        let mut session_id = [0u8; 32];
+        transcript.challenge_bytes(b"session id", &mut session_id);
+        session_id
+    }
+
+    /// Derive `count` unique, non-zero participant ids from the beacon so
+    /// no participant can choose its own id
+    pub fn participant_ids<F: ScalarHash>(&self, count: usize) -> Vec<IdentifierPrimeField<F>> {
+        let mut seen = BTreeSet::new();
+        let mut ids = Vec::with_capacity(count);
+        let mut counter = 0u64;
+        while ids.len() < count {
+            let mut input = self.value.to_vec();
+            input.extend_from_slice(&counter.to_be_bytes());
+            counter += 1;
+            let id = F::hash_to_scalar_with_dst(&input, b"FROST-DKG-V1-BEACON-ID");
+            if bool::from(id.is_zero()) || !seen.insert(id.to_repr().as_ref().to_vec()) {
+                continue;
+            }
+            ids.push(IdentifierPrimeField(id));
+        }
+        ids
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+use crate::serdes::hex_group;
+use bls12_381_plus::elliptic_curve_013::hash2curve::ExpandMsgXmd;
+use bls12_381_plus::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar, pairing};
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The domain separation tag for BLS signatures with public keys in G1 and
+/// signatures in G2, from the proof of possession ciphersuite of the IETF
+/// BLS signature draft
+pub const BLS_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A BLS signature in G2 over a message under a group key in G1
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlsSignature(#[serde(with = "hex_group")] pub(crate) G2Projective);
+
+impl BlsSignature {
+    /// The signature point
+    pub fn point(&self) -> G2Projective {
+        self.0
+    }
+
+    /// Verify the signature over `message` with the group key and the
+    /// generator used by the DKG
+    pub fn verify(
+        &self,
+        public_key: G1Projective,
+        generator: G1Projective,
+        message: &[u8],
+    ) -> DkgResult<()> {
+        if !pairing_matches(generator, self.0, public_key, message) {
+            return Err(Error::Signature("Invalid BLS signature".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// A shareholder's partial BLS signature
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlsSignatureShare {
+    /// The ordinal index of the signer
+    pub(crate) ordinal: usize,
+    /// The signature with the secret share
+    #[serde(with = "hex_group")]
+    pub(crate) signature: G2Projective,
+}
+
+impl BlsSignatureShare {
+    /// The ordinal index of the signer
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The signature point with the signer's secret share
+    pub fn signature(&self) -> G2Projective {
+        self.signature
+    }
+}
+
+impl DkgOutput<G1Projective> {
+    /// Sign `message` with this shareholder's secret share.
+    ///
+    /// Unlike FROST there are no nonces so a share can be produced without
+    /// a round of commitments.
+    pub fn bls_sign_share(&self, message: &[u8]) -> BlsSignatureShare {
+        BlsSignatureShare {
+            ordinal: self.ordinal,
+            signature: hash_message(message) * self.secret_share.value.0,
+        }
+    }
+
+    /// Check a partial signature over `message` against the signer's
+    /// verification share
+    pub fn bls_verify_share(&self, message: &[u8], share: &BlsSignatureShare) -> DkgResult<()> {
+        let verification_share = self
+            .verification_shares
+            .get(&share.ordinal)
+            .ok_or_else(|| {
+                Error::Signature(format!(
+                    "Signer {} does not hold a share of the group key",
+                    share.ordinal
+                ))
+            })?;
+        if !pairing_matches(
+            self.message_generator,
+            share.signature,
+            verification_share.0,
+            message,
+        ) {
+            return Err(Error::Signature(format!(
+                "BLS signature share from {} is invalid",
+                share.ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    /// Combine at least threshold partial signatures over `message` into a
+    /// signature under the group key.
+    ///
+    /// Each share is checked first so a misbehaving signer is identified by
+    /// ordinal in the returned error.
+    pub fn bls_aggregate(
+        &self,
+        message: &[u8],
+        shares: &[BlsSignatureShare],
+    ) -> DkgResult<BlsSignature> {
+        let shares = shares
+            .iter()
+            .map(|share| (share.ordinal, share))
+            .collect::<BTreeMap<_, _>>();
+        if shares.len() < self.threshold {
+            return Err(Error::Signature(format!(
+                "Got {} distinct BLS signature shares but the threshold is {}",
+                shares.len(),
+                self.threshold
+            )));
+        }
+        let mut ids = Vec::with_capacity(shares.len());
+        for (ordinal, share) in &shares {
+            self.bls_verify_share(message, share)?;
+            ids.push(self.participant_ids[ordinal]);
+        }
+        let coefficients = lagrange_coefficients::<Scalar>(&ids, Scalar::ZERO)?;
+        let terms = coefficients
+            .into_iter()
+            .zip(shares.values())
+            .map(|(lambda, share)| (lambda, share.signature))
+            .collect::<Vec<_>>();
+        let signature = BlsSignature(<G2Projective as SumOfProducts>::sum_of_products(&terms));
+        signature.verify(self.public_key.0, self.message_generator, message)?;
+        Ok(signature)
+    }
+}
+
+fn hash_message(message: &[u8]) -> G2Projective {
+    G2Projective::hash::<ExpandMsgXmd<sha2_010::Sha256>>(message, BLS_SIGNATURE_DST)
+}
+
+/// Whether e(`generator`, `signature`) = e(`public_key`, H(`message`))
+fn pairing_matches(
+    generator: G1Projective,
+    signature: G2Projective,
+    public_key: G1Projective,
+    message: &[u8],
+) -> bool {
+    let lhs = pairing(&G1Affine::from(generator), &G2Affine::from(signature));
+    let rhs = pairing(
+        &G1Affine::from(public_key),
+        &G2Affine::from(hash_message(message)),
+    );
+    lhs == rhs
+}
@@ -0,0 +1,158 @@
+use super::*;
+use crate::network::{recv_routed_with_retry, send_with_retry};
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+
+/// Several participants on one device, like a wallet holding more than one
+/// share, driven against remote peers through a single [`MultiTransport`].
+///
+/// Messages between the local participants never touch the transport.
+pub struct Ceremony<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    participants: BTreeMap<usize, Box<dyn AnyParticipant<G> + Send>>,
+}
+
+impl<G> Debug for Ceremony<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ceremony")
+            .field("ordinals", &self.participants.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<G> Default for Ceremony<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn default() -> Self {
+        Self {
+            participants: BTreeMap::new(),
+        }
+    }
+}
+
+impl<G> Ceremony<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a ceremony without any local participants
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a local participant.
+    ///
+    /// Returns an error if a participant with the same ordinal was already added.
+    pub fn add<P>(&mut self, participant: P) -> DkgResult<()>
+    where
+        P: AnyParticipant<G> + Send + 'static,
+    {
+        let ordinal = participant.get_ordinal();
+        if self.participants.contains_key(&ordinal) {
+            return Err(Error::Initialization(format!(
+                "Ceremony already has a participant with ordinal {ordinal}"
+            )));
+        }
+        self.participants.insert(ordinal, Box::new(participant));
+        Ok(())
+    }
+
+    /// The ordinals of the local participants
+    pub fn ordinals(&self) -> impl Iterator<Item = usize> + '_ {
+        self.participants.keys().copied()
+    }
+
+    /// The local participant with `ordinal`
+    pub fn participant(&self, ordinal: usize) -> Option<&(dyn AnyParticipant<G> + Send)> {
+        self.participants.get(&ordinal).map(|p| p.as_ref())
+    }
+
+    /// Consume the ceremony and return the local participants by ordinal
+    pub fn into_participants(self) -> BTreeMap<usize, Box<dyn AnyParticipant<G> + Send>> {
+        self.participants
+    }
+
+    /// Whether every local participant has completed the protocol
+    pub fn completed(&self) -> bool {
+        self.participants.values().all(|p| p.completed())
+    }
+
+    /// Drive every local participant through the protocol like [`drive`]
+    pub async fn drive<T>(&mut self, transport: &mut T) -> DkgResult<()>
+    where
+        T: MultiTransport<G::Scalar>,
+    {
+        self.drive_with_retry(transport, &RetryPolicy::none()).await
+    }
+
+    /// Drive every local participant like [`Ceremony::drive`] but retry sends
+    /// and receives that fail with an error the `policy` considers transient
+    pub async fn drive_with_retry<T>(
+        &mut self,
+        transport: &mut T,
+        policy: &RetryPolicy,
+    ) -> DkgResult<()>
+    where
+        T: MultiTransport<G::Scalar>,
+    {
+        let mut pending = self
+            .participants
+            .keys()
+            .map(|ordinal| (*ordinal, Vec::<Vec<u8>>::new()))
+            .collect::<BTreeMap<_, _>>();
+        while !self.completed() {
+            let mut progressed = false;
+            for (ordinal, participant) in self.participants.iter_mut() {
+                if participant.completed() {
+                    continue;
+                }
+                // Payloads are tagged with the round that produced them
+                let expected = participant
+                    .round_requirements()
+                    .payload_round
+                    .map_or(0, u8::from);
+                let queue = pending.entry(*ordinal).or_default();
+                let (current, later): (Vec<Vec<u8>>, Vec<Vec<u8>>) = queue
+                    .drain(..)
+                    .partition(|message: &Vec<u8>| message.first() == Some(&expected));
+                *queue = later;
+                // Invalid senders are recorded by the participant and reported in its status
+                let _ = participant.receive_batch(&current);
+
+                if participant.get_round() != Round::One
+                    && !participant.status().missing_participants.is_empty()
+                {
+                    continue;
+                }
+                let outputs = participant.run()?.iter().collect::<Vec<_>>();
+                for output in outputs {
+                    match pending.get_mut(&output.dst_ordinal) {
+                        Some(queue) => queue.push(output.data),
+                        None => send_with_retry(transport, output, policy).await?,
+                    }
+                }
+                progressed = true;
+            }
+
+            if !progressed && !self.completed() {
+                let (ordinal, message) = recv_routed_with_retry(transport, policy).await?;
+                if let Some(queue) = pending.get_mut(&ordinal) {
+                    queue.push(message);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+use der::{Reader, SliceReader, Tag, TagNumber};
+use serde::{Deserialize, Serialize};
+use vsss_rs::IdentifierPrimeField;
+
+/// The ceremony identity bound to an X.509 client certificate.
+///
+/// Deployments that authenticate peers with mTLS can use this so
+/// the transport identity and the participant id always agree.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CertificateIdentity<F: ScalarHash> {
+    /// The participant id derived from the subject public key
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<F>: Serialize",
+        deserialize = "IdentifierPrimeField<F>: Deserialize<'de>"
+    ))]
+    pub id: IdentifierPrimeField<F>,
+    /// The digest of the DER encoded subject public key info
+    pub spki_digest: [u8; 32],
+}
+
+impl<F: ScalarHash> CertificateIdentity<F> {
+    /// Derive the identity from a DER encoded X.509 certificate
+    pub fn from_der(certificate: &[u8]) -> DkgResult<Self> {
+        let spki = subject_public_key_info(certificate)
+            .map_err(|e| Error::Initialization(format!("Invalid client certificate: {e}")))?;
+        Self::from_spki(spki)
+    }
+
+    /// Derive the identity from a DER encoded subject public key info
+    pub fn from_spki(spki: &[u8]) -> DkgResult<Self> {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Certificate Identity");
+        transcript.append_message(b"subject public key info", spki);
+        let mut spki_digest = [0u8; 32];
+        transcript.challenge_bytes(b"spki digest", &mut spki_digest);
+
+        let id = IdentifierPrimeField(F::hash_to_scalar(&spki_digest));
+        if id.0.is_zero().into() {
+            return Err(Error::Initialization(
+                "Certificate identity maps to a zero id".to_string(),
+            ));
+        }
+        Ok(Self { id, spki_digest })
+    }
+
+    /// Check whether `certificate` is bound to this identity
+    pub fn matches(&self, certificate: &[u8]) -> bool {
+        Self::from_der(certificate).is_ok_and(|other| other == *self)
+    }
+}
+
+/// Extract the subject public key info from a DER encoded certificate
+fn subject_public_key_info(certificate: &[u8]) -> der::Result<&[u8]> {
+    let mut reader = SliceReader::new(certificate)?;
+    let spki = reader.sequence(|cert| {
+        let spki = cert.sequence(|tbs| {
+            let version = Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber(0),
+            };
+            if Tag::peek(tbs)? == version {
+                tbs.tlv_bytes()?;
+            }
+            // serialNumber, signature, issuer, validity and subject
+            for _ in 0..5 {
+                tbs.tlv_bytes()?;
+            }
+            let spki = tbs.tlv_bytes()?;
+            tbs.read_slice(tbs.remaining_len())?;
+            Ok::<_, der::Error>(spki)
+        })?;
+        cert.read_slice(cert.remaining_len())?;
+        Ok::<_, der::Error>(spki)
+    })?;
+    reader.finish()?;
+    Ok(spki)
+}
@@ -0,0 +1,230 @@
+use super::*;
+use std::collections::BTreeMap;
+
+/// The version byte at the start of every chunk
+const CHUNK_VERSION: u8 = 1;
+
+/// The bytes each chunk adds to its payload: a version, the message id,
+/// the chunk index and count and a checksum
+pub const CHUNK_OVERHEAD: usize = 1 + 8 + 2 + 2 + 4;
+
+/// One piece of a message split with [`chunk_message`] that is small enough
+/// for a QR code or an NFC tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageChunk {
+    pub(crate) message_id: [u8; 8],
+    pub(crate) index: u16,
+    pub(crate) total: u16,
+    pub(crate) payload: Vec<u8>,
+}
+
+impl MessageChunk {
+    /// The id shared by all chunks of a message
+    pub fn message_id(&self) -> [u8; 8] {
+        self.message_id
+    }
+
+    /// The position of this chunk in the message starting at zero
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// The number of chunks in the message
+    pub fn total(&self) -> u16 {
+        self.total
+    }
+
+    /// The part of the message carried by this chunk
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Encode the chunk with its checksum
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CHUNK_OVERHEAD + self.payload.len());
+        bytes.push(CHUNK_VERSION);
+        bytes.extend_from_slice(&self.message_id);
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        bytes.extend_from_slice(&self.total.to_be_bytes());
+        bytes.extend_from_slice(&self.payload);
+        let checksum = chunk_checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        bytes
+    }
+
+    /// Decode a chunk and check its checksum
+    pub fn from_bytes(bytes: &[u8]) -> DkgResult<Self> {
+        if bytes.len() <= CHUNK_OVERHEAD {
+            return Err(Error::Encoding("Chunk is too short".to_string()));
+        }
+        let (frame, checksum) = bytes.split_at(bytes.len() - 4);
+        if chunk_checksum(frame).as_slice() != checksum {
+            return Err(Error::Encoding("Chunk checksum does not match".to_string()));
+        }
+        if frame[0] != CHUNK_VERSION {
+            return Err(Error::Encoding(format!(
+                "Unsupported chunk version {}",
+                frame[0]
+            )));
+        }
+        let mut message_id = [0u8; 8];
+        message_id.copy_from_slice(&frame[1..9]);
+        let index = u16::from_be_bytes([frame[9], frame[10]]);
+        let total = u16::from_be_bytes([frame[11], frame[12]]);
+        if index >= total {
+            return Err(Error::Encoding(format!(
+                "Chunk index {index} is not less than the chunk count {total}"
+            )));
+        }
+        Ok(Self {
+            message_id,
+            index,
+            total,
+            payload: frame[13..].to_vec(),
+        })
+    }
+}
+
+/// Split `message` into chunks of at most `max_chunk_size` encoded bytes
+pub fn chunk_message(message: &[u8], max_chunk_size: usize) -> DkgResult<Vec<MessageChunk>> {
+    if max_chunk_size <= CHUNK_OVERHEAD {
+        return Err(Error::Encoding(format!(
+            "Chunk size must be greater than the {CHUNK_OVERHEAD} byte overhead"
+        )));
+    }
+    if message.is_empty() {
+        return Err(Error::Encoding("Cannot chunk an empty message".to_string()));
+    }
+    let payload_size = max_chunk_size - CHUNK_OVERHEAD;
+    let total = u16::try_from(message.len().div_ceil(payload_size)).map_err(|_| {
+        Error::Encoding(format!(
+            "A {} byte message needs more than {} chunks",
+            message.len(),
+            u16::MAX
+        ))
+    })?;
+    let message_id = message_id(message);
+    Ok(message
+        .chunks(payload_size)
+        .enumerate()
+        .map(|(index, payload)| MessageChunk {
+            message_id,
+            index: index as u16,
+            total,
+            payload: payload.to_vec(),
+        })
+        .collect())
+}
+
+/// Reassembles messages from chunks received in any order.
+///
+/// Chunks of several messages can be interleaved and scanning the same
+/// chunk again is ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkAssembler {
+    messages: BTreeMap<[u8; 8], PartialMessage>,
+}
+
+#[derive(Debug, Clone)]
+struct PartialMessage {
+    total: u16,
+    chunks: BTreeMap<u16, Vec<u8>>,
+}
+
+impl ChunkAssembler {
+    /// Create an empty assembler
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Receive an encoded chunk.
+    ///
+    /// Returns the message once its last missing chunk arrives.
+    pub fn receive(&mut self, bytes: &[u8]) -> DkgResult<Option<Vec<u8>>> {
+        self.receive_chunk(MessageChunk::from_bytes(bytes)?)
+    }
+
+    /// Receive a decoded chunk.
+    ///
+    /// Returns the message once its last missing chunk arrives.
+    pub fn receive_chunk(&mut self, chunk: MessageChunk) -> DkgResult<Option<Vec<u8>>> {
+        let partial = self
+            .messages
+            .entry(chunk.message_id)
+            .or_insert_with(|| PartialMessage {
+                total: chunk.total,
+                chunks: BTreeMap::new(),
+            });
+        if partial.total != chunk.total {
+            return Err(Error::Encoding(format!(
+                "Chunk count {} does not match the {} chunks of the message",
+                chunk.total, partial.total
+            )));
+        }
+        if let Some(payload) = partial.chunks.get(&chunk.index) {
+            if *payload != chunk.payload {
+                return Err(Error::Encoding(format!(
+                    "Chunk {} was received twice with different payloads",
+                    chunk.index
+                )));
+            }
+            return Ok(None);
+        }
+        partial.chunks.insert(chunk.index, chunk.payload);
+        if partial.chunks.len() < partial.total as usize {
+            return Ok(None);
+        }
+
+        let partial = self
+            .messages
+            .remove(&chunk.message_id)
+            .expect("message was just updated");
+        let message = partial.chunks.into_values().flatten().collect::<Vec<_>>();
+        if message_id(&message) != chunk.message_id {
+            return Err(Error::Encoding(
+                "Reassembled message does not match its id".to_string(),
+            ));
+        }
+        Ok(Some(message))
+    }
+
+    /// The indices of the chunks still needed for `message_id`, or `None`
+    /// if no chunk of it was received
+    pub fn missing_chunks(&self, message_id: [u8; 8]) -> Option<Vec<u16>> {
+        self.messages.get(&message_id).map(|partial| {
+            (0..partial.total)
+                .filter(|index| !partial.chunks.contains_key(index))
+                .collect()
+        })
+    }
+}
+
+impl<F: ScalarHash> ParticipantRoundOutput<F> {
+    /// Split the data to send into encoded chunks of at most
+    /// `max_chunk_size` bytes.
+    ///
+    /// The receiving device passes them to a [`ChunkAssembler`] and the
+    /// reassembled message to [`Participant::receive`].
+    pub fn to_chunks(&self, max_chunk_size: usize) -> DkgResult<Vec<Vec<u8>>> {
+        Ok(chunk_message(&self.data, max_chunk_size)?
+            .iter()
+            .map(MessageChunk::to_bytes)
+            .collect())
+    }
+}
+
+fn message_id(message: &[u8]) -> [u8; 8] {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Chunked Message");
+    transcript.append_message(b"message", message);
+    let mut id = [0u8; 8];
+    transcript.challenge_bytes(b"message id", &mut id);
+    id
+}
+
+fn chunk_checksum(frame: &[u8]) -> [u8; 4] {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Chunk Checksum");
+    transcript.append_message(b"chunk", frame);
+    let mut checksum = [0u8; 4];
+    transcript.challenge_bytes(b"checksum", &mut checksum);
+    checksum
+}
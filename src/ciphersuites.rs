@@ -0,0 +1,85 @@
+//! Concrete participant types for each supported curve.
+
+/// A secret participant over secp256k1
+#[cfg(feature = "k256")]
+pub type Secp256k1Secret = crate::SecretParticipant<k256::ProjectivePoint>;
+/// A refresh participant over secp256k1
+#[cfg(feature = "k256")]
+pub type Secp256k1Refresh = crate::RefreshParticipant<k256::ProjectivePoint>;
+
+/// A secret participant over NIST P-256
+#[cfg(feature = "p256")]
+pub type P256Secret = crate::SecretParticipant<p256::ProjectivePoint>;
+/// A refresh participant over NIST P-256
+#[cfg(feature = "p256")]
+pub type P256Refresh = crate::RefreshParticipant<p256::ProjectivePoint>;
+
+/// A secret participant over NIST P-384
+#[cfg(feature = "p384")]
+pub type P384Secret = crate::SecretParticipant<p384::ProjectivePoint>;
+/// A refresh participant over NIST P-384
+#[cfg(feature = "p384")]
+pub type P384Refresh = crate::RefreshParticipant<p384::ProjectivePoint>;
+
+/// A secret participant over edwards25519
+#[cfg(feature = "curve25519-dalek")]
+pub type Ed25519Secret = crate::SecretParticipant<curve25519_dalek::EdwardsPoint>;
+/// A refresh participant over edwards25519
+#[cfg(feature = "curve25519-dalek")]
+pub type Ed25519Refresh = crate::RefreshParticipant<curve25519_dalek::EdwardsPoint>;
+
+/// A secret participant over ristretto25519
+#[cfg(feature = "curve25519-dalek")]
+pub type Ristretto25519Secret = crate::SecretParticipant<curve25519_dalek::RistrettoPoint>;
+/// A refresh participant over ristretto25519
+#[cfg(feature = "curve25519-dalek")]
+pub type Ristretto25519Refresh = crate::RefreshParticipant<curve25519_dalek::RistrettoPoint>;
+
+/// A secret participant over edwards448
+#[cfg(feature = "ed448")]
+pub type Ed448Secret = crate::SecretParticipant<ed448_goldilocks_plus::EdwardsPoint>;
+/// A refresh participant over edwards448
+#[cfg(feature = "ed448")]
+pub type Ed448Refresh = crate::RefreshParticipant<ed448_goldilocks_plus::EdwardsPoint>;
+
+/// A secret participant over BLS12-381 G1
+#[cfg(feature = "bls12_381_plus")]
+pub type Bls12381G1Secret = crate::SecretParticipant<bls12_381_plus::G1Projective>;
+/// A refresh participant over BLS12-381 G1
+#[cfg(feature = "bls12_381_plus")]
+pub type Bls12381G1Refresh = crate::RefreshParticipant<bls12_381_plus::G1Projective>;
+/// A secret participant over BLS12-381 G2
+#[cfg(feature = "bls12_381_plus")]
+pub type Bls12381G2Secret = crate::SecretParticipant<bls12_381_plus::G2Projective>;
+/// A refresh participant over BLS12-381 G2
+#[cfg(feature = "bls12_381_plus")]
+pub type Bls12381G2Refresh = crate::RefreshParticipant<bls12_381_plus::G2Projective>;
+
+/// A secret participant over BLS12-381 G1 backed by blst
+#[cfg(feature = "blstrs_plus")]
+pub type BlstG1Secret = crate::SecretParticipant<blstrs_plus::G1Projective>;
+/// A refresh participant over BLS12-381 G1 backed by blst
+#[cfg(feature = "blstrs_plus")]
+pub type BlstG1Refresh = crate::RefreshParticipant<blstrs_plus::G1Projective>;
+/// A secret participant over BLS12-381 G2 backed by blst
+#[cfg(feature = "blstrs_plus")]
+pub type BlstG2Secret = crate::SecretParticipant<blstrs_plus::G2Projective>;
+/// A refresh participant over BLS12-381 G2 backed by blst
+#[cfg(feature = "blstrs_plus")]
+pub type BlstG2Refresh = crate::RefreshParticipant<blstrs_plus::G2Projective>;
+
+/// A secret participant over BN254 G1 from arkworks
+#[cfg(feature = "ark-bn254")]
+pub type ArkBn254Secret = crate::SecretParticipant<crate::ArkGroup<ark_bn254::G1Projective>>;
+/// A refresh participant over BN254 G1 from arkworks
+#[cfg(feature = "ark-bn254")]
+pub type ArkBn254Refresh = crate::RefreshParticipant<crate::ArkGroup<ark_bn254::G1Projective>>;
+
+/// A secret participant over BLS12-381 G1 from arkworks
+#[cfg(feature = "ark-bls12-381")]
+pub type ArkBls12381G1Secret =
+    crate::SecretParticipant<crate::ArkGroup<ark_bls12_381::G1Projective>>;
+/// A refresh participant over BLS12-381 G1 from arkworks
+#[cfg(feature = "ark-bls12-381")]
+pub type ArkBls12381G1Refresh =
+    crate::RefreshParticipant<crate::ArkGroup<ark_bls12_381::G1Projective>>;
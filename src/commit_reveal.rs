@@ -0,0 +1,255 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+
+/// The commitment to its round 1 broadcast each participant sends before
+/// round 1 when [`Parameters::with_commit_reveal`] is set.
+///
+/// The commitment is signed with the sender's long term signing key, so
+/// nobody else can commit in the sender's place before it does.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
+pub struct Round1Commitment<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the sender
+    pub sender_ordinal: usize,
+    /// The hash of the sender's round 1 broadcast
+    pub commitment: [u8; 32],
+    /// The schnorr signature by the sender's long term signing key
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> Round1Commitment<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The schnorr signature by the sender's long term signing key
+    pub fn signature(&self) -> Signature<G> {
+        self.signature
+    }
+
+    /// Check the commitment was signed by `sender_key` for the ceremony of
+    /// `context`
+    pub fn verify(&self, context: &SignedContext<G>, sender_key: &G) -> DkgResult<()> {
+        if sender_key.is_identity().into() {
+            return Err(Error::Signature(format!(
+                "Round 1 commitment signer {} is the identity",
+                self.sender_ordinal
+            )));
+        }
+        let c = self.challenge(context, sender_key, &self.signature.r);
+        if G::generator() * self.signature.s != self.signature.r + *sender_key * c {
+            return Err(Error::Signature(format!(
+                "Invalid round 1 commitment signature from {}",
+                self.sender_ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    fn challenge(&self, context: &SignedContext<G>, sender_key: &G, r: &G) -> G::Scalar {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Signed Round 1 Commitment");
+        transcript.append_message(b"roster", &context.roster_digest);
+        transcript.append_message(b"parameters", &context.parameters_digest);
+        transcript.append_u64(b"sender_ordinal", self.sender_ordinal as u64);
+        transcript.append_message(b"commitment", &self.commitment);
+        let mut statement = [0u8; 32];
+        transcript.challenge_bytes(b"statement", &mut statement);
+
+        let mut input = statement.to_vec();
+        input.extend_from_slice(sender_key.to_bytes().as_ref());
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-COMMIT");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Fix the round 1 broadcast and return the commitment to send to
+    /// every other participant.
+    ///
+    /// Round 1 only runs once the commitments of all other participants
+    /// were received with [`Participant::receive_round1_commitment`], and
+    /// every round 1 broadcast must match its sender's commitment. Calling
+    /// this again returns the same commitment. The commitment is signed
+    /// with this participant's long term `signing_key`, which the others
+    /// check it against.
+    pub fn commit_round1(&mut self, signing_key: G::Scalar) -> DkgResult<Round1Commitment<G>> {
+        if !self.commit_reveal {
+            return Err(Error::Round(
+                "The parameters do not commit to round 1".to_string(),
+            ));
+        }
+        if self.round != Round::One {
+            return Err(Error::Round(
+                "Round 1 has already been revealed".to_string(),
+            ));
+        }
+        if signing_key.is_zero().into() {
+            return Err(Error::Signature(
+                "Cannot sign a round 1 commitment with a zero key".to_string(),
+            ));
+        }
+        let data = match &self.committed_round1 {
+            Some(data) => data.clone(),
+            None => self.self_round1data(),
+        };
+        let commitment = self.round1_commitment(&data)?;
+        self.committed_round1 = Some(data);
+        self.round1_commitments.insert(self.ordinal, commitment);
+        let mut commitment = Round1Commitment {
+            sender_ordinal: self.ordinal,
+            commitment,
+            signature: Signature::default(),
+        };
+        let sender_key = G::generator() * signing_key;
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = G::generator() * k;
+        let c = commitment.challenge(&self.signed_context(), &sender_key, &r);
+        commitment.signature = Signature {
+            r,
+            s: k + c * signing_key,
+        };
+        Ok(commitment)
+    }
+
+    /// Receive the round 1 commitment of another participant, signed with
+    /// the sender's long term `sender_key`.
+    ///
+    /// A commitment that is not signed by the sender is rejected without
+    /// being stored, so it can't take the place of the sender's own.
+    pub fn receive_round1_commitment(
+        &mut self,
+        commitment: &Round1Commitment<G>,
+        sender_key: &G,
+    ) -> DkgResult<()> {
+        if !self.commit_reveal {
+            return Err(Error::Round(
+                "The parameters do not commit to round 1".to_string(),
+            ));
+        }
+        let sender = commitment.sender_ordinal;
+        if sender == self.ordinal || !self.all_participant_ids.contains_key(&sender) {
+            return Err(Error::Round(format!(
+                "Sender {sender} is not another participant"
+            )));
+        }
+        commitment.verify(&self.signed_context(), sender_key)?;
+        match self.round1_commitments.get(&sender) {
+            Some(existing) if *existing == commitment.commitment => Ok(()),
+            Some(_) => Err(Error::Round(format!(
+                "Sender {sender} already committed to another round 1 broadcast"
+            ))),
+            None => {
+                self.round1_commitments
+                    .insert(sender, commitment.commitment);
+                Ok(())
+            }
+        }
+    }
+
+    /// The participants whose round 1 commitment has not been received,
+    /// including this one until [`Participant::commit_round1`] is called
+    pub fn missing_round1_commitments(&self) -> Vec<usize> {
+        if !self.commit_reveal {
+            return Vec::new();
+        }
+        self.all_participant_ids
+            .keys()
+            .filter(|ordinal| !self.round1_commitments.contains_key(ordinal))
+            .copied()
+            .collect()
+    }
+
+    /// The participants that committed to a round 1 broadcast which has not
+    /// been accepted yet.
+    ///
+    /// Round 2 only runs once this is empty, dropping a participant that
+    /// committed but withheld its reveal would let it choose whether to
+    /// contribute after seeing every other broadcast.
+    pub fn missing_round1_reveals(&self) -> Vec<usize> {
+        if !self.commit_reveal {
+            return Vec::new();
+        }
+        self.round1_commitments
+            .keys()
+            .filter(|ordinal| !self.received_round1_data.contains_key(ordinal))
+            .copied()
+            .collect()
+    }
+
+    /// The committed round 1 broadcast once every commitment is in
+    pub(crate) fn reveal_round1(&self) -> DkgResult<Round1Data<G>> {
+        let data = self.committed_round1.clone().ok_or_else(|| {
+            Error::Round(format!(
+                "Round {}: Commit to round 1 before running it",
+                Round::One
+            ))
+        })?;
+        let missing = self.missing_round1_commitments();
+        if !missing.is_empty() {
+            return Err(Error::Round(format!(
+                "Round {}: Waiting for the commitments of {missing:?}",
+                Round::One
+            )));
+        }
+        Ok(data)
+    }
+
+    /// Refuse a round 1 broadcast from a sender that has not committed yet
+    pub(crate) fn require_round1_commitment(&self, sender_ordinal: usize) -> DkgResult<()> {
+        if self.commit_reveal && !self.round1_commitments.contains_key(&sender_ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: No commitment was received from sender {sender_ordinal}",
+                Round::One
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check a round 1 broadcast matches its sender's commitment
+    pub(crate) fn check_round1_reveal(&self, data: &Round1Data<G>) -> DkgResult<()> {
+        if !self.commit_reveal {
+            return Ok(());
+        }
+        if self.round1_commitments.get(&data.sender_ordinal) != Some(&self.round1_commitment(data)?)
+        {
+            return Err(Error::Round(format!(
+                "Round {}: Sender {} revealed data it did not commit to",
+                Round::One,
+                data.sender_ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    /// Hash the encoded round 1 broadcast bound to the roster and parameters
+    fn round1_commitment(&self, data: &Round1Data<G>) -> DkgResult<[u8; 32]> {
+        let context = self.signed_context();
+        let payload = encode_payload::<G, _>(Round::One, data)?;
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Round 1 Commitment");
+        transcript.append_message(b"roster", &context.roster_digest);
+        transcript.append_message(b"parameters", &context.parameters_digest);
+        transcript.append_u64(b"sender_ordinal", data.sender_ordinal as u64);
+        transcript.append_message(b"round1_data", &payload);
+        let mut commitment = [0u8; 32];
+        transcript.challenge_bytes(b"round 1 commitment", &mut commitment);
+        Ok(commitment)
+    }
+}
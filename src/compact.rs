@@ -0,0 +1,162 @@
+use super::*;
+use crate::serdes::{hex_bytes, hex_group, hex_identifier};
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use vsss_rs::IdentifierPrimeField;
+
+/// Round 1 data that carries a digest in place of the feldman commitments.
+///
+/// With large thresholds the commitments dominate the round 1 broadcast.
+/// Broadcasting this instead keeps the broadcast the same size for any
+/// threshold while the full commitments are uploaded once to a relay or
+/// sent to each peer on request, then checked against the digest with
+/// [`CompactRound1Data::expand`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CompactRound1Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The sender's ordinal index
+    pub(crate) sender_ordinal: usize,
+    /// The sender's ID
+    #[serde(
+        with = "hex_identifier",
+        bound(
+            serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+            deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+        )
+    )]
+    pub(crate) sender_id: IdentifierPrimeField<G::Scalar>,
+    /// The sender's participant type
+    pub(crate) sender_type: ParticipantType,
+    /// The threshold the sender is using
+    pub(crate) threshold: usize,
+    /// The limit the sender is using
+    pub(crate) limit: usize,
+    /// The digest of the feldman commitments
+    #[serde(with = "hex_bytes")]
+    pub(crate) commitments_digest: Vec<u8>,
+    /// The verifying share
+    #[serde(with = "hex_group")]
+    pub(crate) verifying_share: G,
+    /// The schnorr signature
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> CompactRound1Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Get the sender's ordinal index during the DKG
+    pub fn sender_ordinal(&self) -> usize {
+        self.sender_ordinal
+    }
+
+    /// Get the sender's ID during the DKG
+    pub fn sender_id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.sender_id
+    }
+
+    /// Get the sender's participant type during the DKG
+    pub fn sender_type(&self) -> ParticipantType {
+        self.sender_type
+    }
+
+    /// Get the digest of the feldman commitments
+    pub fn commitments_digest(&self) -> &[u8] {
+        &self.commitments_digest
+    }
+
+    /// Get the signature verifying share used by the DKG
+    pub fn verifying_share(&self) -> G {
+        self.verifying_share
+    }
+
+    /// Rebuild the full round 1 data from commitments that arrived
+    /// separately, checking them against the digest
+    pub fn expand(&self, commitments: &[G]) -> DkgResult<Round1Data<G>> {
+        let data = Round1Data {
+            sender_ordinal: self.sender_ordinal,
+            sender_id: self.sender_id,
+            sender_type: self.sender_type,
+            threshold: self.threshold,
+            limit: self.limit,
+            feldman_commitments: commitments.into(),
+            verifying_share: self.verifying_share,
+            signature: self.signature,
+        };
+        if data.commitments_digest().as_slice() != self.commitments_digest {
+            return Err(Error::Round(format!(
+                "Round {}: The commitments from ordinal {} do not match the broadcast digest",
+                Round::One,
+                self.sender_ordinal
+            )));
+        }
+        Ok(data)
+    }
+}
+
+impl<G> Round1Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The round 1 data with a digest in place of the feldman commitments
+    pub fn compact(&self) -> CompactRound1Data<G> {
+        CompactRound1Data {
+            sender_ordinal: self.sender_ordinal,
+            sender_id: self.sender_id,
+            sender_type: self.sender_type,
+            threshold: self.threshold,
+            limit: self.limit,
+            commitments_digest: self.commitments_digest().to_vec(),
+            verifying_share: self.verifying_share,
+            signature: self.signature,
+        }
+    }
+}
+
+#[cfg(feature = "participant")]
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The compact form of this participant's round 1 broadcast.
+    ///
+    /// Available once round 1 has run. The commitments to hand to the relay
+    /// are the feldman commitments of this participant's own entry in
+    /// [`Participant::get_received_round1_data`].
+    pub fn compact_round1(&self) -> DkgResult<CompactRound1Data<G>> {
+        self.received_round1_data
+            .get(&self.ordinal)
+            .map(Round1Data::compact)
+            .ok_or_else(|| {
+                Error::Round("Round 1 has not run or its data was already pruned".to_string())
+            })
+    }
+
+    /// Receive compact round 1 data together with the commitments that
+    /// were fetched separately.
+    ///
+    /// Commitments that don't match the digest are refused without marking
+    /// the sender invalid since they may have been corrupted by the relay.
+    pub fn receive_compact_round1(
+        &mut self,
+        data: &CompactRound1Data<G>,
+        commitments: &[G],
+    ) -> DkgResult<()> {
+        self.check_sending_participant_id(Round::One, data.sender_ordinal, data.sender_id)?;
+        let data = data.expand(commitments)?;
+        self.receive_round1data(data)
+    }
+}
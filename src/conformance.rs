@@ -0,0 +1,184 @@
+//! Standalone validators for relays and gateways.
+//!
+//! They check a message against the [`Parameters`] of a run the same way
+//! a shareholder would, without a live participant, so garbage can be
+//! dropped before it reaches the shareholders. A message that passes may
+//! still be refused by a shareholder for reasons only it can see, like a
+//! duplicate or a round 2 transcript hash that differs from its own.
+
+use crate::{
+    BlindedRound2Data, DkgResult, Error, Parameters, PrimeSubgroup, Round, Round1Data, Round2Data,
+    ScalarHash, check_pedersen_opening, check_round1data, decode_message,
+};
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+
+/// Fully validate a round 1 broadcast against `parameters` and return it
+/// decoded.
+///
+/// The header, the canonical encoding, the sender's place in the roster,
+/// the commitments and the signature are checked. Pedersen commitments
+/// can only be checked for their shape and the signature until they are
+/// opened in round 3, see [`check_round1_opening_bytes`].
+pub fn check_round1_bytes<G>(bytes: &[u8], parameters: &Parameters<G>) -> DkgResult<Round1Data<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let data = decode_message::<G, Round1Data<G>>(Round::One, bytes)?;
+    let context = parameters.signed_context()?;
+    if context.participant_ids.get(&data.sender_ordinal) != Some(&data.sender_id) {
+        return Err(Error::Round(format!(
+            "Round {}: Sender {} is not in the roster",
+            Round::One,
+            data.sender_ordinal
+        )));
+    }
+    check_round1data(
+        &data,
+        &context,
+        &parameters.previous_verification_shares,
+        parameters.handoff_dealers,
+        parameters.blinder_generator.is_some(),
+    )?;
+    Ok(data)
+}
+
+/// Fully validate a round 2 share against `parameters` and the
+/// `sender_round1` broadcast returned by [`check_round1_bytes`].
+///
+/// The share must be dealt by that sender to another shareholder in the
+/// roster and verify with the sender's commitments. With a blinder
+/// generator the message is blinded round 2 data and the share is checked
+/// against the pedersen commitments with the share of the blinder.
+/// Returns the share.
+pub fn check_round2_bytes<G>(
+    bytes: &[u8],
+    parameters: &Parameters<G>,
+    sender_round1: &Round1Data<G>,
+) -> DkgResult<Round2Data<G::Scalar>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let context = parameters.signed_context()?;
+    if let Some(blinder_generator) = parameters.blinder_generator {
+        let data = decode_message::<G, BlindedRound2Data<G>>(Round::Two, bytes)?;
+        check_round2_sender(&data.round2_data, sender_round1, parameters)?;
+        context.verify_round2(&data.round2_data, &sender_round1.verifying_share)?;
+        let mut terms = share_terms(
+            &sender_round1.feldman_commitments,
+            &data.round2_data,
+            context.message_generator,
+        );
+        terms.push((data.blinder_share.value.0, blinder_generator));
+        if !bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity()) {
+            return Err(Error::Round(format!(
+                "Round {}: The share does not verify with the pedersen commitments",
+                Round::Two
+            )));
+        }
+        return Ok(data.round2_data);
+    }
+    let data = decode_message::<G, Round2Data<G::Scalar>>(Round::Two, bytes)?;
+    check_round2_sender(&data, sender_round1, parameters)?;
+    context.verify_round2(&data, &sender_round1.verifying_share)?;
+    let terms = share_terms(
+        &sender_round1.feldman_commitments,
+        &data,
+        context.message_generator,
+    );
+    if !bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity()) {
+        return Err(Error::Round(format!(
+            "Round {}: The share does not verify with the given commitments",
+            Round::Two
+        )));
+    }
+    Ok(data)
+}
+
+/// Fully validate the round 3 opening of the pedersen commitments in the
+/// `sender_round1` broadcast returned by [`check_round1_bytes`] and return
+/// it decoded.
+///
+/// The opened feldman commitments and their signature are checked like a
+/// round 1 broadcast without a blinder generator. Whether they match the
+/// shares can only be checked by the shareholders.
+pub fn check_round1_opening_bytes<G>(
+    bytes: &[u8],
+    parameters: &Parameters<G>,
+    sender_round1: &Round1Data<G>,
+) -> DkgResult<Round1Data<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let opening = decode_message::<G, Round1Data<G>>(Round::Three, bytes)?;
+    check_pedersen_opening(
+        &opening,
+        sender_round1,
+        &parameters.signed_context()?,
+        &parameters.previous_verification_shares,
+        parameters.handoff_dealers,
+    )?;
+    Ok(opening)
+}
+
+/// Check the share is from the sender of `sender_round1` and dealt to
+/// another shareholder
+fn check_round2_sender<G>(
+    data: &Round2Data<G::Scalar>,
+    sender_round1: &Round1Data<G>,
+    parameters: &Parameters<G>,
+) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if data.sender_ordinal != sender_round1.sender_ordinal
+        || data.sender_id != sender_round1.sender_id
+    {
+        return Err(Error::Round(format!(
+            "Round {}: Share is not from the sender of the round 1 data",
+            Round::Two
+        )));
+    }
+    let recipient = parameters
+        .roster_ids()?
+        .iter()
+        .position(|id| *id == data.secret_share.identifier);
+    match recipient {
+        Some(ordinal)
+            if ordinal != data.sender_ordinal && ordinal >= parameters.handoff_dealers =>
+        {
+            Ok(())
+        }
+        _ => Err(Error::Round(format!(
+            "Round {}: Share is not dealt to another shareholder",
+            Round::Two
+        ))),
+    }
+}
+
+/// The terms of the share verification equation which sum to the
+/// identity when the share matches `commitments`
+fn share_terms<G>(
+    commitments: &[G],
+    data: &Round2Data<G::Scalar>,
+    generator: G,
+) -> Vec<(G::Scalar, G)>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut terms = Vec::with_capacity(commitments.len() + 1);
+    let mut power = G::Scalar::ONE;
+    for commitment in commitments {
+        terms.push((-power, *commitment));
+        power *= data.secret_share.identifier.0;
+    }
+    terms.push((data.secret_share.value.0, generator));
+    terms
+}
@@ -0,0 +1,331 @@
+use super::*;
+use elliptic_curve::PrimeField;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::{ConditionallySelectable, ConstantTimeEq};
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorCollection};
+
+/// The roster and configuration every round 1 signature is bound to.
+///
+/// Each dealer's proof of knowledge signs over this context, so a relying
+/// party holding it can check with [`SignedContext::verify`] that a dealer
+/// signed off on exactly this roster and configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignedContext<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The threshold used by the DKG
+    pub(crate) threshold: usize,
+    /// The limit used by the DKG
+    pub(crate) limit: usize,
+    /// The generator used by the DKG
+    #[serde(with = "group")]
+    pub(crate) message_generator: G,
+    /// All participants that started the protocol
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Deserialize<'de>"
+    ))]
+    pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    /// The digest of each participant's id, identifier and identity key
+    pub(crate) roster_digest: [u8; 32],
+    /// The digest of the remaining parameters
+    pub(crate) parameters_digest: [u8; 32],
+    /// The old share id of each reshared participant by ordinal index
+    #[serde(
+        default,
+        bound(
+            serialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Serialize",
+            deserialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Deserialize<'de>"
+        )
+    )]
+    pub(crate) reshare_mapping: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+}
+
+impl<G> SignedContext<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The threshold used by the DKG
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The limit used by the DKG
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The generator used by the DKG
+    pub fn message_generator(&self) -> G {
+        self.message_generator
+    }
+
+    /// All participants that started the protocol
+    pub fn participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.participant_ids
+    }
+
+    /// The digest of each participant's id, application identifier and
+    /// identity key fingerprint
+    pub fn roster_digest(&self) -> [u8; 32] {
+        self.roster_digest
+    }
+
+    /// The digest of the transcript label, hash size, blinder generator,
+    /// handoff split, previous verification shares, reshare mapping and
+    /// whether round 1 is committed to first
+    pub fn parameters_digest(&self) -> [u8; 32] {
+        self.parameters_digest
+    }
+
+    /// The old share id of each participant resharing under a new id by
+    /// ordinal index
+    pub fn reshare_mapping(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.reshare_mapping
+    }
+
+    /// The bytes the round 1 signature of `data` is computed over.
+    ///
+    /// The challenge is [`hash_to_group_scalar`] of these bytes. In order:
+    /// the [`ciphersuite_id`], the sender id, the sender ordinal, type,
+    /// the threshold and limit as big endian u16, the message generator,
+    /// the number of participants as u16 followed by each ordinal as u16
+    /// and id in ascending ordinal order, the roster and parameters
+    /// digests, the signature's `r`, the verifying share and the
+    /// [`Round1Data::commitments_digest`]. Scalars and points use their
+    /// canonical encoding, so the bytes only depend on the roster and not
+    /// on how it was supplied.
+    pub fn schnorr_bytes(&self, data: &Round1Data<G>) -> Vec<u8> {
+        crate::bytes_for_schnorr(
+            data.sender_ordinal,
+            &data.sender_id,
+            &data.sender_type,
+            self,
+            &data.commitments_digest(),
+            &data.verifying_share,
+            &data.signature.r,
+        )
+    }
+
+    /// Check that the dealer of `data` signed off on this context
+    pub fn verify(&self, data: &Round1Data<G>) -> DkgResult<()> {
+        self.verify_with_digest(data, &data.commitments_digest())
+    }
+
+    /// Check the signature of `data` with the digest of its commitments
+    /// computed while they were checked
+    pub(crate) fn verify_with_digest(
+        &self,
+        data: &Round1Data<G>,
+        commitments_digest: &[u8; 32],
+    ) -> DkgResult<()> {
+        crate::verify_signature(
+            data.sender_ordinal,
+            &data.sender_id,
+            &data.sender_type,
+            self,
+            commitments_digest,
+            &data.verifying_share,
+            &data.signature,
+        )
+    }
+
+    /// Check that the dealer holding the secret for `dealer_key` signed the
+    /// round 2 payload `data`.
+    ///
+    /// The key is the verifying share of the dealer's round 1 broadcast, or
+    /// of its opening with pedersen commitments. The signature binds the
+    /// sender, the recipient, the share and the round 2 transcript to this
+    /// context, so a share can be attributed to its dealer.
+    pub fn verify_round2(&self, data: &Round2Data<G::Scalar>, dealer_key: &G) -> DkgResult<()> {
+        let r = self.message_generator * data.signature.s - *dealer_key * data.signature.c;
+        let c = self.round2_challenge(data, dealer_key, &r);
+        if !bool::from(c.ct_eq(&data.signature.c)) {
+            return Err(Error::Signature(format!(
+                "Invalid round 2 signature from {}",
+                data.sender_ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    /// The challenge of the round 2 signature of `data` with the nonce commitment `r`
+    pub(crate) fn round2_challenge(
+        &self,
+        data: &Round2Data<G::Scalar>,
+        dealer_key: &G,
+        r: &G,
+    ) -> G::Scalar {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Round 2 Share");
+        transcript.append_message(b"roster", &self.roster_digest);
+        transcript.append_message(b"parameters", &self.parameters_digest);
+        transcript.append_u64(b"sender", data.sender_ordinal as u64);
+        transcript.append_message(b"sender_id", data.sender_id.0.to_repr().as_ref());
+        transcript.append_u64(b"sender_type", u16::from(data.sender_type) as u64);
+        transcript.append_message(
+            b"recipient_id",
+            data.secret_share.identifier.0.to_repr().as_ref(),
+        );
+        transcript.append_message(b"share", data.secret_share.value.0.to_repr().as_ref());
+        transcript.append_message(b"transcript_hash", &data.transcript_hash);
+        let mut statement = [0u8; 32];
+        transcript.challenge_bytes(b"statement", &mut statement);
+
+        let mut input = statement.to_vec();
+        input.extend_from_slice(dealer_key.to_bytes().as_ref());
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-SHARE");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        threshold: usize,
+        limit: usize,
+        message_generator: G,
+        participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+        participant_metadata: &BTreeMap<usize, ParticipantMetadata>,
+        participant_identifiers: &BTreeMap<usize, ParticipantIdentifier>,
+        transcript_label: &[u8],
+        transcript_hash_size: TranscriptHashSize,
+        blinder_generator: Option<G>,
+        handoff_dealers: usize,
+        previous_verification_shares: &BTreeMap<usize, G>,
+        reshare_mapping: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+        commit_reveal: bool,
+    ) -> Self {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Signed Roster");
+        transcript.append_u64(b"participants", participant_ids.len() as u64);
+        for (ordinal, id) in &participant_ids {
+            transcript.append_u64(b"ordinal", *ordinal as u64);
+            transcript.append_message(b"id", id.0.to_repr().as_ref());
+            let identifier = participant_identifiers
+                .get(ordinal)
+                .map(|identifier| identifier.to_string())
+                .unwrap_or_default();
+            transcript.append_message(b"identifier", identifier.as_bytes());
+            let fingerprint = participant_metadata
+                .get(ordinal)
+                .and_then(|metadata| metadata.key_fingerprint.as_deref())
+                .unwrap_or_default();
+            transcript.append_message(b"key_fingerprint", fingerprint);
+        }
+        let mut roster = [0u8; 32];
+        transcript.challenge_bytes(b"roster digest", &mut roster);
+
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Signed Parameters");
+        transcript.append_message(b"domain", transcript_label);
+        transcript.append_u64(b"hash_size", transcript_hash_size.size() as u64);
+        match blinder_generator {
+            Some(blinder) => transcript.append_message(b"blinder", blinder.to_bytes().as_ref()),
+            None => transcript.append_message(b"blinder", &[]),
+        }
+        transcript.append_u64(b"handoff_dealers", handoff_dealers as u64);
+        transcript.append_u64(
+            b"previous_shares",
+            previous_verification_shares.len() as u64,
+        );
+        for (ordinal, share) in previous_verification_shares {
+            transcript.append_u64(b"previous_ordinal", *ordinal as u64);
+            transcript.append_message(b"previous_share", share.to_bytes().as_ref());
+        }
+        transcript.append_u64(b"reshare_entries", reshare_mapping.len() as u64);
+        for (ordinal, old_id) in &reshare_mapping {
+            transcript.append_u64(b"reshare_ordinal", *ordinal as u64);
+            transcript.append_message(b"reshare_old_id", old_id.0.to_repr().as_ref());
+        }
+        transcript.append_u64(b"commit_reveal", u64::from(commit_reveal));
+        let mut parameters = [0u8; 32];
+        transcript.challenge_bytes(b"parameters digest", &mut parameters);
+
+        Self {
+            threshold,
+            limit,
+            message_generator,
+            participant_ids,
+            roster_digest: roster,
+            parameters_digest: parameters,
+            reshare_mapping,
+        }
+    }
+}
+
+impl<G> Parameters<'_, G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The context the round 1 signatures of a DKG run with these
+    /// parameters are bound to
+    pub fn signed_context(&self) -> DkgResult<SignedContext<G>> {
+        let participant_ids =
+            ParticipantIdGeneratorCollection::from(&self.participant_number_generators)
+                .iter()
+                .take(self.limit)
+                .enumerate()
+                .collect::<BTreeMap<_, _>>();
+        if participant_ids.len() != self.limit {
+            return Err(Error::Initialization(format!(
+                "Participant id generators produced {} ids, expected {}",
+                participant_ids.len(),
+                self.limit
+            )));
+        }
+        let ids = participant_ids.values().copied().collect::<Vec<_>>();
+        let (reshare_mapping, invalid) = reshare_ordinals(&self.reshare_mapping, &ids);
+        if let Some(index) = invalid.first() {
+            return Err(Error::Initialization(format!(
+                "Reshare mapping entry {index} has a zero or repeated id or maps outside the roster"
+            )));
+        }
+        Ok(SignedContext::new(
+            self.threshold,
+            self.limit,
+            self.message_generator,
+            participant_ids,
+            &self.participant_metadata,
+            &self.participant_identifiers,
+            &self.transcript_label,
+            self.transcript_hash_size,
+            self.blinder_generator,
+            self.handoff_dealers,
+            &self.previous_verification_shares,
+            reshare_mapping,
+            self.commit_reveal,
+        ))
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The context this participant's round 1 signature and the ones it
+    /// accepts are bound to
+    pub fn signed_context(&self) -> SignedContext<G> {
+        SignedContext::new(
+            self.threshold,
+            self.limit,
+            self.message_generator,
+            self.all_participant_ids.clone(),
+            &self.participant_metadata,
+            &self.participant_identifiers,
+            &self.transcript_label,
+            self.transcript_hash_size,
+            self.blinder_generator,
+            self.handoff_dealers,
+            &self.previous_verification_shares,
+            self.reshare_mapping.clone(),
+            self.commit_reveal,
+        )
+    }
+}
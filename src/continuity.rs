@@ -0,0 +1,154 @@
+use super::*;
+use crate::serdes::group_map;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How the group key of a new epoch relates to the key of the previous one
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum KeyContinuity {
+    /// A refresh or reshare that keeps the group key
+    Preserved,
+    /// A fresh key generation that replaces the group key
+    Fresh,
+}
+
+/// A proof that the group key of a new epoch equals the key of the
+/// previous epoch or intentionally differs from it.
+///
+/// The proof carries the constant term commitment of every dealer whose
+/// share was combined, so an auditor checks with [`ContinuityProof::verify`]
+/// that they aggregate to the claimed key without re-running the protocol.
+/// Proofs from different shareholders of the same run agree on the
+/// transcript hash.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContinuityProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The claimed relation between the keys
+    pub(crate) continuity: KeyContinuity,
+    /// The group key of the previous epoch
+    #[serde(with = "group")]
+    pub(crate) previous_public_key: G,
+    /// The group key of the new epoch
+    #[serde(with = "group")]
+    pub(crate) public_key: G,
+    /// The constant term commitment of each combined dealer by ordinal index
+    #[serde(with = "group_map")]
+    pub(crate) contributions: BTreeMap<usize, G>,
+    /// The final transcript hash of the new epoch's DKG
+    pub(crate) transcript_hash: Vec<u8>,
+}
+
+impl<G> ContinuityProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The claimed relation between the keys
+    pub fn continuity(&self) -> KeyContinuity {
+        self.continuity
+    }
+
+    /// The group key of the previous epoch
+    pub fn previous_public_key(&self) -> G {
+        self.previous_public_key
+    }
+
+    /// The group key of the new epoch
+    pub fn public_key(&self) -> G {
+        self.public_key
+    }
+
+    /// The constant term commitment of each combined dealer by ordinal index
+    pub fn contributions(&self) -> &BTreeMap<usize, G> {
+        &self.contributions
+    }
+
+    /// The final transcript hash of the new epoch's DKG
+    pub fn transcript_hash(&self) -> &[u8] {
+        &self.transcript_hash
+    }
+
+    /// Check that the dealer contributions support the claimed relation.
+    ///
+    /// A kept key is either refreshed, where the contributions share zero,
+    /// or reshared, where they add up to the previous key. A fresh key is the
+    /// sum of the contributions and must differ from the previous key.
+    pub fn verify(&self) -> DkgResult<()> {
+        if self.contributions.is_empty() {
+            return Err(Error::Signature(
+                "Continuity proof has no dealer contributions".to_string(),
+            ));
+        }
+        if self.previous_public_key.is_identity().into() || self.public_key.is_identity().into() {
+            return Err(Error::Signature(
+                "Continuity proof contains the identity".to_string(),
+            ));
+        }
+        let aggregate = self
+            .contributions
+            .values()
+            .fold(G::identity(), |acc, contribution| acc + contribution);
+        let holds = match self.continuity {
+            KeyContinuity::Preserved => {
+                self.public_key == self.previous_public_key
+                    && (bool::from(aggregate.is_identity())
+                        || aggregate == self.previous_public_key)
+            }
+            KeyContinuity::Fresh => {
+                self.public_key == aggregate && self.public_key != self.previous_public_key
+            }
+        };
+        if !holds {
+            return Err(Error::Signature(format!(
+                "Dealer contributions do not support a {:?} group key",
+                self.continuity
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Prove how the group key of this completed run relates to
+    /// `previous_public_key`.
+    ///
+    /// The claim is checked before the proof is returned. A run of only
+    /// refresh participants has no key of its own, so its key is the
+    /// previous one.
+    pub fn continuity_proof(
+        &self,
+        previous_public_key: NonIdentity<G>,
+        continuity: KeyContinuity,
+    ) -> DkgResult<ContinuityProof<G>> {
+        if !self.completed || self.is_handoff_dealer(self.ordinal) {
+            return Err(Error::Round(
+                "A continuity proof needs a completed run with a key share".to_string(),
+            ));
+        }
+        let public_key = if self.public_key.is_identity().into() {
+            previous_public_key.0
+        } else {
+            self.public_key
+        };
+        let proof = ContinuityProof {
+            continuity,
+            previous_public_key: previous_public_key.0,
+            public_key,
+            contributions: self.get_contribution_public_keys(),
+            transcript_hash: self.get_final_transcript_hash_bytes(),
+        };
+        proof.verify()?;
+        Ok(proof)
+    }
+}
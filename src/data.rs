@@ -1,12 +1,34 @@
 use super::*;
+use crate::serdes::{
+    hex_bytes, hex_commitments, hex_group, hex_identifier, hex_prime_field, hex_share,
+};
+#[cfg(feature = "participant")]
+use elliptic_curve::PrimeField;
 use elliptic_curve::group::GroupEncoding;
 use elliptic_curve::subtle::ConditionallySelectable;
-use elliptic_curve::{Group, PrimeField};
-use elliptic_curve_tools::{SumOfProducts, group, prime_field};
+use elliptic_curve::{Field, Group};
+use elliptic_curve_tools::SumOfProducts;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt::{self, Display, Formatter};
-use vsss_rs::{IdentifierPrimeField, ShareVerifierGroup};
+use vsss_rs::{DefaultShare, IdentifierPrimeField};
+
+/// The inner share representation
+pub type SecretShare<F> = DefaultShare<IdentifierPrimeField<F>, IdentifierPrimeField<F>>;
+
+/// Number of feldman commitments stored inline with the `smallvec` feature
+/// before spilling to the heap
+pub const INLINE_COMMITMENTS: usize = 16;
+
+/// The feldman commitments to a participant's polynomial
+#[cfg(not(feature = "smallvec"))]
+pub type FeldmanCommitments<G> = Vec<G>;
+
+/// The feldman commitments to a participant's polynomial.
+///
+/// Thresholds up to [`INLINE_COMMITMENTS`] don't allocate.
+#[cfg(feature = "smallvec")]
+pub type FeldmanCommitments<G> = smallvec::SmallVec<[G; INLINE_COMMITMENTS]>;
 
 /// Valid rounds
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -105,14 +127,36 @@ macro_rules! impl_participant_to_int {
 impl_participant_to_int!(u8, u16, u32, u128, usize);
 
 /// The schnorr signature
-#[derive(Debug, Default, Copy, Clone, Deserialize, Serialize)]
-pub struct Signature<G: Group<Scalar: ScalarHash> + GroupEncoding + Default> {
-    #[serde(with = "group")]
+#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
+pub struct Signature<G: Group<Scalar: ScalarHash> + GroupEncoding> {
+    #[serde(with = "hex_group")]
     pub(crate) r: G,
-    #[serde(with = "prime_field")]
+    #[serde(with = "hex_prime_field")]
     pub(crate) s: G::Scalar,
 }
 
+/// The placeholder signature of data that is signed after it is built
+impl<G: Group<Scalar: ScalarHash> + GroupEncoding> Default for Signature<G> {
+    fn default() -> Self {
+        Self {
+            r: G::identity(),
+            s: G::Scalar::ZERO,
+        }
+    }
+}
+
+impl<G: Group<Scalar: ScalarHash> + GroupEncoding> Signature<G> {
+    /// The commitment to the nonce
+    pub fn r(&self) -> G {
+        self.r
+    }
+
+    /// The response
+    pub fn s(&self) -> G::Scalar {
+        self.s
+    }
+}
+
 /// The round output for a participant
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ParticipantRoundOutput<F: ScalarHash> {
@@ -142,20 +186,22 @@ where
 #[derive(Debug, Clone)]
 pub enum RoundOutputGenerator<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// The round 1 output generator
     Round1(Round1OutputGenerator<G>),
     /// The round 2 output generator
     Round2(Round2OutputGenerator<G>),
+    /// The opening of the pedersen commitments broadcast in round 3
+    Opening(Round1OutputGenerator<G>),
     /// The round 3 output generator
     Round3,
 }
 
 impl<G> RoundOutputGenerator<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// Iterate over the data to send to other participants
@@ -163,44 +209,19 @@ where
     /// at ordinal index with id.
     pub fn iter(&self) -> Box<dyn Iterator<Item = ParticipantRoundOutput<G::Scalar>> + '_> {
         match self {
-            Self::Round1(data) => {
-                let round1_output_data = Round1Data {
-                    sender_ordinal: data.sender_ordinal,
-                    sender_id: data.sender_id,
-                    sender_type: data.sender_type,
-                    feldman_commitments: data.feldman_commitments.clone(),
-                    verifying_share: data.verifying_share,
-                    signature: data.signature,
-                };
-                let mut output =
-                    postcard::to_stdvec(&round1_output_data).expect("to serialize into bytes");
-                output.insert(0, u8::from(Round::One));
+            Self::Round1(data) | Self::Opening(data) => {
                 Box::new(data.participant_ids.iter().filter_map(move |(index, id)| {
                     if *index == data.sender_ordinal {
                         None
                     } else {
-                        Some(ParticipantRoundOutput::new(*index, *id, output.clone()))
+                        Some(ParticipantRoundOutput::new(*index, *id, data.data.clone()))
                     }
                 }))
             }
             Self::Round2(data) => {
-                let mut round2_output_data = Round2Data {
-                    sender_ordinal: data.sender_ordinal,
-                    sender_id: data.sender_id,
-                    sender_type: data.sender_type,
-                    secret_share: SecretShare::<G::Scalar>::default(),
-                    transcript_hash: data.transcript_hash,
-                };
                 Box::new(data.participant_ids.iter().filter_map(move |(index, &id)| {
-                    if *index == data.sender_ordinal {
-                        return None;
-                    }
-                    debug_assert_eq!(data.secret_shares[index].identifier, id);
-                    round2_output_data.secret_share = data.secret_shares[index];
-                    let mut output =
-                        postcard::to_stdvec(&round2_output_data).expect("to serialize into bytes");
-                    output.insert(0, u8::from(Round::Two));
-                    Some(ParticipantRoundOutput::new(*index, id, output))
+                    let output = data.payloads.get(index)?;
+                    Some(ParticipantRoundOutput::new(*index, id, output.clone()))
                 }))
             }
             Self::Round3 => Box::new(std::iter::empty()),
@@ -208,54 +229,210 @@ where
     }
 }
 
+/// The length of the header in front of every message payload, the round
+/// tag followed by the [`ciphersuite_id`]
+pub const MESSAGE_HEADER_LENGTH: usize = 1 + CIPHERSUITE_ID_LENGTH;
+
+/// Encode a round payload for the wire, tagged with the round that produced
+/// it and the ciphersuite
+#[cfg(feature = "participant")]
+pub(crate) fn encode_payload<G, T>(round: Round, payload: &T) -> DkgResult<Vec<u8>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+    T: Serialize,
+{
+    let mut header = Vec::with_capacity(MESSAGE_HEADER_LENGTH);
+    header.push(u8::from(round));
+    header.extend_from_slice(&ciphersuite_id::<G>());
+    Ok(postcard::to_extend(payload, header)?)
+}
+
+/// The round a message from [`RoundOutputGenerator::iter`] belongs to
+pub fn message_round(bytes: &[u8]) -> DkgResult<Round> {
+    let tag = bytes
+        .first()
+        .ok_or_else(|| Error::Encoding("Message is empty".to_string()))?;
+    Round::try_from(*tag).map_err(Error::Encoding)
+}
+
+/// The ciphersuite a message from [`RoundOutputGenerator::iter`] was produced for
+pub fn message_ciphersuite(bytes: &[u8]) -> DkgResult<[u8; CIPHERSUITE_ID_LENGTH]> {
+    let id = bytes
+        .get(1..MESSAGE_HEADER_LENGTH)
+        .ok_or_else(|| Error::Encoding("Message is too short".to_string()))?;
+    Ok(id.try_into().expect("the length was checked"))
+}
+
+/// The ordinal index of the sender a message from
+/// [`RoundOutputGenerator::iter`] claims, before any validation
+pub fn message_sender(bytes: &[u8]) -> DkgResult<usize> {
+    let payload = bytes
+        .get(MESSAGE_HEADER_LENGTH..)
+        .ok_or_else(|| Error::Encoding("Message is too short".to_string()))?;
+    let (sender, _) = postcard::take_from_bytes::<usize>(payload)?;
+    Ok(sender)
+}
+
+/// Check the message was produced for the ciphersuite over `G` and return
+/// its payload
+pub(crate) fn message_payload<G>(bytes: &[u8]) -> DkgResult<&[u8]>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+{
+    let id = message_ciphersuite(bytes)?;
+    let expected = ciphersuite_id::<G>();
+    if id != expected {
+        return Err(Error::Encoding(format!(
+            "Message is for ciphersuite {} but this participant uses {}",
+            hex::encode(id),
+            hex::encode(expected)
+        )));
+    }
+    Ok(&bytes[MESSAGE_HEADER_LENGTH..])
+}
+
+/// Decode a round 1 message without a participant, like a relay or an
+/// indexer that only routes and inspects messages
+pub fn decode_round1_message<G>(bytes: &[u8]) -> DkgResult<Round1Data<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    decode_message::<G, _>(Round::One, bytes)
+}
+
+/// Decode a round 2 message without a participant.
+///
+/// Round 2 messages carry a secret share so only the recipient should
+/// decode them.
+pub fn decode_round2_message<G>(bytes: &[u8]) -> DkgResult<Round2Data<G::Scalar>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+{
+    decode_message::<G, _>(Round::Two, bytes)
+}
+
+pub(crate) fn decode_message<G, T>(round: Round, bytes: &[u8]) -> DkgResult<T>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let tagged = message_round(bytes)?;
+    if tagged != round {
+        return Err(Error::Encoding(format!(
+            "Expected a round {round} message but got round {tagged}"
+        )));
+    }
+    decode_canonical(round, message_payload::<G>(bytes)?)
+}
+
+/// Decode a payload and reject any encoding other than the canonical one
+pub(crate) fn decode_canonical<T>(round: Round, bytes: &[u8]) -> DkgResult<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let payload = postcard::from_bytes::<T>(bytes).map_err(|source| Error::Decode {
+        round,
+        length: bytes.len(),
+        sender: postcard::take_from_bytes::<usize>(bytes)
+            .ok()
+            .map(|(sender, _)| sender),
+        source,
+    })?;
+    let (len, first_difference) = postcard::serialize_with_flavor(
+        &payload,
+        CanonicalCheck {
+            expected: bytes,
+            len: 0,
+            first_difference: None,
+        },
+    )?;
+    if len != bytes.len() {
+        return Err(Error::NonCanonical(format!(
+            "Round {round}: Payload is {} bytes but its canonical encoding is {len} bytes",
+            bytes.len(),
+        )));
+    }
+    if let Some(offset) = first_difference {
+        return Err(Error::NonCanonical(format!(
+            "Round {round}: Payload differs from its canonical encoding at byte {offset}"
+        )));
+    }
+    Ok(payload)
+}
+
+/// Compares the canonical encoding with the received bytes as it is
+/// produced so checking a payload doesn't need a second copy of it
+struct CanonicalCheck<'a> {
+    expected: &'a [u8],
+    len: usize,
+    first_difference: Option<usize>,
+}
+
+impl postcard::ser_flavors::Flavor for CanonicalCheck<'_> {
+    type Output = (usize, Option<usize>);
+
+    fn try_push(&mut self, data: u8) -> postcard::Result<()> {
+        if self.first_difference.is_none() && self.expected.get(self.len) != Some(&data) {
+            self.first_difference = Some(self.len);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn finalize(self) -> postcard::Result<Self::Output> {
+        Ok((self.len, self.first_difference))
+    }
+}
+
 /// The output generator for round 0
 #[derive(Debug, Clone)]
 pub struct Round1OutputGenerator<G>
 where
-    G: GroupEncoding + Default + SumOfProducts + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// The participant IDs to send to
     pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
-    /// The sender's participant type
-    pub(crate) sender_type: ParticipantType,
     /// The sender's ordinal index
     pub(crate) sender_ordinal: usize,
-    /// The sender's ID
-    pub(crate) sender_id: IdentifierPrimeField<G::Scalar>,
-    /// The feldman verifier set
-    pub(crate) feldman_commitments: Vec<ShareVerifierGroup<G>>,
-    /// The verifying share
-    pub(crate) verifying_share: G,
-    /// The schnorr signature
-    pub(crate) signature: Signature<G>,
+    /// The encoded round 1 data broadcast to every participant
+    pub(crate) data: Vec<u8>,
 }
 
 /// The round 1 data
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Round1Data<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// The sender's ordinal index
     pub(crate) sender_ordinal: usize,
     /// The sender's ID
-    #[serde(bound(
-        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
-        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
-    ))]
+    #[serde(
+        with = "hex_identifier",
+        bound(
+            serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+            deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+        )
+    )]
     pub(crate) sender_id: IdentifierPrimeField<G::Scalar>,
     /// The sender's participant type
     pub(crate) sender_type: ParticipantType,
+    /// The threshold the sender is using
+    pub(crate) threshold: usize,
+    /// The limit the sender is using
+    pub(crate) limit: usize,
     /// The feldman commitments
-    #[serde(bound(
-        serialize = "ShareVerifierGroup<G>: Serialize",
-        deserialize = "ShareVerifierGroup<G>: Deserialize<'de>"
-    ))]
-    pub(crate) feldman_commitments: Vec<ShareVerifierGroup<G>>,
+    #[serde(with = "hex_commitments")]
+    pub(crate) feldman_commitments: FeldmanCommitments<G>,
     /// The verifying share
-    #[serde(with = "group")]
+    #[serde(with = "hex_group")]
     pub(crate) verifying_share: G,
     /// The schnorr signature
     #[serde(bound(
@@ -267,9 +444,10 @@ where
 
 impl<G> Round1Data<G>
 where
-    G: GroupEncoding + Default + SumOfProducts + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
+    #[cfg(feature = "participant")]
     pub(crate) fn add_to_transcript(&self, transcript: &mut merlin::Transcript) {
         transcript.append_message(
             b"sender_ordinal",
@@ -289,6 +467,11 @@ where
         }
     }
 
+    /// A digest of the feldman commitments
+    pub fn commitments_digest(&self) -> [u8; 32] {
+        commitments_digest(&self.feldman_commitments)
+    }
+
     /// Get the sender's ordinal index during the DKG
     pub fn sender_ordinal(&self) -> usize {
         self.sender_ordinal
@@ -304,11 +487,29 @@ where
         self.sender_type
     }
 
+    /// Get the threshold the sender is using
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Get the limit the sender is using
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
     /// Get the feldman commitments used by the DKG
-    pub fn feldman_commitments(&self) -> &[ShareVerifierGroup<G>] {
+    pub fn feldman_commitments(&self) -> &[G] {
         &self.feldman_commitments
     }
 
+    /// Get the feldman commitments encoded for export
+    pub fn feldman_commitments_bytes(&self, encoding: PointEncoding) -> DkgResult<Vec<Vec<u8>>> {
+        self.feldman_commitments
+            .iter()
+            .map(|commitment| encode_point(commitment, encoding))
+            .collect()
+    }
+
     /// Get the signature verifying share used by the DKG
     pub fn verifying_share(&self) -> G {
         self.verifying_share
@@ -320,25 +521,130 @@ where
     }
 }
 
+/// The number of feldman commitments [`CommitmentScanner`] is fed at a time
+#[cfg(feature = "participant")]
+pub(crate) const COMMITMENT_CHUNK: usize = 64;
+
+/// A digest of feldman commitments, see [`Round1Data::commitments_digest`]
+pub(crate) fn commitments_digest<G>(commitments: &[G]) -> [u8; 32]
+where
+    G: Group + GroupEncoding,
+{
+    let mut transcript = commitments_transcript(commitments.len());
+    for commitment in commitments {
+        transcript.append_message(b"feldman_commitment", commitment.to_bytes().as_ref());
+    }
+    let mut digest = [0u8; 32];
+    transcript.challenge_bytes(b"commitments digest", &mut digest);
+    digest
+}
+
+fn commitments_transcript(count: usize) -> merlin::Transcript {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Commitments Digest");
+    transcript.append_message(b"feldman_commitments.len()", &(count as u16).to_be_bytes());
+    transcript
+}
+
+/// Checks feldman commitments and hashes them into their digest in a
+/// single pass.
+///
+/// Commitments are fed a chunk at a time so a caller can stop at the first
+/// chunk with a point outside the prime order subgroup, and the digest is
+/// bound into the round 1 signature instead of the commitments themselves.
+#[cfg(feature = "participant")]
+pub(crate) struct CommitmentScanner<G>
+where
+    G: PrimeSubgroup + GroupEncoding,
+{
+    transcript: merlin::Transcript,
+    scanned: usize,
+    torsion_free: bool,
+    identity: elliptic_curve::subtle::Choice,
+    _group: std::marker::PhantomData<G>,
+}
+
+#[cfg(feature = "participant")]
+impl<G> CommitmentScanner<G>
+where
+    G: PrimeSubgroup + GroupEncoding,
+{
+    /// Start scanning `count` commitments
+    pub(crate) fn new(count: usize) -> Self {
+        Self {
+            transcript: commitments_transcript(count),
+            scanned: 0,
+            torsion_free: true,
+            identity: elliptic_curve::subtle::Choice::from(0u8),
+            _group: std::marker::PhantomData,
+        }
+    }
+
+    /// Check and hash the next chunk of commitments
+    pub(crate) fn update(&mut self, chunk: &[G]) {
+        for commitment in chunk {
+            self.torsion_free &= is_torsion_free(commitment);
+            // The first commitment is checked against the participant type instead
+            if self.scanned > 0 {
+                self.identity |= commitment.is_identity();
+            }
+            self.transcript
+                .append_message(b"feldman_commitment", commitment.to_bytes().as_ref());
+            self.scanned += 1;
+        }
+    }
+
+    /// Whether every commitment so far is in the prime order subgroup
+    pub(crate) fn is_torsion_free(&self) -> bool {
+        self.torsion_free
+    }
+
+    /// Whether a commitment after the first was the identity
+    pub(crate) fn has_identity(&self) -> bool {
+        self.identity.into()
+    }
+
+    /// The digest of the commitments, equal to
+    /// [`Round1Data::commitments_digest`] once every commitment was scanned
+    pub(crate) fn finalize(mut self) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        self.transcript
+            .challenge_bytes(b"commitments digest", &mut digest);
+        digest
+    }
+}
+
 /// The output generator for round 2
 #[derive(Debug, Clone)]
 pub struct Round2OutputGenerator<G>
 where
-    G: GroupEncoding + Default + SumOfProducts + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// The participant IDs to send to
     pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
-    /// The sender's ordinal index
-    pub(crate) sender_ordinal: usize,
-    /// The sender's ID
-    pub(crate) sender_id: IdentifierPrimeField<G::Scalar>,
-    /// The sender's participant type
-    pub(crate) sender_type: ParticipantType,
-    /// The peer 2 peer data based on the participant ordinal index
-    pub(crate) secret_shares: BTreeMap<usize, SecretShare<G::Scalar>>,
-    /// The transcript hash
-    pub(crate) transcript_hash: [u8; 32],
+    /// The encoded peer 2 peer data based on the participant ordinal index
+    pub(crate) payloads: BTreeMap<usize, Vec<u8>>,
+}
+
+/// The first 32 bytes of a transcript hash as returned before the size
+/// was configurable, zero padded if it is shorter
+pub(crate) fn legacy_transcript_hash(transcript_hash: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let len = transcript_hash.len().min(hash.len());
+    hash[..len].copy_from_slice(&transcript_hash[..len]);
+    hash
+}
+
+/// A schnorr signature in challenge and response form.
+///
+/// Unlike [`Signature`] it only needs the scalar field so it fits payloads
+/// that are not generic over the group.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScalarSignature<F: ScalarHash> {
+    #[serde(with = "hex_prime_field")]
+    pub(crate) c: F,
+    #[serde(with = "hex_prime_field")]
+    pub(crate) s: F,
 }
 
 /// The round 2 data
@@ -347,24 +653,39 @@ pub struct Round2Data<F: ScalarHash> {
     /// The sender's ordinal index
     pub(crate) sender_ordinal: usize,
     /// The sender's ID
-    #[serde(bound(
-        serialize = "IdentifierPrimeField<F>: Serialize",
-        deserialize = "IdentifierPrimeField<F>: Deserialize<'de>"
-    ))]
+    #[serde(
+        with = "hex_identifier",
+        bound(
+            serialize = "IdentifierPrimeField<F>: Serialize",
+            deserialize = "IdentifierPrimeField<F>: Deserialize<'de>"
+        )
+    )]
     pub(crate) sender_id: IdentifierPrimeField<F>,
     /// The sender's participant type
     pub(crate) sender_type: ParticipantType,
     /// The peer 2 peer data
-    #[serde(bound(
-        serialize = "SecretShare<F>: Serialize",
-        deserialize = "SecretShare<F>: Deserialize<'de>"
-    ))]
+    #[serde(
+        with = "hex_share",
+        bound(
+            serialize = "SecretShare<F>: Serialize",
+            deserialize = "SecretShare<F>: Deserialize<'de>"
+        )
+    )]
     pub(crate) secret_share: SecretShare<F>,
     /// The transcript of all messages received
-    pub(crate) transcript_hash: [u8; 32],
+    #[serde(with = "hex_bytes")]
+    pub(crate) transcript_hash: Vec<u8>,
+    /// The sender's signature over the payload with the key of its round 1
+    /// proof of knowledge, see [`SignedContext::verify_round2`]
+    #[serde(bound(
+        serialize = "ScalarSignature<F>: Serialize",
+        deserialize = "ScalarSignature<F>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: ScalarSignature<F>,
 }
 
 impl<F: ScalarHash> Round2Data<F> {
+    #[cfg(feature = "participant")]
     pub(crate) fn add_to_transcript(&self, transcript: &mut merlin::Transcript) {
         transcript.append_message(
             b"sender_ordinal",
@@ -396,7 +717,111 @@ impl<F: ScalarHash> Round2Data<F> {
     }
 
     /// Get the transcript hash used by the DKG
+    #[deprecated(note = "use `transcript_hash_bytes` which supports 64 byte transcript hashes")]
     pub fn transcript_hash(&self) -> [u8; 32] {
-        self.transcript_hash
+        legacy_transcript_hash(&self.transcript_hash)
+    }
+
+    /// Get the transcript hash used by the DKG with the configured
+    /// [`TranscriptHashSize`]
+    pub fn transcript_hash_bytes(&self) -> &[u8] {
+        &self.transcript_hash
+    }
+
+    /// Get the sender's signature over the payload
+    pub fn signature(&self) -> ScalarSignature<F> {
+        self.signature
+    }
+}
+
+#[cfg(all(test, feature = "participant"))]
+mod tests {
+    use super::*;
+    use crate::participant::testing::next_round;
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn non_canonical() {
+        let threshold = NonZeroUsize::new(2).expect("threshold is non-zero");
+        let limit = NonZeroUsize::new(3).expect("limit is non-zero");
+        let parameters = Parameters::<k256::ProjectivePoint>::new(threshold, limit, None, None);
+
+        let mut participants = (1..=3)
+            .map(|id| {
+                let id = IdentifierPrimeField(k256::Scalar::from(id as u64));
+                SecretParticipant::<k256::ProjectivePoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        let output = generators[1]
+            .iter()
+            .find(|output| output.dst_ordinal == 0)
+            .expect("output for ordinal 0");
+        let mut trailing = output.data.clone();
+        trailing.push(0);
+        assert!(matches!(
+            participants[0].receive(&trailing),
+            Err(Error::NonCanonical(_))
+        ));
+        let truncated = &output.data[..MESSAGE_HEADER_LENGTH + 8];
+        assert!(matches!(
+            participants[0].receive(truncated),
+            Err(Error::Decode {
+                round: Round::One,
+                length: 8,
+                sender: Some(1),
+                ..
+            })
+        ));
+        assert!(participants[0].receive(&output.data).is_ok());
+    }
+
+    #[cfg(feature = "curve25519-dalek")]
+    #[test]
+    fn non_canonical_point() {
+        use curve25519_dalek::{EdwardsPoint, Scalar};
+        use elliptic_curve::Group;
+
+        let threshold = NonZeroUsize::new(2).expect("threshold is non-zero");
+        let limit = NonZeroUsize::new(3).expect("limit is non-zero");
+        let parameters = Parameters::<EdwardsPoint>::new(threshold, limit, None, None);
+
+        let mut participants = (1..=3)
+            .map(|id| {
+                let id = IdentifierPrimeField(Scalar::from(id as u64));
+                SecretParticipant::<EdwardsPoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        let output = generators[1]
+            .iter()
+            .find(|output| output.dst_ordinal == 0)
+            .expect("output for ordinal 0");
+        let mut round1_data =
+            postcard::from_bytes::<Round1Data<EdwardsPoint>>(&output.data[MESSAGE_HEADER_LENGTH..])
+                .expect("decode round 1 data");
+        round1_data.feldman_commitments[1] = EdwardsPoint::identity();
+        let mut bad_data = encode_payload::<EdwardsPoint, _>(Round::One, &round1_data)
+            .expect("encode round 1 data");
+
+        // y = p + 1 decodes to the identity but is not its canonical encoding
+        let canonical = EdwardsPoint::identity().to_bytes();
+        let mut unreduced = [0xffu8; 32];
+        unreduced[0] = 0xee;
+        unreduced[31] = 0x7f;
+        let offset = bad_data
+            .windows(32)
+            .position(|w| w == canonical.as_slice())
+            .expect("identity encoding in payload");
+        bad_data[offset..offset + 32].copy_from_slice(&unreduced);
+
+        assert!(matches!(
+            participants[0].receive(&bad_data),
+            Err(Error::NonCanonical(_))
+        ));
     }
 }
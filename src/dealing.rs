@@ -0,0 +1,181 @@
+use super::*;
+use crate::signing::SecretScalar;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::zeroize::Zeroize;
+use elliptic_curve_tools::SumOfProducts;
+use rand_core::CryptoRng;
+use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+/// A dealer's secret polynomial, generated independently of the roster.
+///
+/// The polynomial can be committed to with [`DealerPolynomial::commitments`]
+/// before the roster is final and dealt with [`DealerPolynomial::deal`] or
+/// turned into a participant with [`Participant::with_polynomial`] once it
+/// is. The coefficients are zeroized on drop.
+#[derive(Clone)]
+pub struct DealerPolynomial<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    pub(crate) coefficients: Vec<SecretScalar<G::Scalar>>,
+}
+
+impl<G> Drop for DealerPolynomial<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<G> Debug for DealerPolynomial<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DealerPolynomial")
+            .field("threshold", &self.threshold())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G> DealerPolynomial<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// A random polynomial of `threshold` coefficients sharing `secret`.
+    ///
+    /// Refresh participants share zero.
+    pub fn random(threshold: NonZeroUsize, secret: G::Scalar, mut rng: impl CryptoRng) -> Self {
+        let coefficients = std::iter::once(secret)
+            .chain((1..threshold.get()).map(|_| G::Scalar::random(&mut rng)))
+            .map(SecretScalar)
+            .collect();
+        Self { coefficients }
+    }
+
+    /// The polynomial with the given `coefficients`, the first one being
+    /// the shared secret
+    pub fn from_coefficients(coefficients: &[G::Scalar]) -> Self {
+        Self {
+            coefficients: coefficients.iter().copied().map(SecretScalar).collect(),
+        }
+    }
+
+    /// The number of shares needed to recover the secret
+    pub fn threshold(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// The feldman commitment to each coefficient with `generator`, the
+    /// same commitments a participant dealing this polynomial broadcasts
+    pub fn commitments(&self, generator: G) -> FeldmanCommitments<G> {
+        self.coefficients.iter().map(|c| generator * c.0).collect()
+    }
+
+    /// The share of each of `ids`, in order.
+    ///
+    /// The ids must be unique and non-zero.
+    pub fn deal(
+        &self,
+        ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<Vec<SecretShare<G::Scalar>>> {
+        if ids
+            .iter()
+            .enumerate()
+            .any(|(i, x)| x.0.is_zero().into() || ids[..i].contains(x))
+        {
+            return Err(Error::Initialization(
+                "Participant ids must be unique and non-zero".to_string(),
+            ));
+        }
+        Ok(ids
+            .iter()
+            .map(|x| SecretShare {
+                identifier: *x,
+                value: IdentifierPrimeField(self.evaluate(x.0)),
+            })
+            .collect())
+    }
+
+    /// The shared secret
+    pub(crate) fn secret(&self) -> G::Scalar {
+        self.coefficients.first().map_or(G::Scalar::ZERO, |c| c.0)
+    }
+
+    fn evaluate(&self, x: G::Scalar) -> G::Scalar {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(G::Scalar::ZERO, |acc, c| acc * x + c.0)
+    }
+}
+
+/// The ordinal index of `id` in the roster `ids`
+pub fn locate_in_roster<F: ScalarHash>(
+    id: IdentifierPrimeField<F>,
+    ids: &[IdentifierPrimeField<F>],
+) -> DkgResult<usize> {
+    ids.iter().position(|x| *x == id).ok_or_else(|| {
+        Error::Initialization(format!(
+            "Invalid participant id '{id}'. Not in generated set of shares"
+        ))
+    })
+}
+
+impl<G> Participant<SecretParticipantImpl<G>, G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a new participant that deals `polynomial`, which may have
+    /// been generated and committed to before the roster was known
+    pub fn with_polynomial(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        polynomial: &DealerPolynomial<G>,
+    ) -> DkgResult<Self> {
+        Self::initialize_with_polynomial(
+            id,
+            parameters,
+            IdentifierPrimeField(polynomial.secret()),
+            None,
+            polynomial,
+            rand::rng(),
+        )
+    }
+}
+
+impl<G> Participant<RefreshParticipantImpl<G>, G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a new participant to refresh an existing key share that deals
+    /// `polynomial`, which must share zero
+    pub fn new_refresh_with_polynomial(
+        id: IdentifierPrimeField<G::Scalar>,
+        existing_share: Option<G::Scalar>,
+        parameters: &Parameters<G>,
+        polynomial: &DealerPolynomial<G>,
+    ) -> DkgResult<Self> {
+        let secret = existing_share.unwrap_or_else(|| G::Scalar::random(&mut rand::rng()));
+        Self::initialize_with_polynomial(
+            id,
+            parameters,
+            IdentifierPrimeField(secret),
+            Some(parameters.message_generator * secret),
+            polynomial,
+            rand::rng(),
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::{Group, PrimeField};
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use vsss_rs::IdentifierPrimeField;
+
+/// How group elements are encoded when exported
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum PointEncoding {
+    /// The compressed encoding from [`GroupEncoding`]
+    #[default]
+    Compressed,
+    /// The uncompressed SEC1 encoding.
+    ///
+    /// Only supported by secp256k1, NIST P-256 and NIST P-384.
+    Uncompressed,
+}
+
+/// Encode `point` for export
+pub fn encode_point<G: Group + GroupEncoding>(
+    point: &G,
+    encoding: PointEncoding,
+) -> DkgResult<Vec<u8>> {
+    match encoding {
+        PointEncoding::Compressed => Ok(point.to_bytes().as_ref().to_vec()),
+        PointEncoding::Uncompressed => sec1_uncompressed(point).ok_or_else(|| {
+            Error::Encoding("Uncompressed encoding is not supported for this group".to_string())
+        }),
+    }
+}
+
+/// Decode a point exported with either [`PointEncoding`]
+pub fn decode_point<G: Group + GroupEncoding>(bytes: &[u8]) -> DkgResult<G> {
+    let mut repr = G::Repr::default();
+    if repr.as_ref().len() == bytes.len() {
+        repr.as_mut().copy_from_slice(bytes);
+        return Option::from(G::from_bytes(&repr))
+            .ok_or_else(|| Error::Encoding("Invalid point".to_string()));
+    }
+    if bytes.first() == Some(&4) {
+        return sec1_from_uncompressed(bytes)
+            .ok_or_else(|| Error::Encoding("Invalid point".to_string()));
+    }
+    Err(Error::Encoding("Invalid point length".to_string()))
+}
+
+macro_rules! sec1_curves {
+    ($($feature:literal => $curve:ident),+) => {
+        fn sec1_uncompressed<G: Group>(point: &G) -> Option<Vec<u8>> {
+            let _point: &dyn Any = point;
+            $(
+                #[cfg(feature = $feature)]
+                if let Some(p) = _point.downcast_ref::<$curve::ProjectivePoint>() {
+                    use $curve::elliptic_curve::sec1::ToSec1Point;
+
+                    let affine = $curve::AffinePoint::from(*p);
+                    return Some(affine.to_sec1_point(false).as_bytes().to_vec());
+                }
+            )+
+            None
+        }
+
+        fn sec1_from_uncompressed<G: Group>(_bytes: &[u8]) -> Option<G> {
+            $(
+                #[cfg(feature = $feature)]
+                if std::any::TypeId::of::<G>() == std::any::TypeId::of::<$curve::ProjectivePoint>() {
+                    use $curve::elliptic_curve::sec1::FromSec1Point;
+
+                    let encoded = $curve::Sec1Point::from_bytes(_bytes).ok()?;
+                    let affine = Option::<$curve::AffinePoint>::from(
+                        $curve::AffinePoint::from_sec1_point(&encoded),
+                    )?;
+                    let point: Box<dyn Any> = Box::new($curve::ProjectivePoint::from(affine));
+                    return point.downcast::<G>().ok().map(|p| *p);
+                }
+            )+
+            None
+        }
+    };
+}
+
+sec1_curves!("k256" => k256, "p256" => p256, "p384" => p384);
+
+/// Renders a group element such as the group public key or a
+/// verification share as text.
+///
+/// `{}` displays hex and `{:#}` displays base58. [`FromStr`] accepts hex
+/// in either [`PointEncoding`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DisplayPoint<G: Group + GroupEncoding>(pub G);
+
+impl<G: Group + GroupEncoding> DisplayPoint<G> {
+    /// The hex encoding
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.to_bytes())
+    }
+
+    /// The base58 encoding
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0.to_bytes()).into_string()
+    }
+
+    /// The bytes of the element in `encoding`
+    pub fn to_bytes_with(&self, encoding: PointEncoding) -> DkgResult<Vec<u8>> {
+        encode_point(&self.0, encoding)
+    }
+
+    /// The hex encoding of the element in `encoding`
+    pub fn to_hex_with(&self, encoding: PointEncoding) -> DkgResult<String> {
+        self.to_bytes_with(encoding).map(hex::encode)
+    }
+
+    /// Parse a hex encoded group element
+    pub fn from_hex(s: &str) -> DkgResult<Self> {
+        let bytes = hex::decode(s).map_err(|e| Error::Encoding(format!("Invalid hex: {e}")))?;
+        Self::from_slice(&bytes)
+    }
+
+    /// Parse a base58 encoded group element
+    pub fn from_base58(s: &str) -> DkgResult<Self> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|e| Error::Encoding(format!("Invalid base58: {e}")))?;
+        Self::from_slice(&bytes)
+    }
+
+    /// Parse a group element in either [`PointEncoding`]
+    pub fn from_slice(bytes: &[u8]) -> DkgResult<Self> {
+        decode_point(bytes).map(Self)
+    }
+}
+
+impl<G: Group + GroupEncoding> Display for DisplayPoint<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_base58())
+        } else {
+            write!(f, "{}", self.to_hex())
+        }
+    }
+}
+
+impl<G: Group + GroupEncoding> FromStr for DisplayPoint<G> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+#[cfg(feature = "participant")]
+impl<G: Group + GroupEncoding> From<NonIdentity<G>> for DisplayPoint<G> {
+    fn from(point: NonIdentity<G>) -> Self {
+        Self(point.0)
+    }
+}
+
+/// Renders a share identifier as text.
+///
+/// `{}` displays hex and `{:#}` displays base58. [`FromStr`] accepts hex.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DisplayIdentifier<F: PrimeField>(pub IdentifierPrimeField<F>);
+
+impl<F: PrimeField> DisplayIdentifier<F> {
+    /// The hex encoding
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.0.to_repr())
+    }
+
+    /// The base58 encoding
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0.0.to_repr()).into_string()
+    }
+
+    /// Parse a hex encoded identifier
+    pub fn from_hex(s: &str) -> DkgResult<Self> {
+        let bytes = hex::decode(s).map_err(|e| Error::Encoding(format!("Invalid hex: {e}")))?;
+        Self::from_slice(&bytes)
+    }
+
+    /// Parse a base58 encoded identifier
+    pub fn from_base58(s: &str) -> DkgResult<Self> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|e| Error::Encoding(format!("Invalid base58: {e}")))?;
+        Self::from_slice(&bytes)
+    }
+
+    fn from_slice(bytes: &[u8]) -> DkgResult<Self> {
+        let mut repr = F::Repr::default();
+        if repr.as_ref().len() != bytes.len() {
+            return Err(Error::Encoding("Invalid identifier length".to_string()));
+        }
+        repr.as_mut().copy_from_slice(bytes);
+        Option::from(F::from_repr(repr))
+            .map(|f| Self(IdentifierPrimeField(f)))
+            .ok_or_else(|| Error::Encoding("Invalid identifier".to_string()))
+    }
+}
+
+impl<F: PrimeField> Display for DisplayIdentifier<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_base58())
+        } else {
+            write!(f, "{}", self.to_hex())
+        }
+    }
+}
+
+impl<F: PrimeField> FromStr for DisplayIdentifier<F> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
@@ -0,0 +1,161 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use vsss_rs::IdentifierPrimeField;
+
+/// Evidence that a participant dealt a share that does not verify
+/// against its round 1 commitments.
+///
+/// The commitments are bound to the sender by the round 1 proof of knowledge
+/// and the share by the sender's round 2 signature with the same key, so any
+/// third party can re-check the claim offline with [`DisputeEvidence::verify`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisputeEvidence<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the participant that received the share
+    pub(crate) accuser_ordinal: usize,
+    /// The ID of the participant that received the share
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) accuser_id: IdentifierPrimeField<G::Scalar>,
+    /// The context the round 1 signature is bound to
+    #[serde(bound(
+        serialize = "SignedContext<G>: Serialize",
+        deserialize = "SignedContext<G>: Deserialize<'de>"
+    ))]
+    pub(crate) context: SignedContext<G>,
+    /// The sender's round 1 broadcast
+    #[serde(bound(
+        serialize = "Round1Data<G>: Serialize",
+        deserialize = "Round1Data<G>: Deserialize<'de>"
+    ))]
+    pub(crate) round1_data: Round1Data<G>,
+    /// The offending round 2 payload
+    #[serde(bound(
+        serialize = "Round2Data<G::Scalar>: Serialize",
+        deserialize = "Round2Data<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) round2_data: Round2Data<G::Scalar>,
+}
+
+impl<G> DisputeEvidence<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Check the evidence shows the sender dealt an invalid share.
+    ///
+    /// Returns an error if the evidence is inconsistent or the share
+    /// actually verifies against the commitments.
+    pub fn verify(&self) -> DkgResult<()> {
+        if self.round1_data.sender_ordinal != self.round2_data.sender_ordinal
+            || self.round1_data.sender_id != self.round2_data.sender_id
+        {
+            return Err(Error::Pvss(
+                "Round 1 and round 2 data are from different senders".to_string(),
+            ));
+        }
+        if self.context.participant_ids.get(&self.accuser_ordinal) != Some(&self.accuser_id) {
+            return Err(Error::Pvss(
+                "Accuser is not in the set of participants".to_string(),
+            ));
+        }
+        if self.round2_data.secret_share.identifier != self.accuser_id {
+            return Err(Error::Pvss(
+                "Share was not dealt to the accuser".to_string(),
+            ));
+        }
+        if self.round1_data.feldman_commitments.len() != self.context.threshold {
+            return Err(Error::Pvss(
+                "Feldman commitments length is not equal to threshold".to_string(),
+            ));
+        }
+        self.context
+            .verify(&self.round1_data)
+            .map_err(|_| Error::Pvss("Round 1 signature does not verify".to_string()))?;
+        self.context
+            .verify_round2(&self.round2_data, &self.round1_data.verifying_share)
+            .map_err(|_| Error::Pvss("Round 2 signature does not verify".to_string()))?;
+
+        let mut power = G::Scalar::ONE;
+        let mut input = Vec::with_capacity(self.context.threshold);
+        for commitment in &self.round1_data.feldman_commitments {
+            input.push((power, *commitment));
+            power *= *self.accuser_id;
+        }
+        let rhs = <G as SumOfProducts>::sum_of_products(&input);
+        let lhs = self.context.message_generator * self.round2_data.secret_share.value.0;
+        if bool::from((lhs - rhs).is_identity()) {
+            return Err(Error::Pvss(
+                "The share verifies with the given commitments".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The ordinal index of the participant that received the share
+    pub fn accuser_ordinal(&self) -> usize {
+        self.accuser_ordinal
+    }
+
+    /// The ID of the participant that received the share
+    pub fn accuser_id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.accuser_id
+    }
+
+    /// The ordinal index of the accused participant
+    pub fn accused_ordinal(&self) -> usize {
+        self.round1_data.sender_ordinal
+    }
+
+    /// The ID of the accused participant
+    pub fn accused_id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.round1_data.sender_id
+    }
+
+    /// The context the accused participant's round 1 signature is bound to
+    pub fn signed_context(&self) -> &SignedContext<G> {
+        &self.context
+    }
+
+    /// The sender's round 1 broadcast
+    pub fn round1_data(&self) -> &Round1Data<G> {
+        &self.round1_data
+    }
+
+    /// The offending round 2 payload
+    pub fn round2_data(&self) -> &Round2Data<G::Scalar> {
+        &self.round2_data
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Build dispute evidence against the sender of `data` if its share
+    /// is provably invalid
+    pub(crate) fn dispute_evidence(
+        &self,
+        data: &Round2Data<G::Scalar>,
+    ) -> Option<DisputeEvidence<G>> {
+        let evidence = DisputeEvidence {
+            accuser_ordinal: self.ordinal,
+            accuser_id: self.id,
+            context: self.signed_context(),
+            round1_data: self.received_round1_data.get(&data.sender_ordinal)?.clone(),
+            round2_data: data.clone(),
+        };
+        evidence.verify().ok().map(|_| evidence)
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeMap;
+
+/// The session id the signing key is generated under
+pub const SIGNING_SESSION_ID: &[u8] = b"dual/signing";
+/// The session id the encryption key is generated under
+pub const ENCRYPTION_SESSION_ID: &[u8] = b"dual/encryption";
+
+/// Generates a signing key and an independent encryption key in one
+/// ceremony.
+///
+/// Each key has its own polynomial, commitments and transcript, with the
+/// session ids [`SIGNING_SESSION_ID`] and [`ENCRYPTION_SESSION_ID`] added
+/// to the parameters so a message of one can't be replayed as the other.
+/// Every round sends a single message to each peer that carries both.
+#[derive(Debug, Clone)]
+pub struct DualParticipant<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    signing: SecretParticipant<G>,
+    encryption: SecretParticipant<G>,
+}
+
+impl<G> DualParticipant<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a participant with `id` for both keys
+    pub fn new(id: IdentifierPrimeField<G::Scalar>, parameters: &Parameters<G>) -> DkgResult<Self> {
+        let signing = parameters.clone().with_session_id(SIGNING_SESSION_ID);
+        let encryption = parameters.clone().with_session_id(ENCRYPTION_SESSION_ID);
+        Ok(Self {
+            signing: SecretParticipant::new_secret(id, &signing)?,
+            encryption: SecretParticipant::new_secret(id, &encryption)?,
+        })
+    }
+
+    /// Run the next round of both keys and return one message per peer
+    pub fn run(&mut self) -> DkgResult<Vec<ParticipantRoundOutput<G::Scalar>>> {
+        let signing = self.signing.run()?;
+        let encryption = self.encryption.run()?;
+        let mut encryption = encryption
+            .iter()
+            .map(|output| (output.dst_ordinal, output.data))
+            .collect::<BTreeMap<_, _>>();
+        let outputs = signing
+            .iter()
+            .map(|output| {
+                let second = encryption.remove(&output.dst_ordinal).ok_or_else(|| {
+                    Error::Round(format!(
+                        "No encryption key message for ordinal {}",
+                        output.dst_ordinal
+                    ))
+                })?;
+                Ok(ParticipantRoundOutput::new(
+                    output.dst_ordinal,
+                    output.dst_id,
+                    encode_dual(&output.data, &second),
+                ))
+            })
+            .collect::<DkgResult<Vec<_>>>()?;
+        if let Some(ordinal) = encryption.keys().next() {
+            return Err(Error::Round(format!(
+                "No signing key message for ordinal {ordinal}"
+            )));
+        }
+        Ok(outputs)
+    }
+
+    /// Receive a message from a peer's [`DualParticipant::run`]
+    pub fn receive(&mut self, data: &[u8]) -> DkgResult<()> {
+        let (signing, encryption) = decode_dual(data)?;
+        self.signing.receive(signing)?;
+        self.encryption.receive(encryption)
+    }
+
+    /// Whether both keys have been generated
+    pub fn completed(&self) -> bool {
+        self.signing.completed() && self.encryption.completed()
+    }
+
+    /// The round both keys are on
+    pub fn get_round(&self) -> Round {
+        self.signing.get_round().min(self.encryption.get_round())
+    }
+
+    /// The participant generating the signing key
+    pub fn signing(&self) -> &SecretParticipant<G> {
+        &self.signing
+    }
+
+    /// The participant generating the encryption key
+    pub fn encryption(&self) -> &SecretParticipant<G> {
+        &self.encryption
+    }
+
+    /// The outputs for both keys.
+    ///
+    /// [`None`] is returned until both are complete.
+    pub fn get_dkg_output(&self) -> Option<DualOutput<G>> {
+        Some(DualOutput {
+            signing: self.signing.get_dkg_output()?,
+            encryption: self.encryption.get_dkg_output()?,
+        })
+    }
+}
+
+/// The two keys of a [`DualParticipant`]
+#[derive(Debug, Clone)]
+pub struct DualOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    pub(crate) signing: DkgOutput<G>,
+    pub(crate) encryption: DkgOutput<G>,
+}
+
+impl<G> DualOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The output of the signing key
+    pub fn signing(&self) -> &DkgOutput<G> {
+        &self.signing
+    }
+
+    /// The output of the encryption key
+    pub fn encryption(&self) -> &DkgOutput<G> {
+        &self.encryption
+    }
+
+    /// Split into the signing and encryption outputs
+    pub fn into_parts(self) -> (DkgOutput<G>, DkgOutput<G>) {
+        (self.signing, self.encryption)
+    }
+}
+
+/// The signing message prefixed with its length followed by the
+/// encryption message
+fn encode_dual(signing: &[u8], encryption: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 + signing.len() + encryption.len());
+    bytes.extend_from_slice(&(signing.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(signing);
+    bytes.extend_from_slice(encryption);
+    bytes
+}
+
+fn decode_dual(data: &[u8]) -> DkgResult<(&[u8], &[u8])> {
+    let (length, rest) = data
+        .split_first_chunk::<4>()
+        .ok_or_else(|| Error::Encoding("Dual message is too short".to_string()))?;
+    let length = u32::from_be_bytes(*length) as usize;
+    if length > rest.len() {
+        return Err(Error::Encoding(
+            "Dual message is shorter than its signing message".to_string(),
+        ));
+    }
+    Ok(rest.split_at(length))
+}
@@ -24,6 +24,72 @@ pub enum Error {
     /// Publicly Verifiable Secret Sharing Verification Error
     #[error("publicly verifiable secret sharing error: {0}")]
     Pvss(String),
+    /// A received point is not in the prime order subgroup
+    #[error("subgroup error: {0}")]
+    Subgroup(String),
+    /// A received payload is not canonically encoded
+    #[error("non-canonical encoding: {0}")]
+    NonCanonical(String),
+    /// A state snapshot was written with an unknown version
+    #[error("unsupported state version: {0}")]
+    UnsupportedStateVersion(u16),
+    /// Error parsing a textual encoding
+    #[error("encoding error: {0}")]
+    Encoding(String),
+    /// A refresh participant did not start from its share of the previous key
+    #[error("participant {0} did not start the refresh from its previous verification share")]
+    ShareContinuity(usize),
+    /// A stored record is corrupt or inconsistent
+    #[error("integrity error: {0}")]
+    Integrity(String),
+    /// Error verifying or creating a signature
+    #[error("signature error: {0}")]
+    Signature(String),
+    /// A received payload could not be decoded
+    #[error(
+        "could not decode a {length} byte round {round} payload from {}: {source}",
+        .sender.map_or_else(|| "an unknown sender".to_string(), |sender| format!("participant {sender}"))
+    )]
+    Decode {
+        /// The round the message claims
+        round: crate::Round,
+        /// The length of the payload after the message header
+        length: usize,
+        /// The ordinal index the message claims as its sender, if readable
+        sender: Option<usize>,
+        /// Why decoding failed
+        source: postcard::Error,
+    },
+    /// A message was seen before within the time to live of a replay cache
+    #[error("replayed round {round} message from participant {sender}, first seen at {first_seen}")]
+    Replay {
+        /// The ordinal index the message claims as its sender
+        sender: usize,
+        /// The round the message claims
+        round: crate::Round,
+        /// When the message was first seen in unix seconds
+        first_seen: u64,
+    },
+    /// A [`Policy`](crate::Policy) vetoed a validated message
+    #[error("policy rejected the round {round} message from participant {sender}: {reason}")]
+    PolicyRejected {
+        /// The ordinal index of the sender
+        sender: usize,
+        /// The round of the message
+        round: crate::Round,
+        /// Why the policy rejected the message
+        reason: String,
+    },
+    /// A participant is running the DKG with a different threshold or limit
+    #[error("participant {ordinal} is using a different threshold or limit")]
+    ParameterMismatch {
+        /// The ordinal index of the participant
+        ordinal: usize,
+    },
+    /// Error during JSON serialization/deserialization.
+    #[cfg(feature = "json")]
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 impl From<vsss_rs::Error> for Error {
@@ -0,0 +1,236 @@
+use super::*;
+use aes::Aes128;
+use bls12_381_plus::{G1Projective, Scalar};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use elliptic_curve::group::GroupEncoding;
+use rand_core::Rng;
+use serde::{Deserialize, Serialize};
+use sha2_010::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+/// The PBKDF2 iteration count used for new EIP-2335 keystores
+pub const EIP2335_PBKDF2_ROUNDS: u32 = 262_144;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Keystore {
+    crypto: KeystoreCrypto,
+    description: String,
+    pubkey: String,
+    path: String,
+    uuid: String,
+    version: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub(crate) struct KeystoreCrypto {
+    kdf: KeystoreModule<Pbkdf2Params>,
+    checksum: KeystoreModule<EmptyParams>,
+    cipher: KeystoreModule<CipherParams>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct KeystoreModule<P> {
+    function: String,
+    params: P,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct Pbkdf2Params {
+    dklen: u32,
+    c: u32,
+    prf: String,
+    salt: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct EmptyParams {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct CipherParams {
+    iv: String,
+}
+
+impl DkgOutput<G1Projective> {
+    /// The group public key in the 48 byte compressed format used by ETH2
+    pub fn eth2_public_key(&self) -> [u8; 48] {
+        eth2_point(&self.public_key.0)
+    }
+
+    /// The public key of this share in the 48 byte ETH2 format
+    pub fn eth2_share_public_key(&self) -> [u8; 48] {
+        eth2_point(&(self.message_generator * self.secret_share.value.0))
+    }
+
+    /// Encrypt this share as an EIP-2335 keystore with PBKDF2 and AES-128-CTR.
+    ///
+    /// `path` is the EIP-2334 derivation path to record or empty when the
+    /// share wasn't derived from a seed, which is the case for DKG shares.
+    pub fn eip2335_keystore(&self, password: &str, path: &str) -> DkgResult<String> {
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        let mut uuid = [0u8; 16];
+        let mut rng = rand::rng();
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+        rng.fill_bytes(&mut uuid);
+
+        let crypto = encrypt_keystore(
+            &self.secret_share.value.0.to_be_bytes(),
+            password,
+            &salt,
+            &iv,
+            EIP2335_PBKDF2_ROUNDS,
+        );
+        let keystore = Keystore {
+            crypto,
+            description: format!("frost-dkg key {}", self.key_id()),
+            pubkey: hex::encode(self.eth2_share_public_key()),
+            path: path.to_string(),
+            uuid: uuid_v4(uuid),
+            version: 4,
+        };
+        Ok(serde_json::to_string(&keystore)?)
+    }
+}
+
+/// Decrypt the secret key in an EIP-2335 keystore.
+///
+/// Only keystores using PBKDF2 with HMAC-SHA256 and AES-128-CTR are supported.
+pub fn decrypt_eip2335_keystore(keystore: &str, password: &str) -> DkgResult<Scalar> {
+    let keystore: Keystore = serde_json::from_str(keystore)?;
+    let crypto = &keystore.crypto;
+    if crypto.kdf.function != "pbkdf2"
+        || crypto.kdf.params.prf != "hmac-sha256"
+        || crypto.kdf.params.dklen != 32
+        || crypto.checksum.function != "sha256"
+        || crypto.cipher.function != "aes-128-ctr"
+    {
+        return Err(Error::Encoding(
+            "Unsupported keystore kdf, checksum or cipher".to_string(),
+        ));
+    }
+    let decode = |field: &str, value: &str| {
+        hex::decode(value).map_err(|e| Error::Encoding(format!("Invalid keystore {field}: {e}")))
+    };
+    let salt = decode("salt", &crypto.kdf.params.salt)?;
+    let iv = decode("iv", &crypto.cipher.params.iv)?;
+    let mut message = decode("cipher message", &crypto.cipher.message)?;
+    let checksum = decode("checksum", &crypto.checksum.message)?;
+    if iv.len() != 16 || message.len() != 32 {
+        return Err(Error::Encoding(
+            "Invalid keystore iv or cipher message length".to_string(),
+        ));
+    }
+
+    let key = derive_key(password, &salt, crypto.kdf.params.c);
+    if keystore_checksum(&key, &message).as_slice() != checksum.as_slice() {
+        return Err(Error::Encoding(
+            "Keystore checksum does not match, the password is wrong".to_string(),
+        ));
+    }
+    Aes128Ctr::new(key[..16].into(), iv.as_slice().into()).apply_keystream(&mut message);
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&message);
+    Option::<Scalar>::from(Scalar::from_be_bytes(&bytes))
+        .ok_or_else(|| Error::Encoding("Keystore secret is not a valid scalar".to_string()))
+}
+
+pub(crate) fn encrypt_keystore(
+    secret: &[u8; 32],
+    password: &str,
+    salt: &[u8; 32],
+    iv: &[u8; 16],
+    rounds: u32,
+) -> KeystoreCrypto {
+    let key = derive_key(password, salt, rounds);
+    let mut message = *secret;
+    Aes128Ctr::new(key[..16].into(), iv.into()).apply_keystream(&mut message);
+    KeystoreCrypto {
+        kdf: KeystoreModule {
+            function: "pbkdf2".to_string(),
+            params: Pbkdf2Params {
+                dklen: 32,
+                c: rounds,
+                prf: "hmac-sha256".to_string(),
+                salt: hex::encode(salt),
+            },
+            message: String::new(),
+        },
+        checksum: KeystoreModule {
+            function: "sha256".to_string(),
+            params: EmptyParams {},
+            message: hex::encode(keystore_checksum(&key, &message)),
+        },
+        cipher: KeystoreModule {
+            function: "aes-128-ctr".to_string(),
+            params: CipherParams {
+                iv: hex::encode(iv),
+            },
+            message: hex::encode(message),
+        },
+    }
+}
+
+/// Normalize the password as EIP-2335 requires and derive the decryption key
+fn derive_key(password: &str, salt: &[u8], rounds: u32) -> [u8; 32] {
+    let password = password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1f | 0x7f..=0x9f))
+        .collect::<String>();
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, rounds, &mut key);
+    key
+}
+
+fn keystore_checksum(key: &[u8; 32], message: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(&key[16..]);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+fn eth2_point(point: &G1Projective) -> [u8; 48] {
+    let mut bytes = [0u8; 48];
+    bytes.copy_from_slice(point.to_bytes().as_ref());
+    bytes
+}
+
+fn uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    format!(
+        "{}-{}-{}-{}-{}",
+        hex::encode(&bytes[..4]),
+        hex::encode(&bytes[4..6]),
+        hex::encode(&bytes[6..8]),
+        hex::encode(&bytes[8..10]),
+        hex::encode(&bytes[10..])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip2335_known_answer() {
+        let secret =
+            hex::decode("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f")
+                .expect("valid hex");
+        let secret = <[u8; 32]>::try_from(secret.as_slice()).expect("32 bytes");
+        // The password normalizes to "testpassword🔑"
+        let crypto = encrypt_keystore(&secret, "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑", &[1u8; 32], &[2u8; 16], 16);
+        let crypto = serde_json::to_value(&crypto).expect("serialize keystore");
+        assert_eq!(
+            crypto["cipher"]["message"],
+            "82bb1cb5979517113bfa35118928f7edeb5077a1784ef765f5105a4ab023a862"
+        );
+        assert_eq!(
+            crypto["checksum"]["message"],
+            "0c1ac16118125d2f7d5d50a5f4f2a58d449581e7745c1adfaecf9028617f37e7"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The bytes at the start of every exchange file
+const EXCHANGE_MAGIC: &[u8; 8] = b"FROSTDKG";
+/// The version of the exchange file format
+const EXCHANGE_VERSION: u8 = 1;
+/// The extension of exchange files
+const EXCHANGE_EXTENSION: &str = "frost";
+/// The length of the header after the magic bytes
+const EXCHANGE_HEADER: usize = 8 + 1 + 1 + 2 + 2;
+/// The length of the trailing checksum
+const EXCHANGE_CHECKSUM: usize = 32;
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Write every message in `generator` to its own file in `dir` for an
+    /// air-gapped ceremony.
+    ///
+    /// Files are named `round<round>-from<sender>-to<receiver>.frost` by
+    /// ordinal index and carry a checksum. Returns the paths written.
+    pub fn write_outbox<P: AsRef<Path>>(
+        &self,
+        generator: &RoundOutputGenerator<G>,
+        dir: P,
+    ) -> DkgResult<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        let mut paths = Vec::new();
+        for output in generator.iter() {
+            let Some(&round) = output.data.first() else {
+                return Err(Error::Round("Empty payload to write".to_string()));
+            };
+            let header = ExchangeHeader {
+                round,
+                sender_ordinal: self.ordinal,
+                dst_ordinal: output.dst_ordinal,
+            };
+            let path = dir.join(header.file_name());
+            // Written to a temporary file first so a reader never sees half a message
+            let partial = path.with_extension("partial");
+            fs::write(&partial, header.encode(&output.data)?)?;
+            fs::rename(&partial, &path)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Receive the messages in `dir` written by [`Participant::write_outbox`]
+    /// for this participant.
+    ///
+    /// Messages from senders that were already received are skipped so the
+    /// same directory can be read again after every round. Returns the
+    /// paths received.
+    pub fn read_inbox<P: AsRef<Path>>(&mut self, dir: P) -> DkgResult<Vec<PathBuf>> {
+        let mut inbox = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(header) = ExchangeHeader::from_path(&path) else {
+                continue;
+            };
+            if header.dst_ordinal == self.ordinal && header.sender_ordinal != self.ordinal {
+                inbox.push((header, path));
+            }
+        }
+        inbox.sort_by_key(|(header, _)| (header.round, header.sender_ordinal));
+
+        let mut received = Vec::new();
+        for (header, path) in inbox {
+            let already_received = match Round::try_from(header.round) {
+                Ok(Round::One) => self
+                    .received_round1_data
+                    .contains_key(&header.sender_ordinal),
+                Ok(Round::Two) => self
+                    .received_round2_data
+                    .contains_key(&header.sender_ordinal),
+                Ok(Round::Three) => self.round1_openings.contains_key(&header.sender_ordinal),
+                _ => false,
+            };
+            if already_received {
+                continue;
+            }
+            let data = header.decode(&fs::read(&path)?).map_err(|e| {
+                Error::Encoding(format!("Invalid exchange file {}: {e}", path.display()))
+            })?;
+            self.receive(&data)?;
+            received.push(path);
+        }
+        Ok(received)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct ExchangeHeader {
+    round: u8,
+    sender_ordinal: usize,
+    dst_ordinal: usize,
+}
+
+impl ExchangeHeader {
+    fn file_name(&self) -> String {
+        format!(
+            "round{}-from{}-to{}.{EXCHANGE_EXTENSION}",
+            self.round, self.sender_ordinal, self.dst_ordinal
+        )
+    }
+
+    fn from_path(path: &Path) -> Option<Self> {
+        if path.extension()? != EXCHANGE_EXTENSION {
+            return None;
+        }
+        let stem = path.file_stem()?.to_str()?;
+        let (round, rest) = stem.strip_prefix("round")?.split_once("-from")?;
+        let (sender, dst) = rest.split_once("-to")?;
+        let header = Self {
+            round: round.parse().ok()?,
+            sender_ordinal: sender.parse().ok()?,
+            dst_ordinal: dst.parse().ok()?,
+        };
+        // Only the canonical spelling so every file maps to one message
+        (header.file_name() == path.file_name()?.to_str()?).then_some(header)
+    }
+
+    fn encode(&self, data: &[u8]) -> DkgResult<Vec<u8>> {
+        let ordinal = |ordinal: usize| {
+            u16::try_from(ordinal)
+                .map_err(|_| Error::Encoding(format!("Ordinal {ordinal} is too large")))
+        };
+        let mut bytes = Vec::with_capacity(EXCHANGE_HEADER + data.len() + EXCHANGE_CHECKSUM);
+        bytes.extend_from_slice(EXCHANGE_MAGIC);
+        bytes.push(EXCHANGE_VERSION);
+        bytes.push(self.round);
+        bytes.extend_from_slice(&ordinal(self.sender_ordinal)?.to_be_bytes());
+        bytes.extend_from_slice(&ordinal(self.dst_ordinal)?.to_be_bytes());
+        bytes.extend_from_slice(data);
+        let checksum = exchange_checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        Ok(bytes)
+    }
+
+    /// Check the file against this header, which came from its name, and
+    /// return the message
+    fn decode(&self, bytes: &[u8]) -> DkgResult<Vec<u8>> {
+        if bytes.len() <= EXCHANGE_HEADER + EXCHANGE_CHECKSUM {
+            return Err(Error::Encoding("File is too short".to_string()));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - EXCHANGE_CHECKSUM);
+        if exchange_checksum(body).as_slice() != checksum {
+            return Err(Error::Encoding("Checksum does not match".to_string()));
+        }
+        if &body[..8] != EXCHANGE_MAGIC || body[8] != EXCHANGE_VERSION {
+            return Err(Error::Encoding("Not a version 1 exchange file".to_string()));
+        }
+        let header = Self {
+            round: body[9],
+            sender_ordinal: u16::from_be_bytes([body[10], body[11]]) as usize,
+            dst_ordinal: u16::from_be_bytes([body[12], body[13]]) as usize,
+        };
+        let data = &body[EXCHANGE_HEADER..];
+        if header != *self || data[0] != self.round {
+            return Err(Error::Encoding(
+                "Contents do not match the file name".to_string(),
+            ));
+        }
+        Ok(data.to_vec())
+    }
+}
+
+fn exchange_checksum(bytes: &[u8]) -> [u8; EXCHANGE_CHECKSUM] {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Exchange File");
+    transcript.append_message(b"contents", bytes);
+    let mut checksum = [0u8; EXCHANGE_CHECKSUM];
+    transcript.challenge_bytes(b"checksum", &mut checksum);
+    checksum
+}
@@ -0,0 +1,176 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
+use std::ops::{Deref, DerefMut};
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
+
+/// Fixed topology secret participant type
+pub type FixedSecretParticipant<G, const THRESHOLD: usize, const LIMIT: usize> =
+    FixedParticipant<SecretParticipantImpl<G>, G, THRESHOLD, LIMIT>;
+
+/// Fixed topology refresh participant type
+pub type FixedRefreshParticipant<G, const THRESHOLD: usize, const LIMIT: usize> =
+    FixedParticipant<RefreshParticipantImpl<G>, G, THRESHOLD, LIMIT>;
+
+/// A [`Participant`] for a topology fixed at compile time.
+///
+/// `THRESHOLD` and `LIMIT` are checked when the program is built so a
+/// threshold below 2 or above the limit fails to compile, and the roster,
+/// commitments and verification shares cross the API as arrays whose sizes
+/// are known up front. The protocol itself runs on the wrapped participant
+/// which is reachable through [`Deref`].
+#[derive(Clone)]
+pub struct FixedParticipant<I, G, const THRESHOLD: usize, const LIMIT: usize>(Participant<I, G>)
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash;
+
+impl<I, G, const THRESHOLD: usize, const LIMIT: usize> FixedParticipant<I, G, THRESHOLD, LIMIT>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    const TOPOLOGY: () = assert!(
+        THRESHOLD >= 2 && THRESHOLD <= LIMIT,
+        "THRESHOLD must be at least 2 and at most LIMIT"
+    );
+
+    /// The number of feldman commitments in every round 1 broadcast
+    pub const ROUND1_COMMITMENTS: usize = THRESHOLD;
+    /// The number of round 2 payloads each participant sends
+    pub const ROUND2_RECIPIENTS: usize = LIMIT - 1;
+
+    /// The parameters for the roster `ids`
+    pub fn parameters(ids: &[IdentifierPrimeField<G::Scalar>; LIMIT]) -> Parameters<'_, G> {
+        let () = Self::TOPOLOGY;
+        Parameters::new(
+            NonZeroUsize::new(THRESHOLD).expect("checked at compile time"),
+            NonZeroUsize::new(LIMIT).expect("checked at compile time"),
+            None,
+            Some(vec![ParticipantIdGeneratorType::list(ids)]),
+        )
+    }
+
+    /// Unwrap the participant
+    pub fn into_inner(self) -> Participant<I, G> {
+        self.0
+    }
+
+    /// This participant's feldman commitments
+    pub fn feldman_commitments(&self) -> [G; THRESHOLD] {
+        self.0
+            .get_feldman_verifiers()
+            .try_into()
+            .expect("the threshold is fixed")
+    }
+
+    /// Every participant id by ordinal index
+    pub fn participant_ids(&self) -> [IdentifierPrimeField<G::Scalar>; LIMIT] {
+        std::array::from_fn(|ordinal| self.0.all_participant_ids[&ordinal])
+    }
+
+    /// The verification share of every participant by ordinal index once
+    /// the protocol completed with all of them
+    pub fn verification_shares(&self) -> Option<[NonIdentity<G>; LIMIT]> {
+        let output = self.0.get_dkg_output()?;
+        (0..LIMIT)
+            .map(|ordinal| output.verification_share(ordinal))
+            .collect::<Option<Vec<_>>>()?
+            .try_into()
+            .ok()
+    }
+}
+
+impl<G, const THRESHOLD: usize, const LIMIT: usize> FixedSecretParticipant<G, THRESHOLD, LIMIT>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a new secret participant for the roster `ids`
+    pub fn new_secret(
+        id: IdentifierPrimeField<G::Scalar>,
+        ids: &[IdentifierPrimeField<G::Scalar>; LIMIT],
+    ) -> DkgResult<Self> {
+        SecretParticipant::new_secret(id, &Self::parameters(ids)).map(Self)
+    }
+}
+
+impl<G, const THRESHOLD: usize, const LIMIT: usize> FixedRefreshParticipant<G, THRESHOLD, LIMIT>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a new refresh participant for the roster `ids`, see
+    /// [`Participant::new_refresh`]
+    pub fn new_refresh(
+        id: IdentifierPrimeField<G::Scalar>,
+        existing_share: Option<G::Scalar>,
+        ids: &[IdentifierPrimeField<G::Scalar>; LIMIT],
+    ) -> DkgResult<Self> {
+        RefreshParticipant::new_refresh(id, existing_share, &Self::parameters(ids)).map(Self)
+    }
+}
+
+impl<I, G, const THRESHOLD: usize, const LIMIT: usize> TryFrom<Participant<I, G>>
+    for FixedParticipant<I, G, THRESHOLD, LIMIT>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    type Error = Error;
+
+    fn try_from(participant: Participant<I, G>) -> DkgResult<Self> {
+        let () = Self::TOPOLOGY;
+        if participant.threshold != THRESHOLD || participant.limit != LIMIT {
+            return Err(Error::Initialization(format!(
+                "Participant is {}-of-{}, expected {THRESHOLD}-of-{LIMIT}",
+                participant.threshold, participant.limit
+            )));
+        }
+        Ok(Self(participant))
+    }
+}
+
+impl<I, G, const THRESHOLD: usize, const LIMIT: usize> Debug
+    for FixedParticipant<I, G, THRESHOLD, LIMIT>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<I, G, const THRESHOLD: usize, const LIMIT: usize> Deref
+    for FixedParticipant<I, G, THRESHOLD, LIMIT>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    type Target = Participant<I, G>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<I, G, const THRESHOLD: usize, const LIMIT: usize> DerefMut
+    for FixedParticipant<I, G, THRESHOLD, LIMIT>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+use elliptic_curve::PrimeField;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve_tools::SumOfProducts;
+use k256::{ProjectivePoint, Scalar};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
+
+/// A round 2 message sent from one participant to another
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct InteropDirectMessage {
+    /// The ordinal index of the sender
+    pub sender: usize,
+    /// The ordinal index of the receiver
+    pub receiver: usize,
+    /// The hex encoded wire bytes
+    pub data: String,
+}
+
+/// The expected result of the DKG for a single participant
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct InteropParticipantResult {
+    /// The hex encoded secret share value
+    pub secret_share: String,
+    /// The hex encoded verification share
+    pub verification_share: String,
+}
+
+/// A complete set of secp256k1 DKG vectors for matching against
+/// other implementations of the protocol.
+///
+/// All values are hex encoded so the vectors can be published as JSON
+/// and consumed by implementations in other languages.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct InteropVectors {
+    /// The threshold
+    pub threshold: usize,
+    /// The limit
+    pub limit: usize,
+    /// The hex encoded participant ids by ordinal index
+    pub participant_ids: Vec<String>,
+    /// The hex encoded round 1 broadcast wire bytes by ordinal index
+    pub round1_messages: Vec<String>,
+    /// The round 2 wire messages
+    pub round2_messages: Vec<InteropDirectMessage>,
+    /// The expected results by ordinal index
+    pub results: Vec<InteropParticipantResult>,
+    /// The hex encoded group public key
+    pub group_key: String,
+    /// The hex encoded state of each participant after round 1 by ordinal
+    /// index, see [`Participant::export_state`].
+    ///
+    /// The round 1 signatures use fresh nonces, so this crate restores the
+    /// participants from these to replay the recorded messages. Other
+    /// implementations only need the messages and results.
+    pub round1_states: Vec<String>,
+}
+
+impl InteropVectors {
+    /// Run a DKG with sequential participant ids and record every
+    /// message and result.
+    ///
+    /// The participant with id `i` shares the polynomial with the fixed
+    /// coefficients `100 * i + 1, 100 * i + 2, ...`, so the shares and the
+    /// group key are the same every time the vectors are generated.
+    pub fn generate(threshold: NonZeroUsize, limit: NonZeroUsize) -> DkgResult<Self> {
+        let parameters = Parameters::<ProjectivePoint>::new(threshold, limit, None, None);
+        let mut participants = (1..=limit.get() as u64)
+            .map(|id| {
+                let coefficients = (1..=threshold.get() as u64)
+                    .map(|j| Scalar::from(100 * id + j))
+                    .collect::<Vec<_>>();
+                SecretParticipant::<ProjectivePoint>::with_coefficients(
+                    IdentifierPrimeField(Scalar::from(id)),
+                    &parameters,
+                    &coefficients,
+                )
+            })
+            .collect::<DkgResult<Vec<_>>>()?;
+
+        let mut round1_messages = vec![String::new(); participants.len()];
+        let mut round1_states = Vec::with_capacity(participants.len());
+        let mut round2_messages = Vec::new();
+        for round in [Round::One, Round::Two, Round::Three] {
+            let mut outputs = Vec::new();
+            for participant in participants.iter_mut() {
+                let sender = participant.get_ordinal();
+                let generator = participant.run()?;
+                outputs.extend(generator.iter().map(|output| (sender, output)));
+                if round == Round::One {
+                    round1_states.push(hex::encode(participant.export_state()?));
+                }
+            }
+            for (sender, output) in outputs {
+                match round {
+                    Round::One => round1_messages[sender] = hex::encode(&output.data),
+                    _ => round2_messages.push(InteropDirectMessage {
+                        sender,
+                        receiver: output.dst_ordinal,
+                        data: hex::encode(&output.data),
+                    }),
+                }
+                participants[output.dst_ordinal].receive(&output.data)?;
+            }
+        }
+
+        let group_key = participants[0]
+            .get_public_key()
+            .ok_or_else(|| Error::Round("DKG did not produce a group key".to_string()))?;
+        let results = participants
+            .iter()
+            .map(|participant| {
+                let share = participant.secret_share.value.0;
+                InteropParticipantResult {
+                    secret_share: hex::encode(share.to_repr()),
+                    verification_share: hex::encode(
+                        (participant.message_generator * share).to_bytes(),
+                    ),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            threshold: threshold.get(),
+            limit: limit.get(),
+            participant_ids: participants
+                .iter()
+                .map(|participant| hex::encode(participant.id.0.to_repr()))
+                .collect(),
+            round1_messages,
+            round2_messages,
+            results,
+            group_key: hex::encode(group_key.to_bytes()),
+            round1_states,
+        })
+    }
+
+    /// Check the vectors are internally consistent by decoding every
+    /// wire message and recomputing the shares and group key
+    pub fn verify(&self) -> DkgResult<()> {
+        let threshold = NonZeroUsize::new(self.threshold)
+            .ok_or_else(|| Error::Pvss("Threshold cannot be zero".to_string()))?;
+        let limit = NonZeroUsize::new(self.limit)
+            .ok_or_else(|| Error::Pvss("Limit cannot be zero".to_string()))?;
+        if self.participant_ids.len() != self.limit
+            || self.round1_messages.len() != self.limit
+            || self.results.len() != self.limit
+        {
+            return Err(Error::Pvss(
+                "Vectors do not contain an entry for every participant".to_string(),
+            ));
+        }
+        let ids = self
+            .participant_ids
+            .iter()
+            .map(|id| decode_scalar(id).map(IdentifierPrimeField))
+            .collect::<DkgResult<Vec<_>>>()?;
+        let parameters = Parameters::<ProjectivePoint>::new(
+            threshold,
+            limit,
+            None,
+            Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+        );
+
+        let round1_data = self
+            .round1_messages
+            .iter()
+            .map(|message| decode_message::<Round1Data<ProjectivePoint>>(message, Round::One))
+            .collect::<DkgResult<Vec<_>>>()?;
+        let group_key = decode_point(&self.group_key)?;
+        publicly_verify_dkg_results(&round1_data, &parameters, group_key)?;
+
+        for message in &self.round2_messages {
+            let round2_data = decode_message::<Round2Data<Scalar>>(&message.data, Round::Two)?;
+            let (Some(sender_data), Some(receiver_id)) =
+                (round1_data.get(message.sender), ids.get(message.receiver))
+            else {
+                return Err(Error::Pvss(format!(
+                    "Round 2 message from {} to {} has an unknown participant",
+                    message.sender, message.receiver
+                )));
+            };
+            if round2_data.sender_ordinal != message.sender
+                || round2_data.secret_share.identifier != *receiver_id
+            {
+                return Err(Error::Pvss(format!(
+                    "Round 2 message from {} to {} is misaddressed",
+                    message.sender, message.receiver
+                )));
+            }
+            let mut power = Scalar::ONE;
+            let mut input = Vec::with_capacity(self.threshold);
+            for commitment in &sender_data.feldman_commitments {
+                input.push((power, *commitment));
+                power *= receiver_id.0;
+            }
+            if ProjectivePoint::GENERATOR * round2_data.secret_share.value.0
+                != ProjectivePoint::sum_of_products(&input)
+            {
+                return Err(Error::Pvss(format!(
+                    "Round 2 share from {} to {} does not verify",
+                    message.sender, message.receiver
+                )));
+            }
+        }
+
+        for (ordinal, (result, id)) in self.results.iter().zip(&ids).enumerate() {
+            let mut input = Vec::with_capacity(self.threshold * round1_data.len());
+            for data in &round1_data {
+                let mut power = Scalar::ONE;
+                for commitment in &data.feldman_commitments {
+                    input.push((power, *commitment));
+                    power *= id.0;
+                }
+            }
+            let verification_share = ProjectivePoint::sum_of_products(&input);
+            let expected_share = decode_scalar(&result.secret_share)?;
+            if decode_point(&result.verification_share)? != verification_share
+                || ProjectivePoint::GENERATOR * expected_share != verification_share
+            {
+                return Err(Error::Pvss(format!(
+                    "Result for {} does not match the round 1 commitments",
+                    ordinal
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode_message<T: for<'de> Deserialize<'de>>(message: &str, round: Round) -> DkgResult<T> {
+    let bytes = hex::decode(message).map_err(|e| Error::Pvss(format!("Invalid hex: {e}")))?;
+    match bytes.first() {
+        Some(tag) if *tag == u8::from(round) => {
+            let payload = message_payload::<ProjectivePoint>(&bytes)?;
+            Ok(postcard::from_bytes(payload)?)
+        }
+        _ => Err(Error::Pvss(format!(
+            "Message is not a round {round} payload"
+        ))),
+    }
+}
+
+fn decode_scalar(value: &str) -> DkgResult<Scalar> {
+    let bytes = hex::decode(value).map_err(|e| Error::Pvss(format!("Invalid hex: {e}")))?;
+    let mut repr = <Scalar as PrimeField>::Repr::default();
+    if bytes.len() != repr.len() {
+        return Err(Error::Pvss("Invalid scalar length".to_string()));
+    }
+    repr.copy_from_slice(&bytes);
+    Option::from(Scalar::from_repr(repr)).ok_or_else(|| Error::Pvss("Invalid scalar".to_string()))
+}
+
+fn decode_point(value: &str) -> DkgResult<ProjectivePoint> {
+    let bytes = hex::decode(value).map_err(|e| Error::Pvss(format!("Invalid hex: {e}")))?;
+    let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+    if bytes.len() != repr.len() {
+        return Err(Error::Pvss("Invalid point length".to_string()));
+    }
+    repr.copy_from_slice(&bytes);
+    Option::from(ProjectivePoint::from_bytes(&repr))
+        .ok_or_else(|| Error::Pvss("Invalid point".to_string()))
+}
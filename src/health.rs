@@ -0,0 +1,171 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A proof by one shareholder that it still holds its secret share,
+/// answering a health check `challenge`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShareProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder
+    pub(crate) ordinal: usize,
+    /// The challenge the proof answers
+    pub(crate) challenge: Vec<u8>,
+    /// The schnorr proof of knowledge of the share
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> ShareProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder that made the proof
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The challenge the proof answers
+    pub fn challenge(&self) -> &[u8] {
+        &self.challenge
+    }
+}
+
+/// The result of a health check of every shareholder
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The identifier of the checked group key
+    pub key_id: KeyId,
+    /// The threshold needed to use the group key
+    pub threshold: usize,
+    /// The shareholders that proved they hold their share
+    pub healthy: BTreeSet<usize>,
+    /// The shareholders that sent no proof
+    pub missing: BTreeSet<usize>,
+    /// The shareholders that sent an invalid proof and why
+    pub invalid: BTreeMap<usize, String>,
+}
+
+impl HealthReport {
+    /// Whether every shareholder proved it holds its share
+    pub fn is_healthy(&self) -> bool {
+        self.missing.is_empty() && self.invalid.is_empty()
+    }
+
+    /// Whether enough shareholders are healthy to use the group key
+    pub fn meets_threshold(&self) -> bool {
+        self.healthy.len() >= self.threshold
+    }
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Prove this participant still holds its share.
+    ///
+    /// `challenge` should be fresh for every health check so old proofs
+    /// cannot be replayed.
+    pub fn prove_share_possession(&self, challenge: &[u8]) -> DkgResult<ShareProof<G>> {
+        let verification_share = self.verification_share(self.ordinal).ok_or_else(|| {
+            Error::Signature("Missing the verification share of this shareholder".to_string())
+        })?;
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = self.message_generator * k;
+        let c = self.share_proof_challenge(self.ordinal, &verification_share.0, challenge, &r);
+        Ok(ShareProof {
+            ordinal: self.ordinal,
+            challenge: challenge.to_vec(),
+            signature: Signature {
+                r,
+                s: k + c * self.secret_share.value.0,
+            },
+        })
+    }
+
+    /// Check the share proofs for `challenge` against the verification
+    /// shares and report which shareholders are healthy
+    pub fn health_report(&self, challenge: &[u8], proofs: &[ShareProof<G>]) -> HealthReport {
+        let mut report = HealthReport {
+            key_id: self.key_id(),
+            threshold: self.threshold,
+            missing: self.verification_shares.keys().copied().collect(),
+            ..Default::default()
+        };
+        for proof in proofs {
+            if !report.missing.remove(&proof.ordinal) {
+                if !self.verification_shares.contains_key(&proof.ordinal) {
+                    report
+                        .invalid
+                        .insert(proof.ordinal, "Not a shareholder".to_string());
+                }
+                continue;
+            }
+            match self.verify_share_proof(challenge, proof) {
+                Ok(()) => {
+                    report.healthy.insert(proof.ordinal);
+                }
+                Err(e) => {
+                    report.invalid.insert(proof.ordinal, e.to_string());
+                }
+            }
+        }
+        report
+    }
+
+    fn verify_share_proof(&self, challenge: &[u8], proof: &ShareProof<G>) -> DkgResult<()> {
+        if proof.challenge != challenge {
+            return Err(Error::Signature(
+                "Share proof answers a different challenge".to_string(),
+            ));
+        }
+        let verification_share = self.verification_shares[&proof.ordinal];
+        let c = self.share_proof_challenge(
+            proof.ordinal,
+            &verification_share.0,
+            challenge,
+            &proof.signature.r,
+        );
+        if self.message_generator * proof.signature.s
+            != proof.signature.r + verification_share.0 * c
+        {
+            return Err(Error::Signature(format!(
+                "Invalid share proof from shareholder {}",
+                proof.ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    fn share_proof_challenge(
+        &self,
+        ordinal: usize,
+        verification_share: &G,
+        challenge: &[u8],
+        r: &G,
+    ) -> G::Scalar {
+        let mut input = (ordinal as u64).to_be_bytes().to_vec();
+        input.extend_from_slice(self.public_key.0.to_bytes().as_ref());
+        input.extend_from_slice(verification_share.to_bytes().as_ref());
+        input.extend_from_slice(&(self.transcript_hash.len() as u64).to_be_bytes());
+        input.extend_from_slice(&self.transcript_hash);
+        input.extend_from_slice(&(challenge.len() as u64).to_be_bytes());
+        input.extend_from_slice(challenge);
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-HEALTH");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+}
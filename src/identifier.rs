@@ -0,0 +1,89 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use vsss_rs::IdentifierPrimeField;
+
+/// The domain separation tag for identifiers hashed to a scalar
+const IDENTIFIER_DST: &[u8] = b"FROST-DKG-V1-IDENTIFIER";
+
+/// An application level identity for a participant.
+///
+/// Each identifier maps to a unique [`IdentifierPrimeField`] with
+/// [`ParticipantIdentifier::to_identifier`] so applications can name
+/// participants without handling raw scalars.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum ParticipantIdentifier {
+    /// A non-zero integer used directly as the participant id
+    Integer(u64),
+    /// A name hashed to the participant id
+    Name(String),
+    /// A UUID hashed to the participant id
+    Uuid([u8; 16]),
+}
+
+impl Display for ParticipantIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Uuid(uuid) => write!(
+                f,
+                "{}-{}-{}-{}-{}",
+                hex::encode(&uuid[..4]),
+                hex::encode(&uuid[4..6]),
+                hex::encode(&uuid[6..8]),
+                hex::encode(&uuid[8..10]),
+                hex::encode(&uuid[10..])
+            ),
+        }
+    }
+}
+
+impl From<u64> for ParticipantIdentifier {
+    fn from(n: u64) -> Self {
+        Self::Integer(n)
+    }
+}
+
+impl From<&str> for ParticipantIdentifier {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for ParticipantIdentifier {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<[u8; 16]> for ParticipantIdentifier {
+    fn from(uuid: [u8; 16]) -> Self {
+        Self::Uuid(uuid)
+    }
+}
+
+impl ParticipantIdentifier {
+    /// The participant id used by the protocol for this identifier
+    pub fn to_identifier<F: ScalarHash>(&self) -> DkgResult<IdentifierPrimeField<F>> {
+        let id = match self {
+            Self::Integer(n) => F::from(*n),
+            Self::Name(name) => {
+                let mut input = vec![1u8];
+                input.extend_from_slice(name.as_bytes());
+                F::hash_to_scalar_with_dst(&input, IDENTIFIER_DST)
+            }
+            Self::Uuid(uuid) => {
+                let mut input = vec![2u8];
+                input.extend_from_slice(uuid);
+                F::hash_to_scalar_with_dst(&input, IDENTIFIER_DST)
+            }
+        };
+        if id.is_zero().into() {
+            return Err(Error::Initialization(format!(
+                "Participant identifier '{self}' maps to zero"
+            )));
+        }
+        Ok(IdentifierPrimeField(id))
+    }
+}
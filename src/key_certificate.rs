@@ -0,0 +1,227 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::{Field, PrimeField};
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use vsss_rs::IdentifierPrimeField;
+
+/// A statement by one shareholder that it holds a share of the group key
+/// produced by a DKG, signed with that share.
+///
+/// A quorum of certificates checked with [`verify_key_certificates`] proves
+/// to a relying party that the committee generated the key.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeyCertificate<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder
+    pub(crate) ordinal: usize,
+    /// The shareholder's ID
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) id: IdentifierPrimeField<G::Scalar>,
+    /// The threshold needed to use the group key
+    pub(crate) threshold: usize,
+    /// The generator the key was computed with
+    #[serde(with = "group")]
+    pub(crate) message_generator: G,
+    /// The group public key
+    #[serde(with = "group")]
+    pub(crate) public_key: G,
+    /// The shareholder's verification share
+    #[serde(with = "group")]
+    pub(crate) verification_share: G,
+    /// The digest of the shareholder ids
+    pub(crate) roster_digest: [u8; 32],
+    /// The final transcript hash of the DKG
+    pub(crate) transcript_hash: Vec<u8>,
+    /// The signature by the shareholder's secret share
+    #[serde(bound(
+        serialize = "Signature<G>: Serialize",
+        deserialize = "Signature<G>: Deserialize<'de>"
+    ))]
+    pub(crate) signature: Signature<G>,
+}
+
+impl<G> KeyCertificate<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder that signed the certificate
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The identifier of the shareholder that signed the certificate
+    pub fn id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.id
+    }
+
+    /// The threshold needed to use the group key
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The generator the key was computed with
+    pub fn message_generator(&self) -> G {
+        self.message_generator
+    }
+
+    /// The certified group public key
+    pub fn public_key(&self) -> G {
+        self.public_key
+    }
+
+    /// The verification share of the shareholder
+    pub fn verification_share(&self) -> G {
+        self.verification_share
+    }
+
+    /// The digest of the shareholder ids
+    pub fn roster_digest(&self) -> [u8; 32] {
+        self.roster_digest
+    }
+
+    /// The final transcript hash of the DKG
+    pub fn transcript_hash(&self) -> &[u8] {
+        &self.transcript_hash
+    }
+
+    /// Check the signature by the shareholder's verification share
+    pub fn verify(&self) -> DkgResult<()> {
+        if self.public_key.is_identity().into()
+            || self.verification_share.is_identity().into()
+            || self.message_generator.is_identity().into()
+        {
+            return Err(Error::Signature(format!(
+                "Key certificate from shareholder {} contains the identity",
+                self.ordinal
+            )));
+        }
+        let c = self.challenge(&self.signature.r);
+        if self.message_generator * self.signature.s
+            != self.signature.r + self.verification_share * c
+        {
+            return Err(Error::Signature(format!(
+                "Invalid key certificate signature from shareholder {}",
+                self.ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    fn challenge(&self, r: &G) -> G::Scalar {
+        let mut input = (self.ordinal as u64).to_be_bytes().to_vec();
+        input.extend_from_slice(self.id.0.to_repr().as_ref());
+        input.extend_from_slice(&(self.threshold as u64).to_be_bytes());
+        input.extend_from_slice(self.message_generator.to_bytes().as_ref());
+        input.extend_from_slice(self.public_key.to_bytes().as_ref());
+        input.extend_from_slice(self.verification_share.to_bytes().as_ref());
+        input.extend_from_slice(&self.roster_digest);
+        input.extend_from_slice(&(self.transcript_hash.len() as u64).to_be_bytes());
+        input.extend_from_slice(&self.transcript_hash);
+        input.extend_from_slice(r.to_bytes().as_ref());
+        let mut dst = group_dst::<G>();
+        dst.extend_from_slice(b"-KEYCERT");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Sign a certificate for the group key with this secret share
+    pub fn key_certificate(&self) -> DkgResult<KeyCertificate<G>> {
+        let verification_share = self.verification_share(self.ordinal).ok_or_else(|| {
+            Error::Signature("Missing the verification share of this shareholder".to_string())
+        })?;
+        let mut certificate = KeyCertificate {
+            ordinal: self.ordinal,
+            id: self.id,
+            threshold: self.threshold,
+            message_generator: self.message_generator,
+            public_key: self.public_key.0,
+            verification_share: verification_share.0,
+            roster_digest: shareholder_digest(&self.participant_ids),
+            transcript_hash: self.transcript_hash.clone(),
+            signature: Signature::default(),
+        };
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = self.message_generator * k;
+        let c = certificate.challenge(&r);
+        certificate.signature = Signature {
+            r,
+            s: k + c * self.secret_share.value.0,
+        };
+        Ok(certificate)
+    }
+}
+
+/// Verify that a quorum of shareholders certified the same group key.
+///
+/// Every certificate must carry a valid signature, come from a distinct
+/// shareholder and agree on the key, roster and transcript. At least
+/// `threshold` certificates are needed and their verification shares must
+/// interpolate to the group key. Returns the certified group key.
+pub fn verify_key_certificates<G>(certificates: &[KeyCertificate<G>]) -> DkgResult<NonIdentity<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let Some(first) = certificates.first() else {
+        return Err(Error::Signature(
+            "No key certificates to verify".to_string(),
+        ));
+    };
+    let mut ordinals = BTreeSet::new();
+    for certificate in certificates {
+        certificate.verify()?;
+        if certificate.threshold != first.threshold
+            || certificate.message_generator != first.message_generator
+            || certificate.public_key != first.public_key
+            || certificate.roster_digest != first.roster_digest
+            || certificate.transcript_hash != first.transcript_hash
+        {
+            return Err(Error::Signature(format!(
+                "Key certificate from shareholder {} is for a different key",
+                certificate.ordinal
+            )));
+        }
+        if !ordinals.insert(certificate.ordinal) {
+            return Err(Error::Signature(format!(
+                "Duplicate key certificate from shareholder {}",
+                certificate.ordinal
+            )));
+        }
+    }
+    if certificates.len() < first.threshold {
+        return Err(Error::Signature(format!(
+            "Need {} key certificates but only {} were given",
+            first.threshold,
+            certificates.len()
+        )));
+    }
+
+    let ids = certificates.iter().map(|c| c.id).collect::<Vec<_>>();
+    let terms = lagrange_coefficients(&ids, G::Scalar::ZERO)
+        .map_err(|e| Error::Signature(e.to_string()))?
+        .into_iter()
+        .zip(certificates.iter().map(|c| c.verification_share))
+        .collect::<Vec<_>>();
+    if <G as SumOfProducts>::sum_of_products(&terms) != first.public_key {
+        return Err(Error::Signature(
+            "Verification shares do not interpolate to the group key".to_string(),
+        ));
+    }
+    NonIdentity::new(first.public_key)
+        .ok_or_else(|| Error::Signature("Group key is the identity".to_string()))
+}
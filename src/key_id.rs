@@ -0,0 +1,153 @@
+use super::*;
+use elliptic_curve::Group;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::de::Error as DError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use vsss_rs::IdentifierPrimeField;
+
+/// The length of a [`KeyId`] in bytes
+pub const KEY_ID_LENGTH: usize = 32;
+
+/// A stable identifier of a group key for systems that track many of them.
+///
+/// It is a merlin transcript labelled `Frost DKG - Key Id` over the
+/// [`ciphersuite_id`], the encoded group public key and the
+/// [`shareholder_digest`] of the roster, so every shareholder of a key
+/// computes the same id and the same key held by another roster gets a
+/// different one. `{}` displays hex and [`FromStr`] accepts hex.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyId(pub(crate) [u8; KEY_ID_LENGTH]);
+
+impl KeyId {
+    /// Compute the id of `public_key` held by the roster with
+    /// `roster_digest`
+    pub fn compute<G>(public_key: &G, roster_digest: &[u8; 32]) -> Self
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: ScalarHash,
+    {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Key Id");
+        transcript.append_message(b"ciphersuite", &ciphersuite_id::<G>());
+        transcript.append_message(b"public key", public_key.to_bytes().as_ref());
+        transcript.append_message(b"roster", roster_digest);
+        let mut id = [0u8; KEY_ID_LENGTH];
+        transcript.challenge_bytes(b"key id", &mut id);
+        Self(id)
+    }
+
+    /// The raw bytes of the id
+    pub fn to_bytes(&self) -> [u8; KEY_ID_LENGTH] {
+        self.0
+    }
+
+    /// The hex encoding
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    /// Parse a hex encoded id
+    pub fn from_hex(s: &str) -> DkgResult<Self> {
+        let bytes = hex::decode(s).map_err(|e| Error::Encoding(format!("Invalid hex: {e}")))?;
+        let id = <[u8; KEY_ID_LENGTH]>::try_from(bytes.as_slice()).map_err(|_| {
+            Error::Encoding(format!(
+                "A key id is {KEY_ID_LENGTH} bytes but got {}",
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(id))
+    }
+}
+
+impl From<[u8; KEY_ID_LENGTH]> for KeyId {
+    fn from(id: [u8; KEY_ID_LENGTH]) -> Self {
+        Self(id)
+    }
+}
+
+impl Display for KeyId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl FromStr for KeyId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+/// Hex for human-readable formats and the raw bytes otherwise
+impl Serialize for KeyId {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            s.serialize_str(&self.to_hex())
+        } else {
+            self.0.serialize(s)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyId {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            Self::from_hex(&s).map_err(DError::custom)
+        } else {
+            <[u8; KEY_ID_LENGTH]>::deserialize(d).map(Self)
+        }
+    }
+}
+
+/// The digest of the shareholder ids by ordinal index that a [`KeyId`]
+/// and a [`KeyCertificate`] commit to
+pub fn shareholder_digest<F: ScalarHash>(
+    ids: &BTreeMap<usize, IdentifierPrimeField<F>>,
+) -> [u8; 32] {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Key Certificate Roster");
+    for (ordinal, id) in ids {
+        transcript.append_u64(b"ordinal", *ordinal as u64);
+        transcript.append_message(b"id", id.0.to_repr().as_ref());
+    }
+    let mut digest = [0u8; 32];
+    transcript.challenge_bytes(b"roster digest", &mut digest);
+    digest
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The stable identifier of the group key held by this roster
+    pub fn key_id(&self) -> KeyId {
+        KeyId::compute(
+            &self.public_key.0,
+            &shareholder_digest(&self.participant_ids),
+        )
+    }
+}
+
+impl<G> KeyCertificate<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The identifier of the certified group key, the same as
+    /// [`DkgOutput::key_id`] of every shareholder
+    pub fn key_id(&self) -> KeyId {
+        KeyId::compute(&self.public_key, &self.roster_digest)
+    }
+}
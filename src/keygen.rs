@@ -0,0 +1,44 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use rand_core::CryptoRng;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
+
+/// Run the whole protocol in-process and return the output of every
+/// participant in the order of `ids`.
+///
+/// Useful when a single machine needs a fresh threshold key without
+/// driving the rounds itself. `rng` is used for each participant's secret
+/// and polynomial while the proof nonces always come from the thread rng.
+pub fn generate_shares<G>(
+    threshold: NonZeroUsize,
+    limit: NonZeroUsize,
+    ids: &[IdentifierPrimeField<G::Scalar>],
+    rng: &mut impl CryptoRng,
+) -> DkgResult<Vec<DkgOutput<G>>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if ids.len() != limit.get() {
+        return Err(Error::Initialization(format!(
+            "Expected {} participant ids but got {}",
+            limit,
+            ids.len()
+        )));
+    }
+    let seq = vec![ParticipantIdGeneratorType::list(ids)];
+    let parameters = Parameters::<G>::new(threshold, limit, None, Some(seq));
+    let mut participants = ids
+        .iter()
+        .map(|id| {
+            let participant: Box<dyn AnyParticipant<G>> = Box::new(
+                SecretParticipant::<G>::new_secret_with_rng(*id, &parameters, &mut *rng)?,
+            );
+            Ok(participant)
+        })
+        .collect::<DkgResult<Vec<_>>>()?;
+    run_local_ceremony(&mut participants)
+}
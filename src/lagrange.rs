@@ -0,0 +1,145 @@
+use super::*;
+use elliptic_curve::PrimeField;
+use elliptic_curve::subtle::ConstantTimeEq;
+use vsss_rs::IdentifierPrimeField;
+
+/// The Lagrange coefficient of each of `ids` for interpolating at `at`,
+/// in the same order as `ids`.
+///
+/// All denominators are inverted together with Montgomery's trick and the
+/// products are accumulated without branching on the identifiers. Use
+/// `F::ZERO` for `at` to recover the secret from shares.
+///
+/// Returns an error if `ids` is empty or any identifier is zero or
+/// appears more than once.
+pub fn lagrange_coefficients<F: PrimeField>(
+    ids: &[IdentifierPrimeField<F>],
+    at: F,
+) -> DkgResult<Vec<F>> {
+    if ids.is_empty() {
+        return Err(Error::Initialization(
+            "Lagrange coefficients need at least one identifier".to_string(),
+        ));
+    }
+    if ids.iter().any(|id| id.0.is_zero().into()) {
+        return Err(Error::Initialization(
+            "Lagrange coefficient identifiers cannot be zero".to_string(),
+        ));
+    }
+
+    let mut numerators = Vec::with_capacity(ids.len());
+    let mut denominators = Vec::with_capacity(ids.len());
+    for (i, x_i) in ids.iter().enumerate() {
+        let mut num = F::ONE;
+        let mut den = F::ONE;
+        for (j, x_j) in ids.iter().enumerate() {
+            let is_self = i.ct_eq(&j);
+            num *= F::conditional_select(&(at - x_j.0), &F::ONE, is_self);
+            den *= F::conditional_select(&(x_i.0 - x_j.0), &F::ONE, is_self);
+        }
+        numerators.push(num);
+        denominators.push(den);
+    }
+
+    // Montgomery's trick: invert the product once and unwind it
+    let mut prefixes = Vec::with_capacity(denominators.len());
+    let mut acc = F::ONE;
+    for den in &denominators {
+        prefixes.push(acc);
+        acc *= den;
+    }
+    let mut inv = Option::<F>::from(acc.invert()).ok_or_else(|| {
+        Error::Initialization("Lagrange coefficient identifiers must be unique".to_string())
+    })?;
+    let mut coefficients = vec![F::ZERO; ids.len()];
+    for i in (0..denominators.len()).rev() {
+        coefficients[i] = numerators[i] * inv * prefixes[i];
+        inv *= denominators[i];
+    }
+    Ok(coefficients)
+}
+
+/// Lagrange coefficients at zero for a fixed set of share identifiers.
+///
+/// Every coefficient is computed once with [`lagrange_coefficients`] so
+/// resharing a large old committee with [`SecretParticipant::with_lagrange_table`]
+/// doesn't repeat the work for each share.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeTable<F: PrimeField> {
+    pub(crate) coefficients: Vec<(IdentifierPrimeField<F>, F)>,
+}
+
+impl<F: PrimeField> LagrangeTable<F> {
+    /// Compute the coefficients for `shares_ids`.
+    ///
+    /// Returns an error if any identifier is zero or appears more than once.
+    pub fn new(shares_ids: &[IdentifierPrimeField<F>]) -> DkgResult<Self> {
+        let coefficients = lagrange_coefficients(shares_ids, F::ZERO)?;
+        Ok(Self {
+            coefficients: shares_ids.iter().copied().zip(coefficients).collect(),
+        })
+    }
+
+    /// The coefficient for `id` or `None` if it isn't in the table
+    pub fn coefficient(&self, id: &IdentifierPrimeField<F>) -> Option<F> {
+        self.coefficients
+            .iter()
+            .find(|(x, _)| x == id)
+            .map(|(_, coefficient)| *coefficient)
+    }
+
+    /// The share identifiers covered by this table
+    pub fn shares_ids(&self) -> impl Iterator<Item = &IdentifierPrimeField<F>> {
+        self.coefficients.iter().map(|(id, _)| id)
+    }
+
+    /// The number of share identifiers in this table
+    pub fn len(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Whether the table is empty
+    pub fn is_empty(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lagrange_table() {
+        let ids = (1..=5)
+            .map(|id| IdentifierPrimeField(k256::Scalar::from(id as u64 * 7)))
+            .collect::<Vec<_>>();
+        let table = LagrangeTable::new(&ids).expect("create lagrange table");
+        assert_eq!(table.len(), ids.len());
+        let lagrange = |x_i: k256::Scalar, at: k256::Scalar| {
+            ids.iter()
+                .filter(|x_j| x_j.0 != x_i)
+                .fold(k256::Scalar::ONE, |acc, x_j| {
+                    acc * (at - x_j.0) * (x_i - x_j.0).invert().expect("distinct ids")
+                })
+        };
+        for id in &ids {
+            assert_eq!(
+                table.coefficient(id),
+                Some(lagrange(id.0, k256::Scalar::ZERO))
+            );
+        }
+        let at = k256::Scalar::from(3u64);
+        let coefficients = lagrange_coefficients(&ids, at).expect("compute coefficients");
+        for (id, coefficient) in ids.iter().zip(coefficients) {
+            assert_eq!(coefficient, lagrange(id.0, at));
+        }
+        assert_eq!(
+            table.coefficient(&IdentifierPrimeField(k256::Scalar::from(2u64))),
+            None
+        );
+
+        let duplicates = [ids[0], ids[1], ids[0]];
+        assert!(LagrangeTable::new(&duplicates).is_err());
+        assert!(LagrangeTable::new(&[IdentifierPrimeField(k256::Scalar::ZERO)]).is_err());
+    }
+}
@@ -5,6 +5,12 @@
 //! Frost Distributed Key Generation Algorithm.
 //!
 //! The full paper can be found [here](https://eprint.iacr.org/2020/852.pdf).
+//!
+//! Relays, indexers and coordinators that only route messages can disable
+//! the default `participant` feature and enable just the curves they need.
+//! This keeps the message types like [`Round1Data`] and [`Round2Data`] and
+//! the framing helpers like [`decode_round1_message`] and [`chunk_message`]
+//! but drops the participant state machine.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(
@@ -18,61 +24,254 @@
 )]
 #![deny(clippy::unwrap_used)]
 
+#[cfg(feature = "participant")]
+mod abort;
+#[cfg(feature = "participant")]
+mod ack;
+#[cfg(feature = "tokio")]
+mod actor;
+#[cfg(feature = "arkworks")]
+mod arkworks;
+#[cfg(all(feature = "json", feature = "participant"))]
+mod audit;
+#[cfg(feature = "age")]
+mod backup;
+#[cfg(feature = "participant")]
+mod beacon;
+#[cfg(all(feature = "bls12_381_plus", feature = "participant"))]
+mod bls;
+#[cfg(feature = "tokio")]
+mod ceremony;
+#[cfg(feature = "x509")]
+mod certificate;
+mod chunk;
+#[cfg(feature = "participant")]
+pub mod ciphersuites;
+#[cfg(feature = "participant")]
+mod commit_reveal;
+mod compact;
+#[cfg(feature = "participant")]
+pub mod conformance;
+#[cfg(feature = "participant")]
+mod context;
+#[cfg(feature = "participant")]
+mod continuity;
 mod data;
+#[cfg(feature = "participant")]
+mod dealing;
+mod display;
+#[cfg(feature = "participant")]
+mod dispute;
+#[cfg(feature = "participant")]
+mod dual;
 mod error;
+#[cfg(feature = "eth2")]
+mod eth2;
+#[cfg(feature = "participant")]
+mod exchange;
+#[cfg(feature = "participant")]
+mod fixed;
+#[cfg(feature = "fixtures")]
+mod fixtures;
+#[cfg(feature = "participant")]
+mod health;
+mod identifier;
+#[cfg(feature = "participant")]
+mod key_certificate;
+#[cfg(feature = "participant")]
+mod key_id;
+#[cfg(feature = "participant")]
+mod keygen;
+mod lagrange;
+#[cfg(feature = "participant")]
+mod locked;
+#[cfg(feature = "participant")]
+mod missing;
+#[cfg(feature = "tokio")]
+mod network;
+#[cfg(feature = "participant")]
+mod output;
+#[cfg(feature = "participant")]
 mod parameters;
+#[cfg(feature = "participant")]
 mod participant;
+#[cfg(feature = "participant")]
+mod pedersen;
+#[cfg(feature = "participant")]
+mod policy;
+pub mod prelude;
+#[cfg(feature = "participant")]
+mod preprocess;
+#[cfg(feature = "participant")]
+mod quarantine;
+#[cfg(feature = "participant")]
+mod quickstart;
+#[cfg(feature = "participant")]
+mod replay;
+#[cfg(feature = "participant")]
+mod resume;
+#[cfg(feature = "participant")]
+mod robust;
+#[cfg(feature = "participant")]
+mod roster;
+#[cfg(feature = "participant")]
+mod sas;
+#[cfg(feature = "participant")]
+mod schedule;
+#[cfg(feature = "participant")]
+mod senders;
+mod serdes;
+#[cfg(feature = "participant")]
+mod share_record;
+#[cfg(feature = "participant")]
+mod signing;
+#[cfg(feature = "solana")]
+mod solana;
+#[cfg(feature = "participant")]
+mod state;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "participant")]
+mod subkey;
+#[cfg(all(feature = "k256", feature = "participant"))]
+mod taproot;
+#[cfg(feature = "participant")]
+mod telemetry;
 mod traits;
-
+#[cfg(feature = "participant")]
+mod view;
+
+#[cfg(feature = "participant")]
+pub use abort::*;
+#[cfg(feature = "participant")]
+pub use ack::*;
+#[cfg(feature = "tokio")]
+pub use actor::*;
+#[cfg(feature = "arkworks")]
+pub use arkworks::*;
+#[cfg(all(feature = "json", feature = "participant"))]
+pub use audit::*;
+#[cfg(feature = "participant")]
+pub use beacon::*;
+#[cfg(all(feature = "bls12_381_plus", feature = "participant"))]
+pub use bls::*;
+#[cfg(feature = "tokio")]
+pub use ceremony::*;
+#[cfg(feature = "x509")]
+pub use certificate::*;
+pub use chunk::*;
+#[cfg(feature = "participant")]
+pub use commit_reveal::*;
+pub use compact::*;
+#[cfg(feature = "participant")]
+pub use context::*;
+#[cfg(feature = "participant")]
+pub use continuity::*;
 pub use data::*;
+#[cfg(feature = "participant")]
+pub use dealing::*;
+pub use display::*;
+#[cfg(feature = "participant")]
+pub use dispute::*;
+#[cfg(feature = "participant")]
+pub use dual::*;
 pub use error::*;
+#[cfg(feature = "eth2")]
+pub use eth2::*;
+#[cfg(feature = "participant")]
+pub use fixed::*;
+#[cfg(feature = "fixtures")]
+pub use fixtures::*;
+#[cfg(feature = "participant")]
+pub use health::*;
+pub use identifier::*;
+#[cfg(feature = "participant")]
+pub use key_certificate::*;
+#[cfg(feature = "participant")]
+pub use key_id::*;
+#[cfg(feature = "participant")]
+pub use keygen::*;
+pub use lagrange::*;
+#[cfg(feature = "participant")]
+pub use missing::*;
+#[cfg(feature = "tokio")]
+pub use network::*;
+#[cfg(feature = "participant")]
+pub use output::*;
+#[cfg(feature = "participant")]
 pub use parameters::*;
+#[cfg(feature = "participant")]
 pub use participant::*;
+#[cfg(feature = "participant")]
+pub use pedersen::*;
+#[cfg(feature = "participant")]
+pub use policy::*;
+#[cfg(feature = "participant")]
+pub use preprocess::*;
+#[cfg(feature = "participant")]
+pub use quarantine::*;
+#[cfg(feature = "participant")]
+pub use quickstart::*;
+#[cfg(feature = "participant")]
+pub use replay::*;
+#[cfg(feature = "participant")]
+pub use roster::*;
+#[cfg(feature = "participant")]
+pub use sas::*;
+#[cfg(feature = "participant")]
+pub use schedule::*;
+#[cfg(feature = "participant")]
+pub use share_record::*;
+#[cfg(feature = "participant")]
+pub use signing::*;
+#[cfg(feature = "participant")]
+pub use state::*;
+#[cfg(feature = "futures")]
+pub use stream::*;
+#[cfg(feature = "participant")]
+pub use subkey::*;
+#[cfg(all(feature = "k256", feature = "participant"))]
+pub use taproot::*;
+#[cfg(feature = "metrics")]
+pub use telemetry::{MESSAGES_RECEIVED, ROUND_DURATION, VALIDATION_FAILURES};
 pub use traits::*;
+#[cfg(feature = "participant")]
+pub use view::*;
 
 pub use elliptic_curve;
 pub use elliptic_curve_tools;
 pub use rand_core;
 pub use vsss_rs;
 
+#[cfg(feature = "participant")]
 use elliptic_curve::{
     Field, PrimeField,
     group::GroupEncoding,
     subtle::{Choice, ConditionallySelectable},
 };
+#[cfg(feature = "participant")]
 use elliptic_curve_tools::SumOfProducts;
-use std::collections::BTreeMap;
-use vsss_rs::{IdentifierPrimeField, ShareVerifierGroup};
+#[cfg(feature = "participant")]
+use vsss_rs::IdentifierPrimeField;
 
 /// Round1 data represent all the broadcast information. Using this
 /// anyone can publicly verify the output of the DKG.
+#[cfg(feature = "participant")]
 pub fn publicly_verify_dkg_results<G>(
     round1_data: &[Round1Data<G>],
     parameters: &Parameters<G>,
     public_key: G,
 ) -> DkgResult<()>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     // This is essentially performing the same checks as round1::Participant::receive_round1data
     // but also checks that the computed public matches from the commitments
-    let rng = rand::rng();
-    let dummy_shares =
-        vsss_rs::shamir::split_secret_with_participant_generator::<SecretShare<G::Scalar>>(
-            parameters.threshold,
-            parameters.limit,
-            &IdentifierPrimeField(G::Scalar::ZERO),
-            rng,
-            &parameters.participant_number_generators,
-        )?;
-    let all_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>> = dummy_shares
-        .iter()
-        .enumerate()
-        .map(|(i, s)| (i, s.identifier))
-        .collect();
-
-    let mut computed_public_key = G::default();
+    let context = parameters.signed_context()?;
+    let all_participant_ids = &context.participant_ids;
+
+    let mut computed_public_key = G::identity();
     let mut all_refresh = true;
 
     for (i, round1_data) in round1_data.iter().enumerate() {
@@ -94,6 +293,11 @@ where
                 i + 1
             )));
         }
+        if round1_data.threshold != parameters.threshold || round1_data.limit != parameters.limit {
+            return Err(Error::ParameterMismatch {
+                ordinal: round1_data.sender_ordinal,
+            });
+        }
         if round1_data.feldman_commitments.len() != parameters.threshold {
             return Err(Error::Pvss(format!(
                 "Data at {} has commitments that do not match the expected threshold. Expected {}, found {}",
@@ -112,15 +316,27 @@ where
                 i + 1
             )));
         }
+        if !round1_data
+            .feldman_commitments
+            .iter()
+            .all(|c| is_torsion_free(c))
+            || !is_torsion_free(&round1_data.verifying_share)
+            || !is_torsion_free(&round1_data.signature.r)
+        {
+            return Err(Error::Subgroup(format!(
+                "Data at {} has a point outside the prime order subgroup",
+                i + 1
+            )));
+        }
 
         let feldman_valid = match round1_data.sender_type {
             ParticipantType::Secret => {
-                SecretParticipantImpl::check_feldman_verifier(*round1_data.feldman_commitments[0])
-                    && round1_data.feldman_commitments[0].0 == round1_data.verifying_share
+                SecretParticipantImpl::check_feldman_verifier(round1_data.feldman_commitments[0])
+                    && round1_data.feldman_commitments[0] == round1_data.verifying_share
             }
             ParticipantType::Refresh => {
-                RefreshParticipantImpl::check_feldman_verifier(*round1_data.feldman_commitments[0])
-                    && round1_data.feldman_commitments[0].0 != round1_data.verifying_share
+                RefreshParticipantImpl::check_feldman_verifier(round1_data.feldman_commitments[0])
+                    && round1_data.feldman_commitments[0] != round1_data.verifying_share
             }
         };
 
@@ -131,22 +347,12 @@ where
             )));
         }
 
-        verify_signature(
-            round1_data.sender_ordinal,
-            &round1_data.sender_id,
-            &round1_data.sender_type,
-            parameters.threshold,
-            parameters.limit,
-            &parameters.message_generator,
-            &round1_data.feldman_commitments,
-            &round1_data.verifying_share,
-            &all_participant_ids,
-            &round1_data.signature,
-        )
-        .map_err(|_e| Error::Pvss(format!("Data at {} failed signature verification", i + 1)))?;
+        context.verify(round1_data).map_err(|_e| {
+            Error::Pvss(format!("Data at {} failed signature verification", i + 1))
+        })?;
 
         all_refresh &= matches!(round1_data.sender_type, ParticipantType::Refresh);
-        computed_public_key += round1_data.feldman_commitments[0].0;
+        computed_public_key += round1_data.feldman_commitments[0];
     }
 
     let public_key_identity = bool::from(computed_public_key.is_identity());
@@ -167,38 +373,32 @@ where
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "participant")]
 pub(crate) fn verify_signature<G>(
     ordinal: usize,
     id: &IdentifierPrimeField<G::Scalar>,
     p_type: &ParticipantType,
-    threshold: usize,
-    limit: usize,
-    message_generator: &G,
-    feldman_verifiers: &[ShareVerifierGroup<G>],
+    context: &SignedContext<G>,
+    commitments_digest: &[u8; 32],
     verifying_share: &G,
-    all_participant_ids: &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
     signature: &Signature<G>,
 ) -> DkgResult<()>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let bytes = bytes_for_schnorr(
         ordinal,
         id,
         p_type,
-        threshold,
-        limit,
-        message_generator,
-        feldman_verifiers,
+        context,
+        commitments_digest,
         verifying_share,
         &signature.r,
-        all_participant_ids,
     );
-    let challenge = G::Scalar::hash_to_scalar(&bytes);
+    let challenge = hash_to_group_scalar::<G>(&bytes);
 
-    let computed_r = *message_generator * signature.s - *verifying_share * challenge;
+    let computed_r = context.message_generator * signature.s - *verifying_share * challenge;
     if signature.r != computed_r {
         return Err(Error::Round(format!(
             "Round {}: Received invalid round 1 signature proof from ordinal: '{}', id: '{:?}'",
@@ -210,51 +410,62 @@ where
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "participant")]
 pub(crate) fn bytes_for_schnorr<G>(
     ordinal: usize,
     id: &IdentifierPrimeField<G::Scalar>,
     p_type: &ParticipantType,
-    threshold: usize,
-    limit: usize,
-    message_generator: &G,
-    feldman_verifiers: &[ShareVerifierGroup<G>],
+    context: &SignedContext<G>,
+    commitments_digest: &[u8; 32],
     verifying_share: &G,
     r_i: &G,
-    all_participant_ids: &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
 ) -> Vec<u8>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
-    let mut bytes = Vec::with_capacity(512);
+    let scalar_len = <G::Scalar as PrimeField>::Repr::default().as_ref().len();
+    let point_len = G::Repr::default().as_ref().len();
+    let mut bytes = Vec::with_capacity(
+        scalar_len * (1 + context.participant_ids.len())
+            + (5 + 2 * context.participant_ids.len()) * size_of::<u16>()
+            + 3 * 32
+            + CIPHERSUITE_ID_LENGTH
+            + point_len * 3,
+    );
+    // Bind the curve and hash so a signature never verifies under another ciphersuite
+    bytes.extend_from_slice(&ciphersuite_id::<G>());
     // ID
     bytes.extend_from_slice(id.0.to_repr().as_ref());
     // Add these for domain separation to prevent replay attacks
     bytes.extend_from_slice(&(ordinal as u16).to_be_bytes());
     bytes.extend_from_slice(&u16::from(*p_type).to_be_bytes());
-    bytes.extend_from_slice(&(threshold as u16).to_be_bytes());
-    bytes.extend_from_slice(&(limit as u16).to_be_bytes());
-    bytes.extend_from_slice(message_generator.to_bytes().as_ref());
-    for id in all_participant_ids.values() {
+    bytes.extend_from_slice(&(context.threshold as u16).to_be_bytes());
+    bytes.extend_from_slice(&(context.limit as u16).to_be_bytes());
+    bytes.extend_from_slice(context.message_generator.to_bytes().as_ref());
+    // The roster by ordinal with its length so no two rosters encode alike
+    bytes.extend_from_slice(&(context.participant_ids.len() as u16).to_be_bytes());
+    for (ordinal, id) in &context.participant_ids {
+        bytes.extend_from_slice(&(*ordinal as u16).to_be_bytes());
         bytes.extend_from_slice(id.0.to_repr().as_ref());
     }
+    // Bind the identity keys and the rest of the configuration
+    bytes.extend_from_slice(&context.roster_digest);
+    bytes.extend_from_slice(&context.parameters_digest);
     // Add the R_i
     bytes.extend_from_slice(r_i.to_bytes().as_ref());
     // Add the verifying share
     bytes.extend_from_slice(verifying_share.to_bytes().as_ref());
-    // Add the verifiers
-    for vf in feldman_verifiers {
-        bytes.extend_from_slice(vf.0.to_bytes().as_ref());
-    }
+    // Add the verifiers by their digest so the message doesn't grow with the threshold
+    bytes.extend_from_slice(commitments_digest);
     bytes
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "participant"))]
 mod tests {
     use super::*;
-    use elliptic_curve::{Field, group::GroupEncoding, subtle::ConditionallySelectable};
-    use elliptic_curve_tools::SumOfProducts;
+    use crate::participant::testing::{next_round, receive};
+    use elliptic_curve::Field;
     use rand_core::SeedableRng;
     use std::num::NonZeroUsize;
     use vsss_rs::{
@@ -283,6 +494,10 @@ mod tests {
         for _ in [Round::One, Round::Two, Round::Three] {
             let generators = next_round(&mut participants);
             receive(&mut participants, generators);
+            let status = participants[0].status();
+            assert!(status.ready || status.completed);
+            assert!(status.missing_participants.is_empty());
+            assert!(status.invalid_participants.is_empty());
         }
 
         let shares = participants
@@ -299,6 +514,18 @@ mod tests {
 
         let expected_pk = k256::ProjectivePoint::GENERATOR * *secret;
 
+        // Neither Display nor Debug may leak secret values
+        let share = hex::encode(participants[0].secret_share.value.0.to_bytes());
+        let original = hex::encode(participants[0].original_secret.to_bytes());
+        for text in [
+            participants[0].to_string(),
+            format!("{:?}", participants[0]),
+        ] {
+            assert!(!text.contains(&share));
+            assert!(!text.contains(&original));
+        }
+        assert!(participants[0].to_string().contains("completed: true"));
+
         assert_eq!(
             participants[1]
                 .get_public_key()
@@ -382,41 +609,257 @@ mod tests {
         );
     }
 
-    fn next_round<G>(participants: &mut [SecretParticipant<G>]) -> Vec<RoundOutputGenerator<G>>
-    where
-        G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
-        G::Scalar: ScalarHash,
-    {
-        let mut round_generators = Vec::with_capacity(participants.len());
-        for participant in participants {
-            let generator = participant.run().expect("run participant round");
-            round_generators.push(generator);
-        }
-        round_generators
+    #[test]
+    fn dispute() {
+        const THRESHOLD: usize = 2;
+        const LIMIT: usize = 3;
+
+        let threshold = NonZeroUsize::new(THRESHOLD).expect("threshold is non-zero");
+        let limit = NonZeroUsize::new(LIMIT).expect("limit is non-zero");
+
+        let parameters = Parameters::<k256::ProjectivePoint>::new(threshold, limit, None, None);
+
+        let mut participants = (1..=3)
+            .map(|id| {
+                let id = IdentifierPrimeField(k256::Scalar::from(id as u64));
+                SecretParticipant::<k256::ProjectivePoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        receive(&mut participants, generators);
+
+        let generators = next_round(&mut participants);
+        let output = generators[1]
+            .iter()
+            .find(|output| output.dst_ordinal == 0)
+            .expect("output for ordinal 0");
+        let mut round2_data =
+            postcard::from_bytes::<Round2Data<k256::Scalar>>(&output.data[MESSAGE_HEADER_LENGTH..])
+                .expect("decode round 2 data");
+        round2_data.secret_share.value.0 += k256::Scalar::ONE;
+        // A share changed in transit is not signed by the dealer
+        let forged = encode_payload::<k256::ProjectivePoint, _>(Round::Two, &round2_data)
+            .expect("encode round 2 data");
+        assert!(participants[0].receive(&forged).is_err());
+        assert!(participants[0].get_dispute_evidence().is_empty());
+        assert!(participants[0].invalid_participant_ids.is_empty());
+
+        // A signed share dealt to someone else can be replayed by anyone
+        let misdirected = generators[1]
+            .iter()
+            .find(|output| output.dst_ordinal == 2)
+            .expect("output for ordinal 2");
+        assert!(participants[0].receive(&misdirected.data).is_err());
+        assert!(participants[0].invalid_participant_ids.is_empty());
+
+        // The dealer signs the share that does not verify
+        participants[1].sign_round2(&participants[1].signed_context(), &mut round2_data);
+        let bad_data = encode_payload::<k256::ProjectivePoint, _>(Round::Two, &round2_data)
+            .expect("encode round 2 data");
+
+        assert!(participants[0].receive(&bad_data).is_err());
+        let evidence = participants[0]
+            .get_dispute_evidence()
+            .get(&1)
+            .expect("evidence against ordinal 1");
+        assert_eq!(evidence.accused_ordinal(), 1);
+        assert!(evidence.verify().is_ok());
+        assert!(participants[0].invalid_participant_ids.contains_key(&1));
+
+        let bytes = postcard::to_stdvec(evidence).expect("encode evidence");
+        let mut decoded = postcard::from_bytes::<DisputeEvidence<k256::ProjectivePoint>>(&bytes)
+            .expect("decode evidence");
+        assert!(decoded.verify().is_ok());
+
+        // A share the dealer did not sign is not evidence of misbehavior
+        let mut forged = decoded.clone();
+        forged.round2_data.secret_share.value.0 += k256::Scalar::ONE;
+        assert!(forged.verify().is_err());
+
+        // Neither is an honest share
+        decoded.round2_data.secret_share.value.0 -= k256::Scalar::ONE;
+        participants[1].sign_round2(&participants[1].signed_context(), &mut decoded.round2_data);
+        assert!(decoded.verify().is_err());
     }
 
-    fn receive<G>(
-        participants: &mut [SecretParticipant<G>],
-        round_generators: Vec<RoundOutputGenerator<G>>,
-    ) where
-        G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
-        G::Scalar: ScalarHash,
-    {
-        for round_generator in &round_generators {
-            for ParticipantRoundOutput {
-                dst_ordinal: ordinal,
-                dst_id: id,
-                data,
-                ..
-            } in round_generator.iter()
-            {
-                if let Some(participant) = participants.get_mut(ordinal) {
-                    assert_eq!(participant.ordinal, ordinal);
-                    assert_eq!(participant.id, id);
-                    let res = participant.receive(data.as_slice());
-                    assert!(res.is_ok());
+    #[cfg(feature = "bls12_381_plus")]
+    #[test]
+    fn group_domain_separation() {
+        use bls12_381_plus::{G1Projective, G2Projective};
+
+        assert_ne!(group_dst::<G1Projective>(), group_dst::<G2Projective>());
+        assert_ne!(
+            hash_to_group_scalar::<G1Projective>(b"challenge"),
+            hash_to_group_scalar::<G2Projective>(b"challenge")
+        );
+        assert_eq!(
+            hash_to_group_scalar::<G1Projective>(b"challenge"),
+            hash_to_group_scalar::<G1Projective>(b"challenge")
+        );
+
+        // Groups with their own scalar field keep the challenges of earlier releases
+        #[cfg(feature = "k256")]
+        assert_eq!(
+            hash_to_group_scalar::<k256::ProjectivePoint>(b"challenge"),
+            k256::Scalar::hash_to_scalar(b"challenge")
+        );
+    }
+
+    #[test]
+    fn batch_round2() {
+        let parameters = Parameters::<k256::ProjectivePoint>::new(
+            NonZeroUsize::new(2).expect("threshold is non-zero"),
+            NonZeroUsize::new(4).expect("limit is non-zero"),
+            None,
+            None,
+        );
+        let mut participants = (1..=4)
+            .map(|id| {
+                let id = IdentifierPrimeField(k256::Scalar::from(id as u64));
+                SecretParticipant::<k256::ProjectivePoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        receive(&mut participants, generators);
+
+        let generators = next_round(&mut participants);
+        let messages_for = |ordinal: usize| {
+            generators
+                .iter()
+                .flat_map(|generator| generator.iter())
+                .filter(|output| output.dst_ordinal == ordinal)
+                .map(|output| output.data)
+                .collect::<Vec<_>>()
+        };
+
+        let results = participants[1].receive_batch(&messages_for(1));
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert_eq!(participants[1].received_round2_data.len(), 4);
+
+        // Corrupt the share from the second sender
+        let mut messages = messages_for(0);
+        let mut round2_data =
+            postcard::from_bytes::<Round2Data<k256::Scalar>>(&messages[1][MESSAGE_HEADER_LENGTH..])
+                .expect("decode round 2 data");
+        let accused = round2_data.sender_ordinal;
+        round2_data.secret_share.value.0 += k256::Scalar::ONE;
+        participants[accused]
+            .sign_round2(&participants[accused].signed_context(), &mut round2_data);
+        messages[1] = encode_payload::<k256::ProjectivePoint, _>(Round::Two, &round2_data)
+            .expect("encode round 2 data");
+        // A duplicate is rejected even when it would verify
+        messages.push(messages[0].clone());
+        // A share dealt to someone else is rejected without blaming its dealer
+        messages.push(messages_for(3)[1].clone());
+
+        let results = participants[0].receive_batch(&messages);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert!(results[3].is_err());
+        assert!(results[4].is_err());
+        assert_eq!(participants[0].invalid_participant_ids.len(), 1);
+        assert!(
+            participants[0]
+                .invalid_participant_ids
+                .contains_key(&accused)
+        );
+        assert!(participants[0].dispute_evidence.contains_key(&accused));
+        assert_eq!(participants[0].received_round2_data.len(), 3);
+    }
+
+    #[test]
+    fn tolerate_invalid_share() {
+        let parameters = Parameters::<k256::ProjectivePoint>::new(
+            NonZeroUsize::new(2).expect("threshold is non-zero"),
+            NonZeroUsize::new(4).expect("limit is non-zero"),
+            None,
+            None,
+        );
+        let mut participants = (1..=4)
+            .map(|id| {
+                let id = IdentifierPrimeField(k256::Scalar::from(id as u64));
+                let mut participant =
+                    SecretParticipant::<k256::ProjectivePoint>::new_secret(id, &parameters)
+                        .expect("create secret participant");
+                participant
+                    .set_fault_tolerance(1)
+                    .expect("tolerate one dealer");
+                participant
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        receive(&mut participants, generators);
+
+        // Dealer 1 sends participant 0 a share that does not verify
+        let generators = next_round(&mut participants);
+        for (sender, generator) in generators.iter().enumerate() {
+            for output in generator.iter() {
+                if sender == 1 && output.dst_ordinal == 0 {
+                    let mut round2_data = postcard::from_bytes::<Round2Data<k256::Scalar>>(
+                        &output.data[MESSAGE_HEADER_LENGTH..],
+                    )
+                    .expect("decode round 2 data");
+                    round2_data.secret_share.value.0 += k256::Scalar::ONE;
+                    participants[1]
+                        .sign_round2(&participants[1].signed_context(), &mut round2_data);
+                    let bad_data =
+                        encode_payload::<k256::ProjectivePoint, _>(Round::Two, &round2_data)
+                            .expect("encode round 2 data");
+                    assert!(participants[0].receive(&bad_data).is_err());
+                    continue;
                 }
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .expect("receive round 2 data");
             }
         }
+        assert_eq!(
+            participants[0].peer_status(1),
+            Some(PeerStatus::Disqualified)
+        );
+
+        // The others drop the dealer on the evidence
+        let evidence = participants[0].dispute_evidence[&1].clone();
+        let mut forged = evidence.clone();
+        forged.round2_data.secret_share.value.0 += k256::Scalar::ONE;
+        assert!(participants[2].accept_dispute(&forged).is_err());
+        assert_ne!(
+            participants[2].peer_status(1),
+            Some(PeerStatus::Disqualified)
+        );
+        for participant in &mut participants[2..] {
+            participant
+                .accept_dispute(&evidence)
+                .expect("accept dispute evidence");
+            assert_eq!(participant.peer_status(1), Some(PeerStatus::Disqualified));
+        }
+        // A second dealer is beyond the tolerance
+        let mut other = evidence.clone();
+        other.round1_data = participants[0].received_round1_data[&2].clone();
+        other.round2_data.sender_ordinal = 2;
+        other.round2_data.sender_id = participants[2].id;
+        participants[2].sign_round2(&participants[2].signed_context(), &mut other.round2_data);
+        assert!(other.verify().is_ok());
+        assert!(participants[3].accept_dispute(&other).is_err());
+
+        let honest = [0, 2, 3];
+        for ordinal in honest {
+            participants[ordinal].run().expect("run round 3");
+        }
+        let public_key = participants[0].public_key;
+        for ordinal in honest {
+            let participant = &participants[ordinal];
+            assert!(participant.completed());
+            assert_eq!(participant.public_key, public_key);
+            assert_eq!(participant.received_round2_data.len(), 3);
+            assert!(!participant.verification_shares.contains_key(&1));
+        }
     }
 }
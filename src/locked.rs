@@ -0,0 +1,127 @@
+use std::ops::{Deref, DerefMut};
+
+/// Storage for the final secret share of a participant.
+///
+/// With the `mlock` feature the value is kept on its own pages which are
+/// locked with `mlock`/`VirtualLock` so it is never swapped to disk, and
+/// zeroed before they are released.
+#[derive(Clone, Default)]
+pub(crate) struct SecretStorage<T: Copy + Default>(
+    #[cfg(not(feature = "mlock"))] T,
+    #[cfg(feature = "mlock")] pages::LockedPages<T>,
+);
+
+impl<T: Copy + Default> SecretStorage<T> {
+    pub(crate) fn new(value: T) -> Self {
+        #[cfg(not(feature = "mlock"))]
+        {
+            Self(value)
+        }
+        #[cfg(feature = "mlock")]
+        {
+            Self(pages::LockedPages::new(value))
+        }
+    }
+
+    /// Whether the operating system agreed to lock the pages holding the value
+    #[cfg(feature = "mlock")]
+    pub(crate) fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+}
+
+impl<T: Copy + Default> Deref for SecretStorage<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Copy + Default> DerefMut for SecretStorage<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "mlock")]
+mod pages {
+    use elliptic_curve::zeroize::Zeroize;
+    use std::alloc::{self, Layout};
+    use std::ops::{Deref, DerefMut};
+    use std::ptr::NonNull;
+
+    pub(crate) struct LockedPages<T: Copy + Default> {
+        ptr: NonNull<T>,
+        layout: Layout,
+        guard: Option<region::LockGuard>,
+    }
+
+    // The pages are exclusively owned like a `Box<T>`
+    unsafe impl<T: Copy + Default + Send> Send for LockedPages<T> {}
+    unsafe impl<T: Copy + Default + Sync> Sync for LockedPages<T> {}
+
+    impl<T: Copy + Default> LockedPages<T> {
+        pub(crate) fn new(value: T) -> Self {
+            // Whole pages so unlocking never affects another allocation
+            let page_size = region::page::size();
+            let size = size_of::<T>().max(1).next_multiple_of(page_size);
+            let layout = Layout::from_size_align(size, page_size.max(align_of::<T>()))
+                .expect("page aligned layout is valid");
+            // SAFETY: the layout has a non-zero size
+            let ptr = unsafe { alloc::alloc_zeroed(layout) }.cast::<T>();
+            let ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+            // Locking is best effort since the limit for locked memory may be low
+            let guard = region::lock(ptr.as_ptr(), size).ok();
+            // SAFETY: the allocation is large enough and aligned for `T`
+            unsafe { ptr.as_ptr().write(value) };
+            Self { ptr, layout, guard }
+        }
+
+        pub(crate) fn is_locked(&self) -> bool {
+            self.guard.is_some()
+        }
+    }
+
+    impl<T: Copy + Default> Clone for LockedPages<T> {
+        fn clone(&self) -> Self {
+            Self::new(**self)
+        }
+    }
+
+    impl<T: Copy + Default> Default for LockedPages<T> {
+        fn default() -> Self {
+            Self::new(T::default())
+        }
+    }
+
+    impl<T: Copy + Default> Deref for LockedPages<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            // SAFETY: the pointer is valid and initialized for the lifetime of self
+            unsafe { self.ptr.as_ref() }
+        }
+    }
+
+    impl<T: Copy + Default> DerefMut for LockedPages<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            // SAFETY: the pointer is valid and initialized for the lifetime of self
+            unsafe { self.ptr.as_mut() }
+        }
+    }
+
+    impl<T: Copy + Default> Drop for LockedPages<T> {
+        fn drop(&mut self) {
+            // SAFETY: the allocation is `layout.size()` bytes and `T` is `Copy`
+            // so overwriting it with zeros needs no destructor
+            let bytes = unsafe {
+                std::slice::from_raw_parts_mut(self.ptr.as_ptr().cast::<u8>(), self.layout.size())
+            };
+            bytes.zeroize();
+            self.guard.take();
+            // SAFETY: allocated in `new` with the same layout
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast::<u8>(), self.layout) };
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+
+/// What round 3 does when a dealer whose round 1 broadcast was accepted
+/// never sent this participant its round 2 share.
+///
+/// Every participant must combine the shares of the same dealers or they
+/// end up with different keys, so by default a missing share aborts.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum MissingSharePolicy {
+    /// Combine the shares that arrived as long as there are at least
+    /// threshold of them.
+    ///
+    /// Only safe when the application makes every participant agree on
+    /// the dealers that contributed, for example by disqualifying the
+    /// missing dealers everywhere with [`Participant::disqualify_peer`].
+    /// Participants that miss different dealers derive different keys.
+    Proceed,
+    /// Wait for every share so the driver can ask the dealers in
+    /// [`Participant::missing_shares`] to send theirs again
+    RequestResend,
+    /// Stop the ceremony and blame the dealers that did not send a share
+    #[default]
+    Abort,
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Choose what happens when round 2 shares are missing, see
+    /// [`MissingSharePolicy`]
+    pub fn set_missing_share_policy(&mut self, policy: MissingSharePolicy) {
+        self.missing_share_policy = policy;
+    }
+
+    /// What happens when round 2 shares are missing
+    pub fn get_missing_share_policy(&self) -> MissingSharePolicy {
+        self.missing_share_policy
+    }
+
+    /// The dealers that passed round 1 but whose round 2 share has not
+    /// arrived. Disqualified peers are not expected to send one.
+    pub fn missing_shares(&self) -> Vec<usize> {
+        self.valid_participant_ids
+            .keys()
+            .filter(|ordinal| {
+                !self.received_round2_data.contains_key(ordinal)
+                    && !self.disqualified_participant_ids.contains(ordinal)
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Apply the missing share policy before round 3 runs.
+    ///
+    /// Aborting marks the missing dealers invalid so the evidence of an
+    /// [`AbortCertificate`] names them.
+    pub(crate) fn check_missing_shares(&mut self) -> DkgResult<()> {
+        if self.missing_share_policy == MissingSharePolicy::Proceed {
+            return Ok(());
+        }
+        let missing = self.missing_shares();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        if self.missing_share_policy == MissingSharePolicy::Abort {
+            for ordinal in &missing {
+                self.invalid_participant_ids
+                    .insert(*ordinal, self.valid_participant_ids[ordinal]);
+            }
+            return Err(Error::Round(format!(
+                "Round {}: Aborted, no share from {missing:?}",
+                Round::Three
+            )));
+        }
+        Err(Error::Round(format!(
+            "Round {}: Waiting for {missing:?} to resend their shares",
+            Round::Three
+        )))
+    }
+}
@@ -0,0 +1,341 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorCollection};
+
+/// A transport used to exchange round messages between participants
+pub trait Transport<F: ScalarHash>: Send {
+    /// Send the output to the participant it is addressed to
+    fn send(
+        &mut self,
+        output: ParticipantRoundOutput<F>,
+    ) -> impl Future<Output = DkgResult<()>> + Send;
+    /// Receive the next message addressed to this participant
+    fn recv(&mut self) -> impl Future<Output = DkgResult<Vec<u8>>> + Send;
+}
+
+/// A transport shared by several participants on the same device
+pub trait MultiTransport<F: ScalarHash>: Transport<F> {
+    /// Receive the next message addressed to any of the local participants
+    /// along with the ordinal it is addressed to
+    fn recv_routed(&mut self) -> impl Future<Output = DkgResult<(usize, Vec<u8>)>> + Send;
+}
+
+/// How a driver retries transport operations that fail
+#[derive(Debug, Copy, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of retries for a single send or receive
+    pub max_retries: usize,
+    /// The delay before the first retry
+    pub initial_backoff: Duration,
+    /// The upper bound for the delay between retries
+    pub max_backoff: Duration,
+    /// How long to wait for the next message before the receive is treated
+    /// as a timed out failure. `None` waits forever.
+    pub recv_timeout: Option<Duration>,
+    /// Decides whether an error is transient and worth retrying
+    pub retryable: fn(&Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            recv_timeout: None,
+            retryable: Self::is_transient,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// The default [`RetryPolicy::retryable`] check which retries I/O errors
+    /// caused by timeouts, interruptions and dropped connections
+    pub fn is_transient(error: &Error) -> bool {
+        match error {
+            Error::Io(e) => matches!(
+                e.kind(),
+                io::ErrorKind::Interrupted
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::WouldBlock
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::NotConnected
+            ),
+            _ => false,
+        }
+    }
+
+    /// The delay before retry number `attempt`, starting at zero,
+    /// doubling each time up to [`RetryPolicy::max_backoff`]
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 1u32.checked_shl(attempt as u32).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Returns the delay to wait before retrying after `error`, or `None`
+    /// if the error is not retryable or the retries are exhausted
+    fn next_delay(&self, attempt: usize, error: &Error) -> Option<Duration> {
+        (attempt < self.max_retries && (self.retryable)(error)).then(|| self.backoff(attempt))
+    }
+}
+
+/// Drive a participant through every round of the protocol over `transport`.
+///
+/// Each round waits until every expected participant has sent its data or has
+/// been marked invalid. Messages for a later round that arrive early are held
+/// until the participant is ready for them. Transport errors end the protocol,
+/// use [`drive_with_retry`] to retry transient failures.
+pub async fn drive<G, P, T>(participant: &mut P, transport: &mut T) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+    T: Transport<G::Scalar>,
+{
+    drive_with_retry(participant, transport, &RetryPolicy::none()).await
+}
+
+/// Drive a participant like [`drive`] but retry sends and receives that
+/// fail with an error the `policy` considers transient
+pub async fn drive_with_retry<G, P, T>(
+    participant: &mut P,
+    transport: &mut T,
+    policy: &RetryPolicy,
+) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+    T: Transport<G::Scalar>,
+{
+    let mut pending = Vec::new();
+    while !participant.completed() {
+        let outputs = participant.run()?.iter().collect::<Vec<_>>();
+        for output in outputs {
+            send_with_retry(transport, output, policy).await?;
+        }
+        if participant.completed() {
+            break;
+        }
+
+        // Payloads are tagged with the round that produced them
+        let expected = participant
+            .round_requirements()
+            .payload_round
+            .map_or(0, u8::from);
+        let (current, later): (Vec<Vec<u8>>, Vec<Vec<u8>>) = pending
+            .drain(..)
+            .partition(|message: &Vec<u8>| message.first() == Some(&expected));
+        pending = later;
+        // Invalid senders are recorded by the participant and reported in its status
+        let _ = participant.receive_batch(&current);
+        while !participant.status().missing_participants.is_empty() {
+            let message = recv_with_retry(transport, policy).await?;
+            match message.first() {
+                Some(round) if *round == expected => {
+                    let _ = participant.receive(&message);
+                }
+                Some(round) if *round > expected => pending.push(message),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn send_with_retry<F, T>(
+    transport: &mut T,
+    output: ParticipantRoundOutput<F>,
+    policy: &RetryPolicy,
+) -> DkgResult<()>
+where
+    F: ScalarHash,
+    T: Transport<F>,
+{
+    let mut attempt = 0;
+    loop {
+        match transport.send(output.clone()).await {
+            Ok(()) => return Ok(()),
+            Err(e) => match policy.next_delay(attempt, &e) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(e),
+            },
+        }
+        attempt += 1;
+    }
+}
+
+async fn recv_with_retry<F, T>(transport: &mut T, policy: &RetryPolicy) -> DkgResult<Vec<u8>>
+where
+    F: ScalarHash,
+    T: Transport<F>,
+{
+    let mut attempt = 0;
+    loop {
+        let result = match policy.recv_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, transport.recv())
+                .await
+                .unwrap_or_else(|_| Err(Error::Io(io::Error::from(io::ErrorKind::TimedOut)))),
+            None => transport.recv().await,
+        };
+        match result {
+            Ok(message) => return Ok(message),
+            Err(e) => match policy.next_delay(attempt, &e) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(e),
+            },
+        }
+        attempt += 1;
+    }
+}
+
+pub(crate) async fn recv_routed_with_retry<F, T>(
+    transport: &mut T,
+    policy: &RetryPolicy,
+) -> DkgResult<(usize, Vec<u8>)>
+where
+    F: ScalarHash,
+    T: MultiTransport<F>,
+{
+    let mut attempt = 0;
+    loop {
+        let result = match policy.recv_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, transport.recv_routed())
+                .await
+                .unwrap_or_else(|_| Err(Error::Io(io::Error::from(io::ErrorKind::TimedOut)))),
+            None => transport.recv_routed().await,
+        };
+        match result {
+            Ok(message) => return Ok(message),
+            Err(e) => match policy.next_delay(attempt, &e) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(e),
+            },
+        }
+        attempt += 1;
+    }
+}
+
+/// A [`Transport`] over in-process tokio channels
+#[derive(Debug)]
+pub struct ChannelTransport {
+    senders: BTreeMap<usize, UnboundedSender<(usize, Vec<u8>)>>,
+    receiver: UnboundedReceiver<(usize, Vec<u8>)>,
+}
+
+impl<F: ScalarHash> Transport<F> for ChannelTransport {
+    async fn send(&mut self, output: ParticipantRoundOutput<F>) -> DkgResult<()> {
+        let sender = self.senders.get(&output.dst_ordinal).ok_or_else(|| {
+            Error::Round(format!(
+                "No channel for participant ordinal {}",
+                output.dst_ordinal
+            ))
+        })?;
+        sender
+            .send((output.dst_ordinal, output.data))
+            .map_err(|_| Error::Io(io::Error::from(io::ErrorKind::BrokenPipe)))
+    }
+
+    async fn recv(&mut self) -> DkgResult<Vec<u8>> {
+        <Self as MultiTransport<F>>::recv_routed(self)
+            .await
+            .map(|(_, data)| data)
+    }
+}
+
+impl<F: ScalarHash> MultiTransport<F> for ChannelTransport {
+    async fn recv_routed(&mut self) -> DkgResult<(usize, Vec<u8>)> {
+        self.receiver
+            .recv()
+            .await
+            .ok_or_else(|| Error::Io(io::Error::from(io::ErrorKind::UnexpectedEof)))
+    }
+}
+
+impl ChannelTransport {
+    /// Create a fully connected set of transports, one per ordinal
+    pub fn connect(count: usize) -> Vec<Self> {
+        Self::connect_groups(&(0..count).map(|ordinal| vec![ordinal]).collect::<Vec<_>>())
+    }
+
+    /// Create a fully connected set of transports, one per group of ordinals.
+    ///
+    /// Every ordinal in a group shares the same transport, like the
+    /// participants of a [`Ceremony`] running on one device.
+    pub fn connect_groups(groups: &[Vec<usize>]) -> Vec<Self> {
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            groups.iter().map(|_| unbounded_channel()).unzip();
+        let senders = groups
+            .iter()
+            .zip(senders)
+            .flat_map(|(group, sender)| group.iter().map(move |ordinal| (*ordinal, sender.clone())))
+            .collect::<BTreeMap<_, _>>();
+        receivers
+            .into_iter()
+            .map(|receiver| Self {
+                senders: senders.clone(),
+                receiver,
+            })
+            .collect()
+    }
+}
+
+/// An in-process network that runs every participant of a DKG
+/// on its own tokio task
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LocalNetwork;
+
+impl LocalNetwork {
+    /// Run a complete DKG with a secret participant for each id produced
+    /// by the parameters and return the completed participants by ordinal
+    pub async fn run<G>(parameters: &Parameters<'_, G>) -> DkgResult<Vec<SecretParticipant<G>>>
+    where
+        G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup + 'static,
+        G::Scalar: ScalarHash,
+    {
+        let participants =
+            ParticipantIdGeneratorCollection::from(&parameters.participant_number_generators)
+                .iter()
+                .take(parameters.limit)
+                .map(|id: IdentifierPrimeField<G::Scalar>| {
+                    SecretParticipant::<G>::new_secret(id, parameters)
+                })
+                .collect::<DkgResult<Vec<_>>>()?;
+
+        let handles = participants
+            .into_iter()
+            .zip(ChannelTransport::connect(parameters.limit))
+            .map(|(mut participant, mut transport)| {
+                tokio::spawn(async move {
+                    drive(&mut participant, &mut transport).await?;
+                    Ok::<_, Error>(participant)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut completed = Vec::with_capacity(handles.len());
+        for handle in handles {
+            completed.push(handle.await.map_err(|e| Error::Io(io::Error::other(e)))??);
+        }
+        Ok(completed)
+    }
+}
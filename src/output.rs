@@ -0,0 +1,462 @@
+use super::*;
+use crate::serdes::group_map;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use elliptic_curve::{Group, PrimeField};
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use vsss_rs::{IdentifierPrimeField, Share, ValuePrimeField};
+
+/// A group element that is guaranteed to not be the identity element.
+///
+/// Downstream signing code can rely on this invariant without
+/// re-checking the value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NonIdentity<G: Group + GroupEncoding>(pub(crate) G);
+
+impl<G: Group + GroupEncoding> NonIdentity<G> {
+    /// Wrap `point` if it is not the identity element
+    pub fn new(point: G) -> Option<Self> {
+        if point.is_identity().into() {
+            None
+        } else {
+            Some(Self(point))
+        }
+    }
+
+    /// Return the inner group element
+    pub fn get(&self) -> G {
+        self.0
+    }
+}
+
+impl<G: Group + GroupEncoding> Deref for NonIdentity<G> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<G: Group + GroupEncoding> PartialEq<G> for NonIdentity<G> {
+    fn eq(&self, other: &G) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<G: Group + GroupEncoding> ConstantTimeEq for NonIdentity<G> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .to_bytes()
+            .as_ref()
+            .ct_eq(other.0.to_bytes().as_ref())
+    }
+}
+
+impl<G: Group + GroupEncoding> Hash for NonIdentity<G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().as_ref().hash(state);
+    }
+}
+
+/// Points are ordered by their canonical encoding so the order is the
+/// same on every platform but has no arithmetic meaning
+impl<G: Group + GroupEncoding> PartialOrd for NonIdentity<G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G: Group + GroupEncoding> Ord for NonIdentity<G> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_bytes().as_ref().cmp(other.0.to_bytes().as_ref())
+    }
+}
+
+impl<G: Group + GroupEncoding> Serialize for NonIdentity<G> {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        group::serialize(&self.0, s)
+    }
+}
+
+impl<'de, G: Group + GroupEncoding> Deserialize<'de> for NonIdentity<G> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let point: G = group::deserialize(d)?;
+        Self::new(point)
+            .ok_or_else(|| serde::de::Error::custom("the identity element is not allowed"))
+    }
+}
+
+/// The result of a completed DKG for a single participant.
+///
+/// Only produced when the ceremony yields a usable group key, i.e.
+/// not for a refresh where every participant contributed a zero secret.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// This participant's ordinal index
+    pub(crate) ordinal: usize,
+    /// This participant's ID
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) id: IdentifierPrimeField<G::Scalar>,
+    /// The threshold used by the DKG
+    pub(crate) threshold: usize,
+    /// The generator used by the DKG
+    #[serde(with = "group")]
+    pub(crate) message_generator: G,
+    /// This participant's secret share of the group key
+    #[serde(bound(
+        serialize = "SecretShare<G::Scalar>: Serialize",
+        deserialize = "SecretShare<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) secret_share: SecretShare<G::Scalar>,
+    /// The group public key
+    #[serde(bound(
+        serialize = "NonIdentity<G>: Serialize",
+        deserialize = "NonIdentity<G>: Deserialize<'de>"
+    ))]
+    pub(crate) public_key: NonIdentity<G>,
+    /// The participant IDs holding a share of the group key
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Deserialize<'de>"
+    ))]
+    pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    /// The public verification share of each shareholder by ordinal index
+    #[serde(bound(
+        serialize = "BTreeMap<usize, NonIdentity<G>>: Serialize",
+        deserialize = "BTreeMap<usize, NonIdentity<G>>: Deserialize<'de>"
+    ))]
+    pub(crate) verification_shares: BTreeMap<usize, NonIdentity<G>>,
+    /// The final transcript hash
+    pub(crate) transcript_hash: Vec<u8>,
+    /// The application identifiers of the shareholders by ordinal index
+    #[serde(default)]
+    pub(crate) participant_identifiers: BTreeMap<usize, ParticipantIdentifier>,
+    /// The part of the group key dealt by each combined dealer by ordinal index
+    #[serde(default = "BTreeMap::new", with = "group_map")]
+    pub(crate) contribution_public_keys: BTreeMap<usize, G>,
+}
+
+impl<G> Debug for DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DkgOutput")
+            .field("ordinal", &self.ordinal)
+            .field("id", &self.id)
+            .field("threshold", &self.threshold)
+            .field("message_generator", &self.message_generator)
+            .field("public_key", &self.public_key)
+            .field("participant_ids", &self.participant_ids)
+            .field("verification_shares", &self.verification_shares)
+            .field("transcript_hash", &hex::encode(&self.transcript_hash))
+            .field("participant_identifiers", &self.participant_identifiers)
+            .field("contribution_public_keys", &self.contribution_public_keys)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Compares every field including the secret share without branching on
+/// the secret
+impl<G> ConstantTimeEq for DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let ids_eq = self
+            .participant_ids
+            .iter()
+            .zip(&other.participant_ids)
+            .fold(
+                Choice::from(u8::from(
+                    self.participant_ids.len() == other.participant_ids.len(),
+                )),
+                |acc, ((ordinal_a, id_a), (ordinal_b, id_b))| {
+                    acc & ordinal_a.ct_eq(ordinal_b) & id_a.0.ct_eq(&id_b.0)
+                },
+            );
+        let shares_eq = self
+            .verification_shares
+            .iter()
+            .zip(&other.verification_shares)
+            .fold(
+                Choice::from(u8::from(
+                    self.verification_shares.len() == other.verification_shares.len(),
+                )),
+                |acc, ((ordinal_a, share_a), (ordinal_b, share_b))| {
+                    acc & ordinal_a.ct_eq(ordinal_b) & share_a.ct_eq(share_b)
+                },
+            );
+        let contributions_eq = self
+            .contribution_public_keys
+            .iter()
+            .zip(&other.contribution_public_keys)
+            .fold(
+                Choice::from(u8::from(
+                    self.contribution_public_keys.len() == other.contribution_public_keys.len(),
+                )),
+                |acc, ((ordinal_a, key_a), (ordinal_b, key_b))| {
+                    acc & ordinal_a.ct_eq(ordinal_b)
+                        & key_a.to_bytes().as_ref().ct_eq(key_b.to_bytes().as_ref())
+                },
+            );
+        self.ordinal.ct_eq(&other.ordinal)
+            & self.id.0.ct_eq(&other.id.0)
+            & self.threshold.ct_eq(&other.threshold)
+            & self
+                .message_generator
+                .to_bytes()
+                .as_ref()
+                .ct_eq(other.message_generator.to_bytes().as_ref())
+            & self
+                .secret_share
+                .identifier
+                .0
+                .ct_eq(&other.secret_share.identifier.0)
+            & self.secret_share.value.0.ct_eq(&other.secret_share.value.0)
+            & self.public_key.ct_eq(&other.public_key)
+            & ids_eq
+            & shares_eq
+            & self
+                .transcript_hash
+                .as_slice()
+                .ct_eq(other.transcript_hash.as_slice())
+            & Choice::from(u8::from(
+                self.participant_identifiers == other.participant_identifiers,
+            ))
+            & contributions_eq
+    }
+}
+
+impl<G> PartialEq for DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<G> Eq for DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+}
+
+/// Only the public fields are hashed so hashes never depend on the
+/// secret share. Equal outputs still hash the same.
+impl<G> Hash for DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ordinal.hash(state);
+        self.id.0.to_repr().as_ref().hash(state);
+        self.threshold.hash(state);
+        self.message_generator.to_bytes().as_ref().hash(state);
+        self.public_key.hash(state);
+        for (ordinal, id) in &self.participant_ids {
+            ordinal.hash(state);
+            id.0.to_repr().as_ref().hash(state);
+        }
+        self.verification_shares.hash(state);
+        self.transcript_hash.hash(state);
+        self.participant_identifiers.hash(state);
+        for (ordinal, key) in &self.contribution_public_keys {
+            ordinal.hash(state);
+            key.to_bytes().as_ref().hash(state);
+        }
+    }
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the participant that produced this output
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The identifier of the participant that produced this output
+    pub fn id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.id
+    }
+
+    /// The threshold needed to use the group key
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The generator the group key and verification shares are computed with
+    pub fn message_generator(&self) -> G {
+        self.message_generator
+    }
+
+    /// The secret share of the group key
+    pub fn secret_share(&self) -> SecretShare<G::Scalar> {
+        self.secret_share
+    }
+
+    /// The secret share as another vsss-rs share type over the same field.
+    ///
+    /// Both the identifier and the value are prime field elements so only
+    /// types that can hold them without loss are accepted. Byte-wise shares
+    /// like those split over GF(256) cannot represent a prime field share.
+    pub fn secret_share_as<S>(&self) -> S
+    where
+        S: Share,
+        S::Identifier: From<IdentifierPrimeField<G::Scalar>>,
+        S::Value: From<ValuePrimeField<G::Scalar>>,
+    {
+        S::with_identifier_and_value(
+            self.secret_share.identifier.into(),
+            self.secret_share.value.into(),
+        )
+    }
+
+    /// The secret share as a share type that numbers shares with small
+    /// integers like [`vsss_rs::IdentifierPrimitive`].
+    ///
+    /// Fails unless the identifier is an integer that fits the target type.
+    pub fn secret_share_with_integer_id<S>(&self) -> DkgResult<S>
+    where
+        S: Share,
+        S::Identifier: TryFrom<u64>,
+        S::Value: From<ValuePrimeField<G::Scalar>>,
+    {
+        let identifier = self
+            .integer_id()
+            .and_then(|n| S::Identifier::try_from(n).ok())
+            .ok_or_else(|| {
+                Error::Encoding(format!(
+                    "The identifier of ordinal {} is not a small integer",
+                    self.ordinal
+                ))
+            })?;
+        Ok(S::with_identifier_and_value(
+            identifier,
+            self.secret_share.value.into(),
+        ))
+    }
+
+    /// The identifier as an integer when it is one
+    pub fn integer_id(&self) -> Option<u64> {
+        let repr = self.id.0.to_repr();
+        let bytes = repr.as_ref();
+        let n = bytes.len();
+        if n < 8 {
+            return None;
+        }
+        let mut le = [0u8; 8];
+        let mut be = [0u8; 8];
+        le.copy_from_slice(&bytes[..8]);
+        be.copy_from_slice(&bytes[n - 8..]);
+        [u64::from_le_bytes(le), u64::from_be_bytes(be)]
+            .into_iter()
+            .find(|candidate| G::Scalar::from(*candidate) == self.id.0)
+    }
+
+    /// The group public key
+    pub fn public_key(&self) -> NonIdentity<G> {
+        self.public_key
+    }
+
+    /// The group public key encoded for export
+    pub fn public_key_bytes(&self, encoding: PointEncoding) -> DkgResult<Vec<u8>> {
+        encode_point(&self.public_key.0, encoding)
+    }
+
+    /// The identifiers of all shareholders
+    pub fn participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.participant_ids
+    }
+
+    /// The verification shares of all shareholders
+    pub fn verification_shares(&self) -> &BTreeMap<usize, NonIdentity<G>> {
+        &self.verification_shares
+    }
+
+    /// The verification share of the shareholder at `ordinal`
+    pub fn verification_share(&self, ordinal: usize) -> Option<NonIdentity<G>> {
+        self.verification_shares.get(&ordinal).copied()
+    }
+
+    /// The final transcript hash of the DKG
+    #[deprecated(note = "use `transcript_hash_bytes` which supports 64 byte transcript hashes")]
+    pub fn transcript_hash(&self) -> [u8; 32] {
+        legacy_transcript_hash(&self.transcript_hash)
+    }
+
+    /// The final transcript hash of the DKG with the configured
+    /// [`TranscriptHashSize`]
+    pub fn transcript_hash_bytes(&self) -> &[u8] {
+        &self.transcript_hash
+    }
+
+    /// The application identifiers of the shareholders that have one
+    pub fn participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    /// The application identifier of the shareholder at `ordinal`
+    pub fn participant_identifier(&self, ordinal: usize) -> Option<&ParticipantIdentifier> {
+        self.participant_identifiers.get(&ordinal)
+    }
+
+    /// The part of the group key dealt by each combined dealer
+    pub fn contribution_public_keys(&self) -> &BTreeMap<usize, G> {
+        &self.contribution_public_keys
+    }
+
+    /// The part of the group key dealt by the dealer at `ordinal`
+    pub fn contribution_public_key(&self, ordinal: usize) -> Option<G> {
+        self.contribution_public_keys.get(&ordinal).copied()
+    }
+
+    /// Check that the dealer contributions add up to the group key.
+    ///
+    /// A refresh keeps the key and its contributions add up to the identity.
+    pub fn verify_contributions(&self) -> DkgResult<()> {
+        if self.contribution_public_keys.is_empty() {
+            return Err(Error::Signature(
+                "The output has no dealer contributions".to_string(),
+            ));
+        }
+        let aggregate = self
+            .contribution_public_keys
+            .values()
+            .fold(G::identity(), |acc, key| acc + key);
+        if aggregate != self.public_key.0 && !bool::from(aggregate.is_identity()) {
+            return Err(Error::Signature(
+                "Dealer contributions do not add up to the group key".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,208 @@
 use super::*;
+use crate::serdes::{group_map, group_option, hex_group};
 use elliptic_curve::group::GroupEncoding;
 use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::{Field, PrimeField};
 use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DError};
+use std::collections::{BTreeMap, btree_map::Entry};
+use std::fmt::{self, Display, Formatter};
 use std::num::NonZeroUsize;
-use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
+use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorCollection, ParticipantIdGeneratorType};
+
+/// The default application domain label added to the transcripts
+pub const DEFAULT_TRANSCRIPT_LABEL: &[u8] = b"Frost DKG";
+
+/// An old share id and the new id it maps to in a reshare, see
+/// [`Parameters::with_reshare_mapping`]
+pub type ReshareIdPair<F> = (IdentifierPrimeField<F>, IdentifierPrimeField<F>);
+
+/// The size of the transcript hashes exchanged in round 2
+/// and produced at the end of the protocol
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TranscriptHashSize {
+    /// 32 bytes
+    #[default]
+    Bytes32,
+    /// 64 bytes
+    Bytes64,
+}
+
+impl TranscriptHashSize {
+    /// The number of bytes in the hash
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Bytes32 => 32,
+            Self::Bytes64 => 64,
+        }
+    }
+}
+
+/// Optional information describing a participant in the roster.
+///
+/// A digest of the roster metadata is added to the transcripts so every
+/// participant must agree on it for the protocol to complete.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ParticipantMetadata {
+    /// A human readable name
+    pub display_name: Option<String>,
+    /// Where the participant can be reached
+    pub endpoint: Option<String>,
+    /// The fingerprint of the participant's long term public key
+    pub key_fingerprint: Option<Vec<u8>>,
+}
+
+/// Hash the roster metadata for inclusion in the transcripts
+pub(crate) fn roster_digest(metadata: &BTreeMap<usize, ParticipantMetadata>) -> [u8; 32] {
+    fn append_field(
+        transcript: &mut merlin::Transcript,
+        label: &'static [u8],
+        field: Option<&[u8]>,
+    ) {
+        match field {
+            Some(value) => {
+                transcript.append_u64(label, 1);
+                transcript.append_message(label, value);
+            }
+            None => transcript.append_u64(label, 0),
+        }
+    }
+
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Roster");
+    transcript.append_u64(b"entries", metadata.len() as u64);
+    for (ordinal, entry) in metadata {
+        transcript.append_u64(b"ordinal", *ordinal as u64);
+        append_field(
+            &mut transcript,
+            b"display_name",
+            entry.display_name.as_ref().map(|s| s.as_bytes()),
+        );
+        append_field(
+            &mut transcript,
+            b"endpoint",
+            entry.endpoint.as_ref().map(|s| s.as_bytes()),
+        );
+        append_field(
+            &mut transcript,
+            b"key_fingerprint",
+            entry.key_fingerprint.as_deref(),
+        );
+    }
+    let mut digest = [0u8; 32];
+    transcript.challenge_bytes(b"roster digest", &mut digest);
+    digest
+}
+
+/// A problem with a set of [`Parameters`] found by [`Parameters::validate`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParameterDiagnostic {
+    /// The threshold is greater than the limit
+    ThresholdExceedsLimit {
+        /// The threshold
+        threshold: usize,
+        /// The limit
+        limit: usize,
+    },
+    /// The threshold is less than 2
+    ThresholdTooSmall {
+        /// The threshold
+        threshold: usize,
+    },
+    /// The participant id generators do not produce an id for every participant
+    RosterTooSmall {
+        /// The number of ids required
+        expected: usize,
+        /// The number of ids produced
+        actual: usize,
+    },
+    /// The message generator is the identity element
+    IdentityGenerator,
+    /// The blinder generator is the identity element or the message generator
+    InvalidBlinderGenerator,
+    /// A participant id is zero
+    ZeroId {
+        /// The ordinal index of the id
+        ordinal: usize,
+    },
+    /// A participant id is used more than once
+    DuplicateId {
+        /// The ordinal index of the first use
+        first: usize,
+        /// The ordinal index of the repeated use
+        duplicate: usize,
+    },
+    /// The transcript domain label is empty
+    EmptyTranscriptLabel,
+    /// Metadata is attached to an ordinal outside the roster
+    UnknownMetadataOrdinal {
+        /// The ordinal index of the metadata
+        ordinal: usize,
+    },
+    /// A participant identifier does not map to the participant id at its ordinal
+    IdentifierMismatch {
+        /// The ordinal index of the identifier
+        ordinal: usize,
+    },
+    /// The threshold of a handoff is greater than the successor committee
+    HandoffThresholdExceedsSuccessors {
+        /// The threshold
+        threshold: usize,
+        /// The number of successors
+        successors: usize,
+    },
+    /// A reshare mapping entry has a zero or repeated id or maps to an id
+    /// outside the roster
+    InvalidReshareMapping {
+        /// The index of the entry in the mapping
+        index: usize,
+    },
+}
+
+impl Display for ParameterDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ThresholdExceedsLimit { threshold, limit } => {
+                write!(f, "threshold {threshold} is greater than limit {limit}")
+            }
+            Self::ThresholdTooSmall { threshold } => {
+                write!(f, "threshold {threshold} is less than 2")
+            }
+            Self::RosterTooSmall { expected, actual } => write!(
+                f,
+                "participant id generators produced {actual} ids, expected {expected}"
+            ),
+            Self::IdentityGenerator => write!(f, "message generator is the identity element"),
+            Self::InvalidBlinderGenerator => write!(
+                f,
+                "blinder generator is the identity element or the message generator"
+            ),
+            Self::ZeroId { ordinal } => write!(f, "participant id at {ordinal} is zero"),
+            Self::DuplicateId { first, duplicate } => write!(
+                f,
+                "participant id at {duplicate} is the same as the id at {first}"
+            ),
+            Self::EmptyTranscriptLabel => write!(f, "transcript domain label is empty"),
+            Self::UnknownMetadataOrdinal { ordinal } => {
+                write!(f, "metadata is attached to unknown ordinal {ordinal}")
+            }
+            Self::IdentifierMismatch { ordinal } => write!(
+                f,
+                "participant identifier at {ordinal} does not map to the participant id"
+            ),
+            Self::HandoffThresholdExceedsSuccessors {
+                threshold,
+                successors,
+            } => write!(
+                f,
+                "threshold {threshold} is greater than the {successors} handoff successors"
+            ),
+            Self::InvalidReshareMapping { index } => write!(
+                f,
+                "reshare mapping entry {index} has a zero or repeated id or maps outside the roster"
+            ),
+        }
+    }
+}
 
 /// The parameters used by the DKG participants.
 /// This must be the same for all of them otherwise the protocol
@@ -11,7 +210,7 @@ use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
 #[derive(Debug, Default, Clone)]
 pub struct Parameters<'a, G>
 where
-    G: GroupEncoding + Default + SumOfProducts + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     pub(crate) threshold: usize,
@@ -19,15 +218,26 @@ where
     pub(crate) message_generator: G,
     pub(crate) participant_number_generators:
         Vec<ParticipantIdGeneratorType<'a, IdentifierPrimeField<G::Scalar>>>,
+    pub(crate) transcript_label: Vec<u8>,
+    pub(crate) transcript_hash_size: TranscriptHashSize,
+    pub(crate) participant_metadata: BTreeMap<usize, ParticipantMetadata>,
+    pub(crate) participant_identifiers: BTreeMap<usize, ParticipantIdentifier>,
+    pub(crate) previous_verification_shares: BTreeMap<usize, G>,
+    pub(crate) blinder_generator: Option<G>,
+    pub(crate) handoff_dealers: usize,
+    pub(crate) reshare_mapping: Vec<ReshareIdPair<G::Scalar>>,
+    pub(crate) commit_reveal: bool,
 }
 
 impl<'a, G> Parameters<'a, G>
 where
-    G: GroupEncoding + Default + SumOfProducts + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
-    /// Create regular parameters with the message_generator as the default generator
-    /// and a random blinder_generator
+    /// Create regular parameters with the message_generator as the default generator.
+    ///
+    /// No blinder generator is set so the round 1 commitments are feldman
+    /// commitments, see [`Self::with_blinder_generator`].
     pub fn new(
         threshold: NonZeroUsize,
         limit: NonZeroUsize,
@@ -49,9 +259,205 @@ where
             limit: limit.get(),
             message_generator,
             participant_number_generators: participant_number_generator,
+            transcript_label: DEFAULT_TRANSCRIPT_LABEL.to_vec(),
+            transcript_hash_size: TranscriptHashSize::default(),
+            participant_metadata: BTreeMap::new(),
+            participant_identifiers: BTreeMap::new(),
+            previous_verification_shares: BTreeMap::new(),
+            blinder_generator: None,
+            handoff_dealers: 0,
+            reshare_mapping: Vec::new(),
+            commit_reveal: false,
         }
     }
 
+    /// Create parameters for the current committee to hand the key off to
+    /// a disjoint successor committee.
+    ///
+    /// The `dealers` come first by ordinal index followed by the `successors`.
+    /// Dealers reshare their existing shares with
+    /// [`Participant::handoff_dealer`] and never receive new shares.
+    /// Successors join with [`Participant::handoff_successor`] and never see
+    /// the old shares. `threshold` is the threshold of the successor committee.
+    pub fn handoff(
+        threshold: NonZeroUsize,
+        message_generator: Option<G>,
+        dealers: &'a [IdentifierPrimeField<G::Scalar>],
+        successors: &'a [IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<Self> {
+        check_handoff_committees(threshold, dealers, successors)?;
+        let limit =
+            NonZeroUsize::new(dealers.len() + successors.len()).expect("committees are not empty");
+        let mut parameters = Self::new(
+            threshold,
+            limit,
+            message_generator,
+            Some(vec![
+                ParticipantIdGeneratorType::list(dealers),
+                ParticipantIdGeneratorType::list(successors),
+            ]),
+        );
+        parameters.handoff_dealers = dealers.len();
+        Ok(parameters)
+    }
+
+    /// Use an application specific domain label for the transcripts
+    pub fn with_transcript_label(mut self, label: &[u8]) -> Self {
+        self.transcript_label = label.to_vec();
+        self
+    }
+
+    /// Use a different size for the transcript hashes
+    pub fn with_transcript_hash_size(mut self, size: TranscriptHashSize) -> Self {
+        self.transcript_hash_size = size;
+        self
+    }
+
+    /// Bind the transcripts to a session id like [`BeaconOutput::session_id`].
+    ///
+    /// The id is appended to the transcript label so this must be called
+    /// after [`Self::with_transcript_label`].
+    pub fn with_session_id(mut self, session_id: &[u8]) -> Self {
+        self.transcript_label.extend_from_slice(b"/session/");
+        self.transcript_label.extend_from_slice(session_id);
+        self
+    }
+
+    /// Attach metadata to the participant at `ordinal`
+    pub fn with_participant_metadata(
+        mut self,
+        ordinal: usize,
+        metadata: ParticipantMetadata,
+    ) -> Self {
+        self.participant_metadata.insert(ordinal, metadata);
+        self
+    }
+
+    /// Name the participant at `ordinal` with an application identifier.
+    ///
+    /// The identifier must map to the participant id at that ordinal.
+    pub fn with_participant_identifier(
+        mut self,
+        ordinal: usize,
+        identifier: impl Into<ParticipantIdentifier>,
+    ) -> Self {
+        self.participant_identifiers
+            .insert(ordinal, identifier.into());
+        self
+    }
+
+    /// Check a refresh against the verification shares of the key being
+    /// refreshed, as in [`DkgOutput::verification_shares`].
+    ///
+    /// Every refresh participant must then start from the share at its
+    /// ordinal and the refreshed verification shares are available after
+    /// round 3 from [`Participant::get_refreshed_verification_shares`].
+    /// The round 1 signature proves knowledge of that share with a hedged
+    /// nonce, so the broadcast doesn't reveal it.
+    pub fn with_previous_verification_shares(
+        mut self,
+        shares: &BTreeMap<usize, NonIdentity<G>>,
+    ) -> Self {
+        self.previous_verification_shares = shares
+            .iter()
+            .map(|(ordinal, share)| (*ordinal, share.0))
+            .collect();
+        self
+    }
+
+    /// Use pedersen commitments in round 1 that hide the participants'
+    /// contributions until the feldman commitments are opened in round 3.
+    ///
+    /// Each dealer also shares a random blinder polynomial and broadcasts
+    /// the sums of both polynomials' commitments in round 1, signed with a
+    /// key derived for the run. Its round 2 messages are
+    /// [`BlindedRound2Data`] carrying the blinder share, signed with the
+    /// same key, and are checked against the pedersen commitments. Once a
+    /// participant fixed the dealers whose shares it accepted,
+    /// [`Participant::run`] broadcasts the opening of its feldman
+    /// commitments with their proof of knowledge instead of completing, so
+    /// no dealer sees another's contribution before its own is fixed. The
+    /// next run checks every opening against the shares and completes with
+    /// the same outputs as without a blinder.
+    ///
+    /// A dealer that withholds its opening stalls round 3, see
+    /// [`Participant::missing_round1_openings`]. Restarting without it, or
+    /// disqualifying it, lets it choose between two keys, which biases the
+    /// key by one bit.
+    ///
+    /// Nobody may know the discrete log of `blinder_generator` with respect to
+    /// the message generator, so derive it with a hash to curve rather
+    /// than by multiplying a known scalar.
+    ///
+    /// ```
+    /// use frost_dkg::*;
+    /// use hash2curve::ExpandMsgXmd;
+    /// use std::num::NonZeroUsize;
+    /// use vsss_rs::IdentifierPrimeField;
+    ///
+    /// type G = k256::ProjectivePoint;
+    ///
+    /// let blinder_generator = hash2curve::hash_from_bytes::<
+    ///     k256::Secp256k1,
+    ///     ExpandMsgXmd<sha2::Sha256>,
+    /// >(&[b"blinder generator"], &[b"my-application-v1"])
+    /// .unwrap();
+    /// let parameters = Parameters::<G>::new(
+    ///     NonZeroUsize::new(2).unwrap(),
+    ///     NonZeroUsize::new(3).unwrap(),
+    ///     None,
+    ///     None,
+    /// )
+    /// .with_blinder_generator(blinder_generator);
+    /// let mut participants = (1..=3u64)
+    ///     .map(|id| {
+    ///         let id = IdentifierPrimeField(k256::Scalar::from(id));
+    ///         Ok(Box::new(SecretParticipant::<G>::new_secret(id, &parameters)?)
+    ///             as Box<dyn AnyParticipant<G>>)
+    ///     })
+    ///     .collect::<DkgResult<Vec<_>>>()?;
+    /// let outputs = run_local_ceremony(&mut participants)?;
+    /// assert_eq!(outputs[0].public_key(), outputs[2].public_key());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn with_blinder_generator(mut self, blinder_generator: G) -> Self {
+        self.blinder_generator = Some(blinder_generator);
+        self
+    }
+
+    /// Reshare an existing key where the shareholders may join the new
+    /// roster under a different id.
+    ///
+    /// Each entry maps the id of an old share to the id its holder uses in
+    /// this roster. Those holders join with [`Participant::reshare`] and
+    /// every one of them must take part since the old shares are combined
+    /// over all of them. Everyone else joins with [`Participant::new_refresh`]
+    /// without a share. The mapping is bound into the round 1 signatures
+    /// and the transcripts so all participants must agree on it.
+    pub fn with_reshare_mapping(mut self, mapping: &[ReshareIdPair<G::Scalar>]) -> Self {
+        self.reshare_mapping = mapping.to_vec();
+        self
+    }
+
+    /// Precede round 1 with a round of hash commitments to the round 1
+    /// broadcasts.
+    ///
+    /// A participant only reveals its broadcast once it holds the
+    /// commitment of every other participant, see
+    /// [`Participant::commit_round1`], so the last dealer can't pick its
+    /// contribution after seeing the others and bias the group key.
+    ///
+    /// Round 2 waits for the reveal of every participant that committed,
+    /// see [`Participant::missing_round1_reveals`]. A participant that
+    /// withholds its reveal, or reveals data that fails the checks, stalls
+    /// the run, which has to be started over without it. It can still
+    /// decide to do that after seeing the other broadcasts, so it keeps
+    /// the choice between the key of this run and a fresh one.
+    pub fn with_commit_reveal(mut self) -> Self {
+        self.commit_reveal = true;
+        self
+    }
+
     /// The threshold parameter
     pub fn threshold(&self) -> usize {
         self.threshold
@@ -73,4 +479,575 @@ where
     ) -> &[ParticipantIdGeneratorType<'a, IdentifierPrimeField<G::Scalar>>] {
         &self.participant_number_generators
     }
+
+    /// Get the transcript domain label
+    pub fn transcript_label(&self) -> &[u8] {
+        &self.transcript_label
+    }
+
+    /// Get the transcript hash size
+    pub fn transcript_hash_size(&self) -> TranscriptHashSize {
+        self.transcript_hash_size
+    }
+
+    /// Get the metadata attached to participants by ordinal index
+    pub fn participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata> {
+        &self.participant_metadata
+    }
+
+    /// Get the application identifiers of the participants by ordinal index
+    pub fn participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    /// Get the verification shares of the key being refreshed by ordinal index
+    pub fn previous_verification_shares(&self) -> &BTreeMap<usize, G> {
+        &self.previous_verification_shares
+    }
+
+    /// Get the blinder generator for pedersen commitments if one is set
+    pub fn blinder_generator(&self) -> Option<G> {
+        self.blinder_generator
+    }
+
+    /// Get the number of dealers at the start of a handoff roster, zero
+    /// if these are not handoff parameters
+    pub fn handoff_dealers(&self) -> usize {
+        self.handoff_dealers
+    }
+
+    /// Whether round 1 is preceded by a round of commitments
+    pub fn commit_reveal(&self) -> bool {
+        self.commit_reveal
+    }
+
+    /// Get the old id to new id mapping of a reshare
+    pub fn reshare_mapping(&self) -> &[ReshareIdPair<G::Scalar>] {
+        &self.reshare_mapping
+    }
+
+    /// The ids of the roster in ordinal order, as produced by the
+    /// participant number generators
+    pub fn roster_ids(&self) -> DkgResult<Vec<IdentifierPrimeField<G::Scalar>>> {
+        let ids = ParticipantIdGeneratorCollection::from(&self.participant_number_generators)
+            .iter()
+            .take(self.limit)
+            .collect::<Vec<_>>();
+        if ids.len() != self.limit {
+            return Err(Error::Initialization(format!(
+                "Expected {} participant ids but the generators produced {}",
+                self.limit,
+                ids.len()
+            )));
+        }
+        Ok(ids)
+    }
+
+    /// Check the parameters and report every problem found.
+    ///
+    /// Participants cannot be created from parameters that produce any
+    /// diagnostics other than [`ParameterDiagnostic::EmptyTranscriptLabel`].
+    /// An empty list means the parameters are usable.
+    pub fn validate(&self) -> Vec<ParameterDiagnostic> {
+        let mut diagnostics = Vec::new();
+        if self.threshold > self.limit {
+            diagnostics.push(ParameterDiagnostic::ThresholdExceedsLimit {
+                threshold: self.threshold,
+                limit: self.limit,
+            });
+        }
+        if self.threshold < 2 {
+            diagnostics.push(ParameterDiagnostic::ThresholdTooSmall {
+                threshold: self.threshold,
+            });
+        }
+        if self.message_generator.is_identity().into() {
+            diagnostics.push(ParameterDiagnostic::IdentityGenerator);
+        }
+        if self.blinder_generator.is_some_and(|blinder| {
+            bool::from(blinder.is_identity()) || blinder == self.message_generator
+        }) {
+            diagnostics.push(ParameterDiagnostic::InvalidBlinderGenerator);
+        }
+
+        // The generators stop a list at a zero id, read lists directly to
+        // report it
+        let participant_ids = self
+            .participant_number_generators
+            .iter()
+            .flat_map(|generator| match generator {
+                ParticipantIdGeneratorType::List { list } => list.to_vec(),
+                _ => ParticipantIdGeneratorCollection::from(std::slice::from_ref(generator))
+                    .iter()
+                    .collect(),
+            })
+            .take(self.limit)
+            .collect::<Vec<_>>();
+        if participant_ids.len() != self.limit {
+            diagnostics.push(ParameterDiagnostic::RosterTooSmall {
+                expected: self.limit,
+                actual: participant_ids.len(),
+            });
+        }
+        let mut seen = BTreeMap::new();
+        for (ordinal, id) in participant_ids.iter().enumerate() {
+            if id.is_zero().into() {
+                diagnostics.push(ParameterDiagnostic::ZeroId { ordinal });
+            }
+            match seen.entry(id.0.to_repr().as_ref().to_vec()) {
+                Entry::Occupied(first) => {
+                    diagnostics.push(ParameterDiagnostic::DuplicateId {
+                        first: *first.get(),
+                        duplicate: ordinal,
+                    });
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(ordinal);
+                }
+            }
+        }
+
+        if self.transcript_label.is_empty() {
+            diagnostics.push(ParameterDiagnostic::EmptyTranscriptLabel);
+        }
+        for ordinal in self.participant_metadata.keys() {
+            if *ordinal >= self.limit {
+                diagnostics.push(ParameterDiagnostic::UnknownMetadataOrdinal { ordinal: *ordinal });
+            }
+        }
+        for (ordinal, identifier) in &self.participant_identifiers {
+            let matches = identifier
+                .to_identifier::<G::Scalar>()
+                .is_ok_and(|id| participant_ids.get(*ordinal) == Some(&id));
+            if !matches {
+                diagnostics.push(ParameterDiagnostic::IdentifierMismatch { ordinal: *ordinal });
+            }
+        }
+        let successors = self.limit.saturating_sub(self.handoff_dealers);
+        if self.handoff_dealers > 0 && self.threshold > successors {
+            diagnostics.push(ParameterDiagnostic::HandoffThresholdExceedsSuccessors {
+                threshold: self.threshold,
+                successors,
+            });
+        }
+        let (_, invalid) = reshare_ordinals(&self.reshare_mapping, &participant_ids);
+        diagnostics.extend(
+            invalid
+                .into_iter()
+                .map(|index| ParameterDiagnostic::InvalidReshareMapping { index }),
+        );
+        diagnostics
+    }
+}
+
+/// An owned version of [`Parameters`] with the participant ids materialized.
+///
+/// Unlike [`Parameters`] this does not borrow the id list so it can be
+/// stored in long lived structs, sent across threads and deserialized.
+///
+/// Deserializing checks the threshold and the limit against the participant
+/// ids like [`OwnedParameters::new`] does.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct OwnedParameters<G>
+where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    pub(crate) threshold: usize,
+    pub(crate) limit: usize,
+    #[serde(with = "hex_group")]
+    pub(crate) message_generator: G,
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) participant_ids: Vec<IdentifierPrimeField<G::Scalar>>,
+    pub(crate) transcript_label: Vec<u8>,
+    pub(crate) transcript_hash_size: TranscriptHashSize,
+    pub(crate) participant_metadata: BTreeMap<usize, ParticipantMetadata>,
+    #[serde(default)]
+    pub(crate) participant_identifiers: BTreeMap<usize, ParticipantIdentifier>,
+    #[serde(default = "BTreeMap::new", with = "group_map")]
+    pub(crate) previous_verification_shares: BTreeMap<usize, G>,
+    #[serde(default = "Option::default", with = "group_option")]
+    pub(crate) blinder_generator: Option<G>,
+    #[serde(default)]
+    pub(crate) handoff_dealers: usize,
+    #[serde(
+        default,
+        bound(
+            serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+            deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+        )
+    )]
+    pub(crate) reshare_mapping: Vec<ReshareIdPair<G::Scalar>>,
+    #[serde(default)]
+    pub(crate) commit_reveal: bool,
+}
+
+impl<G> OwnedParameters<G>
+where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create owned parameters with an explicit list of participant ids
+    pub fn new(
+        threshold: NonZeroUsize,
+        message_generator: Option<G>,
+        participant_ids: Vec<IdentifierPrimeField<G::Scalar>>,
+    ) -> Self {
+        Self {
+            threshold: threshold.get(),
+            limit: participant_ids.len(),
+            message_generator: message_generator.unwrap_or_else(G::generator),
+            participant_ids,
+            transcript_label: DEFAULT_TRANSCRIPT_LABEL.to_vec(),
+            transcript_hash_size: TranscriptHashSize::default(),
+            participant_metadata: BTreeMap::new(),
+            participant_identifiers: BTreeMap::new(),
+            previous_verification_shares: BTreeMap::new(),
+            blinder_generator: None,
+            handoff_dealers: 0,
+            reshare_mapping: Vec::new(),
+            commit_reveal: false,
+        }
+    }
+
+    /// Create owned parameters for a handoff to a disjoint successor
+    /// committee, see [`Parameters::handoff`]
+    pub fn handoff(
+        threshold: NonZeroUsize,
+        message_generator: Option<G>,
+        dealers: Vec<IdentifierPrimeField<G::Scalar>>,
+        successors: Vec<IdentifierPrimeField<G::Scalar>>,
+    ) -> DkgResult<Self> {
+        check_handoff_committees(threshold, &dealers, &successors)?;
+        let handoff_dealers = dealers.len();
+        let mut parameters = Self::new(
+            threshold,
+            message_generator,
+            dealers.into_iter().chain(successors).collect(),
+        );
+        parameters.handoff_dealers = handoff_dealers;
+        Ok(parameters)
+    }
+
+    /// Create owned parameters with the participant ids derived from
+    /// application `identifiers`
+    pub fn from_identifiers(
+        threshold: NonZeroUsize,
+        message_generator: Option<G>,
+        identifiers: Vec<ParticipantIdentifier>,
+    ) -> DkgResult<Self> {
+        let participant_ids = identifiers
+            .iter()
+            .map(|identifier| identifier.to_identifier::<G::Scalar>())
+            .collect::<DkgResult<Vec<_>>>()?;
+        let mut parameters = Self::new(threshold, message_generator, participant_ids);
+        parameters.participant_identifiers = identifiers.into_iter().enumerate().collect();
+        Ok(parameters)
+    }
+
+    /// Use an application specific domain label for the transcripts
+    pub fn with_transcript_label(mut self, label: &[u8]) -> Self {
+        self.transcript_label = label.to_vec();
+        self
+    }
+
+    /// Use a different size for the transcript hashes
+    pub fn with_transcript_hash_size(mut self, size: TranscriptHashSize) -> Self {
+        self.transcript_hash_size = size;
+        self
+    }
+
+    /// Bind the transcripts to a session id like [`BeaconOutput::session_id`].
+    ///
+    /// The id is appended to the transcript label so this must be called
+    /// after [`Self::with_transcript_label`].
+    pub fn with_session_id(mut self, session_id: &[u8]) -> Self {
+        self.transcript_label.extend_from_slice(b"/session/");
+        self.transcript_label.extend_from_slice(session_id);
+        self
+    }
+
+    /// Attach metadata to the participant at `ordinal`
+    pub fn with_participant_metadata(
+        mut self,
+        ordinal: usize,
+        metadata: ParticipantMetadata,
+    ) -> Self {
+        self.participant_metadata.insert(ordinal, metadata);
+        self
+    }
+
+    /// Name the participant at `ordinal` with an application identifier.
+    ///
+    /// The identifier must map to the participant id at that ordinal.
+    pub fn with_participant_identifier(
+        mut self,
+        ordinal: usize,
+        identifier: impl Into<ParticipantIdentifier>,
+    ) -> Self {
+        self.participant_identifiers
+            .insert(ordinal, identifier.into());
+        self
+    }
+
+    /// Check a refresh against the verification shares of the key being
+    /// refreshed, as in [`DkgOutput::verification_shares`]
+    pub fn with_previous_verification_shares(
+        mut self,
+        shares: &BTreeMap<usize, NonIdentity<G>>,
+    ) -> Self {
+        self.previous_verification_shares = shares
+            .iter()
+            .map(|(ordinal, share)| (*ordinal, share.0))
+            .collect();
+        self
+    }
+
+    /// Use pedersen commitments in round 1, see [`Parameters::with_blinder_generator`]
+    pub fn with_blinder_generator(mut self, blinder_generator: G) -> Self {
+        self.blinder_generator = Some(blinder_generator);
+        self
+    }
+
+    /// Map the ids of old shares to the ids their holders use in this
+    /// roster, see [`Parameters::with_reshare_mapping`]
+    pub fn with_reshare_mapping(mut self, mapping: &[ReshareIdPair<G::Scalar>]) -> Self {
+        self.reshare_mapping = mapping.to_vec();
+        self
+    }
+
+    /// Commit to the round 1 broadcasts before revealing them, see
+    /// [`Parameters::with_commit_reveal`]
+    pub fn with_commit_reveal(mut self) -> Self {
+        self.commit_reveal = true;
+        self
+    }
+
+    /// Borrow these as [`Parameters`] to create participants
+    pub fn as_parameters(&self) -> Parameters<'_, G> {
+        Parameters {
+            threshold: self.threshold,
+            limit: self.limit,
+            message_generator: self.message_generator,
+            participant_number_generators: vec![ParticipantIdGeneratorType::list(
+                &self.participant_ids,
+            )],
+            transcript_label: self.transcript_label.clone(),
+            transcript_hash_size: self.transcript_hash_size,
+            participant_metadata: self.participant_metadata.clone(),
+            participant_identifiers: self.participant_identifiers.clone(),
+            previous_verification_shares: self.previous_verification_shares.clone(),
+            blinder_generator: self.blinder_generator,
+            handoff_dealers: self.handoff_dealers,
+            reshare_mapping: self.reshare_mapping.clone(),
+            commit_reveal: self.commit_reveal,
+        }
+    }
+
+    /// The threshold parameter
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The limit parameter
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Get the message generator
+    pub fn message_generator(&self) -> G {
+        self.message_generator
+    }
+
+    /// Get the participant ids
+    pub fn participant_ids(&self) -> &[IdentifierPrimeField<G::Scalar>] {
+        &self.participant_ids
+    }
+
+    /// Get the transcript domain label
+    pub fn transcript_label(&self) -> &[u8] {
+        &self.transcript_label
+    }
+
+    /// Get the transcript hash size
+    pub fn transcript_hash_size(&self) -> TranscriptHashSize {
+        self.transcript_hash_size
+    }
+
+    /// Get the metadata attached to participants by ordinal index
+    pub fn participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata> {
+        &self.participant_metadata
+    }
+
+    /// Get the application identifiers of the participants by ordinal index
+    pub fn participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    /// Get the verification shares of the key being refreshed by ordinal index
+    pub fn previous_verification_shares(&self) -> &BTreeMap<usize, G> {
+        &self.previous_verification_shares
+    }
+
+    /// Get the blinder generator for pedersen commitments if one is set
+    pub fn blinder_generator(&self) -> Option<G> {
+        self.blinder_generator
+    }
+
+    /// Get the number of dealers at the start of a handoff roster
+    pub fn handoff_dealers(&self) -> usize {
+        self.handoff_dealers
+    }
+
+    /// Whether round 1 is preceded by a round of commitments
+    pub fn commit_reveal(&self) -> bool {
+        self.commit_reveal
+    }
+
+    /// Get the old id to new id mapping of a reshare
+    pub fn reshare_mapping(&self) -> &[ReshareIdPair<G::Scalar>] {
+        &self.reshare_mapping
+    }
+
+    /// Check the parameters and report every problem found
+    pub fn validate(&self) -> Vec<ParameterDiagnostic> {
+        self.as_parameters().validate()
+    }
+}
+
+impl<G> Serialize for OwnedParameters<G>
+where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    IdentifierPrimeField<G::Scalar>: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(self, s)
+    }
+}
+
+impl<'de, G> Deserialize<'de> for OwnedParameters<G>
+where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    IdentifierPrimeField<G::Scalar>: Deserialize<'de>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parameters = Self::deserialize(d)?;
+        if parameters.threshold == 0 {
+            return Err(DError::custom("the threshold is zero"));
+        }
+        if parameters.limit != parameters.participant_ids.len() {
+            return Err(DError::custom(format!(
+                "the limit {} is not the number of participant ids {}",
+                parameters.limit,
+                parameters.participant_ids.len()
+            )));
+        }
+        if parameters.threshold > parameters.limit {
+            return Err(DError::custom(format!(
+                "the threshold {} is greater than the limit {}",
+                parameters.threshold, parameters.limit
+            )));
+        }
+        Ok(parameters)
+    }
+}
+
+impl<G> TryFrom<&Parameters<'_, G>> for OwnedParameters<G>
+where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    type Error = Error;
+
+    fn try_from(parameters: &Parameters<'_, G>) -> DkgResult<Self> {
+        let participant_ids =
+            ParticipantIdGeneratorCollection::from(&parameters.participant_number_generators)
+                .iter()
+                .take(parameters.limit)
+                .collect::<Vec<_>>();
+        if participant_ids.len() != parameters.limit {
+            return Err(Error::Initialization(format!(
+                "Participant id generators produced {} ids, expected {}",
+                participant_ids.len(),
+                parameters.limit
+            )));
+        }
+        Ok(Self {
+            threshold: parameters.threshold,
+            limit: parameters.limit,
+            message_generator: parameters.message_generator,
+            participant_ids,
+            transcript_label: parameters.transcript_label.clone(),
+            transcript_hash_size: parameters.transcript_hash_size,
+            participant_metadata: parameters.participant_metadata.clone(),
+            participant_identifiers: parameters.participant_identifiers.clone(),
+            previous_verification_shares: parameters.previous_verification_shares.clone(),
+            blinder_generator: parameters.blinder_generator,
+            handoff_dealers: parameters.handoff_dealers,
+            reshare_mapping: parameters.reshare_mapping.clone(),
+            commit_reveal: parameters.commit_reveal,
+        })
+    }
+}
+
+/// Check that the committees of a handoff are disjoint and the successors
+/// can meet the threshold
+fn check_handoff_committees<F: ScalarHash>(
+    threshold: NonZeroUsize,
+    dealers: &[IdentifierPrimeField<F>],
+    successors: &[IdentifierPrimeField<F>],
+) -> DkgResult<()> {
+    if dealers.is_empty() || successors.is_empty() {
+        return Err(Error::Initialization(
+            "A handoff needs dealers and successors".to_string(),
+        ));
+    }
+    if let Some(id) = successors.iter().find(|id| dealers.contains(id)) {
+        return Err(Error::Initialization(format!(
+            "Participant id '{id}' is both a dealer and a successor"
+        )));
+    }
+    if threshold.get() > successors.len() {
+        return Err(Error::Initialization(format!(
+            "Threshold {threshold} is greater than the {} successors",
+            successors.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Resolve a reshare mapping into the old share id of each mapped
+/// participant by ordinal index, along with the index of every entry that
+/// has a zero or repeated id or maps to an id outside `participant_ids`
+pub(crate) fn reshare_ordinals<F: ScalarHash>(
+    mapping: &[ReshareIdPair<F>],
+    participant_ids: &[IdentifierPrimeField<F>],
+) -> (BTreeMap<usize, IdentifierPrimeField<F>>, Vec<usize>) {
+    let mut ordinals = BTreeMap::new();
+    let mut invalid = Vec::new();
+    for (index, (old_id, new_id)) in mapping.iter().enumerate() {
+        let repeated = mapping[..index]
+            .iter()
+            .any(|(old, new)| old == old_id || new == new_id);
+        let ordinal = participant_ids.iter().position(|id| id == new_id);
+        match ordinal {
+            Some(ordinal) if !repeated && !bool::from(old_id.is_zero()) => {
+                ordinals.insert(ordinal, *old_id);
+            }
+            _ => invalid.push(index),
+        }
+    }
+    (ordinals, invalid)
 }
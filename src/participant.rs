@@ -1,20 +1,29 @@
 mod round1;
 mod round2;
 mod round3;
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub(crate) use round1::check_round1data;
 
 use super::*;
+use crate::locked::SecretStorage;
+use crate::telemetry::{self, RoundTimer};
 use elliptic_curve::group::GroupEncoding;
 use elliptic_curve::subtle::ConditionallySelectable;
-use elliptic_curve::{Field, Group};
+use elliptic_curve::{Field, Group, PrimeField};
 use elliptic_curve_tools::SumOfProducts;
 use rand_core::CryptoRng;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
-use std::fmt::{self, Debug, Formatter};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use vsss_rs::{
-    DefaultShare, IdentifierPrimeField, ShareElement, ShareVerifierGroup, ValueGroup,
-    ValuePrimeField, subtle::ConstantTimeEq,
+    IdentifierPrimeField, ParticipantIdGeneratorCollection, Share, ShareElement, ValuePrimeField,
+    subtle::ConstantTimeEq,
 };
 
 /// Secret Participant type
@@ -23,16 +32,13 @@ pub type SecretParticipant<G> = Participant<SecretParticipantImpl<G>, G>;
 /// Refresh Participant type
 pub type RefreshParticipant<G> = Participant<RefreshParticipantImpl<G>, G>;
 
-/// The inner share representation
-pub type SecretShare<F> = DefaultShare<IdentifierPrimeField<F>, IdentifierPrimeField<F>>;
-
 /// The inner feldman share verifiers
-pub type FeldmanShareVerifier<G> = ShareVerifierGroup<G>;
+pub type FeldmanShareVerifier<G> = G;
 
 /// Participant implementation
 pub trait ParticipantImpl<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// Get the participant type
@@ -50,7 +56,7 @@ where
 pub struct Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     pub(crate) ordinal: usize,
@@ -60,46 +66,85 @@ where
     pub(crate) round: Round,
     pub(crate) completed: bool,
     pub(crate) secret_shares: BTreeMap<usize, SecretShare<G::Scalar>>,
-    pub(crate) feldman_verifiers: Vec<ValueGroup<G>>,
+    pub(crate) feldman_verifiers: FeldmanCommitments<G>,
     pub(crate) original_secret: G::Scalar,
-    pub(crate) verifying_share: G,
-    pub(crate) secret_share: SecretShare<G::Scalar>,
+    pub(crate) verifying_share: NonIdentity<G>,
+    pub(crate) secret_share: SecretStorage<SecretShare<G::Scalar>>,
     pub(crate) message_generator: G,
-    pub(crate) public_key: ValueGroup<G>,
+    pub(crate) public_key: G,
+    pub(crate) verification_shares: BTreeMap<usize, G>,
     pub(crate) powers_of_i: Vec<G::Scalar>,
     pub(crate) received_round1_data: BTreeMap<usize, Round1Data<G>>,
     pub(crate) received_round2_data: BTreeMap<usize, Round2Data<G::Scalar>>,
+    pub(crate) round1_openings: BTreeMap<usize, Round1Data<G>>,
     pub(crate) all_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
     pub(crate) valid_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    pub(crate) invalid_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    pub(crate) dispute_evidence: BTreeMap<usize, DisputeEvidence<G>>,
+    pub(crate) transcript_label: Vec<u8>,
+    pub(crate) transcript_hash_size: TranscriptHashSize,
+    pub(crate) final_transcript_hash: Option<Vec<u8>>,
+    pub(crate) participant_metadata: BTreeMap<usize, ParticipantMetadata>,
+    pub(crate) participant_identifiers: BTreeMap<usize, ParticipantIdentifier>,
+    pub(crate) previous_verification_shares: BTreeMap<usize, G>,
+    pub(crate) blinder_generator: Option<G>,
+    pub(crate) pedersen_commitments: FeldmanCommitments<G>,
+    pub(crate) blinder_shares: BTreeMap<usize, SecretShare<G::Scalar>>,
+    pub(crate) handoff_dealers: usize,
+    pub(crate) reshare_mapping: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    pub(crate) transcript_extensions: Vec<(Vec<u8>, Vec<u8>)>,
+    pub(crate) quarantined_messages: BTreeMap<usize, Vec<Vec<u8>>>,
+    pub(crate) disqualified_participant_ids: BTreeSet<usize>,
+    pub(crate) share_acks: BTreeMap<usize, ShareAck<G>>,
+    pub(crate) missing_share_policy: MissingSharePolicy,
+    pub(crate) fault_tolerance: usize,
+    pub(crate) commit_reveal: bool,
+    pub(crate) round1_commitments: BTreeMap<usize, [u8; 32]>,
+    pub(crate) committed_round1: Option<Round1Data<G>>,
+    pub(crate) expected_senders: BTreeMap<Round, BTreeSet<usize>>,
+    pub(crate) policy: Option<Arc<dyn Policy>>,
+    pub(crate) round_timer: RoundTimer,
     pub(crate) participant_impl: I,
 }
 
 impl<I, G> Debug for Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.public_view(), f)
+    }
+}
+
+impl<I, G> Display for Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Participant")
-            .field("ordinal", &self.ordinal)
-            .field("id", &self.id)
-            .field("threshold", &self.threshold)
-            .field("limit", &self.limit)
-            .field("round", &self.round)
-            .field("feldman_verifiers", &self.feldman_verifiers)
-            .field("secret_share", &self.secret_share)
-            .field("public_key", &self.public_key)
-            .field("powers_of_i", &self.powers_of_i)
-            .field("received_round1_data", &self.received_round1_data)
-            .field("received_round2_data", &self.received_round2_data)
-            .finish()
+        write!(
+            f,
+            "{:?} participant {} (id {}): round {}, {}/{} round 1, {}/{} round 2, {} invalid, completed: {}",
+            self.participant_impl.get_type(),
+            self.ordinal,
+            self.id,
+            self.round,
+            self.received_round1_data.len(),
+            self.all_participant_ids.len(),
+            self.received_round2_data.len(),
+            self.valid_participant_ids.len(),
+            self.invalid_participant_ids.len(),
+            self.completed,
+        )
     }
 }
 
 impl<G> Participant<SecretParticipantImpl<G>, G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// Create a new participant to generate a new key share
@@ -107,9 +152,39 @@ where
         id: IdentifierPrimeField<G::Scalar>,
         parameters: &Parameters<G>,
     ) -> DkgResult<Self> {
-        let rng = rand::rng();
-        let secret = SecretParticipantImpl::<G>::random_value(rng);
-        Self::initialize(id, parameters, IdentifierPrimeField(secret), None)
+        Self::new_secret_with_rng(id, parameters, rand::rng())
+    }
+
+    /// Create a new participant to generate a new key share using `rng`
+    /// for the secret and the polynomial
+    pub fn new_secret_with_rng(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        mut rng: impl CryptoRng,
+    ) -> DkgResult<Self> {
+        let secret = SecretParticipantImpl::<G>::random_value(&mut rng);
+        Self::initialize(id, parameters, IdentifierPrimeField(secret), None, rng)
+    }
+
+    /// Create a new participant that shares the polynomial with the given
+    /// `coefficients` instead of a random one.
+    ///
+    /// The first coefficient is the secret. There must be exactly `threshold`
+    /// coefficients and none of them may be zero. Reusing coefficients across
+    /// ceremonies reveals the secret, only use this to reproduce a specific sharing.
+    pub fn with_coefficients(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        coefficients: &[G::Scalar],
+    ) -> DkgResult<Self> {
+        let secret = coefficients.first().copied().unwrap_or(G::Scalar::ZERO);
+        Self::initialize_with_coefficients(
+            id,
+            parameters,
+            IdentifierPrimeField(secret),
+            None,
+            coefficients,
+        )
     }
 
     /// Create a new participant with an existing secret.
@@ -121,19 +196,97 @@ where
         parameters: &Parameters<G>,
         shares_ids: &[IdentifierPrimeField<G::Scalar>],
     ) -> DkgResult<Self> {
-        let secret = *old_share.value * *Self::lagrange(old_share, shares_ids);
+        let position = shares_ids
+            .iter()
+            .position(|id| *id == old_share.identifier)
+            .ok_or_else(|| {
+                Error::Initialization("Old share is not in the share ids".to_string())
+            })?;
+        let lambda = lagrange_coefficients(shares_ids, G::Scalar::ZERO)?[position];
+        let secret = *old_share.value * lambda;
         Self::initialize(
             new_identifier,
             parameters,
             IdentifierPrimeField(secret),
             None,
+            rand::rng(),
+        )
+    }
+
+    /// Create a new participant with an existing secret using coefficients
+    /// precomputed for the old share identifiers.
+    ///
+    /// Equivalent to [`Participant::with_secret`] but the same `table` can be
+    /// reused for every share of the old committee.
+    pub fn with_lagrange_table(
+        new_identifier: IdentifierPrimeField<G::Scalar>,
+        old_share: &SecretShare<G::Scalar>,
+        parameters: &Parameters<G>,
+        table: &LagrangeTable<G::Scalar>,
+    ) -> DkgResult<Self> {
+        let lambda = table.coefficient(&old_share.identifier).ok_or_else(|| {
+            Error::Initialization("Old share is not in the Lagrange table".to_string())
+        })?;
+        Self::initialize(
+            new_identifier,
+            parameters,
+            IdentifierPrimeField(*old_share.value * lambda),
+            None,
+            rand::rng(),
         )
     }
+
+    /// Create a dealer of a handoff from its share of the current key.
+    ///
+    /// The dealer keeps the id of its share and every dealer in `parameters`
+    /// must take part since the old shares are combined over all of them.
+    /// The dealer completes without a new share.
+    pub fn handoff_dealer(
+        old_share: &SecretShare<G::Scalar>,
+        parameters: &Parameters<G>,
+    ) -> DkgResult<Self> {
+        if parameters.handoff_dealers == 0 {
+            return Err(Error::Initialization(
+                "Parameters are not for a handoff".to_string(),
+            ));
+        }
+        let dealers =
+            ParticipantIdGeneratorCollection::from(&parameters.participant_number_generators)
+                .iter()
+                .take(parameters.handoff_dealers)
+                .collect::<Vec<_>>();
+        Self::with_secret(old_share.identifier, old_share, parameters, &dealers)
+    }
+
+    /// Create a participant that reshares its share of the current key
+    /// under the id the reshare mapping of `parameters` gives it.
+    ///
+    /// Every old share in the mapping must take part, see
+    /// [`Parameters::with_reshare_mapping`].
+    pub fn reshare(
+        old_share: &SecretShare<G::Scalar>,
+        parameters: &Parameters<G>,
+    ) -> DkgResult<Self> {
+        let new_id = parameters
+            .reshare_mapping
+            .iter()
+            .find(|(old_id, _)| *old_id == old_share.identifier)
+            .map(|(_, new_id)| *new_id)
+            .ok_or_else(|| {
+                Error::Initialization("Old share is not in the reshare mapping".to_string())
+            })?;
+        let old_ids = parameters
+            .reshare_mapping
+            .iter()
+            .map(|(old_id, _)| *old_id)
+            .collect::<Vec<_>>();
+        Self::with_secret(new_id, old_share, parameters, &old_ids)
+    }
 }
 
 impl<G> Participant<RefreshParticipantImpl<G>, G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// Create a new participant to refresh an existing key share if it exists.
@@ -151,24 +304,93 @@ where
             parameters,
             IdentifierPrimeField(secret),
             Some(parameters.message_generator * secret),
+            rand::rng(),
+        )
+    }
+
+    /// Create a new participant to refresh an existing key share using the
+    /// polynomial with the given `coefficients` instead of a random one.
+    ///
+    /// The first coefficient must be zero so the refresh doesn't change the
+    /// key. There must be exactly `threshold` coefficients.
+    pub fn new_refresh_with_coefficients(
+        id: IdentifierPrimeField<G::Scalar>,
+        existing_share: Option<G::Scalar>,
+        parameters: &Parameters<G>,
+        coefficients: &[G::Scalar],
+    ) -> DkgResult<Self> {
+        let secret = existing_share.unwrap_or_else(|| G::Scalar::random(&mut rand::rng()));
+        Self::initialize_with_coefficients(
+            id,
+            parameters,
+            IdentifierPrimeField(secret),
+            Some(parameters.message_generator * secret),
+            coefficients,
         )
     }
+
+    /// Create a member of the successor committee of a handoff.
+    ///
+    /// Successors only deal a sharing of zero so they receive shares of the
+    /// existing key without ever seeing the old shares.
+    pub fn handoff_successor(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+    ) -> DkgResult<Self> {
+        if parameters.handoff_dealers == 0 {
+            return Err(Error::Initialization(
+                "Parameters are not for a handoff".to_string(),
+            ));
+        }
+        Self::new_refresh(id, None, parameters)
+    }
+
+    /// Rotate the polynomial of an existing key when every shareholder
+    /// keeps its id.
+    ///
+    /// Unlike [`Participant::with_secret`] nothing is interpolated: the
+    /// participant keeps the id of `old_share` and deals a sharing of zero,
+    /// see [`Participant::get_refreshed_secret_share`]. The roster of
+    /// `parameters` must be exactly `shares_ids`, with the same threshold,
+    /// and carry every previous verification share so each shareholder is
+    /// checked to start from its own share. The round 1 signature proves
+    /// the old share with a hedged nonce and doesn't reveal it.
+    pub fn with_same_roster_share(
+        old_share: &SecretShare<G::Scalar>,
+        parameters: &Parameters<G>,
+        shares_ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<Self> {
+        let roster = parameters.roster_ids()?;
+        if roster.len() != shares_ids.len()
+            || roster.iter().any(|id| !shares_ids.contains(id))
+            || parameters.handoff_dealers > 0
+            || !parameters.reshare_mapping.is_empty()
+        {
+            return Err(Error::Initialization(
+                "The roster is not the same as the share ids".to_string(),
+            ));
+        }
+        if !shares_ids.contains(&old_share.identifier) {
+            return Err(Error::Initialization(
+                "Old share is not in the share ids".to_string(),
+            ));
+        }
+        if parameters.previous_verification_shares.len() != roster.len() {
+            return Err(Error::Initialization(
+                "Every previous verification share is required".to_string(),
+            ));
+        }
+        Self::new_refresh(old_share.identifier, Some(*old_share.value), parameters)
+    }
 }
 
 impl<I, G> Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
-    fn initialize(
-        id: IdentifierPrimeField<G::Scalar>,
-        parameters: &Parameters<G>,
-        secret: ValuePrimeField<G::Scalar>,
-        verifying_share: Option<G>,
-    ) -> DkgResult<Self> {
-        let rng = rand::rng();
-
+    fn check_parameters(parameters: &Parameters<G>) -> DkgResult<()> {
         if parameters.threshold > parameters.limit {
             return Err(Error::Initialization(
                 "Threshold greater than limit".to_string(),
@@ -182,7 +404,109 @@ where
                 "Invalid message generator".to_string(),
             ));
         }
+        if parameters.handoff_dealers > 0
+            && parameters.threshold > parameters.limit.saturating_sub(parameters.handoff_dealers)
+        {
+            return Err(Error::Initialization(
+                "Threshold greater than the handoff successors".to_string(),
+            ));
+        }
+        if parameters.blinder_generator.is_some_and(|blinder| {
+            bool::from(blinder.is_identity()) || blinder == parameters.message_generator
+        }) {
+            return Err(Error::Initialization(
+                "Invalid blinder generator".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn initialize(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        secret: ValuePrimeField<G::Scalar>,
+        verifying_share: Option<G>,
+        mut rng: impl CryptoRng,
+    ) -> DkgResult<Self> {
+        Self::check_parameters(parameters)?;
+        let secret_to_split = match I::default().get_type() {
+            ParticipantType::Secret => secret.0,
+            ParticipantType::Refresh => G::Scalar::ZERO,
+        };
+        let threshold = NonZeroUsize::new(parameters.threshold).expect("the threshold was checked");
+        let polynomial = DealerPolynomial::random(threshold, secret_to_split, &mut rng);
+        Self::initialize_with_polynomial(id, parameters, secret, verifying_share, &polynomial, rng)
+    }
+
+    fn initialize_with_coefficients(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        secret: ValuePrimeField<G::Scalar>,
+        verifying_share: Option<G>,
+        coefficients: &[G::Scalar],
+    ) -> DkgResult<Self> {
+        let polynomial = DealerPolynomial::from_coefficients(coefficients);
+        Self::initialize_with_polynomial(
+            id,
+            parameters,
+            secret,
+            verifying_share,
+            &polynomial,
+            rand::rng(),
+        )
+    }
+
+    /// Deal `polynomial` to the roster of `parameters` and locate `id` in
+    /// it, drawing the blinders of pedersen commitments from `rng`
+    pub(crate) fn initialize_with_polynomial(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        secret: ValuePrimeField<G::Scalar>,
+        verifying_share: Option<G>,
+        polynomial: &DealerPolynomial<G>,
+        rng: impl CryptoRng,
+    ) -> DkgResult<Self> {
+        Self::check_parameters(parameters)?;
+        if polynomial.threshold() != parameters.threshold {
+            return Err(Error::Initialization(format!(
+                "Expected {} polynomial coefficients but got {}",
+                parameters.threshold,
+                polynomial.threshold()
+            )));
+        }
+        let constant_matches = match I::default().get_type() {
+            ParticipantType::Secret => polynomial.secret() == secret.0,
+            ParticipantType::Refresh => bool::from(polynomial.secret().is_zero()),
+        };
+        if !constant_matches {
+            return Err(Error::Initialization(
+                "Polynomial constant term does not match the secret".to_string(),
+            ));
+        }
+
+        let ids = parameters.roster_ids()?;
+        let shares = polynomial.deal(&ids)?;
+        let verifiers = polynomial.commitments(parameters.message_generator);
+        Self::from_sharing(
+            id,
+            parameters,
+            secret,
+            verifying_share,
+            &shares,
+            verifiers,
+            rng,
+        )
+    }
 
+    fn from_sharing(
+        id: IdentifierPrimeField<G::Scalar>,
+        parameters: &Parameters<G>,
+        secret: ValuePrimeField<G::Scalar>,
+        verifying_share: Option<G>,
+        shares: &[SecretShare<G::Scalar>],
+        verifiers: FeldmanCommitments<G>,
+        mut rng: impl CryptoRng,
+    ) -> DkgResult<Self> {
         let mut powers_of_i = vec![G::Scalar::ONE; parameters.threshold];
         powers_of_i[1] = *id;
         for i in 2..parameters.threshold {
@@ -190,53 +514,89 @@ where
         }
 
         let participant_type = I::default().get_type();
-        let secret_to_split = match participant_type {
-            ParticipantType::Secret => secret,
-            ParticipantType::Refresh => IdentifierPrimeField(G::Scalar::ZERO),
-        };
-
-        let (shares, verifiers) = vsss_rs::feldman::split_secret_with_participant_generator::<
-            SecretShare<G::Scalar>,
-            ShareVerifierGroup<G>,
-        >(
-            parameters.threshold,
-            parameters.limit,
-            &secret_to_split,
-            Some(ValueGroup(parameters.message_generator)),
-            rng,
-            &parameters.participant_number_generators,
-        )?;
-        let verifiers = verifiers.iter().skip(1).copied().collect::<Vec<_>>();
-
         let verifying_share = match participant_type {
-            ParticipantType::Secret => verifiers[0].0,
+            ParticipantType::Secret => verifiers[0],
             ParticipantType::Refresh => verifying_share.ok_or(Error::Initialization(
                 "Verifying share is required for refresh".to_string(),
             ))?,
         };
+        let verifying_share = NonIdentity::new(verifying_share).ok_or_else(|| {
+            Error::Initialization("Verifying share cannot be the identity".to_string())
+        })?;
 
         if verifiers.iter().skip(1).any(|c| c.is_identity().into())
-            || !I::check_feldman_verifier(*verifiers[0])
+            || !I::check_feldman_verifier(verifiers[0])
         {
             return Err(Error::Initialization(
                 "Invalid feldman verifier".to_string(),
             ));
         }
 
-        let ordinal = shares
+        let roster = shares.iter().map(|s| s.identifier).collect::<Vec<_>>();
+        let ordinal = locate_in_roster(id, &roster)?;
+        let all_participant_ids = roster
             .iter()
-            .position(|s| s.identifier == id)
-            .ok_or_else(|| {
+            .copied()
+            .enumerate()
+            .collect::<BTreeMap<_, _>>();
+        for (ordinal, identifier) in &parameters.participant_identifiers {
+            let expected = all_participant_ids.get(ordinal).ok_or_else(|| {
                 Error::Initialization(format!(
-                    "Invalid participant id '{id}'. Not in generated set of shares"
+                    "Participant identifier '{identifier}' has no participant at ordinal {ordinal}"
                 ))
             })?;
+            if identifier.to_identifier::<G::Scalar>()? != *expected {
+                return Err(Error::Initialization(format!(
+                    "Participant identifier '{identifier}' does not match the id at ordinal {ordinal}"
+                )));
+            }
+        }
+        if matches!(participant_type, ParticipantType::Refresh)
+            && !parameters.previous_verification_shares.is_empty()
+            && parameters.previous_verification_shares.get(&ordinal) != Some(&verifying_share.0)
+        {
+            return Err(Error::ShareContinuity(ordinal));
+        }
+        if parameters.handoff_dealers > 0
+            && !handoff_role_matches(ordinal, participant_type, parameters.handoff_dealers)
+        {
+            return Err(Error::Initialization(format!(
+                "Handoff dealers must be secret participants and successors refresh participants, ordinal {ordinal} is a {participant_type:?} participant"
+            )));
+        }
+        let participant_ids = all_participant_ids.values().copied().collect::<Vec<_>>();
+        let (reshare_mapping, invalid) =
+            reshare_ordinals(&parameters.reshare_mapping, &participant_ids);
+        if let Some(index) = invalid.first() {
+            return Err(Error::Initialization(format!(
+                "Reshare mapping entry {index} has a zero or repeated id or maps outside the roster"
+            )));
+        }
+        if !reshare_mapping.is_empty()
+            && !reshare_role_matches(ordinal, participant_type, &reshare_mapping)
+        {
+            return Err(Error::Initialization(format!(
+                "Mapped shareholders must be secret participants and everyone else refresh participants, ordinal {ordinal} is a {participant_type:?} participant"
+            )));
+        }
 
-        let all_participant_ids = shares
-            .iter()
-            .enumerate()
-            .map(|(i, s)| (i, s.identifier))
-            .collect();
+        // Blind every feldman commitment with a random polynomial so the
+        // round 1 broadcast hides the contribution
+        let mut pedersen_commitments = FeldmanCommitments::<G>::new();
+        let mut blinder_shares = BTreeMap::new();
+        if let Some(blinder_generator) = parameters.blinder_generator {
+            let blinders = DealerPolynomial::<G>::random(
+                NonZeroUsize::new(parameters.threshold).expect("the threshold was checked"),
+                G::Scalar::random(&mut rng),
+                &mut rng,
+            );
+            pedersen_commitments = verifiers
+                .iter()
+                .zip(blinders.commitments(blinder_generator))
+                .map(|(verifier, blinder)| *verifier + blinder)
+                .collect();
+            blinder_shares = blinders.deal(&roster)?.into_iter().enumerate().collect();
+        }
         Ok(Self {
             ordinal,
             id,
@@ -252,14 +612,41 @@ where
                 .map(|(ordinal, share)| (ordinal, *share))
                 .collect(),
             feldman_verifiers: verifiers,
-            secret_share: SecretShare::<G::Scalar>::default(),
+            secret_share: SecretStorage::default(),
             message_generator: parameters.message_generator,
-            public_key: ValueGroup::<G>::identity(),
+            public_key: G::identity(),
+            verification_shares: BTreeMap::new(),
             powers_of_i,
             received_round1_data: BTreeMap::new(),
             received_round2_data: BTreeMap::new(),
+            round1_openings: BTreeMap::new(),
             all_participant_ids,
             valid_participant_ids: BTreeMap::new(),
+            invalid_participant_ids: BTreeMap::new(),
+            dispute_evidence: BTreeMap::new(),
+            transcript_label: parameters.transcript_label.clone(),
+            transcript_hash_size: parameters.transcript_hash_size,
+            final_transcript_hash: None,
+            participant_metadata: parameters.participant_metadata.clone(),
+            participant_identifiers: parameters.participant_identifiers.clone(),
+            previous_verification_shares: parameters.previous_verification_shares.clone(),
+            blinder_generator: parameters.blinder_generator,
+            pedersen_commitments,
+            blinder_shares,
+            handoff_dealers: parameters.handoff_dealers,
+            reshare_mapping,
+            transcript_extensions: Vec::new(),
+            quarantined_messages: BTreeMap::new(),
+            disqualified_participant_ids: BTreeSet::new(),
+            share_acks: BTreeMap::new(),
+            missing_share_policy: MissingSharePolicy::default(),
+            fault_tolerance: 0,
+            commit_reveal: parameters.commit_reveal,
+            round1_commitments: BTreeMap::new(),
+            committed_round1: None,
+            expected_senders: BTreeMap::new(),
+            policy: None,
+            round_timer: RoundTimer::default(),
             participant_impl: Default::default(),
         })
     }
@@ -296,97 +683,610 @@ where
 
     /// Computed secret share.
     /// This value is useless until at least 2 rounds have been run
-    /// so [`None`] is returned until completion
+    /// so [`None`] is returned until completion or for a handoff dealer
     pub fn get_secret_share(&self) -> Option<SecretShare<G::Scalar>> {
-        if self.completed {
-            Some(self.secret_share)
+        if self.completed && !self.is_handoff_dealer(self.ordinal) {
+            Some(*self.secret_share)
         } else {
             None
         }
     }
 
+    /// Whether the secret share is held in memory locked against swapping.
+    ///
+    /// Locking is best effort and can fail when the process exceeds its
+    /// limit for locked memory.
+    #[cfg(feature = "mlock")]
+    pub fn is_secret_share_locked(&self) -> bool {
+        self.secret_share.is_locked()
+    }
+
     /// Computed public key
     /// This value is useless until all rounds have been run
-    /// so [`None`] is returned until completion
-    pub fn get_public_key(&self) -> Option<G> {
-        if self.completed {
-            Some(*self.public_key)
+    /// so [`None`] is returned until completion.
+    ///
+    /// [`None`] is also returned when every participant was a refresh
+    /// participant since the aggregated key is the identity element, and
+    /// for a handoff dealer which never computes the key.
+    pub fn get_public_key(&self) -> Option<NonIdentity<G>> {
+        if self.completed && !self.is_handoff_dealer(self.ordinal) {
+            NonIdentity::new(self.public_key)
         } else {
             None
         }
     }
 
-    /// Return the list of all participants that started the protocol
-    pub fn get_all_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
-        &self.all_participant_ids
+    /// The verifying share used for the round 1 proof of knowledge
+    pub fn get_verifying_share(&self) -> NonIdentity<G> {
+        self.verifying_share
     }
 
-    /// Return the list of valid participant ids
-    pub fn get_valid_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
-        &self.valid_participant_ids
+    /// The part of the group key dealt by the participant at `ordinal`, the
+    /// constant term of its feldman commitments.
+    ///
+    /// Only returned once the dealer's round 2 share was verified against
+    /// it, so every returned key is combined into the group key. Refresh
+    /// participants contribute the identity. With pedersen commitments the
+    /// contribution is only known once the dealer opened them in round 3.
+    pub fn get_contribution_public_key(&self, ordinal: usize) -> Option<G> {
+        if !self.received_round2_data.contains_key(&ordinal) {
+            return None;
+        }
+        self.dealer_round1data(ordinal)
+            .map(|data| data.feldman_commitments[0])
     }
 
-    /// Return the feldman verifiers
-    pub fn get_feldman_verifiers(&self) -> Vec<ShareVerifierGroup<G>> {
-        self.feldman_verifiers.clone()
+    /// The contribution to the group key of every dealer whose round 2
+    /// share was verified, see [`Participant::get_contribution_public_key`]
+    pub fn get_contribution_public_keys(&self) -> BTreeMap<usize, G> {
+        self.received_round2_data
+            .keys()
+            .filter_map(|ordinal| {
+                let data = self.dealer_round1data(*ordinal)?;
+                Some((*ordinal, data.feldman_commitments[0]))
+            })
+            .collect()
     }
 
-    /// Get the received round 1 data so far
-    pub fn get_received_round1_data(&self) -> &BTreeMap<usize, Round1Data<G>> {
-        &self.received_round1_data
+    /// The output of the DKG.
+    ///
+    /// [`None`] is returned until completion or if the DKG did not
+    /// produce a group key.
+    pub fn get_dkg_output(&self) -> Option<DkgOutput<G>> {
+        let public_key = self.get_public_key()?;
+        let verification_shares = self
+            .verification_shares
+            .iter()
+            .map(|(ordinal, share)| NonIdentity::new(*share).map(|share| (*ordinal, share)))
+            .collect::<Option<BTreeMap<_, _>>>()?;
+        Some(DkgOutput {
+            ordinal: self.ordinal,
+            id: self.id,
+            threshold: self.threshold,
+            message_generator: self.message_generator,
+            secret_share: *self.secret_share,
+            public_key,
+            participant_ids: self
+                .valid_participant_ids
+                .iter()
+                .filter(|(ordinal, _)| !self.is_handoff_dealer(**ordinal))
+                .map(|(ordinal, id)| (*ordinal, *id))
+                .collect(),
+            verification_shares,
+            transcript_hash: self.get_final_transcript_hash_bytes(),
+            participant_identifiers: self.participant_identifiers.clone(),
+            contribution_public_keys: self.get_contribution_public_keys(),
+        })
     }
 
-    /// Get the received round 2 data so far
-    pub fn get_received_round2_data(&self) -> &BTreeMap<usize, Round2Data<G::Scalar>> {
-        &self.received_round2_data
+    /// Get the final transcript hash over all data received during the protocol
+    #[deprecated(
+        note = "use `get_final_transcript_hash_bytes` which supports 64 byte transcript hashes"
+    )]
+    pub fn get_final_transcript_hash(&self) -> [u8; 32] {
+        legacy_transcript_hash(&self.get_final_transcript_hash_bytes())
     }
 
-    /// Receive data from another participant
-    pub fn receive(&mut self, data: &[u8]) -> DkgResult<()> {
-        let round = Round::try_from(data[0]).map_err(Error::Initialization)?;
-        match round {
-            Round::One => {
-                let round1_payload = postcard::from_bytes::<Round1Data<G>>(&data[1..])?;
-                self.receive_round1data(round1_payload)
-            }
-            Round::Two => {
-                let round2_payload = postcard::from_bytes::<Round2Data<G::Scalar>>(&data[1..])?;
-                self.receive_round2data(round2_payload)
+    /// Get the final transcript hash over all data received during the
+    /// protocol with the configured [`TranscriptHashSize`]
+    pub fn get_final_transcript_hash_bytes(&self) -> Vec<u8> {
+        if let Some(transcript_hash) = &self.final_transcript_hash {
+            return transcript_hash.clone();
+        }
+        let mut transcript = self.new_transcript(b"Frost DKG - Final Transcript");
+        let round1data = self
+            .received_round1_data
+            .iter()
+            .filter(|(ordinal, _)| !self.disqualified_participant_ids.contains(ordinal))
+            .map(|(_, round1data)| round1data)
+            .collect::<Vec<_>>();
+        transcript.append_u64(b"round1_messages", round1data.len() as u64);
+        for round1data in round1data {
+            round1data.add_to_transcript(&mut transcript);
+        }
+        transcript.append_u64(b"round2_messages", self.received_round2_data.len() as u64);
+        for round2data in self.received_round2_data.values() {
+            round2data.add_to_transcript(&mut transcript);
+        }
+        if !self.round1_openings.is_empty() {
+            transcript.append_u64(b"round1_openings", self.round1_openings.len() as u64);
+            for opening in self.round1_openings.values() {
+                opening.add_to_transcript(&mut transcript);
             }
-            _ => Err(Error::Round("Protocol is complete".to_string())),
         }
+        let mut transcript_hash = vec![0u8; self.transcript_hash_size.size()];
+        transcript.challenge_bytes(b"final result", &mut transcript_hash);
+        transcript_hash
     }
 
-    /// Run the next step in the protocol
-    pub fn run(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
-        match self.round {
-            Round::One => self.round1(),
-            Round::Two => self.round2(),
-            Round::Three => self.round3(),
-            Round::Four => Err(Error::Round("Protocol is complete".to_string())),
+    /// An estimate of the memory in bytes held by this participant.
+    ///
+    /// Counts the participant itself and the heap allocations of its
+    /// collections but not allocator or map node overhead.
+    pub fn memory_footprint(&self) -> usize {
+        let id_map_entry = size_of::<usize>() + size_of::<IdentifierPrimeField<G::Scalar>>();
+        let commitments_heap = |commitments: &FeldmanCommitments<G>| {
+            #[cfg(feature = "smallvec")]
+            if !commitments.spilled() {
+                return 0;
+            }
+            commitments.capacity() * size_of::<G>()
+        };
+        let round1_heap = |data: &Round1Data<G>| commitments_heap(&data.feldman_commitments);
+        let round2_heap = |data: &Round2Data<G::Scalar>| data.transcript_hash.capacity();
+
+        size_of::<Self>()
+            + (self.secret_shares.len() + self.blinder_shares.len())
+                * (size_of::<usize>() + size_of::<SecretShare<G::Scalar>>())
+            + commitments_heap(&self.feldman_verifiers)
+            + commitments_heap(&self.pedersen_commitments)
+            + (self.verification_shares.len() + self.previous_verification_shares.len())
+                * (size_of::<usize>() + size_of::<G>())
+            + self.powers_of_i.capacity() * size_of::<G::Scalar>()
+            + self
+                .received_round1_data
+                .values()
+                .chain(self.round1_openings.values())
+                .map(|data| size_of::<usize>() + size_of::<Round1Data<G>>() + round1_heap(data))
+                .sum::<usize>()
+            + self
+                .received_round2_data
+                .values()
+                .map(|data| {
+                    size_of::<usize>() + size_of::<Round2Data<G::Scalar>>() + round2_heap(data)
+                })
+                .sum::<usize>()
+            + (self.all_participant_ids.len()
+                + self.valid_participant_ids.len()
+                + self.invalid_participant_ids.len()
+                + self.reshare_mapping.len())
+                * id_map_entry
+            + self
+                .dispute_evidence
+                .values()
+                .map(|evidence| {
+                    size_of::<usize>()
+                        + size_of::<DisputeEvidence<G>>()
+                        + evidence.context.participant_ids.len() * id_map_entry
+                        + round1_heap(&evidence.round1_data)
+                        + round2_heap(&evidence.round2_data)
+                })
+                .sum::<usize>()
+            + self.transcript_label.capacity()
+            + self
+                .transcript_extensions
+                .iter()
+                .map(|(label, data)| {
+                    size_of::<(Vec<u8>, Vec<u8>)>() + label.capacity() + data.capacity()
+                })
+                .sum::<usize>()
+            + self
+                .participant_metadata
+                .values()
+                .map(|metadata| {
+                    size_of::<usize>()
+                        + size_of::<ParticipantMetadata>()
+                        + metadata.display_name.as_ref().map_or(0, |s| s.capacity())
+                        + metadata.endpoint.as_ref().map_or(0, |s| s.capacity())
+                        + metadata
+                            .key_fingerprint
+                            .as_ref()
+                            .map_or(0, |f| f.capacity())
+                })
+                .sum::<usize>()
+            + self
+                .quarantined_messages
+                .values()
+                .map(|messages| {
+                    size_of::<usize>()
+                        + messages
+                            .iter()
+                            .map(|data| size_of::<Vec<u8>>() + data.capacity())
+                            .sum::<usize>()
+                })
+                .sum::<usize>()
+            + self.disqualified_participant_ids.len() * size_of::<usize>()
+            + self.share_acks.len() * (size_of::<usize>() + size_of::<ShareAck<G>>())
+            + self
+                .final_transcript_hash
+                .as_ref()
+                .map_or(0, |transcript_hash| transcript_hash.capacity())
+    }
+
+    /// Release the data that is no longer needed once the protocol has completed.
+    ///
+    /// The final transcript hash is computed and kept before the received
+    /// round data and the dealt shares are dropped, so the output of the DKG
+    /// is unchanged. Progress counters in [`Participant::status`] report the
+    /// pruned collections afterwards. Returns an error if the protocol has
+    /// not completed.
+    pub fn gc(&mut self) -> DkgResult<()> {
+        if !self.completed {
+            return Err(Error::Round(
+                "Cannot prune data before the protocol is complete".to_string(),
+            ));
+        }
+        if self.final_transcript_hash.is_none() {
+            self.final_transcript_hash = Some(self.get_final_transcript_hash_bytes());
         }
+        self.received_round1_data = BTreeMap::new();
+        self.received_round2_data = BTreeMap::new();
+        self.round1_openings = BTreeMap::new();
+        self.secret_shares = BTreeMap::new();
+        self.blinder_shares = BTreeMap::new();
+        self.quarantined_messages = BTreeMap::new();
+        self.powers_of_i = Vec::new();
+        Ok(())
     }
 
-    pub(crate) fn check_sending_participant_id(
-        &self,
-        round: Round,
-        sender_ordinal: usize,
-        sender_id: IdentifierPrimeField<G::Scalar>,
-    ) -> DkgResult<()> {
-        let id = self
-            .all_participant_ids
-            .get(&sender_ordinal)
-            .ok_or_else(|| {
-                Error::Round(format!(
-                    "Round {round}: Unknown sender ordinal, {sender_ordinal}"
-                ))
-            })?;
-        if *id != sender_id {
-            return Err(Error::Round(format!(
-                "Round {round}: Sender id mismatch, expected '{id}', got '{sender_id}'"
-            )));
+    pub(crate) fn new_transcript(&self, label: &'static [u8]) -> merlin::Transcript {
+        let mut transcript = merlin::Transcript::new(label);
+        // Transcripts with the default label are the same as before it was
+        // configurable
+        if self.transcript_label != DEFAULT_TRANSCRIPT_LABEL {
+            transcript.append_message(b"domain", &self.transcript_label);
         }
-        if sender_id.is_zero().into() {
+        if !self.participant_metadata.is_empty() {
+            transcript.append_message(b"roster", &roster_digest(&self.participant_metadata));
+        }
+        transcript.append_u64(b"reshare_entries", self.reshare_mapping.len() as u64);
+        for (ordinal, old_id) in &self.reshare_mapping {
+            transcript.append_u64(b"reshare_ordinal", *ordinal as u64);
+            transcript.append_message(b"reshare_old_id", old_id.0.to_repr().as_ref());
+        }
+        for (label, data) in &self.transcript_extensions {
+            transcript.append_message(b"extension_label", label);
+            transcript.append_message(b"extension_data", data);
+        }
+        transcript
+    }
+
+    /// Bind application data such as a hash of the ceremony agreement into
+    /// the round 2 and final transcripts under `label`.
+    ///
+    /// Every participant must append the same data in the same order
+    /// before running round 2, otherwise their round 2 transcript hashes
+    /// differ and the shares are rejected.
+    pub fn extend_transcript(&mut self, label: &[u8], data: &[u8]) -> DkgResult<()> {
+        if self.round > Round::Two {
+            return Err(Error::Round(
+                "Cannot extend the transcript after round 2 has run".to_string(),
+            ));
+        }
+        self.transcript_extensions
+            .push((label.to_vec(), data.to_vec()));
+        Ok(())
+    }
+
+    /// The application data bound into the transcripts in order
+    pub fn get_transcript_extensions(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.transcript_extensions
+    }
+
+    /// Return the list of all participants that started the protocol
+    pub fn get_all_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.all_participant_ids
+    }
+
+    /// Return the metadata attached to participants by ordinal index
+    pub fn get_participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata> {
+        &self.participant_metadata
+    }
+
+    /// Return the application identifiers of participants by ordinal index
+    pub fn get_participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    /// Return the application identifier of the participant at `ordinal`
+    pub fn participant_identifier(&self, ordinal: usize) -> Option<&ParticipantIdentifier> {
+        self.participant_identifiers.get(&ordinal)
+    }
+
+    /// The verification shares after a completed refresh, the previous
+    /// verification shares plus the refresh deltas.
+    ///
+    /// [`None`] is returned until completion or if no previous verification
+    /// shares were given in the parameters.
+    pub fn get_refreshed_verification_shares(&self) -> Option<BTreeMap<usize, G>> {
+        if !self.completed || self.previous_verification_shares.is_empty() {
+            return None;
+        }
+        self.verification_shares
+            .iter()
+            .map(|(ordinal, delta)| {
+                self.previous_verification_shares
+                    .get(ordinal)
+                    .map(|previous| (*ordinal, *previous + *delta))
+            })
+            .collect()
+    }
+
+    /// The secret share after a completed refresh, the share the participant
+    /// started from plus the refresh delta.
+    ///
+    /// [`None`] is returned until completion, for a secret participant or if
+    /// no previous verification shares were given in the parameters.
+    pub fn get_refreshed_secret_share(&self) -> Option<SecretShare<G::Scalar>> {
+        if !self.completed
+            || self.previous_verification_shares.is_empty()
+            || !matches!(I::default().get_type(), ParticipantType::Refresh)
+        {
+            return None;
+        }
+        Some(SecretShare::with_identifier_and_value(
+            self.id,
+            IdentifierPrimeField(self.original_secret + *self.secret_share.value),
+        ))
+    }
+
+    /// Return the list of valid participant ids
+    pub fn get_valid_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.valid_participant_ids
+    }
+
+    /// Return the list of participant ids that sent invalid data.
+    ///
+    /// Only failures attributable to the sender are recorded, like a share
+    /// signed by the sender that does not verify. Data that anyone could
+    /// have sent under the sender's ordinal is rejected without a record.
+    pub fn get_invalid_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.invalid_participant_ids
+    }
+
+    /// Return the evidence against participants that dealt invalid shares
+    pub fn get_dispute_evidence(&self) -> &BTreeMap<usize, DisputeEvidence<G>> {
+        &self.dispute_evidence
+    }
+
+    /// Return the feldman verifiers
+    pub fn get_feldman_verifiers(&self) -> Vec<G> {
+        self.feldman_verifiers.to_vec()
+    }
+
+    /// Get the received round 1 data so far
+    pub fn get_received_round1_data(&self) -> &BTreeMap<usize, Round1Data<G>> {
+        &self.received_round1_data
+    }
+
+    /// Get the received round 2 data so far
+    pub fn get_received_round2_data(&self) -> &BTreeMap<usize, Round2Data<G::Scalar>> {
+        &self.received_round2_data
+    }
+
+    /// Receive data from another participant
+    ///
+    /// Payloads must be canonically encoded. Values that only decode because
+    /// a curve crate reduces them, like a point with an unreduced coordinate,
+    /// or payloads with trailing bytes are rejected with [`Error::NonCanonical`]
+    /// so every honest participant hashes the same transcript.
+    ///
+    /// Peers can be faster than this participant. Round 1 broadcasts are
+    /// accepted from the moment the participant is created, before its own
+    /// round 1 ran, and until its round 2 runs. Round 2 shares are checked
+    /// against the peers accepted when round 2 ran, so a share that arrives
+    /// earlier is rejected without blaming the sender and must be delivered
+    /// again after [`Participant::run`]. The same holds for the round 3
+    /// openings of pedersen commitments, which are only accepted once this
+    /// participant opened its own.
+    pub fn receive(&mut self, data: &[u8]) -> DkgResult<()> {
+        let payload = message_payload::<G>(data)?;
+        let round = Round::try_from(data[0]).map_err(Error::Initialization)?;
+        if let Ok(sender_ordinal) = message_sender(data) {
+            self.check_expected_sender(round, sender_ordinal)?;
+        }
+        match round {
+            Round::One => {
+                let round1_payload = decode_canonical::<Round1Data<G>>(round, payload)?;
+                self.receive_round1data(round1_payload)
+            }
+            Round::Two if self.blinder_generator.is_some() => {
+                let round2_payload = decode_canonical::<BlindedRound2Data<G>>(round, payload)?;
+                self.receive_blinded_round2data(round2_payload)
+            }
+            Round::Two => {
+                let round2_payload = decode_canonical::<Round2Data<G::Scalar>>(round, payload)?;
+                self.receive_round2data(round2_payload)
+            }
+            Round::Three => {
+                let opening = decode_canonical::<Round1Data<G>>(round, payload)?;
+                self.receive_round1_opening_data(opening)
+            }
+            _ => Err(Error::Round("Protocol is complete".to_string())),
+        }
+    }
+
+    /// Receive round 1 data that was already decoded by the transport.
+    ///
+    /// Like [`Participant::receive`] without the byte framing for transports
+    /// that carry structured messages.
+    pub fn receive_round1(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1data(data)
+    }
+
+    /// Receive the opening of a peer's pedersen commitments that was
+    /// already decoded by the transport.
+    ///
+    /// Like [`Participant::receive`] without the byte framing for transports
+    /// that carry structured messages.
+    pub fn receive_round1_opening(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1_opening_data(data)
+    }
+
+    /// Receive round 2 data that was already decoded by the transport.
+    ///
+    /// Like [`Participant::receive`] without the byte framing for transports
+    /// that carry structured messages.
+    pub fn receive_round2(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()> {
+        if self.blinder_generator.is_some() {
+            return Err(Error::Round(format!(
+                "Round {}: Pedersen commitments need the blinded round 2 data",
+                Round::Two
+            )));
+        }
+        self.receive_round2data(data)
+    }
+
+    /// Receive blinded round 2 data that was already decoded by the transport
+    /// when the parameters have a blinder generator.
+    ///
+    /// Like [`Participant::receive`] without the byte framing for transports
+    /// that carry structured messages.
+    pub fn receive_blinded_round2(&mut self, data: BlindedRound2Data<G>) -> DkgResult<()> {
+        self.receive_blinded_round2data(data)
+    }
+
+    /// Receive several messages from other participants at once.
+    ///
+    /// Round 2 shares are verified together with a single multi-scalar
+    /// multiplication and only checked one by one when that fails, which
+    /// is much faster than calling [`Participant::receive`] for each message.
+    /// Returns the result for each message in order.
+    pub fn receive_batch(&mut self, messages: &[Vec<u8>]) -> Vec<DkgResult<()>> {
+        let mut results = Vec::with_capacity(messages.len());
+        let mut batch = Vec::new();
+        let mut batch_indices = Vec::new();
+        for (index, data) in messages.iter().enumerate() {
+            let Some(round) = data.first() else {
+                results.push(Err(Error::Round("Empty payload received".to_string())));
+                continue;
+            };
+            // Blinded shares are checked one at a time
+            if !matches!(Round::try_from(*round), Ok(Round::Two))
+                || self.blinder_generator.is_some()
+            {
+                results.push(self.receive(data));
+                continue;
+            }
+            match message_payload::<G>(data)
+                .and_then(|payload| decode_canonical::<Round2Data<G::Scalar>>(Round::Two, payload))
+            {
+                Ok(round2_payload) => {
+                    batch.push(round2_payload);
+                    batch_indices.push(index);
+                    results.push(Ok(()));
+                }
+                Err(e) => results.push(Err(e)),
+            }
+        }
+        for (index, result) in batch_indices
+            .into_iter()
+            .zip(self.receive_round2data_batch(batch))
+        {
+            results[index] = result;
+        }
+        results
+    }
+
+    /// Run the next step in the protocol
+    pub fn run(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
+        let round = self.round;
+        let output = match round {
+            Round::One => self.round1(),
+            Round::Two => self.round2(),
+            Round::Three => self.round3(),
+            Round::Four => Err(Error::Round("Protocol is complete".to_string())),
+        }?;
+        telemetry::round_completed(round, &mut self.round_timer);
+        Ok(output)
+    }
+
+    /// Rebuild the output of the last round that ran from the current state.
+    ///
+    /// Peers disqualified with [`Participant::disqualify_peer`] after the
+    /// round ran are left out, so a driver can send these messages instead
+    /// of the stale ones returned by [`Participant::run`]. Round 2 shares are
+    /// rebuilt with the transcript hash computed when the round ran.
+    pub fn regenerate_round_output(&self) -> DkgResult<RoundOutputGenerator<G>> {
+        let live_ids = |ids: &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>| {
+            ids.iter()
+                .filter(|(ordinal, _)| !self.disqualified_participant_ids.contains(ordinal))
+                .map(|(ordinal, id)| (*ordinal, *id))
+                .collect::<BTreeMap<_, _>>()
+        };
+        match self.round {
+            Round::One => Err(Error::Round("Round 1 has not run yet".to_string())),
+            Round::Two => {
+                let self_round1_data = self
+                    .received_round1_data
+                    .get(&self.ordinal)
+                    .ok_or_else(|| Error::Round("Self doesn't have round 1 data".to_string()))?;
+                Ok(RoundOutputGenerator::Round1(Round1OutputGenerator {
+                    participant_ids: live_ids(&self.all_participant_ids),
+                    sender_ordinal: self.ordinal,
+                    data: encode_payload::<G, _>(Round::One, self_round1_data)?,
+                }))
+            }
+            Round::Three if self.round1_openings.contains_key(&self.ordinal) => {
+                self.opening_output()
+            }
+            Round::Three => {
+                let self_round2_data = self
+                    .received_round2_data
+                    .get(&self.ordinal)
+                    .ok_or_else(|| Error::Round("Self doesn't have round 2 data".to_string()))?;
+                let participant_ids = live_ids(&self.valid_participant_ids);
+                let payloads = self.round2_payloads(
+                    &self.signed_context(),
+                    &participant_ids,
+                    &self_round2_data.transcript_hash,
+                )?;
+                Ok(RoundOutputGenerator::Round2(Round2OutputGenerator {
+                    participant_ids,
+                    payloads,
+                }))
+            }
+            Round::Four
+                if self.is_handoff_dealer(self.ordinal)
+                    && self.round1_openings.contains_key(&self.ordinal) =>
+            {
+                self.opening_output()
+            }
+            Round::Four => Ok(RoundOutputGenerator::Round3),
+        }
+    }
+
+    pub(crate) fn check_sending_participant_id(
+        &self,
+        round: Round,
+        sender_ordinal: usize,
+        sender_id: IdentifierPrimeField<G::Scalar>,
+    ) -> DkgResult<()> {
+        self.check_expected_sender(round, sender_ordinal)?;
+        let id = self
+            .all_participant_ids
+            .get(&sender_ordinal)
+            .ok_or_else(|| {
+                Error::Round(format!(
+                    "Round {round}: Unknown sender ordinal, {sender_ordinal}"
+                ))
+            })?;
+        if *id != sender_id {
+            return Err(Error::Round(format!(
+                "Round {round}: Sender id mismatch, expected '{id}', got '{sender_id}'"
+            )));
+        }
+        if sender_id.is_zero().into() {
             return Err(Error::Round(format!("Round {round}: Sender id is zero")));
         }
         if self.id.ct_eq(&sender_id).into() {
@@ -397,31 +1297,63 @@ where
         Ok(())
     }
 
-    pub(crate) fn lagrange(
-        share: &SecretShare<G::Scalar>,
-        shares_ids: &[IdentifierPrimeField<G::Scalar>],
-    ) -> ValuePrimeField<G::Scalar> {
-        let mut num = G::Scalar::ONE;
-        let mut den = G::Scalar::ONE;
-        for &x_j in shares_ids.iter() {
-            if x_j == share.identifier {
-                continue;
-            }
-            num *= *x_j;
-            den *= *x_j - *share.identifier;
+    /// Whether the participant at `ordinal` is a handoff dealer that
+    /// doesn't receive a new share
+    pub(crate) fn is_handoff_dealer(&self, ordinal: usize) -> bool {
+        ordinal < self.handoff_dealers
+    }
+
+    /// Name the sender in a rejection when it has an application identifier
+    pub(crate) fn blame(&self, sender_ordinal: usize, e: Error) -> Error {
+        let Some(identifier) = self.participant_identifiers.get(&sender_ordinal) else {
+            return e;
+        };
+        match e {
+            Error::Round(msg) => Error::Round(format!("{msg}, sent by '{identifier}'")),
+            Error::Subgroup(msg) => Error::Subgroup(format!("{msg}, sent by '{identifier}'")),
+            e => e,
         }
+    }
+}
 
-        IdentifierPrimeField(num * den.invert().expect("Denominator should not be zero"))
+/// Whether the participant type fits the role of `ordinal` in a handoff
+/// with `dealers` dealers at the start of the roster
+pub(crate) fn handoff_role_matches(
+    ordinal: usize,
+    participant_type: ParticipantType,
+    dealers: usize,
+) -> bool {
+    match participant_type {
+        ParticipantType::Secret => ordinal < dealers,
+        ParticipantType::Refresh => ordinal >= dealers,
+    }
+}
+
+/// Whether a participant has the role the reshare mapping gives its ordinal
+pub(crate) fn reshare_role_matches<F: ScalarHash>(
+    ordinal: usize,
+    participant_type: ParticipantType,
+    mapping: &BTreeMap<usize, IdentifierPrimeField<F>>,
+) -> bool {
+    match participant_type {
+        ParticipantType::Secret => mapping.contains_key(&ordinal),
+        ParticipantType::Refresh => !mapping.contains_key(&ordinal),
     }
 }
 
 /// Secret Participant Implementation
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SecretParticipantImpl<G>(PhantomData<G>);
 
+impl<G> Default for SecretParticipantImpl<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
 impl<G> ParticipantImpl<G> for SecretParticipantImpl<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     fn get_type(&self) -> ParticipantType {
@@ -438,12 +1370,18 @@ where
 }
 
 /// Refresh Participant Implementation
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefreshParticipantImpl<G>(PhantomData<G>);
 
+impl<G> Default for RefreshParticipantImpl<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
 impl<G> ParticipantImpl<G> for RefreshParticipantImpl<G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     fn get_type(&self) -> ParticipantType {
@@ -462,7 +1400,7 @@ where
 /// A trait to allow for dynamic dispatch of the participant
 pub trait AnyParticipant<G>: Send + Sync + Debug
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     /// Get the ordinal index of this participant
@@ -480,32 +1418,187 @@ where
     /// Get the secret share if completed
     fn get_secret_share(&self) -> Option<SecretShare<G::Scalar>>;
     /// Get the public key if completed
-    fn get_public_key(&self) -> Option<G>;
+    fn get_public_key(&self) -> Option<NonIdentity<G>>;
     /// Get the valid participant ids from the last round
     fn get_valid_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>;
     /// Get all participant ids that started the protocol
     fn get_all_participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>;
+    /// Get the metadata attached to participants by ordinal index
+    fn get_participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata>;
+    /// Get the application identifiers of participants by ordinal index
+    fn get_participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier>;
+    /// Sign a record that this ceremony was abandoned
+    fn abort_certificate(
+        &self,
+        reason: AbortReason,
+        signing_key: G::Scalar,
+    ) -> DkgResult<AbortCertificate<G>>;
+    /// Countersign another participant's record that this ceremony was abandoned
+    fn countersign_abort(
+        &self,
+        certificate: &mut AbortCertificate<G>,
+        signing_key: G::Scalar,
+    ) -> DkgResult<()>;
+    /// Get the application identifier of the participant at `ordinal`
+    fn participant_identifier(&self, ordinal: usize) -> Option<&ParticipantIdentifier>;
+    /// Get the verification shares after a completed refresh
+    fn get_refreshed_verification_shares(&self) -> Option<BTreeMap<usize, G>>;
+    /// Get the secret share after a completed refresh
+    fn get_refreshed_secret_share(&self) -> Option<SecretShare<G::Scalar>>;
     /// Return the feldman verifiers
-    fn get_feldman_verifiers(&self) -> Vec<ShareVerifierGroup<G>>;
+    fn get_feldman_verifiers(&self) -> Vec<G>;
     /// Get the received round 1 data so far
     fn get_received_round1_data(&self) -> &BTreeMap<usize, Round1Data<G>>;
     /// Get the received round 2 data so far
     fn get_received_round2_data(&self) -> &BTreeMap<usize, Round2Data<G::Scalar>>;
+    /// Get the openings of the pedersen commitments received so far
+    fn get_round1_openings(&self) -> &BTreeMap<usize, Round1Data<G>>;
     /// Get the verifying share
-    fn get_verifying_share(&self) -> G;
+    fn get_verifying_share(&self) -> NonIdentity<G>;
+    /// Get the blinder generator if round 1 uses pedersen commitments
+    fn get_blinder_generator(&self) -> Option<G>;
+    /// Get the part of the group key dealt by the participant at `ordinal`
+    fn get_contribution_public_key(&self, ordinal: usize) -> Option<G>;
+    /// Get the part of the group key dealt by every combined dealer
+    fn get_contribution_public_keys(&self) -> BTreeMap<usize, G>;
+    /// Get the output of the DKG if completed
+    fn get_dkg_output(&self) -> Option<DkgOutput<G>>;
+    /// Get the evidence against participants that dealt invalid shares
+    fn get_dispute_evidence(&self) -> &BTreeMap<usize, DisputeEvidence<G>>;
     /// Get the final transcript hash
+    #[deprecated(
+        note = "use `get_final_transcript_hash_bytes` which supports 64 byte transcript hashes"
+    )]
     fn get_final_transcript_hash(&self) -> [u8; 32];
+    /// Get the final transcript hash with the configured size
+    fn get_final_transcript_hash_bytes(&self) -> Vec<u8>;
+    /// Get the short authentication string of the round 2 transcript
+    fn sas_code(&self) -> DkgResult<SasCode>;
+    /// Get a snapshot of the protocol progress
+    fn status(&self) -> DkgStatus;
+    /// Get the validation status of the peer at `ordinal`
+    fn peer_status(&self, ordinal: usize) -> Option<PeerStatus>;
+    /// Get the validation status of every peer
+    fn peer_statuses(&self) -> BTreeMap<usize, PeerStatus>;
+    /// Exclude a peer from the protocol
+    fn disqualify_peer(&mut self, ordinal: usize) -> DkgResult<()>;
+    /// Clear the quarantine of a peer and receive the messages held from it
+    fn release_peer(&mut self, ordinal: usize) -> DkgResult<Vec<DkgResult<()>>>;
+    /// Get the payloads still needed before the next round can run
+    fn round_requirements(&self) -> RoundRequirements;
+    /// Get an estimate of the memory in bytes held by the participant
+    fn memory_footprint(&self) -> usize;
+    /// Release the data that is no longer needed once the protocol has completed
+    fn gc(&mut self) -> DkgResult<()>;
     /// Check if the participant is completed
     fn completed(&self) -> bool;
     /// Receive data from another participant
     fn receive(&mut self, data: &[u8]) -> DkgResult<()>;
+    /// Receive several messages at once, verifying round 2 shares together
+    fn receive_batch(&mut self, messages: &[Vec<u8>]) -> Vec<DkgResult<()>>;
+    /// Receive data from another participant unless `cache` saw it before
+    fn receive_with_replay_cache(
+        &mut self,
+        data: &[u8],
+        cache: &mut ReplayCache,
+        now: u64,
+    ) -> DkgResult<()>;
+    /// Write the messages of a round to files in `dir` for an air-gapped ceremony
+    fn write_outbox(
+        &self,
+        generator: &RoundOutputGenerator<G>,
+        dir: &Path,
+    ) -> DkgResult<Vec<PathBuf>>;
+    /// Receive the messages in `dir` written for this participant
+    fn read_inbox(&mut self, dir: &Path) -> DkgResult<Vec<PathBuf>>;
+    /// Receive decoded round 1 data from another participant
+    fn receive_round1(&mut self, data: Round1Data<G>) -> DkgResult<()>;
+    /// Receive the decoded opening of another participant's pedersen commitments
+    fn receive_round1_opening(&mut self, data: Round1Data<G>) -> DkgResult<()>;
+    /// Get the compact form of this participant's round 1 broadcast
+    fn compact_round1(&self) -> DkgResult<CompactRound1Data<G>>;
+    /// The context the round 1 signatures are bound to
+    fn signed_context(&self) -> SignedContext<G>;
+    /// Receive compact round 1 data with the commitments fetched separately
+    fn receive_compact_round1(
+        &mut self,
+        data: &CompactRound1Data<G>,
+        commitments: &[G],
+    ) -> DkgResult<()>;
+    /// Receive decoded round 2 data from another participant
+    fn receive_round2(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()>;
+    /// Receive decoded blinded round 2 data from another participant
+    fn receive_blinded_round2(&mut self, data: BlindedRound2Data<G>) -> DkgResult<()>;
+    /// Bind application data into the round 2 and final transcripts
+    fn extend_transcript(&mut self, label: &[u8], data: &[u8]) -> DkgResult<()>;
+    /// Get the application data bound into the transcripts
+    fn get_transcript_extensions(&self) -> &[(Vec<u8>, Vec<u8>)];
+    /// Choose what happens when round 2 shares are missing
+    fn set_missing_share_policy(&mut self, policy: MissingSharePolicy);
+    /// What happens when round 2 shares are missing
+    fn get_missing_share_policy(&self) -> MissingSharePolicy;
+    /// The dealers whose round 2 share has not arrived
+    fn missing_shares(&self) -> Vec<usize>;
+    /// Whether the peer at `ordinal` can be replaced by one that starts over
+    fn accepts_replacement(&self, ordinal: usize) -> bool;
+    /// Tolerate up to `fault_tolerance` invalid dealers
+    fn set_fault_tolerance(&mut self, fault_tolerance: usize) -> DkgResult<()>;
+    /// The number of invalid dealers that are disqualified automatically
+    fn get_fault_tolerance(&self) -> usize;
+    /// Disqualify the dealer accused by another participant's evidence
+    fn accept_dispute(&mut self, evidence: &DisputeEvidence<G>) -> DkgResult<()>;
+    /// Only accept messages for `round` from the peers at `ordinals`
+    fn expect_senders(&mut self, round: Round, ordinals: &[usize]) -> DkgResult<()>;
+    /// Accept messages for `round` from every peer again
+    fn clear_expected_senders(&mut self, round: Round);
+    /// The peers messages for `round` are accepted from, if restricted
+    fn get_expected_senders(&self, round: Round) -> Option<&BTreeSet<usize>>;
+    /// Check every validated message with `policy` before accepting it
+    fn set_policy(&mut self, policy: Arc<dyn Policy>);
+    /// Fix the round 1 broadcast and return the commitment to it
+    fn commit_round1(&mut self, signing_key: G::Scalar) -> DkgResult<Round1Commitment<G>>;
+    /// Receive the round 1 commitment of another participant signed with its long term key
+    fn receive_round1_commitment(
+        &mut self,
+        commitment: &Round1Commitment<G>,
+        sender_key: &G,
+    ) -> DkgResult<()>;
+    /// The participants whose round 1 commitment has not been received
+    fn missing_round1_commitments(&self) -> Vec<usize>;
+    /// The participants that committed to round 1 but haven't revealed it
+    fn missing_round1_reveals(&self) -> Vec<usize>;
+    /// The dealers whose pedersen commitments have not been opened
+    fn missing_round1_openings(&self) -> Vec<usize>;
+    /// Accept every validated message again
+    fn clear_policy(&mut self);
+    /// Whether a policy checks the validated messages
+    fn has_policy(&self) -> bool;
+    /// Change the roster before round 1 runs
+    fn remap_roster(
+        &mut self,
+        ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<BTreeMap<usize, usize>>;
+    /// Prove how the group key relates to the previous epoch's key
+    fn continuity_proof(
+        &self,
+        previous_public_key: NonIdentity<G>,
+        continuity: KeyContinuity,
+    ) -> DkgResult<ContinuityProof<G>>;
+    /// Acknowledge the verified round 2 share from a dealer
+    fn acknowledge_share(&self, dealer_ordinal: usize) -> DkgResult<ShareAck<G>>;
+    /// Check and keep an acknowledgment of a share this participant dealt
+    fn receive_share_ack(&mut self, ack: ShareAck<G>) -> DkgResult<()>;
+    /// Get the acknowledgments of this participant's shares
+    fn get_share_acks(&self) -> &BTreeMap<usize, ShareAck<G>>;
     /// Run the next round in the protocol after receiving data from other participants
     fn run(&mut self) -> DkgResult<RoundOutputGenerator<G>>;
+    /// Rebuild the output of the last round that ran without disqualified peers
+    fn regenerate_round_output(&self) -> DkgResult<RoundOutputGenerator<G>>;
 }
 
 impl<G> AnyParticipant<G> for Participant<SecretParticipantImpl<G>, G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     fn get_ordinal(&self) -> usize {
@@ -536,7 +1629,7 @@ where
         self.get_secret_share()
     }
 
-    fn get_public_key(&self) -> Option<G> {
+    fn get_public_key(&self) -> Option<NonIdentity<G>> {
         self.get_public_key()
     }
 
@@ -548,7 +1641,43 @@ where
         &self.all_participant_ids
     }
 
-    fn get_feldman_verifiers(&self) -> Vec<ShareVerifierGroup<G>> {
+    fn get_participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata> {
+        &self.participant_metadata
+    }
+
+    fn get_participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    fn abort_certificate(
+        &self,
+        reason: AbortReason,
+        signing_key: G::Scalar,
+    ) -> DkgResult<AbortCertificate<G>> {
+        self.abort_certificate(reason, signing_key)
+    }
+
+    fn countersign_abort(
+        &self,
+        certificate: &mut AbortCertificate<G>,
+        signing_key: G::Scalar,
+    ) -> DkgResult<()> {
+        self.countersign_abort(certificate, signing_key)
+    }
+
+    fn participant_identifier(&self, ordinal: usize) -> Option<&ParticipantIdentifier> {
+        self.participant_identifiers.get(&ordinal)
+    }
+
+    fn get_refreshed_verification_shares(&self) -> Option<BTreeMap<usize, G>> {
+        self.get_refreshed_verification_shares()
+    }
+
+    fn get_refreshed_secret_share(&self) -> Option<SecretShare<G::Scalar>> {
+        self.get_refreshed_secret_share()
+    }
+
+    fn get_feldman_verifiers(&self) -> Vec<G> {
         self.get_feldman_verifiers()
     }
 
@@ -560,12 +1689,77 @@ where
         &self.received_round2_data
     }
 
-    fn get_verifying_share(&self) -> G {
+    fn get_round1_openings(&self) -> &BTreeMap<usize, Round1Data<G>> {
+        &self.round1_openings
+    }
+
+    fn get_verifying_share(&self) -> NonIdentity<G> {
         self.verifying_share
     }
 
+    fn get_blinder_generator(&self) -> Option<G> {
+        self.blinder_generator
+    }
+
+    fn get_contribution_public_key(&self, ordinal: usize) -> Option<G> {
+        self.get_contribution_public_key(ordinal)
+    }
+
+    fn get_contribution_public_keys(&self) -> BTreeMap<usize, G> {
+        self.get_contribution_public_keys()
+    }
+
+    fn get_dkg_output(&self) -> Option<DkgOutput<G>> {
+        self.get_dkg_output()
+    }
+
+    fn get_dispute_evidence(&self) -> &BTreeMap<usize, DisputeEvidence<G>> {
+        &self.dispute_evidence
+    }
+
+    #[allow(deprecated)]
     fn get_final_transcript_hash(&self) -> [u8; 32] {
-        get_final_transcript_hash(&self.received_round1_data, &self.received_round2_data)
+        self.get_final_transcript_hash()
+    }
+
+    fn get_final_transcript_hash_bytes(&self) -> Vec<u8> {
+        self.get_final_transcript_hash_bytes()
+    }
+
+    fn sas_code(&self) -> DkgResult<SasCode> {
+        self.sas_code()
+    }
+
+    fn status(&self) -> DkgStatus {
+        self.status()
+    }
+
+    fn peer_status(&self, ordinal: usize) -> Option<PeerStatus> {
+        self.peer_status(ordinal)
+    }
+
+    fn peer_statuses(&self) -> BTreeMap<usize, PeerStatus> {
+        self.peer_statuses()
+    }
+
+    fn disqualify_peer(&mut self, ordinal: usize) -> DkgResult<()> {
+        self.disqualify_peer(ordinal)
+    }
+
+    fn release_peer(&mut self, ordinal: usize) -> DkgResult<Vec<DkgResult<()>>> {
+        self.release_peer(ordinal)
+    }
+
+    fn round_requirements(&self) -> RoundRequirements {
+        self.round_requirements()
+    }
+
+    fn memory_footprint(&self) -> usize {
+        self.memory_footprint()
+    }
+
+    fn gc(&mut self) -> DkgResult<()> {
+        self.gc()
     }
 
     fn completed(&self) -> bool {
@@ -576,14 +1770,186 @@ where
         self.receive(data)
     }
 
+    fn receive_batch(&mut self, messages: &[Vec<u8>]) -> Vec<DkgResult<()>> {
+        self.receive_batch(messages)
+    }
+
+    fn receive_with_replay_cache(
+        &mut self,
+        data: &[u8],
+        cache: &mut ReplayCache,
+        now: u64,
+    ) -> DkgResult<()> {
+        self.receive_with_replay_cache(data, cache, now)
+    }
+
+    fn write_outbox(
+        &self,
+        generator: &RoundOutputGenerator<G>,
+        dir: &Path,
+    ) -> DkgResult<Vec<PathBuf>> {
+        self.write_outbox(generator, dir)
+    }
+
+    fn read_inbox(&mut self, dir: &Path) -> DkgResult<Vec<PathBuf>> {
+        self.read_inbox(dir)
+    }
+
+    fn receive_round1(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1(data)
+    }
+
+    fn receive_round1_opening(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1_opening(data)
+    }
+
+    fn compact_round1(&self) -> DkgResult<CompactRound1Data<G>> {
+        self.compact_round1()
+    }
+
+    fn signed_context(&self) -> SignedContext<G> {
+        self.signed_context()
+    }
+
+    fn receive_compact_round1(
+        &mut self,
+        data: &CompactRound1Data<G>,
+        commitments: &[G],
+    ) -> DkgResult<()> {
+        self.receive_compact_round1(data, commitments)
+    }
+
+    fn receive_round2(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()> {
+        self.receive_round2(data)
+    }
+
+    fn receive_blinded_round2(&mut self, data: BlindedRound2Data<G>) -> DkgResult<()> {
+        self.receive_blinded_round2(data)
+    }
+
+    fn extend_transcript(&mut self, label: &[u8], data: &[u8]) -> DkgResult<()> {
+        self.extend_transcript(label, data)
+    }
+
+    fn get_transcript_extensions(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        self.get_transcript_extensions()
+    }
+
+    fn set_missing_share_policy(&mut self, policy: MissingSharePolicy) {
+        self.set_missing_share_policy(policy)
+    }
+
+    fn get_missing_share_policy(&self) -> MissingSharePolicy {
+        self.get_missing_share_policy()
+    }
+
+    fn missing_shares(&self) -> Vec<usize> {
+        self.missing_shares()
+    }
+
+    fn accepts_replacement(&self, ordinal: usize) -> bool {
+        self.accepts_replacement(ordinal)
+    }
+
+    fn set_fault_tolerance(&mut self, fault_tolerance: usize) -> DkgResult<()> {
+        self.set_fault_tolerance(fault_tolerance)
+    }
+
+    fn get_fault_tolerance(&self) -> usize {
+        self.get_fault_tolerance()
+    }
+
+    fn accept_dispute(&mut self, evidence: &DisputeEvidence<G>) -> DkgResult<()> {
+        self.accept_dispute(evidence)
+    }
+
+    fn expect_senders(&mut self, round: Round, ordinals: &[usize]) -> DkgResult<()> {
+        self.expect_senders(round, ordinals)
+    }
+
+    fn clear_expected_senders(&mut self, round: Round) {
+        self.clear_expected_senders(round)
+    }
+
+    fn get_expected_senders(&self, round: Round) -> Option<&BTreeSet<usize>> {
+        self.get_expected_senders(round)
+    }
+
+    fn set_policy(&mut self, policy: Arc<dyn Policy>) {
+        self.set_policy(policy)
+    }
+
+    fn commit_round1(&mut self, signing_key: G::Scalar) -> DkgResult<Round1Commitment<G>> {
+        self.commit_round1(signing_key)
+    }
+
+    fn receive_round1_commitment(
+        &mut self,
+        commitment: &Round1Commitment<G>,
+        sender_key: &G,
+    ) -> DkgResult<()> {
+        self.receive_round1_commitment(commitment, sender_key)
+    }
+
+    fn missing_round1_commitments(&self) -> Vec<usize> {
+        self.missing_round1_commitments()
+    }
+
+    fn missing_round1_reveals(&self) -> Vec<usize> {
+        self.missing_round1_reveals()
+    }
+
+    fn missing_round1_openings(&self) -> Vec<usize> {
+        self.missing_round1_openings()
+    }
+
+    fn clear_policy(&mut self) {
+        self.clear_policy()
+    }
+
+    fn has_policy(&self) -> bool {
+        self.has_policy()
+    }
+
+    fn remap_roster(
+        &mut self,
+        ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<BTreeMap<usize, usize>> {
+        self.remap_roster(ids)
+    }
+
+    fn continuity_proof(
+        &self,
+        previous_public_key: NonIdentity<G>,
+        continuity: KeyContinuity,
+    ) -> DkgResult<ContinuityProof<G>> {
+        self.continuity_proof(previous_public_key, continuity)
+    }
+
+    fn acknowledge_share(&self, dealer_ordinal: usize) -> DkgResult<ShareAck<G>> {
+        self.acknowledge_share(dealer_ordinal)
+    }
+
+    fn receive_share_ack(&mut self, ack: ShareAck<G>) -> DkgResult<()> {
+        self.receive_share_ack(ack)
+    }
+
+    fn get_share_acks(&self) -> &BTreeMap<usize, ShareAck<G>> {
+        self.get_share_acks()
+    }
+
     fn run(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
         self.run()
     }
+
+    fn regenerate_round_output(&self) -> DkgResult<RoundOutputGenerator<G>> {
+        self.regenerate_round_output()
+    }
 }
 
 impl<G> AnyParticipant<G> for Participant<RefreshParticipantImpl<G>, G>
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     fn get_ordinal(&self) -> usize {
@@ -614,7 +1980,7 @@ where
         self.get_secret_share()
     }
 
-    fn get_public_key(&self) -> Option<G> {
+    fn get_public_key(&self) -> Option<NonIdentity<G>> {
         self.get_public_key()
     }
 
@@ -626,7 +1992,43 @@ where
         &self.all_participant_ids
     }
 
-    fn get_feldman_verifiers(&self) -> Vec<ShareVerifierGroup<G>> {
+    fn get_participant_metadata(&self) -> &BTreeMap<usize, ParticipantMetadata> {
+        &self.participant_metadata
+    }
+
+    fn get_participant_identifiers(&self) -> &BTreeMap<usize, ParticipantIdentifier> {
+        &self.participant_identifiers
+    }
+
+    fn abort_certificate(
+        &self,
+        reason: AbortReason,
+        signing_key: G::Scalar,
+    ) -> DkgResult<AbortCertificate<G>> {
+        self.abort_certificate(reason, signing_key)
+    }
+
+    fn countersign_abort(
+        &self,
+        certificate: &mut AbortCertificate<G>,
+        signing_key: G::Scalar,
+    ) -> DkgResult<()> {
+        self.countersign_abort(certificate, signing_key)
+    }
+
+    fn participant_identifier(&self, ordinal: usize) -> Option<&ParticipantIdentifier> {
+        self.participant_identifiers.get(&ordinal)
+    }
+
+    fn get_refreshed_verification_shares(&self) -> Option<BTreeMap<usize, G>> {
+        self.get_refreshed_verification_shares()
+    }
+
+    fn get_refreshed_secret_share(&self) -> Option<SecretShare<G::Scalar>> {
+        self.get_refreshed_secret_share()
+    }
+
+    fn get_feldman_verifiers(&self) -> Vec<G> {
         self.get_feldman_verifiers()
     }
 
@@ -638,12 +2040,77 @@ where
         &self.received_round2_data
     }
 
-    fn get_verifying_share(&self) -> G {
+    fn get_round1_openings(&self) -> &BTreeMap<usize, Round1Data<G>> {
+        &self.round1_openings
+    }
+
+    fn get_verifying_share(&self) -> NonIdentity<G> {
         self.verifying_share
     }
 
+    fn get_blinder_generator(&self) -> Option<G> {
+        self.blinder_generator
+    }
+
+    fn get_contribution_public_key(&self, ordinal: usize) -> Option<G> {
+        self.get_contribution_public_key(ordinal)
+    }
+
+    fn get_contribution_public_keys(&self) -> BTreeMap<usize, G> {
+        self.get_contribution_public_keys()
+    }
+
+    fn get_dkg_output(&self) -> Option<DkgOutput<G>> {
+        self.get_dkg_output()
+    }
+
+    fn get_dispute_evidence(&self) -> &BTreeMap<usize, DisputeEvidence<G>> {
+        &self.dispute_evidence
+    }
+
+    #[allow(deprecated)]
     fn get_final_transcript_hash(&self) -> [u8; 32] {
-        get_final_transcript_hash(&self.received_round1_data, &self.received_round2_data)
+        self.get_final_transcript_hash()
+    }
+
+    fn get_final_transcript_hash_bytes(&self) -> Vec<u8> {
+        self.get_final_transcript_hash_bytes()
+    }
+
+    fn sas_code(&self) -> DkgResult<SasCode> {
+        self.sas_code()
+    }
+
+    fn status(&self) -> DkgStatus {
+        self.status()
+    }
+
+    fn peer_status(&self, ordinal: usize) -> Option<PeerStatus> {
+        self.peer_status(ordinal)
+    }
+
+    fn peer_statuses(&self) -> BTreeMap<usize, PeerStatus> {
+        self.peer_statuses()
+    }
+
+    fn disqualify_peer(&mut self, ordinal: usize) -> DkgResult<()> {
+        self.disqualify_peer(ordinal)
+    }
+
+    fn release_peer(&mut self, ordinal: usize) -> DkgResult<Vec<DkgResult<()>>> {
+        self.release_peer(ordinal)
+    }
+
+    fn round_requirements(&self) -> RoundRequirements {
+        self.round_requirements()
+    }
+
+    fn memory_footprint(&self) -> usize {
+        self.memory_footprint()
+    }
+
+    fn gc(&mut self) -> DkgResult<()> {
+        self.gc()
     }
 
     fn completed(&self) -> bool {
@@ -654,27 +2121,179 @@ where
         self.receive(data)
     }
 
+    fn receive_batch(&mut self, messages: &[Vec<u8>]) -> Vec<DkgResult<()>> {
+        self.receive_batch(messages)
+    }
+
+    fn receive_with_replay_cache(
+        &mut self,
+        data: &[u8],
+        cache: &mut ReplayCache,
+        now: u64,
+    ) -> DkgResult<()> {
+        self.receive_with_replay_cache(data, cache, now)
+    }
+
+    fn write_outbox(
+        &self,
+        generator: &RoundOutputGenerator<G>,
+        dir: &Path,
+    ) -> DkgResult<Vec<PathBuf>> {
+        self.write_outbox(generator, dir)
+    }
+
+    fn read_inbox(&mut self, dir: &Path) -> DkgResult<Vec<PathBuf>> {
+        self.read_inbox(dir)
+    }
+
+    fn receive_round1(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1(data)
+    }
+
+    fn receive_round1_opening(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        self.receive_round1_opening(data)
+    }
+
+    fn compact_round1(&self) -> DkgResult<CompactRound1Data<G>> {
+        self.compact_round1()
+    }
+
+    fn signed_context(&self) -> SignedContext<G> {
+        self.signed_context()
+    }
+
+    fn receive_compact_round1(
+        &mut self,
+        data: &CompactRound1Data<G>,
+        commitments: &[G],
+    ) -> DkgResult<()> {
+        self.receive_compact_round1(data, commitments)
+    }
+
+    fn receive_round2(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()> {
+        self.receive_round2(data)
+    }
+
+    fn receive_blinded_round2(&mut self, data: BlindedRound2Data<G>) -> DkgResult<()> {
+        self.receive_blinded_round2(data)
+    }
+
+    fn extend_transcript(&mut self, label: &[u8], data: &[u8]) -> DkgResult<()> {
+        self.extend_transcript(label, data)
+    }
+
+    fn get_transcript_extensions(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        self.get_transcript_extensions()
+    }
+
+    fn set_missing_share_policy(&mut self, policy: MissingSharePolicy) {
+        self.set_missing_share_policy(policy)
+    }
+
+    fn get_missing_share_policy(&self) -> MissingSharePolicy {
+        self.get_missing_share_policy()
+    }
+
+    fn missing_shares(&self) -> Vec<usize> {
+        self.missing_shares()
+    }
+
+    fn accepts_replacement(&self, ordinal: usize) -> bool {
+        self.accepts_replacement(ordinal)
+    }
+
+    fn set_fault_tolerance(&mut self, fault_tolerance: usize) -> DkgResult<()> {
+        self.set_fault_tolerance(fault_tolerance)
+    }
+
+    fn get_fault_tolerance(&self) -> usize {
+        self.get_fault_tolerance()
+    }
+
+    fn accept_dispute(&mut self, evidence: &DisputeEvidence<G>) -> DkgResult<()> {
+        self.accept_dispute(evidence)
+    }
+
+    fn expect_senders(&mut self, round: Round, ordinals: &[usize]) -> DkgResult<()> {
+        self.expect_senders(round, ordinals)
+    }
+
+    fn clear_expected_senders(&mut self, round: Round) {
+        self.clear_expected_senders(round)
+    }
+
+    fn get_expected_senders(&self, round: Round) -> Option<&BTreeSet<usize>> {
+        self.get_expected_senders(round)
+    }
+
+    fn set_policy(&mut self, policy: Arc<dyn Policy>) {
+        self.set_policy(policy)
+    }
+
+    fn commit_round1(&mut self, signing_key: G::Scalar) -> DkgResult<Round1Commitment<G>> {
+        self.commit_round1(signing_key)
+    }
+
+    fn receive_round1_commitment(
+        &mut self,
+        commitment: &Round1Commitment<G>,
+        sender_key: &G,
+    ) -> DkgResult<()> {
+        self.receive_round1_commitment(commitment, sender_key)
+    }
+
+    fn missing_round1_commitments(&self) -> Vec<usize> {
+        self.missing_round1_commitments()
+    }
+
+    fn missing_round1_reveals(&self) -> Vec<usize> {
+        self.missing_round1_reveals()
+    }
+
+    fn missing_round1_openings(&self) -> Vec<usize> {
+        self.missing_round1_openings()
+    }
+
+    fn clear_policy(&mut self) {
+        self.clear_policy()
+    }
+
+    fn has_policy(&self) -> bool {
+        self.has_policy()
+    }
+
+    fn remap_roster(
+        &mut self,
+        ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<BTreeMap<usize, usize>> {
+        self.remap_roster(ids)
+    }
+
+    fn continuity_proof(
+        &self,
+        previous_public_key: NonIdentity<G>,
+        continuity: KeyContinuity,
+    ) -> DkgResult<ContinuityProof<G>> {
+        self.continuity_proof(previous_public_key, continuity)
+    }
+
+    fn acknowledge_share(&self, dealer_ordinal: usize) -> DkgResult<ShareAck<G>> {
+        self.acknowledge_share(dealer_ordinal)
+    }
+
+    fn receive_share_ack(&mut self, ack: ShareAck<G>) -> DkgResult<()> {
+        self.receive_share_ack(ack)
+    }
+
+    fn get_share_acks(&self) -> &BTreeMap<usize, ShareAck<G>> {
+        self.get_share_acks()
+    }
+
     fn run(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
         self.run()
     }
-}
 
-fn get_final_transcript_hash<G>(
-    received_round1_data: &BTreeMap<usize, Round1Data<G>>,
-    received_round2_data: &BTreeMap<usize, Round2Data<G::Scalar>>,
-) -> [u8; 32]
-where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
-    G::Scalar: ScalarHash,
-{
-    let mut transcript = merlin::Transcript::new(b"Frost DKG - Final Transcript");
-    for round1data in received_round1_data.values() {
-        round1data.add_to_transcript(&mut transcript);
-    }
-    for round2data in received_round2_data.values() {
-        round2data.add_to_transcript(&mut transcript);
+    fn regenerate_round_output(&self) -> DkgResult<RoundOutputGenerator<G>> {
+        self.regenerate_round_output()
     }
-    let mut transcript_hash = [0u8; 32];
-    transcript.challenge_bytes(b"final result", &mut transcript_hash);
-    transcript_hash
 }
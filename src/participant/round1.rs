@@ -1,79 +1,150 @@
 use crate::{
-    DkgResult, Error, Participant, ParticipantImpl, ParticipantType, RefreshParticipantImpl, Round,
-    Round1Data, Round1OutputGenerator, RoundOutputGenerator, ScalarHash, SecretParticipantImpl,
-    Signature,
+    COMMITMENT_CHUNK, CommitmentScanner, DkgResult, Error, FeldmanCommitments, Participant,
+    ParticipantImpl, ParticipantType, PrimeSubgroup, RefreshParticipantImpl, Round, Round1Data,
+    Round1OutputGenerator, RoundOutputGenerator, ScalarHash, SecretParticipantImpl, Signature,
+    SignedContext, commitments_digest, encode_payload, handoff_role_matches, is_torsion_free,
+    reshare_role_matches, telemetry,
 };
+use elliptic_curve::PrimeField;
 use elliptic_curve::group::GroupEncoding;
-use elliptic_curve::subtle::{Choice, ConditionallySelectable};
+use elliptic_curve::subtle::ConditionallySelectable;
 use elliptic_curve_tools::SumOfProducts;
+use rand_core::Rng;
+use std::collections::BTreeMap;
 
 impl<I, G> Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     pub(crate) fn round1(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
-        let k = I::random_value(rand::rng());
-        let r_i = self.message_generator * k;
-        let signature = self.compute_signature(k, r_i);
-
-        let self_round1_data = Round1Data {
-            sender_ordinal: self.ordinal,
-            sender_id: self.id,
-            sender_type: self.participant_impl.get_type(),
-            feldman_commitments: self.feldman_verifiers.clone(),
-            verifying_share: self.verifying_share,
-            signature,
+        let self_round1_data = if self.commit_reveal {
+            self.reveal_round1()?
+        } else {
+            self.self_round1data()
         };
+        let data = encode_payload::<G, _>(Round::One, &self_round1_data)?;
         self.received_round1_data
             .insert(self.ordinal, self_round1_data);
         self.round = Round::Two;
         Ok(RoundOutputGenerator::Round1(Round1OutputGenerator {
             participant_ids: self.all_participant_ids.clone(),
-            sender_type: self.participant_impl.get_type(),
             sender_ordinal: self.ordinal,
-            sender_id: self.id,
-            feldman_commitments: self.feldman_verifiers.clone(),
-            verifying_share: self.verifying_share,
-            signature,
+            data,
         }))
     }
 
-    pub(crate) fn compute_signature(&self, k: G::Scalar, r_i: G) -> Signature<G> {
+    /// The round 1 data this participant broadcasts
+    pub(crate) fn self_round1data(&self) -> Round1Data<G> {
+        if self.blinder_generator.is_some() {
+            // The feldman commitments are opened in round 3
+            let secret = self.pedersen_secret();
+            self.signed_round1data(
+                &self.pedersen_commitments,
+                secret,
+                self.message_generator * secret,
+            )
+        } else {
+            self.feldman_round1data()
+        }
+    }
+
+    /// The round 1 data with the feldman commitments and a fresh signature
+    pub(crate) fn feldman_round1data(&self) -> Round1Data<G> {
+        self.signed_round1data(
+            &self.feldman_verifiers,
+            self.original_secret,
+            *self.verifying_share,
+        )
+    }
+
+    /// The round 1 data with `commitments` signed with `secret`, the
+    /// discrete log of `verifying_share`
+    fn signed_round1data(
+        &self,
+        commitments: &FeldmanCommitments<G>,
+        secret: G::Scalar,
+        verifying_share: G,
+    ) -> Round1Data<G> {
+        let digest = commitments_digest(commitments);
+        let k = self.hedged_nonce(secret, &digest, &verifying_share, &mut rand::rng());
+        let r = self.message_generator * k;
         let bytes = crate::bytes_for_schnorr(
             self.ordinal,
             &self.id,
             &self.participant_impl.get_type(),
-            self.threshold,
-            self.limit,
-            &self.message_generator,
-            &self.feldman_verifiers,
-            &self.verifying_share,
-            &r_i,
-            &self.all_participant_ids,
+            &self.signed_context(),
+            &digest,
+            &verifying_share,
+            &r,
         );
-        let challenge = G::Scalar::hash_to_scalar(&bytes);
-        let s = k + challenge * self.original_secret;
-        Signature { r: r_i, s }
-    }
-
-    pub(crate) fn verify_signature(&self, round1data: &Round1Data<G>) -> DkgResult<()> {
-        crate::verify_signature(
-            round1data.sender_ordinal,
-            &round1data.sender_id,
-            &round1data.sender_type,
-            self.threshold,
-            self.limit,
-            &self.message_generator,
-            &round1data.feldman_commitments,
-            &round1data.verifying_share,
-            &self.all_participant_ids,
-            &round1data.signature,
+        let challenge = crate::hash_to_group_scalar::<G>(&bytes);
+        Round1Data {
+            sender_ordinal: self.ordinal,
+            sender_id: self.id,
+            sender_type: self.participant_impl.get_type(),
+            threshold: self.threshold,
+            limit: self.limit,
+            feldman_commitments: commitments.clone(),
+            verifying_share,
+            signature: Signature {
+                r,
+                s: k + challenge * secret,
+            },
+        }
+    }
+
+    /// The nonce for the round 1 proof of knowledge of the feldman
+    /// commitments
+    #[cfg(test)]
+    fn round1_nonce(&self, rng: &mut impl Rng) -> G::Scalar {
+        self.hedged_nonce(
+            self.original_secret,
+            &commitments_digest(&self.feldman_verifiers),
+            &self.verifying_share,
+            rng,
         )
     }
 
+    /// The nonce for a round 1 proof of knowledge of `secret`.
+    ///
+    /// Hedged in the style of RFC 6979 by hashing the secret and the signed
+    /// fields together with fresh randomness, so a faulty RNG that repeats
+    /// itself cannot reuse a nonce for another secret or message and leak
+    /// the secret. Refresh participants sign with their existing share, so
+    /// their nonce is hedged the same way.
+    fn hedged_nonce(
+        &self,
+        secret: G::Scalar,
+        commitments_digest: &[u8; 32],
+        verifying_share: &G,
+        rng: &mut impl Rng,
+    ) -> G::Scalar {
+        let mut randomness = [0u8; 32];
+        rng.fill_bytes(&mut randomness);
+        let message = crate::bytes_for_schnorr(
+            self.ordinal,
+            &self.id,
+            &self.participant_impl.get_type(),
+            &self.signed_context(),
+            commitments_digest,
+            verifying_share,
+            &G::identity(),
+        );
+        let secret = secret.to_repr();
+        let mut input =
+            Vec::with_capacity(secret.as_ref().len() + randomness.len() + message.len());
+        input.extend_from_slice(secret.as_ref());
+        input.extend_from_slice(&randomness);
+        input.extend_from_slice(&message);
+        let mut dst = crate::group_dst::<G>();
+        dst.extend_from_slice(b"-NONCE");
+        G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+    }
+
     pub(crate) fn receive_round1data(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        telemetry::message_received(Round::One);
         if self.round > Round::Two {
             return Err(Error::Round(format!(
                 "Round {}: Invalid round payload received",
@@ -87,47 +158,355 @@ where
             )));
         }
         self.check_sending_participant_id(Round::One, data.sender_ordinal, data.sender_id)?;
-        if data.feldman_commitments.is_empty() {
-            return Err(Error::Round(format!(
-                "Round: {}, Feldman commitments are empty",
-                Round::One
-            )));
-        }
-        if data.feldman_commitments.len() != self.threshold {
-            return Err(Error::Round(format!(
-                "Round: {}, Feldman commitments length is not equal to threshold",
-                Round::One
-            )));
+        self.require_round1_commitment(data.sender_ordinal)?;
+        if self.hold_if_quarantined(Round::One, data.sender_ordinal, &data)? {
+            return Ok(());
         }
-        if data.feldman_commitments[1..]
-            .iter()
-            .fold(Choice::from(0u8), |acc, c| acc | c.is_identity())
-            .into()
-        {
-            return Err(Error::Round(format!(
-                "Round: {}, Feldman commitments contain the identity point",
-                Round::One
-            )));
+        if let Err(e) = self.check_round1_reveal(&data) {
+            telemetry::validation_failed(Round::One, &e);
+            return Err(self.blame(data.sender_ordinal, e));
         }
-        let feldman_valid = match data.sender_type {
-            ParticipantType::Secret => {
-                SecretParticipantImpl::check_feldman_verifier(*data.feldman_commitments[0])
-                    && data.feldman_commitments[0].0 == data.verifying_share
-            }
-            ParticipantType::Refresh => {
-                RefreshParticipantImpl::check_feldman_verifier(*data.feldman_commitments[0])
-                    && data.feldman_commitments[0].0 != data.verifying_share
+        if let Err(e) = self.validate_round1data(&data) {
+            telemetry::validation_failed(Round::One, &e);
+            // Round 1 data is only attributable to the sender when it opens
+            // the commitment the sender signed with its long term key,
+            // anyone can send data under another sender's ordinal otherwise
+            if self.commit_reveal {
+                self.invalid_participant_ids
+                    .insert(data.sender_ordinal, data.sender_id);
+                self.tolerate_invalid_dealer(data.sender_ordinal);
             }
-        };
-        if !feldman_valid {
-            return Err(Error::Round(format!(
-                "Round: {}, Feldman commitment is not a valid verifier",
-                Round::One
-            )));
+            return Err(self.blame(data.sender_ordinal, e));
         }
-        self.verify_signature(&data)?;
+        self.check_policy(Round::One, data.sender_ordinal)?;
 
         self.received_round1_data.insert(data.sender_ordinal, data);
         Ok(())
     }
+
+    pub(crate) fn validate_round1data(&self, data: &Round1Data<G>) -> DkgResult<()> {
+        check_round1data(
+            data,
+            &self.signed_context(),
+            &self.previous_verification_shares,
+            self.handoff_dealers,
+            self.blinder_generator.is_some(),
+        )
+    }
+}
+
+/// Check round 1 data against the `context` of the run and the roles the
+/// parameters assign.
+///
+/// Pedersen commitments are checked for their shape and the signature of
+/// the key the sender signs its round 2 shares with. The feldman
+/// commitments they hide are checked when they are opened in round 3.
+pub(crate) fn check_round1data<G>(
+    data: &Round1Data<G>,
+    context: &SignedContext<G>,
+    previous_verification_shares: &BTreeMap<usize, G>,
+    handoff_dealers: usize,
+    pedersen: bool,
+) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if data.threshold != context.threshold || data.limit != context.limit {
+        return Err(Error::ParameterMismatch {
+            ordinal: data.sender_ordinal,
+        });
+    }
+    let commitments_digest = check_round1_commitments(data, context.threshold)?;
+    if matches!(data.sender_type, ParticipantType::Refresh)
+        && !previous_verification_shares.is_empty()
+        && previous_verification_shares.get(&data.sender_ordinal) != Some(&data.verifying_share)
+    {
+        return Err(Error::ShareContinuity(data.sender_ordinal));
+    }
+    if handoff_dealers > 0
+        && !handoff_role_matches(data.sender_ordinal, data.sender_type, handoff_dealers)
+    {
+        return Err(Error::Round(format!(
+            "Round: {}, Sender is a {:?} participant in the wrong handoff committee",
+            Round::One,
+            data.sender_type
+        )));
+    }
+    if !context.reshare_mapping.is_empty()
+        && !reshare_role_matches(
+            data.sender_ordinal,
+            data.sender_type,
+            &context.reshare_mapping,
+        )
+    {
+        return Err(Error::Round(format!(
+            "Round: {}, Sender is a {:?} participant against the reshare mapping",
+            Round::One,
+            data.sender_type
+        )));
+    }
+    if pedersen {
+        // The feldman commitments are checked when they are opened in round 3
+        return context.verify_with_digest(data, &commitments_digest);
+    }
+    check_round1_opening(data, context, &commitments_digest)
+}
+
+/// Check the points and the shape of the commitments in round 1 data
+/// and return the digest of the commitments.
+///
+/// The length is checked first and the commitments are then checked
+/// and hashed in a single pass, stopping at the first chunk with a point
+/// outside the prime order subgroup.
+pub(crate) fn check_round1_commitments<G>(
+    data: &Round1Data<G>,
+    threshold: usize,
+) -> DkgResult<[u8; 32]>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if data.feldman_commitments.is_empty() {
+        return Err(Error::Round(format!(
+            "Round: {}, Feldman commitments are empty",
+            Round::One
+        )));
+    }
+    if data.feldman_commitments.len() != threshold {
+        return Err(Error::Round(format!(
+            "Round: {}, Feldman commitments length is not equal to threshold",
+            Round::One
+        )));
+    }
+    let mut scanner = CommitmentScanner::new(data.feldman_commitments.len());
+    for chunk in data.feldman_commitments.chunks(COMMITMENT_CHUNK) {
+        scanner.update(chunk);
+        if !scanner.is_torsion_free() {
+            break;
+        }
+    }
+    if !scanner.is_torsion_free()
+        || !is_torsion_free(&data.verifying_share)
+        || !is_torsion_free(&data.signature.r)
+    {
+        return Err(Error::Subgroup(format!(
+            "Round: {}, Received a point outside the prime order subgroup",
+            Round::One
+        )));
+    }
+    if scanner.has_identity() {
+        return Err(Error::Round(format!(
+            "Round: {}, Feldman commitments contain the identity point",
+            Round::One
+        )));
+    }
+    Ok(scanner.finalize())
+}
+
+/// Check the feldman commitments and the signature of round 1 data
+fn check_round1_opening<G>(
+    data: &Round1Data<G>,
+    context: &SignedContext<G>,
+    commitments_digest: &[u8; 32],
+) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let feldman_valid = match data.sender_type {
+        ParticipantType::Secret => {
+            SecretParticipantImpl::check_feldman_verifier(data.feldman_commitments[0])
+                && data.feldman_commitments[0] == data.verifying_share
+        }
+        ParticipantType::Refresh => {
+            RefreshParticipantImpl::check_feldman_verifier(data.feldman_commitments[0])
+                && data.feldman_commitments[0] != data.verifying_share
+        }
+    };
+    if !feldman_valid {
+        return Err(Error::Round(format!(
+            "Round: {}, Feldman commitment is not a valid verifier",
+            Round::One
+        )));
+    }
+    context.verify_with_digest(data, commitments_digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::participant::testing::next_round;
+    use crate::{
+        MESSAGE_HEADER_LENGTH, Parameters, RefreshParticipant, SecretParticipant,
+        hash_to_group_scalar,
+    };
+    use elliptic_curve::Field;
+    use rand_core::SeedableRng;
+    use std::num::NonZeroUsize;
+    use vsss_rs::IdentifierPrimeField;
+
+    #[cfg(feature = "curve25519-dalek")]
+    #[test]
+    fn small_subgroup() {
+        use curve25519_dalek::{EdwardsPoint, Scalar, constants::EIGHT_TORSION};
+
+        let threshold = NonZeroUsize::new(2).expect("threshold is non-zero");
+        let limit = NonZeroUsize::new(3).expect("limit is non-zero");
+        let parameters = Parameters::<EdwardsPoint>::new(threshold, limit, None, None);
+
+        let mut participants = (1..=3)
+            .map(|id| {
+                let id = IdentifierPrimeField(Scalar::from(id as u64));
+                SecretParticipant::<EdwardsPoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let generators = next_round(&mut participants);
+        let output = generators[1]
+            .iter()
+            .find(|output| output.dst_ordinal == 0)
+            .expect("output for ordinal 0");
+        let mut round1_data =
+            postcard::from_bytes::<Round1Data<EdwardsPoint>>(&output.data[MESSAGE_HEADER_LENGTH..])
+                .expect("decode round 1 data");
+        round1_data.feldman_commitments[1] += EIGHT_TORSION[1];
+        let bad_data = encode_payload::<EdwardsPoint, _>(Round::One, &round1_data)
+            .expect("encode round 1 data");
+
+        assert!(matches!(
+            participants[0].receive(&bad_data),
+            Err(Error::Subgroup(_))
+        ));
+        // Anyone can send round 1 data under the ordinal
+        assert!(participants[0].get_invalid_participant_ids().is_empty());
+    }
+
+    fn check_hedged_round1_nonce<G>()
+    where
+        G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+        G::Scalar: ScalarHash,
+    {
+        let parameters = Parameters::<G>::new(
+            NonZeroUsize::new(2).expect("threshold is non-zero"),
+            NonZeroUsize::new(4).expect("limit is non-zero"),
+            None,
+            None,
+        );
+        let id = |id: u64| IdentifierPrimeField(G::Scalar::from(id));
+        let secrets = (1..=2)
+            .map(|i| SecretParticipant::<G>::new_secret(id(i), &parameters))
+            .collect::<DkgResult<Vec<_>>>()
+            .expect("create secret participants");
+        // A stuck RNG still gives each secret its own nonce
+        let stuck = || rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let nonce = secrets[0].round1_nonce(&mut stuck());
+        assert_eq!(nonce, secrets[0].round1_nonce(&mut stuck()));
+        assert_ne!(nonce, secrets[1].round1_nonce(&mut stuck()));
+        assert_ne!(nonce, G::Scalar::ZERO);
+
+        let round1data = secrets[0].feldman_round1data();
+        assert!(secrets[1].signed_context().verify(&round1data).is_ok());
+
+        // Refresh participants sign with their existing share, which the
+        // signature must not give away
+        let share = G::Scalar::random(&mut rand::rng());
+        let refresh = RefreshParticipant::<G>::new_refresh(id(3), Some(share), &parameters)
+            .expect("create refresh participant");
+        assert_ne!(refresh.round1_nonce(&mut stuck()), G::Scalar::ZERO);
+        let round1data = refresh.feldman_round1data();
+        assert_ne!(round1data.signature.r, G::identity());
+        assert_ne!(
+            round1data.signature.r,
+            refresh.feldman_round1data().signature.r
+        );
+        assert!(secrets[0].signed_context().verify(&round1data).is_ok());
+        let c = hash_to_group_scalar::<G>(&secrets[0].signed_context().schnorr_bytes(&round1data));
+        assert_ne!(
+            round1data.signature.s * c.invert().expect("non-zero challenge"),
+            share
+        );
+    }
+
+    #[test]
+    fn hedged_round1_nonce() {
+        check_hedged_round1_nonce::<k256::ProjectivePoint>();
+        check_hedged_round1_nonce::<p256::ProjectivePoint>();
+        check_hedged_round1_nonce::<ed448_goldilocks_plus::EdwardsPoint>();
+    }
+
+    #[cfg(feature = "curve25519-dalek")]
+    #[test]
+    fn commitment_scanner() {
+        use curve25519_dalek::{EdwardsPoint, Scalar, constants::EIGHT_TORSION};
+        use elliptic_curve::Group;
+
+        let mut commitments = (1..=2 * COMMITMENT_CHUNK as u64 + 1)
+            .map(|i| EdwardsPoint::generator() * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let mut scanner = CommitmentScanner::new(commitments.len());
+        for chunk in commitments.chunks(COMMITMENT_CHUNK) {
+            scanner.update(chunk);
+        }
+        assert!(scanner.is_torsion_free());
+        assert!(!scanner.has_identity());
+        assert_eq!(scanner.finalize(), commitments_digest(&commitments));
+
+        // Found wherever they are in the vector
+        commitments[COMMITMENT_CHUNK + 1] += EIGHT_TORSION[1];
+        commitments[2 * COMMITMENT_CHUNK] = EdwardsPoint::identity();
+        let mut scanner = CommitmentScanner::new(commitments.len());
+        scanner.update(&commitments);
+        assert!(!scanner.is_torsion_free());
+        assert!(scanner.has_identity());
+        assert_eq!(scanner.finalize(), commitments_digest(&commitments));
+    }
+
+    fn check_chunked_commitments<G>()
+    where
+        G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+        G::Scalar: ScalarHash,
+    {
+        let count = 2 * COMMITMENT_CHUNK + 3;
+        let commitments = (1..=count as u64)
+            .map(|i| G::generator() * G::Scalar::from(i))
+            .collect::<FeldmanCommitments<G>>();
+        let mut data = Round1Data {
+            sender_ordinal: 1,
+            sender_id: IdentifierPrimeField(G::Scalar::from(2u64)),
+            sender_type: ParticipantType::Secret,
+            threshold: count,
+            limit: count,
+            feldman_commitments: commitments.clone(),
+            verifying_share: commitments[0],
+            signature: Signature::default(),
+        };
+        let digest = check_round1_commitments(&data, count).expect("check commitments");
+        assert_eq!(digest, data.commitments_digest());
+
+        // The digest doesn't depend on how the commitments are split
+        let mut scanner = CommitmentScanner::<G>::new(count);
+        let (head, rest) = commitments.split_at(1);
+        let (middle, tail) = rest.split_at(COMMITMENT_CHUNK + 5);
+        for chunk in [head, middle, tail] {
+            scanner.update(chunk);
+        }
+        assert!(!scanner.has_identity());
+        assert_eq!(scanner.finalize(), digest);
+
+        assert!(check_round1_commitments(&data, count - 1).is_err());
+        data.feldman_commitments[count - 1] = G::identity();
+        assert!(matches!(
+            check_round1_commitments(&data, count),
+            Err(Error::Round(_))
+        ));
+    }
+
+    #[test]
+    fn chunked_commitments() {
+        check_chunked_commitments::<k256::ProjectivePoint>();
+        check_chunked_commitments::<p256::ProjectivePoint>();
+        check_chunked_commitments::<ed448_goldilocks_plus::EdwardsPoint>();
+    }
 }
@@ -1,23 +1,35 @@
 use crate::{
-    DkgResult, Error, Participant, ParticipantImpl, Round, Round2Data, Round2OutputGenerator,
-    RoundOutputGenerator, ScalarHash,
+    BlindedRound2Data, DkgResult, Error, Participant, ParticipantImpl, PrimeSubgroup, Round,
+    Round2Data, Round2OutputGenerator, RoundOutputGenerator, ScalarHash, ScalarSignature,
+    SignedContext, encode_payload, telemetry,
 };
+use elliptic_curve::Field;
 use elliptic_curve::group::GroupEncoding;
 use elliptic_curve::subtle::ConditionallySelectable;
 use elliptic_curve_tools::SumOfProducts;
 use std::collections::BTreeMap;
+use vsss_rs::IdentifierPrimeField;
 
 impl<I, G> Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     pub(crate) fn round2_ready(&self) -> bool {
-        self.round == Round::Two && self.received_round1_data.len() >= self.threshold
+        self.round == Round::Two
+            && self.received_round1_data.len() >= self.threshold
+            && self.missing_round1_reveals().is_empty()
     }
 
     pub(crate) fn round2(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
+        let missing = self.missing_round1_reveals();
+        if self.round == Round::Two && !missing.is_empty() {
+            return Err(Error::Round(format!(
+                "Round {}: Waiting for the committed round 1 broadcasts of {missing:?}",
+                Round::Two
+            )));
+        }
         if !self.round2_ready() {
             return Err(Error::Round(format!(
                 "Round 2 is not ready, haven't received enough data from other participants. Need {} more",
@@ -26,44 +38,208 @@ where
         }
 
         let mut valid_participant_ids = BTreeMap::new();
-        let mut transcript = merlin::Transcript::new(b"Frost DKG - Round 2 Transcript");
+        let mut transcript = self.new_transcript(b"Frost DKG - Round 2 Transcript");
+        transcript.append_u64(b"round1_messages", self.received_round1_data.len() as u64);
         for round1data in self.received_round1_data.values() {
             round1data.add_to_transcript(&mut transcript);
             valid_participant_ids.insert(round1data.sender_ordinal, round1data.sender_id);
         }
-        self.valid_participant_ids = valid_participant_ids.clone();
-        let mut transcript_hash = [0u8; 32];
+        let mut transcript_hash = vec![0u8; self.transcript_hash_size.size()];
         transcript.challenge_bytes(b"round 2 result", &mut transcript_hash);
-        self.received_round2_data.insert(
-            self.ordinal,
-            Round2Data {
-                sender_ordinal: self.ordinal,
-                sender_id: self.id,
-                sender_type: self.participant_impl.get_type(),
-                secret_share: self.secret_shares[&self.ordinal],
-                transcript_hash,
-            },
-        );
+        let context = self.signed_context();
+        let payloads = self.round2_payloads(&context, &valid_participant_ids, &transcript_hash)?;
+        let self_round2_data = self.round2_data(&context, self.ordinal, &transcript_hash);
 
+        self.valid_participant_ids = valid_participant_ids.clone();
+        self.received_round2_data
+            .insert(self.ordinal, self_round2_data);
         self.round = Round::Three;
         Ok(RoundOutputGenerator::Round2(Round2OutputGenerator {
             participant_ids: valid_participant_ids,
+            payloads,
+        }))
+    }
+
+    /// The round 2 payload for the participant at `ordinal`, signed with
+    /// the key of the round 1 proof of knowledge
+    fn round2_data(
+        &self,
+        context: &SignedContext<G>,
+        ordinal: usize,
+        transcript_hash: &[u8],
+    ) -> Round2Data<G::Scalar> {
+        let mut data = Round2Data {
             sender_ordinal: self.ordinal,
             sender_id: self.id,
             sender_type: self.participant_impl.get_type(),
-            secret_shares: self.secret_shares.clone(),
-            transcript_hash,
-        }))
+            secret_share: self.secret_shares[&ordinal],
+            transcript_hash: transcript_hash.to_vec(),
+            signature: ScalarSignature::default(),
+        };
+        self.sign_round2(context, &mut data);
+        data
+    }
+
+    /// Encode the round 2 payload for every other participant in
+    /// `participant_ids`, with the share of the blinder when the round 1
+    /// commitments are pedersen commitments
+    pub(crate) fn round2_payloads(
+        &self,
+        context: &SignedContext<G>,
+        participant_ids: &BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+        transcript_hash: &[u8],
+    ) -> DkgResult<BTreeMap<usize, Vec<u8>>> {
+        participant_ids
+            .iter()
+            .filter(|(ordinal, _)| **ordinal != self.ordinal && !self.is_handoff_dealer(**ordinal))
+            .map(|(ordinal, id)| {
+                debug_assert_eq!(self.secret_shares[ordinal].identifier, *id);
+                let round2_data = self.round2_data(context, *ordinal, transcript_hash);
+                let data = if self.blinder_generator.is_some() {
+                    encode_payload::<G, _>(
+                        Round::Two,
+                        &BlindedRound2Data::<G> {
+                            round2_data,
+                            blinder_share: self.blinder_shares[ordinal],
+                        },
+                    )
+                } else {
+                    encode_payload::<G, _>(Round::Two, &round2_data)
+                };
+                data.map(|data| (*ordinal, data))
+            })
+            .collect()
+    }
+
+    /// Sign `data` with the key of the round 1 proof of knowledge
+    pub(crate) fn sign_round2(&self, context: &SignedContext<G>, data: &mut Round2Data<G::Scalar>) {
+        let (secret, verifying_share) = self.round1_key();
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = self.message_generator * k;
+        let c = context.round2_challenge(data, &verifying_share, &r);
+        data.signature = ScalarSignature {
+            c,
+            s: k + c * secret,
+        };
     }
 
     pub(crate) fn receive_round2data(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()> {
+        telemetry::message_received(Round::Two);
+        self.accept_round2data(data)
+    }
+
+    /// Check and store round 2 data that was already counted as received
+    fn accept_round2data(&mut self, data: Round2Data<G::Scalar>) -> DkgResult<()> {
+        self.check_sending_participant_id(Round::Two, data.sender_ordinal, data.sender_id)?;
+        if self.hold_if_quarantined(Round::Two, data.sender_ordinal, &data)? {
+            return Ok(());
+        }
+        self.check_round2data_sender(&data)?;
+        self.verify_round2_signature(&data)?;
+        if let Err(e) = self.check_round2data_recipient(&data) {
+            telemetry::validation_failed(Round::Two, &e);
+            return Err(e);
+        }
+        if let Err(e) = self.validate_round2data(&data) {
+            telemetry::validation_failed(Round::Two, &e);
+            if let Some(evidence) = self.dispute_evidence(&data) {
+                self.dispute_evidence.insert(data.sender_ordinal, evidence);
+            }
+            self.invalid_participant_ids
+                .insert(data.sender_ordinal, data.sender_id);
+            self.tolerate_invalid_dealer(data.sender_ordinal);
+            return Err(self.blame(data.sender_ordinal, e));
+        }
+        self.check_policy(Round::Two, data.sender_ordinal)?;
+        self.received_round2_data.insert(data.sender_ordinal, data);
+        Ok(())
+    }
+
+    /// Receive several round 2 payloads at once.
+    ///
+    /// The shares are checked with a single random linear combination of
+    /// every verification equation. Only when that fails is each share
+    /// checked on its own to find the senders that dealt invalid shares.
+    /// Payloads that fail the checks which don't need the commitments take
+    /// the same path as a single payload. Returns the result for each
+    /// payload in order.
+    pub(crate) fn receive_round2data_batch(
+        &mut self,
+        batch: Vec<Round2Data<G::Scalar>>,
+    ) -> Vec<DkgResult<()>> {
+        let mut results = Vec::with_capacity(batch.len());
+        let mut candidates: Vec<(usize, Round2Data<G::Scalar>)> = Vec::with_capacity(batch.len());
+        let mut deferred = Vec::new();
+        let mut terms = Vec::with_capacity(candidates.capacity() * (self.threshold + 1));
+        let mut rng = rand::rng();
+        for (index, data) in batch.into_iter().enumerate() {
+            results.push(Ok(()));
+            telemetry::message_received(Round::Two);
+            let sender_terms = self
+                .check_round2data_sender(&data)
+                .and_then(|_| self.verify_round2_signature(&data))
+                .and_then(|_| self.check_round2data_recipient(&data))
+                .and_then(|_| self.round2_share_terms(&data));
+            match sender_terms {
+                Ok(sender_terms)
+                    if !self
+                        .invalid_participant_ids
+                        .contains_key(&data.sender_ordinal)
+                        && !candidates
+                            .iter()
+                            .any(|(_, c)| c.sender_ordinal == data.sender_ordinal) =>
+                {
+                    let r = G::Scalar::random(&mut rng);
+                    terms.extend(sender_terms.into_iter().map(|(s, p)| (s * r, p)));
+                    candidates.push((index, data));
+                }
+                // Report the error through the regular path so invalid
+                // senders are recorded the same way
+                _ => deferred.push((index, data)),
+            }
+        }
+
+        if candidates.len() > 1
+            && bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity())
+        {
+            for (index, data) in candidates {
+                if let Err(e) = self.check_policy(Round::Two, data.sender_ordinal) {
+                    results[index] = Err(e);
+                    continue;
+                }
+                self.received_round2_data.insert(data.sender_ordinal, data);
+            }
+        } else {
+            deferred.extend(candidates);
+            deferred.sort_by_key(|(index, _)| *index);
+        }
+        for (index, data) in deferred {
+            results[index] = self.accept_round2data(data);
+        }
+        results
+    }
+
+    pub(crate) fn check_round2data_sender(&self, data: &Round2Data<G::Scalar>) -> DkgResult<()> {
         if self.round > Round::Three {
             return Err(Error::Round(format!(
                 "Round {}: Invalid round payload received",
                 Round::Two
             )));
         }
+        if self.is_handoff_dealer(self.ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: Handoff dealers do not receive shares",
+                Round::Two
+            )));
+        }
         self.check_sending_participant_id(Round::Two, data.sender_ordinal, data.sender_id)?;
+        if self.round < Round::Three {
+            return Err(Error::Round(format!(
+                "Round {}: Share from {} arrived before round 2 ran, deliver it again after running",
+                Round::Two,
+                data.sender_ordinal
+            )));
+        }
         if !self
             .valid_participant_ids
             .contains_key(&data.sender_ordinal)
@@ -79,6 +255,27 @@ where
                 Round::Two
             )));
         }
+        if self.round1_openings.contains_key(&self.ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: Share from {} arrived after the dealers were fixed",
+                Round::Two,
+                data.sender_ordinal
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check `data` was dealt to this participant in this run.
+    ///
+    /// A signed share for another recipient or transcript can be replayed
+    /// by anyone, so these failures are not held against the sender.
+    pub(crate) fn check_round2data_recipient(&self, data: &Round2Data<G::Scalar>) -> DkgResult<()> {
+        if data.secret_share.identifier != self.id {
+            return Err(Error::Round(format!(
+                "Round {}: Share was dealt to another participant",
+                Round::Two
+            )));
+        }
         let self_data = self
             .received_round2_data
             .get(&self.ordinal)
@@ -94,7 +291,11 @@ where
                 Round::Two
             )));
         }
+        Ok(())
+    }
 
+    /// Check the sender signed `data` with the key of its round 1 broadcast
+    pub(crate) fn verify_round2_signature(&self, data: &Round2Data<G::Scalar>) -> DkgResult<()> {
         let round1_data = self
             .received_round1_data
             .get(&data.sender_ordinal)
@@ -104,23 +305,61 @@ where
                     Round::Two
                 ))
             })?;
+        self.signed_context()
+            .verify_round2(data, &round1_data.verifying_share)
+    }
 
-        // verify the share
-        let input = self
-            .powers_of_i
-            .iter()
-            .copied()
-            .zip(round1_data.feldman_commitments.iter().map(|g| **g))
-            .collect::<Vec<(G::Scalar, G)>>();
-        let rhs = <G as SumOfProducts>::sum_of_products(&input);
-        let lhs = self.message_generator * data.secret_share.value.0;
-        if !bool::from((lhs - rhs).is_identity()) {
+    pub(crate) fn validate_round2data(&self, data: &Round2Data<G::Scalar>) -> DkgResult<()> {
+        let terms = self.round2_share_terms(data)?;
+        if !bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity()) {
             return Err(Error::Round(format!(
                 "Round {}: The share does not verify with the given commitments",
                 Round::Three
             )));
         }
-        self.received_round2_data.insert(data.sender_ordinal, data);
         Ok(())
     }
+
+    /// The terms of the share verification equation which sum to the
+    /// identity when the share matches the sender's commitments
+    pub(crate) fn round2_share_terms(
+        &self,
+        data: &Round2Data<G::Scalar>,
+    ) -> DkgResult<Vec<(G::Scalar, G)>> {
+        let self_data = self
+            .received_round2_data
+            .get(&self.ordinal)
+            .ok_or_else(|| {
+                Error::Round(format!(
+                    "Round {}: Self doesn't have round 2 data",
+                    Round::Two
+                ))
+            })?;
+        if data.transcript_hash != self_data.transcript_hash {
+            return Err(Error::Round(format!(
+                "Round {}: Transcript hash does not match",
+                Round::Two
+            )));
+        }
+
+        let round1_data = self
+            .received_round1_data
+            .get(&data.sender_ordinal)
+            .ok_or_else(|| {
+                Error::Round(format!(
+                    "Round {}: Sender has not sent round 1 data",
+                    Round::Two
+                ))
+            })?;
+
+        let mut terms = Vec::with_capacity(self.threshold + 1);
+        terms.extend(
+            self.powers_of_i
+                .iter()
+                .zip(round1_data.feldman_commitments.iter())
+                .map(|(power, commitment)| (-*power, *commitment)),
+        );
+        terms.push((data.secret_share.value.0, self.message_generator));
+        Ok(terms)
+    }
 }
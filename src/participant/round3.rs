@@ -1,65 +1,297 @@
+use crate::locked::SecretStorage;
 use crate::{
-    DkgResult, Error, Participant, ParticipantImpl, ParticipantType, Round, RoundOutputGenerator,
-    ScalarHash, SecretShare,
+    DkgResult, Error, MissingSharePolicy, Participant, ParticipantImpl, ParticipantType,
+    PrimeSubgroup, Round, RoundOutputGenerator, ScalarHash, SecretShare,
 };
 use elliptic_curve::Field;
 use elliptic_curve::group::GroupEncoding;
-use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use elliptic_curve_tools::SumOfProducts;
-use vsss_rs::{IdentifierPrimeField, Share, ValueGroup};
+use std::collections::BTreeMap;
+use vsss_rs::{IdentifierPrimeField, Share};
 
 impl<I, G> Participant<I, G>
 where
     I: ParticipantImpl<G> + Default,
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     pub(crate) fn round3_ready(&self) -> bool {
-        self.round == Round::Three && self.received_round2_data.len() >= self.threshold
+        self.round == Round::Three
+            && (self.is_handoff_dealer(self.ordinal)
+                || (self.received_round2_data.len() >= self.threshold
+                    && (self.missing_share_policy == MissingSharePolicy::Proceed
+                        || self.missing_shares().is_empty())
+                    && self.missing_round1_openings().is_empty()))
     }
 
     pub(crate) fn round3(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
+        if self.round == Round::Three && self.is_handoff_dealer(self.ordinal) {
+            // Dealers hand the key off without a new share, after opening
+            // their pedersen commitments
+            let output = if self.blinder_generator.is_some() {
+                self.open_round1()?
+            } else {
+                RoundOutputGenerator::Round3
+            };
+            self.round = Round::Four;
+            self.completed = true;
+            return Ok(output);
+        }
+        if self.round == Round::Three {
+            self.check_missing_shares()?;
+            let missing = self.missing_round1_openings();
+            if !missing.is_empty() {
+                return Err(Error::Round(format!(
+                    "Round {}: Waiting for the openings of {missing:?}",
+                    Round::Three
+                )));
+            }
+        }
         if !self.round3_ready() {
             return Err(Error::Round(format!(
                 "Round 3 is not ready, haven't received enough data from other participants. Need {} more",
                 self.threshold - self.received_round2_data.len()
             )));
         }
+        self.validate_roster()?;
+        if self.blinder_generator.is_some() && !self.round1_openings.contains_key(&self.ordinal) {
+            // The dealers are fixed, so the commitments can be opened
+            return self.open_round1();
+        }
+        // Disqualified peers neither deal nor hold a share
+        self.valid_participant_ids
+            .retain(|ordinal, _| !self.disqualified_participant_ids.contains(ordinal));
 
         let mut secret_share = SecretShare::<G::Scalar>::with_identifier_and_value(
             self.id,
             IdentifierPrimeField(G::Scalar::ZERO),
         );
-        let mut public_key = ValueGroup::<G>::default();
+        let mut public_key = G::identity();
         let og_secret = self.secret_shares[&self.ordinal];
 
-        let mut all_refresh = true;
-
+        // Aggregate and check without branching on secret values or on the
+        // mix of participant types so timing only reveals success or failure
+        let mut all_refresh = Choice::from(1u8);
         for (ordinal, round2data) in self.received_round2_data.iter() {
-            let participant_type = self.received_round1_data[ordinal].sender_type;
-            all_refresh &= matches!(participant_type, ParticipantType::Refresh);
+            let round1data = &self.received_round1_data[ordinal];
+            let dealt = self.dealer_round1data(*ordinal).unwrap_or(round1data);
+            all_refresh &=
+                u16::from(round1data.sender_type).ct_eq(&u16::from(ParticipantType::Refresh));
 
-            public_key.0 += self.received_round1_data[ordinal].feldman_commitments[0].0;
+            public_key += dealt.feldman_commitments[0];
             secret_share.value.0 += round2data.secret_share.value.0;
         }
 
-        let public_key_identity = bool::from(public_key.is_identity());
-        if all_refresh && !public_key_identity || !all_refresh && public_key_identity {
-            return Err(Error::Round(
-                "Round 3: The resulting public key is invalid".to_string(),
-            ));
-        }
+        let public_key_identity = public_key.is_identity();
+        let refresh_changed_key = all_refresh & !public_key_identity;
+        let secrets_cancelled = !all_refresh & public_key_identity;
+        let share_unchanged = secret_share.value.0.ct_eq(&og_secret.value.0);
+        let verification_shares = self.compute_verification_shares();
+        let expected_share = self.message_generator * secret_share.value.0;
+        let share_matches = verification_shares
+            .get(&self.ordinal)
+            .map_or(Choice::from(0u8), |share| {
+                (*share - expected_share).is_identity()
+            });
 
-        if secret_share.value == og_secret.value {
-            return Err(Error::Round(format!(
-                "Round {}: The resulting secret key share is invalid",
-                Round::Three
-            )));
+        if bool::from(refresh_changed_key | secrets_cancelled | share_unchanged | !share_matches) {
+            let reason = if bool::from(refresh_changed_key) {
+                "The resulting public key is invalid, refresh participants cannot change the public key"
+            } else if bool::from(secrets_cancelled) {
+                "The resulting public key is invalid, the secret participants' contributions cancel out"
+            } else if bool::from(share_unchanged) {
+                "The resulting secret key share is invalid"
+            } else {
+                "The resulting secret key share does not match the verification share"
+            };
+            return Err(Error::Round(format!("Round {}: {reason}", Round::Three)));
         }
         self.round = Round::Four;
         self.completed = true;
         self.public_key = public_key;
-        self.secret_share = secret_share;
+        self.secret_share = SecretStorage::new(secret_share);
+        self.verification_shares = verification_shares;
         Ok(RoundOutputGenerator::Round3)
     }
+
+    /// Check that the secret and refresh participants whose shares are
+    /// combined form a roster that can produce a valid key.
+    ///
+    /// A fresh key generation needs at least `threshold` secret participants.
+    /// Refresh participants contribute a sharing of zero, so when they are mixed
+    /// with secret participants the existing key is only carried by the secret
+    /// participants and every one of them must be combined.
+    fn validate_roster(&self) -> DkgResult<()> {
+        let (secret, refresh) = self.roster_counts();
+        if refresh == 0 && secret < self.threshold {
+            return Err(Error::Round(format!(
+                "Round {}: A new key needs at least {} secret participants but only {} contributed",
+                Round::Three,
+                self.threshold,
+                secret
+            )));
+        }
+        if secret > 0 && refresh > 0 {
+            let missing = self.missing_secret_senders();
+            if !missing.is_empty() {
+                return Err(Error::Round(format!(
+                    "Round {}: Refresh participants can only join an existing key when all secret participants contribute, missing {:?}",
+                    Round::Three,
+                    missing
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of secret and refresh participants that sent round 2 data
+    pub(crate) fn roster_counts(&self) -> (usize, usize) {
+        let mut secret = 0;
+        let mut refresh = 0;
+        for ordinal in self.received_round2_data.keys() {
+            match self.received_round1_data[ordinal].sender_type {
+                ParticipantType::Secret => secret += 1,
+                ParticipantType::Refresh => refresh += 1,
+            }
+        }
+        (secret, refresh)
+    }
+
+    /// The secret participants from round 1 that haven't sent round 2 data
+    pub(crate) fn missing_secret_senders(&self) -> Vec<usize> {
+        self.received_round1_data
+            .iter()
+            .filter(|(ordinal, data)| {
+                matches!(data.sender_type, ParticipantType::Secret)
+                    && !self.received_round2_data.contains_key(ordinal)
+            })
+            .map(|(ordinal, _)| *ordinal)
+            .collect()
+    }
+
+    /// Compute the verification share of every valid participant using the
+    /// commitments of the dealers whose shares were combined.
+    pub(crate) fn compute_verification_shares(&self) -> BTreeMap<usize, G> {
+        let mut commitments = vec![G::identity(); self.threshold];
+        for ordinal in self.received_round2_data.keys() {
+            let Some(round1data) = self.dealer_round1data(*ordinal) else {
+                continue;
+            };
+            for (acc, c) in commitments
+                .iter_mut()
+                .zip(round1data.feldman_commitments.iter())
+            {
+                *acc += c;
+            }
+        }
+        // Reuse one buffer for every participant instead of allocating per share
+        let mut input = Vec::<(G::Scalar, G)>::with_capacity(self.threshold);
+        self.valid_participant_ids
+            .iter()
+            .filter(|(ordinal, _)| !self.is_handoff_dealer(**ordinal))
+            .map(|(ordinal, id)| {
+                let mut power = G::Scalar::ONE;
+                input.clear();
+                input.extend(commitments.iter().map(|c| {
+                    let pair = (power, *c);
+                    power *= id.0;
+                    pair
+                }));
+                (*ordinal, <G as SumOfProducts>::sum_of_products(&input))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::participant::testing::{next_round, receive};
+    use crate::{Parameters, RefreshParticipant, SecretParticipant};
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn round3_failure_reasons() {
+        type G = p256::ProjectivePoint;
+
+        let parameters = Parameters::<G>::new(
+            NonZeroUsize::new(3).expect("threshold is non-zero"),
+            NonZeroUsize::new(4).expect("limit is non-zero"),
+            None,
+            None,
+        );
+        let id = |id: u64| IdentifierPrimeField(p256::Scalar::from(id));
+        fn round3_error<I: ParticipantImpl<G> + Default>(
+            participant: &mut Participant<I, G>,
+        ) -> String {
+            match participant.run() {
+                Err(Error::Round(reason)) => reason,
+                other => panic!("expected a round 3 error, got {other:?}"),
+            }
+        }
+
+        // Refresh participants only share zero
+        let mut refresh = (1..=4)
+            .map(|i| RefreshParticipant::<G>::new_refresh(id(i), None, &parameters))
+            .collect::<DkgResult<Vec<_>>>()
+            .expect("create refresh participants");
+        for _ in [Round::One, Round::Two] {
+            let generators = next_round(&mut refresh);
+            receive(&mut refresh, generators);
+        }
+        refresh[0]
+            .received_round1_data
+            .get_mut(&2)
+            .expect("round 1 data from ordinal 2")
+            .feldman_commitments[0] = G::GENERATOR;
+        assert!(round3_error(&mut refresh[0]).contains("cannot change the public key"));
+
+        let mut secret = (1..=4)
+            .map(|i| SecretParticipant::<G>::new_secret(id(i), &parameters))
+            .collect::<DkgResult<Vec<_>>>()
+            .expect("create secret participants");
+        for _ in [Round::One, Round::Two] {
+            let generators = next_round(&mut secret);
+            receive(&mut secret, generators);
+        }
+
+        let mut cancelled = secret[0].clone();
+        let others = (0..3)
+            .map(|ordinal| cancelled.received_round1_data[&ordinal].feldman_commitments[0])
+            .sum::<G>();
+        cancelled
+            .received_round1_data
+            .get_mut(&3)
+            .expect("round 1 data from ordinal 3")
+            .feldman_commitments[0] = -others;
+        assert!(round3_error(&mut cancelled).contains("contributions cancel out"));
+
+        // Only the participant's own share is left
+        let mut unchanged = secret[0].clone();
+        for ordinal in 1..4 {
+            unchanged
+                .received_round2_data
+                .get_mut(&ordinal)
+                .expect("round 2 data")
+                .secret_share
+                .value
+                .0 = p256::Scalar::ZERO;
+        }
+        assert!(round3_error(&mut unchanged).contains("secret key share is invalid"));
+
+        let mut mismatched = secret[0].clone();
+        mismatched
+            .received_round2_data
+            .get_mut(&1)
+            .expect("round 2 data from ordinal 1")
+            .secret_share
+            .value
+            .0 += p256::Scalar::ONE;
+        assert!(round3_error(&mut mismatched).contains("does not match the verification share"));
+        assert!(!mismatched.completed());
+
+        secret[0].run().expect("run round 3");
+        assert!(secret[0].completed());
+    }
 }
@@ -0,0 +1,47 @@
+//! Helpers for driving participants through the rounds in unit tests
+
+use super::*;
+
+/// Run the current round of every participant and collect their outputs
+pub(crate) fn next_round<I, G>(
+    participants: &mut [Participant<I, G>],
+) -> Vec<RoundOutputGenerator<G>>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut round_generators = Vec::with_capacity(participants.len());
+    for participant in participants {
+        let generator = participant.run().expect("run participant round");
+        round_generators.push(generator);
+    }
+    round_generators
+}
+
+/// Deliver every output to its recipient, expecting each to be accepted
+pub(crate) fn receive<I, G>(
+    participants: &mut [Participant<I, G>],
+    round_generators: Vec<RoundOutputGenerator<G>>,
+) where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    for round_generator in &round_generators {
+        for ParticipantRoundOutput {
+            dst_ordinal: ordinal,
+            dst_id: id,
+            data,
+            ..
+        } in round_generator.iter()
+        {
+            if let Some(participant) = participants.get_mut(ordinal) {
+                assert_eq!(participant.ordinal, ordinal);
+                assert_eq!(participant.id, id);
+                let res = participant.receive(data.as_slice());
+                assert!(res.is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use super::*;
+use crate::serdes::hex_share;
+use crate::telemetry;
+use elliptic_curve::PrimeField;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The round 2 data sent when the round 1 commitments are pedersen
+/// commitments.
+///
+/// Carries the share of the blinder polynomial along with the secret share.
+/// The feldman commitments are only opened in round 3, once the dealers
+/// whose shares are combined are fixed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BlindedRound2Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The round 2 data
+    #[serde(bound(
+        serialize = "Round2Data<G::Scalar>: Serialize",
+        deserialize = "Round2Data<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) round2_data: Round2Data<G::Scalar>,
+    /// The share of the blinder polynomial
+    #[serde(
+        with = "hex_share",
+        bound(
+            serialize = "SecretShare<G::Scalar>: Serialize",
+            deserialize = "SecretShare<G::Scalar>: Deserialize<'de>"
+        )
+    )]
+    pub(crate) blinder_share: SecretShare<G::Scalar>,
+}
+
+impl<G> BlindedRound2Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Get the round 2 data
+    pub fn round2_data(&self) -> &Round2Data<G::Scalar> {
+        &self.round2_data
+    }
+
+    /// Get the share of the blinder polynomial
+    pub fn blinder_share(&self) -> SecretShare<G::Scalar> {
+        self.blinder_share
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The blinder generator if round 1 uses pedersen commitments
+    pub fn get_blinder_generator(&self) -> Option<G> {
+        self.blinder_generator
+    }
+
+    /// The dealers whose shares were accepted but whose opening of their
+    /// pedersen commitments has not arrived.
+    ///
+    /// Empty without a blinder generator and until this participant opened
+    /// its own commitments in round 3, which fixes the dealers. Round 3
+    /// only completes once this is empty, see
+    /// [`Parameters::with_blinder_generator`].
+    pub fn missing_round1_openings(&self) -> Vec<usize> {
+        if !self.round1_openings.contains_key(&self.ordinal) {
+            return Vec::new();
+        }
+        self.received_round2_data
+            .keys()
+            .filter(|ordinal| !self.round1_openings.contains_key(ordinal))
+            .copied()
+            .collect()
+    }
+
+    /// The secret of the key signing the pedersen round 1 broadcast and the
+    /// round 2 shares.
+    ///
+    /// The verifying share of a secret participant is its first feldman
+    /// commitment, which the pedersen commitments hide, so it signs with a
+    /// key derived from its secret and the pedersen commitments instead.
+    /// Refresh participants sign with their existing share.
+    pub(crate) fn pedersen_secret(&self) -> G::Scalar {
+        match self.participant_impl.get_type() {
+            ParticipantType::Refresh => self.original_secret,
+            ParticipantType::Secret => {
+                let secret = self.original_secret.to_repr();
+                let mut input = secret.as_ref().to_vec();
+                input.extend_from_slice(&commitments_digest(&self.pedersen_commitments));
+                let mut dst = group_dst::<G>();
+                dst.extend_from_slice(b"-PEDERSEN-KEY");
+                G::Scalar::hash_to_scalar_with_dst(&input, &dst)
+            }
+        }
+    }
+
+    /// The secret and the public key of this participant's round 1
+    /// broadcast, which round 2 data and share acknowledgments are signed with
+    pub(crate) fn round1_key(&self) -> (G::Scalar, G) {
+        if self.blinder_generator.is_some() {
+            let secret = self.pedersen_secret();
+            (secret, self.message_generator * secret)
+        } else {
+            (self.original_secret, *self.verifying_share)
+        }
+    }
+
+    /// The opened feldman commitments of the dealer at `ordinal`.
+    ///
+    /// The round 1 broadcast itself without a blinder generator, only the
+    /// opening from round 3 otherwise.
+    pub(crate) fn dealer_round1data(&self, ordinal: usize) -> Option<&Round1Data<G>> {
+        if self.blinder_generator.is_some() {
+            self.round1_openings.get(&ordinal)
+        } else {
+            self.received_round1_data.get(&ordinal)
+        }
+    }
+
+    /// The openings of the pedersen commitments received in round 3 by
+    /// sender ordinal index.
+    ///
+    /// [`Participant::get_received_round1_data`] keeps the signed pedersen
+    /// broadcasts.
+    pub fn get_round1_openings(&self) -> &BTreeMap<usize, Round1Data<G>> {
+        &self.round1_openings
+    }
+
+    pub(crate) fn receive_blinded_round2data(
+        &mut self,
+        data: BlindedRound2Data<G>,
+    ) -> DkgResult<()> {
+        telemetry::message_received(Round::Two);
+        let sender_ordinal = data.round2_data.sender_ordinal;
+        self.check_sending_participant_id(Round::Two, sender_ordinal, data.round2_data.sender_id)?;
+        if self.hold_if_quarantined(Round::Two, sender_ordinal, &data)? {
+            return Ok(());
+        }
+        self.check_round2data_sender(&data.round2_data)?;
+        self.verify_round2_signature(&data.round2_data)?;
+        if let Err(e) = self.check_round2data_recipient(&data.round2_data) {
+            telemetry::validation_failed(Round::Two, &e);
+            return Err(e);
+        }
+        // The share is signed with the key of the sender's round 1
+        // broadcast, so a share that doesn't match its commitments is
+        // held against the sender like without a blinder
+        if let Err(e) = self.check_pedersen_share(&data) {
+            telemetry::validation_failed(Round::Two, &e);
+            self.invalid_participant_ids
+                .insert(sender_ordinal, data.round2_data.sender_id);
+            self.tolerate_invalid_dealer(sender_ordinal);
+            return Err(self.blame(sender_ordinal, e));
+        }
+        self.check_policy(Round::Two, sender_ordinal)?;
+        self.received_round2_data
+            .insert(sender_ordinal, data.round2_data);
+        Ok(())
+    }
+
+    /// Check the shares against the sender's pedersen commitments
+    fn check_pedersen_share(&self, data: &BlindedRound2Data<G>) -> DkgResult<()> {
+        let blinder_generator = self.blinder_generator.ok_or_else(|| {
+            Error::Round(format!(
+                "Round {}: Received blinded data without a blinder generator",
+                Round::Two
+            ))
+        })?;
+        let mut terms = self.round2_share_terms(&data.round2_data)?;
+        terms.push((data.blinder_share.value.0, blinder_generator));
+        if !bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity()) {
+            return Err(Error::Round(format!(
+                "Round {}: The share does not verify with the pedersen commitments",
+                Round::Two
+            )));
+        }
+        Ok(())
+    }
+
+    /// Broadcast the feldman commitments hidden by this participant's
+    /// pedersen commitments.
+    ///
+    /// Only runs once the shares it combines are accepted, so the dealers
+    /// are fixed before anyone sees a contribution to the key.
+    pub(crate) fn open_round1(&mut self) -> DkgResult<RoundOutputGenerator<G>> {
+        let opening = self.feldman_round1data();
+        self.round1_openings.insert(self.ordinal, opening);
+        self.opening_output()
+    }
+
+    /// The broadcast of this participant's opening to the participants
+    /// that receive a share
+    pub(crate) fn opening_output(&self) -> DkgResult<RoundOutputGenerator<G>> {
+        let opening = self.round1_openings.get(&self.ordinal).ok_or_else(|| {
+            Error::Round(format!(
+                "Round {}: The commitments have not been opened",
+                Round::Three
+            ))
+        })?;
+        Ok(RoundOutputGenerator::Opening(Round1OutputGenerator {
+            participant_ids: self
+                .valid_participant_ids
+                .iter()
+                .filter(|(ordinal, _)| {
+                    !self.is_handoff_dealer(**ordinal)
+                        && !self.disqualified_participant_ids.contains(ordinal)
+                })
+                .map(|(ordinal, id)| (*ordinal, *id))
+                .collect(),
+            sender_ordinal: self.ordinal,
+            data: encode_payload::<G, _>(Round::Three, opening)?,
+        }))
+    }
+
+    pub(crate) fn receive_round1_opening_data(&mut self, data: Round1Data<G>) -> DkgResult<()> {
+        telemetry::message_received(Round::Three);
+        if self.blinder_generator.is_none() || self.round > Round::Three {
+            return Err(Error::Round(format!(
+                "Round {}: Invalid round payload received",
+                Round::Three
+            )));
+        }
+        self.check_sending_participant_id(Round::Three, data.sender_ordinal, data.sender_id)?;
+        if !self.round1_openings.contains_key(&self.ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: Opening from {} arrived before the commitments were opened, deliver it again after running",
+                Round::Three,
+                data.sender_ordinal
+            )));
+        }
+        if !self.received_round2_data.contains_key(&data.sender_ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: No share was accepted from sender {}",
+                Round::Three,
+                data.sender_ordinal
+            )));
+        }
+        if self.round1_openings.contains_key(&data.sender_ordinal) {
+            return Err(Error::Round(format!(
+                "Round {}: Sender has already sent data",
+                Round::Three
+            )));
+        }
+        if self.hold_if_quarantined(Round::Three, data.sender_ordinal, &data)? {
+            return Ok(());
+        }
+        // Anyone can send an opening under another sender's ordinal, so a
+        // bad opening is dropped without blaming the sender
+        if let Err(e) = self.validate_round1_opening_data(&data) {
+            telemetry::validation_failed(Round::Three, &e);
+            return Err(e);
+        }
+        self.check_policy(Round::Three, data.sender_ordinal)?;
+        self.round1_openings.insert(data.sender_ordinal, data);
+        Ok(())
+    }
+
+    /// Check an opening against the sender's pedersen broadcast and the
+    /// share it dealt to this participant
+    fn validate_round1_opening_data(&self, data: &Round1Data<G>) -> DkgResult<()> {
+        check_pedersen_opening(
+            data,
+            &self.received_round1_data[&data.sender_ordinal],
+            &self.signed_context(),
+            &self.previous_verification_shares,
+            self.handoff_dealers,
+        )?;
+        let round2_data = &self.received_round2_data[&data.sender_ordinal];
+        let mut terms = Vec::with_capacity(self.threshold + 1);
+        terms.extend(
+            self.powers_of_i
+                .iter()
+                .zip(data.feldman_commitments.iter())
+                .map(|(power, commitment)| (-*power, *commitment)),
+        );
+        terms.push((round2_data.secret_share.value.0, self.message_generator));
+        if !bool::from(<G as SumOfProducts>::sum_of_products(&terms).is_identity()) {
+            return Err(Error::Round(format!(
+                "Round {}: The share does not verify with the opened feldman commitments",
+                Round::Three
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Check the `opening` of the sender's `pedersen` round 1 broadcast.
+///
+/// The opening is checked like round 1 data without a blinder and must be
+/// from the same sender. A refresh participant's opening keeps the
+/// verifying share the continuity check was done against.
+pub(crate) fn check_pedersen_opening<G>(
+    opening: &Round1Data<G>,
+    pedersen: &Round1Data<G>,
+    context: &SignedContext<G>,
+    previous_verification_shares: &BTreeMap<usize, G>,
+    handoff_dealers: usize,
+) -> DkgResult<()>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if opening.sender_ordinal != pedersen.sender_ordinal
+        || opening.sender_id != pedersen.sender_id
+        || u16::from(opening.sender_type) != u16::from(pedersen.sender_type)
+        || (matches!(opening.sender_type, ParticipantType::Refresh)
+            && opening.verifying_share != pedersen.verifying_share)
+    {
+        return Err(Error::Round(format!(
+            "Round {}: The opening does not match the sender's round 1 broadcast",
+            Round::Three
+        )));
+    }
+    check_round1data(
+        opening,
+        context,
+        previous_verification_shares,
+        handoff_dealers,
+        false,
+    )
+}
@@ -0,0 +1,85 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::sync::Arc;
+
+/// A message that passed the protocol checks, offered to a [`Policy`]
+/// before it is accepted
+#[derive(Debug, Copy, Clone)]
+pub struct PolicyMessage<'a> {
+    /// The ordinal index of the sender
+    pub sender: usize,
+    /// The round of the message
+    pub round: Round,
+    /// The roster metadata of the sender, if it has any
+    pub metadata: Option<&'a ParticipantMetadata>,
+    /// The application identifier of the sender, if it has one
+    pub identifier: Option<&'a ParticipantIdentifier>,
+}
+
+/// Application rules, like time windows or quotas, that can veto a
+/// message the protocol would accept.
+///
+/// Closures taking a [`PolicyMessage`] implement it.
+pub trait Policy: Send + Sync {
+    /// Return the reason to reject `message` or `Ok` to accept it
+    fn check(&self, message: &PolicyMessage<'_>) -> Result<(), String>;
+}
+
+impl<F> Policy for F
+where
+    F: Fn(&PolicyMessage<'_>) -> Result<(), String> + Send + Sync,
+{
+    fn check(&self, message: &PolicyMessage<'_>) -> Result<(), String> {
+        self(message)
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Check every validated round 1 broadcast and round 2 share with
+    /// `policy` before accepting it.
+    ///
+    /// A vetoed message is dropped without blaming its sender, so it can be
+    /// delivered again once the policy allows it. Clones of the participant
+    /// share the policy and it is not part of a state snapshot.
+    pub fn set_policy(&mut self, policy: Arc<dyn Policy>) {
+        self.policy = Some(policy);
+    }
+
+    /// Accept every validated message again
+    pub fn clear_policy(&mut self) {
+        self.policy = None;
+    }
+
+    /// Whether a policy checks the validated messages
+    pub fn has_policy(&self) -> bool {
+        self.policy.is_some()
+    }
+
+    /// Ask the policy whether the validated `round` message from `sender`
+    /// is accepted
+    pub(crate) fn check_policy(&self, round: Round, sender: usize) -> DkgResult<()> {
+        let Some(policy) = &self.policy else {
+            return Ok(());
+        };
+        let message = PolicyMessage {
+            sender,
+            round,
+            metadata: self.participant_metadata.get(&sender),
+            identifier: self.participant_identifiers.get(&sender),
+        };
+        policy
+            .check(&message)
+            .map_err(|reason| Error::PolicyRejected {
+                sender,
+                round,
+                reason,
+            })
+    }
+}
@@ -0,0 +1,9 @@
+//! Re-exports of the types and traits needed by almost every user.
+
+#[cfg(feature = "participant")]
+pub use crate::{
+    AnyParticipant, DkgOutput, Parameters, RefreshParticipant, SecretParticipant,
+    run_local_ceremony,
+};
+pub use crate::{DkgResult, Error, Round, ScalarHash};
+pub use vsss_rs::{IdentifierPrimeField, ReadableShareSet};
@@ -0,0 +1,115 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+
+/// Nonces generated ahead of time so signing only needs one online round.
+///
+/// The commitments returned by [`NonceStore::generate`] are published to the
+/// coordinator in advance. When a [`SigningPackage`] arrives the matching nonces
+/// are removed from the store so they can never be used twice, and they are
+/// zeroized as soon as the signature share is computed.
+pub struct NonceStore<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    ordinal: usize,
+    nonces: BTreeMap<Vec<u8>, SigningNonces<G>>,
+}
+
+impl<G> Debug for NonceStore<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonceStore")
+            .field("ordinal", &self.ordinal)
+            .field("remaining", &self.nonces.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G> NonceStore<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create an empty store for the signer that produced `output`
+    pub fn new(output: &DkgOutput<G>) -> Self {
+        Self {
+            ordinal: output.ordinal,
+            nonces: BTreeMap::new(),
+        }
+    }
+
+    /// Generate `count` more nonces and return their commitments
+    pub fn generate(
+        &mut self,
+        output: &DkgOutput<G>,
+        count: usize,
+    ) -> DkgResult<Vec<SigningCommitments<G>>> {
+        if output.ordinal != self.ordinal {
+            return Err(Error::Signature(format!(
+                "Nonce store belongs to signer {}, not {}",
+                self.ordinal, output.ordinal
+            )));
+        }
+        let mut commitments = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (nonces, c) = output.commit();
+            self.nonces.insert(Self::key(&c), nonces);
+            commitments.push(c);
+        }
+        Ok(commitments)
+    }
+
+    /// Remove and return the nonces for `commitments`.
+    ///
+    /// Returns an error if they were never generated by this store
+    /// or were already used.
+    pub fn take(&mut self, commitments: &SigningCommitments<G>) -> DkgResult<SigningNonces<G>> {
+        self.nonces.remove(&Self::key(commitments)).ok_or_else(|| {
+            Error::Signature("Nonces for these commitments are unknown or already used".to_string())
+        })
+    }
+
+    /// The number of unused nonces
+    pub fn remaining(&self) -> usize {
+        self.nonces.len()
+    }
+
+    fn key(commitments: &SigningCommitments<G>) -> Vec<u8> {
+        let mut key = commitments.hiding.to_bytes().as_ref().to_vec();
+        key.extend_from_slice(commitments.binding.to_bytes().as_ref());
+        key
+    }
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Compute this signer's share of the signature using nonces
+    /// preprocessed in `store`.
+    ///
+    /// The nonces are consumed even if signing fails so a rejected
+    /// package cannot be replayed with the same commitments.
+    pub fn sign_with_store(
+        &self,
+        package: &SigningPackage<G>,
+        store: &mut NonceStore<G>,
+    ) -> DkgResult<SignatureShare<G>> {
+        let Some(commitments) = package.commitments.get(&self.ordinal) else {
+            return Err(Error::Signature(
+                "Signer is not part of the signing package".to_string(),
+            ));
+        };
+        let nonces = store.take(commitments)?;
+        self.sign(package, nonces)
+    }
+}
@@ -0,0 +1,140 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use vsss_rs::IdentifierPrimeField;
+
+/// The validation status of a peer as seen by one participant
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PeerStatus {
+    /// Nothing from the peer has been validated yet
+    Pending,
+    /// Everything received from the peer so far was valid
+    Validated,
+    /// The peer sent invalid data and its later messages are held until
+    /// [`Participant::disqualify_peer`] or [`Participant::release_peer`]
+    /// decides what to do with it
+    Quarantined,
+    /// The peer is excluded from the protocol
+    Disqualified,
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The status of the peer at `ordinal` or [`None`] if there is no such
+    /// peer
+    pub fn peer_status(&self, ordinal: usize) -> Option<PeerStatus> {
+        if ordinal == self.ordinal || !self.all_participant_ids.contains_key(&ordinal) {
+            return None;
+        }
+        let status = if self.disqualified_participant_ids.contains(&ordinal) {
+            PeerStatus::Disqualified
+        } else if self.invalid_participant_ids.contains_key(&ordinal) {
+            PeerStatus::Quarantined
+        } else if self.received_round1_data.contains_key(&ordinal) {
+            PeerStatus::Validated
+        } else {
+            PeerStatus::Pending
+        };
+        Some(status)
+    }
+
+    /// The status of every peer by ordinal
+    pub fn peer_statuses(&self) -> BTreeMap<usize, PeerStatus> {
+        self.all_participant_ids
+            .keys()
+            .filter_map(|ordinal| Some((*ordinal, self.peer_status(*ordinal)?)))
+            .collect()
+    }
+
+    /// The messages held from a quarantined peer in the order they arrived
+    pub fn quarantined_messages(&self, ordinal: usize) -> &[Vec<u8>] {
+        self.quarantined_messages
+            .get(&ordinal)
+            .map_or(&[], |messages| messages.as_slice())
+    }
+
+    /// Exclude the peer at `ordinal` from the protocol and drop anything
+    /// held from it.
+    ///
+    /// Data already accepted from the peer is discarded as long as the round
+    /// that uses it has not run.
+    pub fn disqualify_peer(&mut self, ordinal: usize) -> DkgResult<()> {
+        let id = self.peer_id(ordinal)?;
+        if self.round <= Round::Two {
+            self.received_round1_data.remove(&ordinal);
+        }
+        if self.round <= Round::Three {
+            self.received_round2_data.remove(&ordinal);
+            self.round1_openings.remove(&ordinal);
+        }
+        self.quarantined_messages.remove(&ordinal);
+        self.invalid_participant_ids.insert(ordinal, id);
+        self.disqualified_participant_ids.insert(ordinal);
+        Ok(())
+    }
+
+    /// Clear the quarantine of the peer at `ordinal` and receive the messages
+    /// held from it.
+    ///
+    /// The message that failed validation was not kept so the peer has to
+    /// send it again. Returns the result for each held message in order.
+    pub fn release_peer(&mut self, ordinal: usize) -> DkgResult<Vec<DkgResult<()>>> {
+        self.peer_id(ordinal)?;
+        if self.peer_status(ordinal) != Some(PeerStatus::Quarantined) {
+            return Err(Error::Round(format!(
+                "Participant {ordinal} is not quarantined"
+            )));
+        }
+        self.invalid_participant_ids.remove(&ordinal);
+        self.dispute_evidence.remove(&ordinal);
+        let messages = self
+            .quarantined_messages
+            .remove(&ordinal)
+            .unwrap_or_default();
+        Ok(messages.iter().map(|data| self.receive(data)).collect())
+    }
+
+    /// Hold the payload when its sender is quarantined.
+    ///
+    /// Returns `true` when the payload was held and should not be processed.
+    pub(crate) fn hold_if_quarantined<T: Serialize>(
+        &mut self,
+        round: Round,
+        sender_ordinal: usize,
+        payload: &T,
+    ) -> DkgResult<bool> {
+        match self.peer_status(sender_ordinal) {
+            Some(PeerStatus::Disqualified) => Err(Error::Round(format!(
+                "Round {round}: Sender {sender_ordinal} is disqualified"
+            ))),
+            Some(PeerStatus::Quarantined) => {
+                let data = encode_payload::<G, _>(round, payload)?;
+                self.quarantined_messages
+                    .entry(sender_ordinal)
+                    .or_default()
+                    .push(data);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn peer_id(&self, ordinal: usize) -> DkgResult<IdentifierPrimeField<G::Scalar>> {
+        if ordinal == self.ordinal {
+            return Err(Error::Round(
+                "A participant cannot change its own status".to_string(),
+            ));
+        }
+        self.all_participant_ids
+            .get(&ordinal)
+            .copied()
+            .ok_or_else(|| Error::Round(format!("Unknown participant ordinal, {ordinal}")))
+    }
+}
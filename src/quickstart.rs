@@ -0,0 +1,119 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+
+/// Drive `participants` through every round in-process and return the
+/// output of each in order.
+///
+/// Messages are delivered directly so this is only meant for examples,
+/// tests and single machine setups. Each participant must sit at the index
+/// of its ordinal.
+///
+/// ```
+/// use frost_dkg::*;
+/// use std::num::NonZeroUsize;
+/// use vsss_rs::{IdentifierPrimeField, ParticipantIdGeneratorType};
+///
+/// type G = k256::ProjectivePoint;
+///
+/// let id = |i: u64| IdentifierPrimeField(k256::Scalar::from(i));
+/// let two = NonZeroUsize::new(2).unwrap();
+/// let three = NonZeroUsize::new(3).unwrap();
+///
+/// // A fresh 2-of-3 key
+/// let ids = [id(1), id(2), id(3)];
+/// let seq = vec![ParticipantIdGeneratorType::list(&ids)];
+/// let parameters = Parameters::<G>::new(two, three, None, Some(seq));
+/// let mut participants = ids
+///     .iter()
+///     .map(|id| {
+///         Ok(Box::new(SecretParticipant::<G>::new_secret(*id, &parameters)?)
+///             as Box<dyn AnyParticipant<G>>)
+///     })
+///     .collect::<DkgResult<Vec<_>>>()?;
+/// let outputs = run_local_ceremony(&mut participants)?;
+///
+/// // Two shareholders hand the same key to a roster with a newcomer
+/// let roster = [id(1), id(2), id(4)];
+/// let seq = vec![ParticipantIdGeneratorType::list(&roster)];
+/// let parameters = Parameters::<G>::new(two, three, None, Some(seq));
+/// let mut participants = vec![
+///     Box::new(SecretParticipant::<G>::with_secret(
+///         id(1),
+///         &outputs[0].secret_share(),
+///         &parameters,
+///         &ids[..2],
+///     )?) as Box<dyn AnyParticipant<G>>,
+///     Box::new(SecretParticipant::<G>::with_secret(
+///         id(2),
+///         &outputs[1].secret_share(),
+///         &parameters,
+///         &ids[..2],
+///     )?),
+///     Box::new(RefreshParticipant::<G>::new_refresh(id(4), None, &parameters)?),
+/// ];
+/// let reshared = run_local_ceremony(&mut participants)?;
+/// assert_eq!(reshared[2].public_key(), outputs[0].public_key());
+/// # Ok::<(), Error>(())
+/// ```
+pub fn run_local_ceremony<G>(
+    participants: &mut [Box<dyn AnyParticipant<G>>],
+) -> DkgResult<Vec<DkgOutput<G>>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    if let Some((index, _)) = participants
+        .iter()
+        .enumerate()
+        .find(|(index, p)| p.get_ordinal() != *index)
+    {
+        return Err(Error::Initialization(format!(
+            "Participant at index {index} is not at its ordinal"
+        )));
+    }
+
+    // Pedersen commitments are opened in an extra run of round 3
+    while participants.iter().any(|p| !p.completed()) {
+        let generators = participants
+            .iter_mut()
+            .filter(|p| !p.completed())
+            .map(|p| p.run())
+            .collect::<DkgResult<Vec<_>>>()?;
+        for generator in &generators {
+            for output in generator.iter() {
+                if let Some(participant) = participants.get_mut(output.dst_ordinal) {
+                    participant.receive(&output.data)?;
+                }
+            }
+        }
+    }
+
+    participants
+        .iter()
+        .map(|p| {
+            p.get_dkg_output().ok_or_else(|| {
+                Error::Round(format!(
+                    "Participant {} did not complete the protocol",
+                    p.get_ordinal()
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Run [`run_local_ceremony`] and confirm the new key with
+/// [`prove_liveness`] over `canary` before returning the outputs
+pub fn run_local_ceremony_with_liveness<G>(
+    participants: &mut [Box<dyn AnyParticipant<G>>],
+    canary: &[u8],
+) -> DkgResult<(Vec<DkgOutput<G>>, ThresholdSignature<G>)>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let outputs = run_local_ceremony(participants)?;
+    let signature = prove_liveness(&outputs, canary)?;
+    Ok((outputs, signature))
+}
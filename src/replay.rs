@@ -0,0 +1,111 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Remembers the messages received recently so a replayed message is
+/// reported as [`Error::Replay`] instead of as an invalid message.
+///
+/// Messages are keyed by the sender and round they claim and a digest of
+/// their bytes. Each receiving participant needs its own cache, which it
+/// can keep across ceremonies to catch messages replayed from another
+/// session. Times are unix seconds supplied by the caller.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ReplayCache {
+    /// How long a message is remembered
+    pub(crate) ttl: Duration,
+    /// When each message was first seen
+    pub(crate) seen: BTreeMap<(usize, Round, [u8; 32]), u64>,
+}
+
+impl ReplayCache {
+    /// Create a cache that remembers messages for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            seen: BTreeMap::new(),
+        }
+    }
+
+    /// How long a message is remembered
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The number of messages remembered
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Whether no message is remembered
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Record `message` from `sender` for `round` at `now`.
+    ///
+    /// Returns [`Error::Replay`] if the same message was seen within the
+    /// time to live, which does not refresh when it was first seen.
+    pub fn check(
+        &mut self,
+        sender: usize,
+        round: Round,
+        message: &[u8],
+        now: u64,
+    ) -> DkgResult<()> {
+        let key = (sender, round, Self::digest(message));
+        if let Some(first_seen) = self.seen.get(&key)
+            && now.saturating_sub(*first_seen) < self.ttl.as_secs()
+        {
+            return Err(Error::Replay {
+                sender,
+                round,
+                first_seen: *first_seen,
+            });
+        }
+        self.seen.insert(key, now);
+        Ok(())
+    }
+
+    /// Forget the messages that were first seen longer than the time to
+    /// live before `now`
+    pub fn prune(&mut self, now: u64) {
+        let ttl = self.ttl.as_secs();
+        self.seen
+            .retain(|_, first_seen| now.saturating_sub(*first_seen) < ttl);
+    }
+
+    fn digest(message: &[u8]) -> [u8; 32] {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Replay Cache");
+        transcript.append_message(b"message", message);
+        let mut digest = [0u8; 32];
+        transcript.challenge_bytes(b"message digest", &mut digest);
+        digest
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Like [`Participant::receive`] but checks `data` against `cache`
+    /// first so a replayed message fails with [`Error::Replay`].
+    ///
+    /// Every message is recorded, including those that fail validation.
+    pub fn receive_with_replay_cache(
+        &mut self,
+        data: &[u8],
+        cache: &mut ReplayCache,
+        now: u64,
+    ) -> DkgResult<()> {
+        let round = message_round(data)?;
+        let sender = message_sender(data)?;
+        cache.check(sender, round, data, now)?;
+        self.receive(data)
+    }
+}
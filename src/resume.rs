@@ -0,0 +1,49 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Take over the role of a crashed participant from a snapshot it
+    /// exported with [`Participant::export_state`].
+    ///
+    /// The snapshot must be for the ceremony run with `parameters`, so the
+    /// roster and configuration its round 1 signature is bound to are
+    /// checked against them. Send the output of
+    /// [`Participant::regenerate_round_output`] to any peer that missed the
+    /// last round the crashed participant ran.
+    ///
+    /// Without a snapshot the role can only be restarted with a new
+    /// participant for the same id and parameters while no peer has
+    /// accepted a round 1 broadcast from it, see
+    /// [`Participant::accepts_replacement`]. The same holds for a snapshot
+    /// taken before round 1 ran.
+    pub fn resume(state: &[u8], parameters: &Parameters<G>) -> DkgResult<Self> {
+        let participant = Self::import_state(state)?;
+        if participant.signed_context() != parameters.signed_context()? {
+            return Err(Error::Initialization(format!(
+                "Snapshot of participant {} is for a different roster or parameters",
+                participant.ordinal
+            )));
+        }
+        Ok(participant)
+    }
+
+    /// Whether the peer at `ordinal` can be replaced by a participant that
+    /// starts its role over.
+    ///
+    /// A restarted participant deals a new polynomial, so this is only the
+    /// case while no round 1 broadcast from the peer was accepted. Every
+    /// remaining peer must agree before the replacement runs round 1.
+    pub fn accepts_replacement(&self, ordinal: usize) -> bool {
+        ordinal != self.ordinal
+            && self.all_participant_ids.contains_key(&ordinal)
+            && !self.received_round1_data.contains_key(&ordinal)
+            && !self.disqualified_participant_ids.contains(&ordinal)
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Tolerate up to `fault_tolerance` invalid dealers.
+    ///
+    /// A dealer whose round 1 broadcast or round 2 share fails validation is
+    /// disqualified instead of quarantined while fewer than `fault_tolerance`
+    /// peers are disqualified, and round 3 combines the dealers that remain
+    /// as long as there are at least threshold of them. A share that only
+    /// this participant received invalid is shared with the others as
+    /// [`DisputeEvidence`] for [`Participant::accept_dispute`] so everyone
+    /// drops the same dealers. Every participant must use the same value.
+    pub fn set_fault_tolerance(&mut self, fault_tolerance: usize) -> DkgResult<()> {
+        if fault_tolerance > self.limit - self.threshold {
+            return Err(Error::Initialization(format!(
+                "Tolerating {fault_tolerance} invalid dealers leaves fewer than {} of {}",
+                self.threshold, self.limit
+            )));
+        }
+        if self.round > Round::Three {
+            return Err(Error::Round(
+                "The fault tolerance cannot change after round 3".to_string(),
+            ));
+        }
+        self.fault_tolerance = fault_tolerance;
+        Ok(())
+    }
+
+    /// The number of invalid dealers that are disqualified automatically
+    pub fn get_fault_tolerance(&self) -> usize {
+        self.fault_tolerance
+    }
+
+    /// Disqualify the dealer accused by `evidence` from another participant.
+    ///
+    /// The evidence must verify, be for this ceremony and be against the
+    /// round 1 broadcast this participant accepted from the dealer. As the
+    /// share in the evidence is signed with the key of that broadcast, a
+    /// participant cannot accuse a dealer of a share it made up. Fails
+    /// when round 3 already ran or the dealer would be one more than the
+    /// fault tolerance allows, in which case the ceremony has to restart.
+    pub fn accept_dispute(&mut self, evidence: &DisputeEvidence<G>) -> DkgResult<()> {
+        evidence.verify()?;
+        if *evidence.signed_context() != self.signed_context() {
+            return Err(Error::Round(
+                "The dispute evidence is for another ceremony".to_string(),
+            ));
+        }
+        let accused = evidence.accused_ordinal();
+        if self.disqualified_participant_ids.contains(&accused) {
+            return Ok(());
+        }
+        if self.round > Round::Three {
+            return Err(Error::Round(
+                "Dealers cannot be disqualified after round 3".to_string(),
+            ));
+        }
+        let accepted = self.received_round1_data.get(&accused).is_some_and(|data| {
+            data.verifying_share == evidence.round1_data().verifying_share
+                && data.feldman_commitments == evidence.round1_data().feldman_commitments
+        });
+        if !accepted {
+            return Err(Error::Round(format!(
+                "The dispute evidence is against commitments not accepted from {accused}"
+            )));
+        }
+        if !self.tolerate_invalid_dealer(accused) {
+            return Err(Error::Round(format!(
+                "Disqualifying {accused} exceeds the tolerance of {} invalid dealers",
+                self.fault_tolerance
+            )));
+        }
+        Ok(())
+    }
+
+    /// Disqualify the dealer at `ordinal` that sent invalid data if the
+    /// fault tolerance allows it and return whether it was
+    pub(crate) fn tolerate_invalid_dealer(&mut self, ordinal: usize) -> bool {
+        if self.disqualified_participant_ids.len() >= self.fault_tolerance {
+            return false;
+        }
+        self.disqualify_peer(ordinal).is_ok()
+    }
+}
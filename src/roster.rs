@@ -0,0 +1,163 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeMap;
+use vsss_rs::{IdentifierPrimeField, Share};
+
+/// Where each participant of `old` that stays in the roster `new` moves,
+/// as old ordinal index to new ordinal index.
+///
+/// Ordinals are the positions in `new`. Participants missing from `new`
+/// were removed and have no entry.
+pub fn remap_ordinals<F: ScalarHash>(
+    old: &BTreeMap<usize, IdentifierPrimeField<F>>,
+    new: &[IdentifierPrimeField<F>],
+) -> DkgResult<BTreeMap<usize, usize>> {
+    if new
+        .iter()
+        .enumerate()
+        .any(|(i, id)| id.is_zero().into() || new[..i].contains(id))
+    {
+        return Err(Error::Initialization(
+            "Participant ids must be unique and non-zero".to_string(),
+        ));
+    }
+    Ok(old
+        .iter()
+        .filter_map(|(ordinal, id)| {
+            let position = new.iter().position(|new_id| new_id == id)?;
+            Some((*ordinal, position))
+        })
+        .collect())
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Change the roster to `ids` before round 1 runs.
+    ///
+    /// Every ordinal keyed field is moved to the position of its id in
+    /// `ids`, participants no longer in the roster are dropped and shares
+    /// for new participants are interpolated from this participant's
+    /// polynomial so its commitments stay the same. Messages held from
+    /// quarantined peers were signed for the old roster and are dropped.
+    ///
+    /// Returns the mapping of old to new ordinals, see [`remap_ordinals`].
+    pub fn remap_roster(
+        &mut self,
+        ids: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<BTreeMap<usize, usize>> {
+        if self.round != Round::One || !self.received_round1_data.is_empty() {
+            return Err(Error::Round(
+                "The roster can only change before round 1".to_string(),
+            ));
+        }
+        if self.handoff_dealers > 0 {
+            return Err(Error::Initialization(
+                "The roster of a handoff cannot change".to_string(),
+            ));
+        }
+        if ids.len() < self.threshold {
+            return Err(Error::Initialization(format!(
+                "Roster of {} is smaller than the threshold {}",
+                ids.len(),
+                self.threshold
+            )));
+        }
+        let mapping = remap_ordinals(&self.all_participant_ids, ids)?;
+        let ordinal = mapping.get(&self.ordinal).copied().ok_or_else(|| {
+            Error::Initialization(format!(
+                "Participant id '{}' is not in the new roster",
+                self.id
+            ))
+        })?;
+
+        let secret_shares = interpolate_shares(&self.secret_shares, self.threshold, ids)?;
+        let blinder_shares = if self.blinder_shares.is_empty() {
+            BTreeMap::new()
+        } else {
+            interpolate_shares(&self.blinder_shares, self.threshold, ids)?
+        };
+
+        self.ordinal = ordinal;
+        self.limit = ids.len();
+        self.secret_shares = secret_shares;
+        self.blinder_shares = blinder_shares;
+        self.all_participant_ids = ids.iter().copied().enumerate().collect();
+        self.valid_participant_ids = remap_keys(&self.valid_participant_ids, &mapping);
+        self.invalid_participant_ids = remap_keys(&self.invalid_participant_ids, &mapping);
+        self.dispute_evidence = remap_keys(&self.dispute_evidence, &mapping);
+        self.participant_metadata = remap_keys(&self.participant_metadata, &mapping);
+        self.participant_identifiers = remap_keys(&self.participant_identifiers, &mapping);
+        self.previous_verification_shares =
+            remap_keys(&self.previous_verification_shares, &mapping);
+        self.reshare_mapping = remap_keys(&self.reshare_mapping, &mapping);
+        self.disqualified_participant_ids = self
+            .disqualified_participant_ids
+            .iter()
+            .filter_map(|ordinal| mapping.get(ordinal).copied())
+            .collect();
+        self.expected_senders = self
+            .expected_senders
+            .iter()
+            .map(|(round, ordinals)| {
+                (
+                    *round,
+                    ordinals
+                        .iter()
+                        .filter_map(|ordinal| mapping.get(ordinal).copied())
+                        .collect(),
+                )
+            })
+            .collect();
+        self.quarantined_messages = BTreeMap::new();
+        self.share_acks = BTreeMap::new();
+        Ok(mapping)
+    }
+}
+
+fn remap_keys<T: Clone>(
+    values: &BTreeMap<usize, T>,
+    mapping: &BTreeMap<usize, usize>,
+) -> BTreeMap<usize, T> {
+    values
+        .iter()
+        .filter_map(|(ordinal, value)| Some((*mapping.get(ordinal)?, value.clone())))
+        .collect()
+}
+
+/// The share of every id in `ids` by its ordinal, kept from `shares` when
+/// present and interpolated from `threshold` of them otherwise
+fn interpolate_shares<F: ScalarHash>(
+    shares: &BTreeMap<usize, SecretShare<F>>,
+    threshold: usize,
+    ids: &[IdentifierPrimeField<F>],
+) -> DkgResult<BTreeMap<usize, SecretShare<F>>> {
+    let basis = shares.values().take(threshold).collect::<Vec<_>>();
+    let basis_ids = basis
+        .iter()
+        .map(|share| share.identifier)
+        .collect::<Vec<_>>();
+    ids.iter()
+        .enumerate()
+        .map(|(ordinal, id)| {
+            if let Some(share) = shares.values().find(|share| share.identifier == *id) {
+                return Ok((ordinal, *share));
+            }
+            let value = lagrange_coefficients(&basis_ids, id.0)?
+                .iter()
+                .zip(&basis)
+                .fold(F::ZERO, |acc, (lambda, share)| {
+                    acc + *lambda * share.value.0
+                });
+            Ok((
+                ordinal,
+                SecretShare::<F>::with_identifier_and_value(*id, IdentifierPrimeField(value)),
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,148 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::fmt::{self, Display, Formatter};
+
+/// The number of symbols in a [`SasCode`]
+pub const SAS_LENGTH: usize = 7;
+
+/// The emoji and their names, the same table Matrix uses for its
+/// short authentication strings so the symbols are easy to tell apart
+const SAS_SYMBOLS: [(&str, &str); 64] = [
+    ("🐶", "Dog"),
+    ("🐱", "Cat"),
+    ("🦁", "Lion"),
+    ("🐎", "Horse"),
+    ("🦄", "Unicorn"),
+    ("🐷", "Pig"),
+    ("🐘", "Elephant"),
+    ("🐰", "Rabbit"),
+    ("🐼", "Panda"),
+    ("🐓", "Rooster"),
+    ("🐧", "Penguin"),
+    ("🐢", "Turtle"),
+    ("🐟", "Fish"),
+    ("🐙", "Octopus"),
+    ("🦋", "Butterfly"),
+    ("🌷", "Flower"),
+    ("🌳", "Tree"),
+    ("🌵", "Cactus"),
+    ("🍄", "Mushroom"),
+    ("🌏", "Globe"),
+    ("🌙", "Moon"),
+    ("☁️", "Cloud"),
+    ("🔥", "Fire"),
+    ("🍌", "Banana"),
+    ("🍎", "Apple"),
+    ("🍓", "Strawberry"),
+    ("🌽", "Corn"),
+    ("🍕", "Pizza"),
+    ("🎂", "Cake"),
+    ("❤️", "Heart"),
+    ("😀", "Smiley"),
+    ("🤖", "Robot"),
+    ("🎩", "Hat"),
+    ("👓", "Glasses"),
+    ("🔧", "Spanner"),
+    ("🎅", "Santa"),
+    ("👍", "Thumbs Up"),
+    ("☂️", "Umbrella"),
+    ("⌛", "Hourglass"),
+    ("⏰", "Clock"),
+    ("🎁", "Gift"),
+    ("💡", "Light Bulb"),
+    ("📕", "Book"),
+    ("✏️", "Pencil"),
+    ("📎", "Paperclip"),
+    ("✂️", "Scissors"),
+    ("🔒", "Lock"),
+    ("🔑", "Key"),
+    ("🔨", "Hammer"),
+    ("☎️", "Telephone"),
+    ("🏁", "Flag"),
+    ("🚂", "Train"),
+    ("🚲", "Bicycle"),
+    ("✈️", "Aeroplane"),
+    ("🚀", "Rocket"),
+    ("🏆", "Trophy"),
+    ("⚽", "Ball"),
+    ("🎸", "Guitar"),
+    ("🎺", "Trumpet"),
+    ("🔔", "Bell"),
+    ("⚓", "Anchor"),
+    ("🎧", "Headphones"),
+    ("📁", "Folder"),
+    ("📌", "Pin"),
+];
+
+/// A short authentication string for operators to compare out of band.
+///
+/// Every participant that saw the same round 1 data derives the same code.
+/// `{}` displays the words and `{:#}` displays the emoji.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SasCode {
+    indices: [u8; SAS_LENGTH],
+}
+
+impl SasCode {
+    /// Derive the code from a transcript hash
+    pub fn from_transcript_hash(transcript_hash: &[u8]) -> Self {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Short Authentication String");
+        transcript.append_message(b"transcript hash", transcript_hash);
+        let mut bytes = [0u8; 8];
+        transcript.challenge_bytes(b"sas", &mut bytes);
+        // Six bits per symbol from the top of the challenge
+        let bits = u64::from_be_bytes(bytes);
+        let mut indices = [0u8; SAS_LENGTH];
+        for (i, index) in indices.iter_mut().enumerate() {
+            *index = ((bits >> (58 - 6 * i)) & 0x3f) as u8;
+        }
+        Self { indices }
+    }
+
+    /// The index of each symbol in the table
+    pub fn indices(&self) -> [u8; SAS_LENGTH] {
+        self.indices
+    }
+
+    /// The code as emoji
+    pub fn emoji(&self) -> [&'static str; SAS_LENGTH] {
+        self.indices.map(|i| SAS_SYMBOLS[i as usize].0)
+    }
+
+    /// The code as words to read aloud
+    pub fn words(&self) -> [&'static str; SAS_LENGTH] {
+        self.indices.map(|i| SAS_SYMBOLS[i as usize].1)
+    }
+}
+
+impl Display for SasCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.emoji().join(" "))
+        } else {
+            write!(f, "{}", self.words().join(" "))
+        }
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The short authentication string for the round 2 transcript hash.
+    ///
+    /// Available once round 2 has run so operators can confirm they are in
+    /// the same ceremony before round 3 finalizes the key.
+    pub fn sas_code(&self) -> DkgResult<SasCode> {
+        self.received_round2_data
+            .get(&self.ordinal)
+            .map(|data| SasCode::from_transcript_hash(&data.transcript_hash))
+            .ok_or_else(|| {
+                Error::Round("Round 2 has not run or its data was already pruned".to_string())
+            })
+    }
+}
@@ -0,0 +1,239 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use vsss_rs::IdentifierPrimeField;
+
+/// When a [`RefreshSchedule`] considers the shares due for a refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RefreshPolicy {
+    /// Refresh once this much time passed since the last epoch started
+    pub interval: Option<Duration>,
+    /// Refresh as soon as the committee changes
+    pub on_member_change: bool,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            on_member_change: true,
+        }
+    }
+}
+
+/// Why a [`RefreshSchedule`] considers the shares due for a refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum RefreshReason {
+    /// The refresh interval passed
+    Interval,
+    /// The committee changed
+    MemberChange,
+}
+
+/// Proactive refresh bookkeeping for a group key.
+///
+/// Tracks the epoch of the current shares and when they were dealt, tells
+/// when the next refresh is due under a [`RefreshPolicy`] and builds the
+/// parameters and participants for it. Every epoch reshares the key over
+/// the shareholders that stay so the outputs are new shares of the same key.
+/// Times are unix seconds supplied by the caller.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RefreshSchedule<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The epoch of the current shares
+    pub(crate) epoch: u64,
+    /// When the current epoch started
+    pub(crate) started_at: u64,
+    /// When to refresh
+    pub(crate) policy: RefreshPolicy,
+    /// The threshold of the current shares
+    pub(crate) threshold: usize,
+    /// The generator used by the DKG
+    #[serde(with = "group")]
+    pub(crate) message_generator: G,
+    /// The group public key
+    #[serde(bound(
+        serialize = "NonIdentity<G>: Serialize",
+        deserialize = "NonIdentity<G>: Deserialize<'de>"
+    ))]
+    pub(crate) public_key: NonIdentity<G>,
+    /// The participant IDs holding the current shares
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Deserialize<'de>"
+    ))]
+    pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+}
+
+impl<G> RefreshSchedule<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Start a schedule at epoch 0 from the output of the initial DKG
+    pub fn new(output: &DkgOutput<G>, policy: RefreshPolicy, now: u64) -> Self {
+        Self {
+            epoch: 0,
+            started_at: now,
+            policy,
+            threshold: output.threshold,
+            message_generator: output.message_generator,
+            public_key: output.public_key,
+            participant_ids: output.participant_ids.clone(),
+        }
+    }
+
+    /// The epoch of the current shares
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// When the current epoch started
+    pub fn started_at(&self) -> u64 {
+        self.started_at
+    }
+
+    /// When to refresh
+    pub fn policy(&self) -> RefreshPolicy {
+        self.policy
+    }
+
+    /// The group public key every epoch must keep
+    pub fn public_key(&self) -> NonIdentity<G> {
+        self.public_key
+    }
+
+    /// The participant IDs holding the current shares
+    pub fn participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.participant_ids
+    }
+
+    /// When the refresh interval passes, if the policy has one
+    pub fn next_due_at(&self) -> Option<u64> {
+        self.policy
+            .interval
+            .map(|interval| self.started_at.saturating_add(interval.as_secs()))
+    }
+
+    /// Whether a refresh is due at `now` for the committee `members`
+    pub fn due(
+        &self,
+        now: u64,
+        members: &[IdentifierPrimeField<G::Scalar>],
+    ) -> Option<RefreshReason> {
+        if self.policy.on_member_change && self.members_changed(members) {
+            return Some(RefreshReason::MemberChange);
+        }
+        match self.next_due_at() {
+            Some(due_at) if now >= due_at => Some(RefreshReason::Interval),
+            _ => None,
+        }
+    }
+
+    /// The parameters of the next epoch for the committee `members`.
+    ///
+    /// The threshold stays the same and the transcripts are bound to the
+    /// next epoch number. At least threshold of the current shareholders
+    /// must be in `members` to reshare the key.
+    pub fn next_parameters(
+        &self,
+        members: &[IdentifierPrimeField<G::Scalar>],
+    ) -> DkgResult<OwnedParameters<G>> {
+        let threshold = NonZeroUsize::new(self.threshold)
+            .ok_or_else(|| Error::Initialization("The threshold is zero".to_string()))?;
+        let dealers = self.dealers(members);
+        if dealers.len() < self.threshold {
+            return Err(Error::Initialization(format!(
+                "Only {} current shareholders stay, the threshold is {}",
+                dealers.len(),
+                self.threshold
+            )));
+        }
+        Ok(
+            OwnedParameters::new(threshold, Some(self.message_generator), members.to_vec())
+                .with_session_id(&(self.epoch + 1).to_be_bytes()),
+        )
+    }
+
+    /// Create the participant `id` for the epoch described by `parameters`
+    /// from [`Self::next_parameters`].
+    ///
+    /// Current shareholders pass their share and deal it to the next
+    /// committee, newcomers pass `None` and only deal a sharing of zero.
+    pub fn participant(
+        &self,
+        id: IdentifierPrimeField<G::Scalar>,
+        share: Option<&SecretShare<G::Scalar>>,
+        parameters: &OwnedParameters<G>,
+    ) -> DkgResult<Box<dyn AnyParticipant<G>>> {
+        let dealers = self.dealers(parameters.participant_ids());
+        match share {
+            Some(share) => {
+                if !dealers.contains(&share.identifier) {
+                    return Err(Error::Initialization(
+                        "The share is not held by a member of the next committee".to_string(),
+                    ));
+                }
+                Ok(Box::new(SecretParticipant::<G>::with_secret(
+                    id,
+                    share,
+                    &parameters.as_parameters(),
+                    &dealers,
+                )?))
+            }
+            None => {
+                if dealers.contains(&id) {
+                    return Err(Error::Initialization(
+                        "Current shareholders must deal their share".to_string(),
+                    ));
+                }
+                Ok(Box::new(RefreshParticipant::<G>::new_refresh(
+                    id,
+                    None,
+                    &parameters.as_parameters(),
+                )?))
+            }
+        }
+    }
+
+    /// Move to the next epoch once its ceremony completed with `output`
+    pub fn advance(&mut self, output: &DkgOutput<G>, now: u64) -> DkgResult<()> {
+        if output.public_key != self.public_key {
+            return Err(Error::Round(
+                "The refreshed shares are for a different group key".to_string(),
+            ));
+        }
+        self.epoch += 1;
+        self.started_at = now;
+        self.threshold = output.threshold;
+        self.participant_ids = output.participant_ids.clone();
+        Ok(())
+    }
+
+    fn members_changed(&self, members: &[IdentifierPrimeField<G::Scalar>]) -> bool {
+        members.len() != self.participant_ids.len()
+            || members
+                .iter()
+                .any(|id| !self.participant_ids.values().any(|current| current == id))
+    }
+
+    /// The current shareholders that stay in `members`
+    fn dealers(
+        &self,
+        members: &[IdentifierPrimeField<G::Scalar>],
+    ) -> Vec<IdentifierPrimeField<G::Scalar>> {
+        self.participant_ids
+            .values()
+            .filter(|id| members.contains(id))
+            .copied()
+            .collect()
+    }
+}
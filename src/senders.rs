@@ -0,0 +1,61 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use std::collections::BTreeSet;
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Only accept messages for `round` from the peers at `ordinals`.
+    ///
+    /// Messages from any other sender are rejected before they are decoded
+    /// or validated, so the driver's decision which peers to talk to stays
+    /// apart from the protocol checks. Registering again replaces the set.
+    /// The set is not part of a state snapshot.
+    pub fn expect_senders(&mut self, round: Round, ordinals: &[usize]) -> DkgResult<()> {
+        if !matches!(round, Round::One | Round::Two) {
+            return Err(Error::Initialization(format!(
+                "Round {round} has no messages to receive"
+            )));
+        }
+        if let Some(ordinal) = ordinals.iter().find(|ordinal| {
+            **ordinal == self.ordinal || !self.all_participant_ids.contains_key(ordinal)
+        }) {
+            return Err(Error::Initialization(format!(
+                "Ordinal {ordinal} is not a peer of participant {}",
+                self.ordinal
+            )));
+        }
+        self.expected_senders
+            .insert(round, ordinals.iter().copied().collect());
+        Ok(())
+    }
+
+    /// Accept messages for `round` from every peer again
+    pub fn clear_expected_senders(&mut self, round: Round) {
+        self.expected_senders.remove(&round);
+    }
+
+    /// The peers messages for `round` are accepted from, if restricted
+    pub fn get_expected_senders(&self, round: Round) -> Option<&BTreeSet<usize>> {
+        self.expected_senders.get(&round)
+    }
+
+    /// Reject a message for `round` from a sender that was not registered
+    pub(crate) fn check_expected_sender(
+        &self,
+        round: Round,
+        sender_ordinal: usize,
+    ) -> DkgResult<()> {
+        match self.expected_senders.get(&round) {
+            Some(ordinals) if !ordinals.contains(&sender_ordinal) => Err(Error::Round(format!(
+                "Round {round}: Sender {sender_ordinal} is not expected"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
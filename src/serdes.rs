@@ -0,0 +1,277 @@
+//! Serde helpers that render points, scalars and byte strings as hex
+//! for human-readable formats while binary formats keep the compact
+//! encoding used on the wire.
+
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::{Group, PrimeField};
+use elliptic_curve_tools::{group, prime_field};
+use serde::de::Error as DError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use vsss_rs::{IdentifierPrimeField, Share};
+
+use crate::{FeldmanCommitments, SecretShare};
+
+/// A group element encoded the same way as vsss-rs's `ValueGroup`, which
+/// unlike it doesn't need the group to implement [`Default`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[repr(transparent)]
+pub(crate) struct GroupElement<G: Group + GroupEncoding>(#[serde(with = "group")] pub(crate) G);
+
+fn decode_hex_str<E: DError>(s: &str, len: usize) -> Result<Vec<u8>, E> {
+    let bytes = hex::decode(s).map_err(E::custom)?;
+    if bytes.len() != len {
+        return Err(E::invalid_length(bytes.len(), &"the encoded length"));
+    }
+    Ok(bytes)
+}
+
+fn point_from_hex<G: GroupEncoding, E: DError>(s: &str) -> Result<G, E> {
+    let mut repr = G::Repr::default();
+    let bytes = decode_hex_str::<E>(s, repr.as_ref().len())?;
+    repr.as_mut().copy_from_slice(&bytes);
+    Option::from(G::from_bytes(&repr)).ok_or_else(|| E::custom("invalid point"))
+}
+
+fn scalar_from_hex<F: PrimeField, E: DError>(s: &str) -> Result<F, E> {
+    let mut repr = F::Repr::default();
+    let bytes = decode_hex_str::<E>(s, repr.as_ref().len())?;
+    repr.as_mut().copy_from_slice(&bytes);
+    Option::from(F::from_repr(repr)).ok_or_else(|| E::custom("invalid scalar"))
+}
+
+pub(crate) mod hex_group {
+    use super::*;
+
+    pub fn serialize<G, S>(point: &G, s: S) -> Result<S::Ok, S::Error>
+    where
+        G: Group + GroupEncoding,
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            s.serialize_str(&hex::encode(point.to_bytes()))
+        } else {
+            group::serialize(point, s)
+        }
+    }
+
+    pub fn deserialize<'de, G, D>(d: D) -> Result<G, D::Error>
+    where
+        G: Group + GroupEncoding,
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            point_from_hex::<G, D::Error>(&s)
+        } else {
+            group::deserialize(d)
+        }
+    }
+}
+
+pub(crate) mod hex_prime_field {
+    use super::*;
+
+    pub fn serialize<F, S>(scalar: &F, s: S) -> Result<S::Ok, S::Error>
+    where
+        F: PrimeField,
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            s.serialize_str(&hex::encode(scalar.to_repr()))
+        } else {
+            prime_field::serialize(scalar, s)
+        }
+    }
+
+    pub fn deserialize<'de, F, D>(d: D) -> Result<F, D::Error>
+    where
+        F: PrimeField,
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            scalar_from_hex::<F, D::Error>(&s)
+        } else {
+            prime_field::deserialize(d)
+        }
+    }
+}
+
+pub(crate) mod hex_identifier {
+    use super::*;
+
+    pub fn serialize<F, S>(id: &IdentifierPrimeField<F>, s: S) -> Result<S::Ok, S::Error>
+    where
+        F: PrimeField,
+        IdentifierPrimeField<F>: Serialize,
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            s.serialize_str(&hex::encode(id.0.to_repr()))
+        } else {
+            id.serialize(s)
+        }
+    }
+
+    pub fn deserialize<'de, F, D>(d: D) -> Result<IdentifierPrimeField<F>, D::Error>
+    where
+        F: PrimeField,
+        IdentifierPrimeField<F>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            scalar_from_hex::<F, D::Error>(&s).map(IdentifierPrimeField)
+        } else {
+            IdentifierPrimeField::<F>::deserialize(d)
+        }
+    }
+}
+
+pub(crate) mod hex_commitments {
+    use super::*;
+
+    pub fn serialize<G, S>(commitments: &FeldmanCommitments<G>, s: S) -> Result<S::Ok, S::Error>
+    where
+        G: Group + GroupEncoding,
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            s.collect_seq(commitments.iter().map(|c| hex::encode(c.to_bytes())))
+        } else {
+            s.collect_seq(commitments.iter().copied().map(GroupElement))
+        }
+    }
+
+    pub fn deserialize<'de, G, D>(d: D) -> Result<FeldmanCommitments<G>, D::Error>
+    where
+        G: Group + GroupEncoding,
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            Vec::<String>::deserialize(d)?
+                .iter()
+                .map(|s| point_from_hex::<G, D::Error>(s))
+                .collect()
+        } else {
+            Ok(Vec::<GroupElement<G>>::deserialize(d)?
+                .into_iter()
+                .map(|c| c.0)
+                .collect())
+        }
+    }
+}
+
+#[cfg(feature = "participant")]
+pub(crate) mod group_map {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    pub fn serialize<G, S>(map: &BTreeMap<usize, G>, s: S) -> Result<S::Ok, S::Error>
+    where
+        G: Group + GroupEncoding,
+        S: Serializer,
+    {
+        s.collect_map(map.iter().map(|(k, v)| (k, GroupElement(*v))))
+    }
+
+    pub fn deserialize<'de, G, D>(d: D) -> Result<BTreeMap<usize, G>, D::Error>
+    where
+        G: Group + GroupEncoding,
+        D: Deserializer<'de>,
+    {
+        Ok(BTreeMap::<usize, GroupElement<G>>::deserialize(d)?
+            .into_iter()
+            .map(|(k, v)| (k, v.0))
+            .collect())
+    }
+}
+
+#[cfg(feature = "participant")]
+pub(crate) mod group_option {
+    use super::*;
+
+    pub fn serialize<G, S>(value: &Option<G>, s: S) -> Result<S::Ok, S::Error>
+    where
+        G: Group + GroupEncoding,
+        S: Serializer,
+    {
+        value.map(GroupElement).serialize(s)
+    }
+
+    pub fn deserialize<'de, G, D>(d: D) -> Result<Option<G>, D::Error>
+    where
+        G: Group + GroupEncoding,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<GroupElement<G>>::deserialize(d)?.map(|v| v.0))
+    }
+}
+
+pub(crate) mod hex_share {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct HexShare<F: PrimeField> {
+        #[serde(with = "hex_prime_field")]
+        identifier: F,
+        #[serde(with = "hex_prime_field")]
+        value: F,
+    }
+
+    pub fn serialize<F, S>(share: &SecretShare<F>, s: S) -> Result<S::Ok, S::Error>
+    where
+        F: PrimeField,
+        SecretShare<F>: Serialize,
+        S: Serializer,
+    {
+        if s.is_human_readable() {
+            HexShare {
+                identifier: share.identifier.0,
+                value: share.value.0,
+            }
+            .serialize(s)
+        } else {
+            share.serialize(s)
+        }
+    }
+
+    pub fn deserialize<'de, F, D>(d: D) -> Result<SecretShare<F>, D::Error>
+    where
+        F: PrimeField,
+        SecretShare<F>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            let share = HexShare::<F>::deserialize(d)?;
+            Ok(SecretShare::with_identifier_and_value(
+                IdentifierPrimeField(share.identifier),
+                IdentifierPrimeField(share.value),
+            ))
+        } else {
+            SecretShare::<F>::deserialize(d)
+        }
+    }
+}
+
+pub(crate) mod hex_bytes {
+    use super::*;
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(bytes: &Vec<u8>, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.serialize_str(&hex::encode(bytes))
+        } else {
+            bytes.serialize(s)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            hex::decode(s).map_err(DError::custom)
+        } else {
+            Vec::<u8>::deserialize(d)
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::{Field, PrimeField};
+use elliptic_curve_tools::{SumOfProducts, group};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+use vsss_rs::IdentifierPrimeField;
+
+/// A share of a group key as it is kept at rest, tagged with the epoch it
+/// belongs to.
+///
+/// The epoch starts wherever the application likes and moves forward by
+/// one with every refresh or reshare, so an old share can never be
+/// mistaken for the current one. The checksum covers every field and
+/// [`ShareRecord::check`] also checks the share against the verification
+/// data, which catches corruption but not a forgery by someone able to
+/// write the store.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ShareRecord<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The epoch of the share
+    pub(crate) epoch: u64,
+    /// The id of the group key in this epoch
+    pub(crate) key_id: KeyId,
+    /// The shareholder's ordinal index
+    pub(crate) ordinal: usize,
+    /// The shareholder's ID
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) id: IdentifierPrimeField<G::Scalar>,
+    /// The threshold of the key
+    pub(crate) threshold: usize,
+    /// The generator of the key
+    #[serde(with = "group")]
+    pub(crate) message_generator: G,
+    /// The secret share
+    #[serde(bound(
+        serialize = "SecretShare<G::Scalar>: Serialize",
+        deserialize = "SecretShare<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) secret_share: SecretShare<G::Scalar>,
+    /// The group public key
+    #[serde(bound(
+        serialize = "NonIdentity<G>: Serialize",
+        deserialize = "NonIdentity<G>: Deserialize<'de>"
+    ))]
+    pub(crate) public_key: NonIdentity<G>,
+    /// The shareholder IDs by ordinal index
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<G::Scalar>>: Deserialize<'de>"
+    ))]
+    pub(crate) participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    /// The verification share of each shareholder by ordinal index
+    #[serde(bound(
+        serialize = "BTreeMap<usize, NonIdentity<G>>: Serialize",
+        deserialize = "BTreeMap<usize, NonIdentity<G>>: Deserialize<'de>"
+    ))]
+    pub(crate) verification_shares: BTreeMap<usize, NonIdentity<G>>,
+    /// The [`shareholder_digest`] of the shareholder IDs
+    pub(crate) roster_digest: [u8; 32],
+    /// The checksum over all other fields
+    pub(crate) checksum: [u8; 32],
+}
+
+impl<G> Debug for ShareRecord<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShareRecord")
+            .field("epoch", &self.epoch)
+            .field("key_id", &self.key_id)
+            .field("ordinal", &self.ordinal)
+            .field("id", &self.id)
+            .field("threshold", &self.threshold)
+            .field("public_key", &self.public_key)
+            .field("participant_ids", &self.participant_ids)
+            .field("roster_digest", &hex::encode(self.roster_digest))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G> ShareRecord<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Record the share of a completed DKG as the share of `epoch`
+    pub fn from_output(output: &DkgOutput<G>, epoch: u64) -> DkgResult<Self> {
+        let roster_digest = shareholder_digest(&output.participant_ids);
+        let mut record = Self {
+            epoch,
+            key_id: KeyId::compute(&output.public_key.0, &roster_digest),
+            ordinal: output.ordinal,
+            id: output.id,
+            threshold: output.threshold,
+            message_generator: output.message_generator,
+            secret_share: output.secret_share,
+            public_key: output.public_key,
+            participant_ids: output.participant_ids.clone(),
+            verification_shares: output.verification_shares.clone(),
+            roster_digest,
+            checksum: [0u8; 32],
+        };
+        record.checksum = record.compute_checksum();
+        record.check()?;
+        Ok(record)
+    }
+
+    /// The record of the next epoch after a refresh of this share.
+    ///
+    /// `participant` must have refreshed this share with the
+    /// [`ShareRecord::verification_shares`] as the previous verification
+    /// shares, see [`Participant::with_same_roster_share`].
+    pub fn refresh<I>(&self, participant: &Participant<I, G>) -> DkgResult<Self>
+    where
+        I: ParticipantImpl<G> + Default,
+    {
+        let secret_share = participant.get_refreshed_secret_share().ok_or_else(|| {
+            Error::Initialization("The participant has not completed a refresh".to_string())
+        })?;
+        if secret_share.identifier != self.id || participant.get_ordinal() != self.ordinal {
+            return Err(Error::Initialization(
+                "The participant refreshed another share".to_string(),
+            ));
+        }
+        let verification_shares = participant
+            .get_refreshed_verification_shares()
+            .and_then(|shares| {
+                shares
+                    .into_iter()
+                    .map(|(ordinal, share)| NonIdentity::new(share).map(|share| (ordinal, share)))
+                    .collect::<Option<BTreeMap<_, _>>>()
+            })
+            .ok_or_else(|| {
+                Error::Initialization("The refreshed verification shares are invalid".to_string())
+            })?;
+        let mut record = Self {
+            epoch: self.next_epoch()?,
+            secret_share,
+            verification_shares,
+            ..self.clone()
+        };
+        record.checksum = record.compute_checksum();
+        record.check()?;
+        Ok(record)
+    }
+
+    /// The record of the next epoch after this share was reshared into
+    /// `output`, which must be for the same group key
+    pub fn reshare(&self, output: &DkgOutput<G>) -> DkgResult<Self> {
+        if output.public_key != self.public_key {
+            return Err(Error::Initialization(
+                "The reshare produced another group key".to_string(),
+            ));
+        }
+        Self::from_output(output, self.next_epoch()?)
+    }
+
+    /// Check the checksum and that the share, the verification shares, the
+    /// group key, the roster digest and the key id are consistent
+    pub fn check(&self) -> DkgResult<()> {
+        if self.compute_checksum() != self.checksum {
+            return Err(Error::Integrity("The checksum does not match".to_string()));
+        }
+        if shareholder_digest(&self.participant_ids) != self.roster_digest
+            || KeyId::compute(&self.public_key.0, &self.roster_digest) != self.key_id
+        {
+            return Err(Error::Integrity(
+                "The key id does not match the key and roster".to_string(),
+            ));
+        }
+        if self.participant_ids.get(&self.ordinal) != Some(&self.id)
+            || self.secret_share.identifier != self.id
+        {
+            return Err(Error::Integrity(
+                "The share is not held by the recorded shareholder".to_string(),
+            ));
+        }
+        if self
+            .verification_shares
+            .get(&self.ordinal)
+            .map(|share| share.0)
+            != Some(self.message_generator * *self.secret_share.value)
+        {
+            return Err(Error::Integrity(
+                "The share does not match its verification share".to_string(),
+            ));
+        }
+        if self.threshold == 0
+            || self.verification_shares.len() < self.threshold
+            || self
+                .verification_shares
+                .keys()
+                .any(|ordinal| !self.participant_ids.contains_key(ordinal))
+        {
+            return Err(Error::Integrity(
+                "The verification shares do not match the roster".to_string(),
+            ));
+        }
+        let (ids, shares): (Vec<_>, Vec<_>) = self
+            .verification_shares
+            .iter()
+            .take(self.threshold)
+            .map(|(ordinal, share)| (self.participant_ids[ordinal], share.0))
+            .unzip();
+        let terms = lagrange_coefficients(&ids, G::Scalar::ZERO)
+            .map_err(|e| Error::Integrity(e.to_string()))?
+            .into_iter()
+            .zip(shares)
+            .collect::<Vec<_>>();
+        if <G as SumOfProducts>::sum_of_products(&terms) != self.public_key.0 {
+            return Err(Error::Integrity(
+                "The verification shares do not interpolate to the group key".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serialize for storage
+    pub fn to_bytes(&self) -> DkgResult<Vec<u8>> {
+        Ok(postcard::to_stdvec(self)?)
+    }
+
+    /// Deserialize a stored record and [`ShareRecord::check`] it
+    pub fn from_bytes(bytes: &[u8]) -> DkgResult<Self> {
+        let record = postcard::from_bytes::<Self>(bytes)?;
+        record.check()?;
+        Ok(record)
+    }
+
+    /// The epoch of the share
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// The id of the group key in this epoch
+    pub fn key_id(&self) -> KeyId {
+        self.key_id
+    }
+
+    /// The shareholder's ordinal index
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The shareholder's ID
+    pub fn id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.id
+    }
+
+    /// The threshold of the key
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The secret share
+    pub fn secret_share(&self) -> SecretShare<G::Scalar> {
+        self.secret_share
+    }
+
+    /// The group public key
+    pub fn public_key(&self) -> NonIdentity<G> {
+        self.public_key
+    }
+
+    /// The shareholder IDs by ordinal index
+    pub fn participant_ids(&self) -> &BTreeMap<usize, IdentifierPrimeField<G::Scalar>> {
+        &self.participant_ids
+    }
+
+    /// The verification share of each shareholder by ordinal index, the
+    /// previous verification shares of the next refresh
+    pub fn verification_shares(&self) -> &BTreeMap<usize, NonIdentity<G>> {
+        &self.verification_shares
+    }
+
+    /// The [`shareholder_digest`] of the shareholder IDs
+    pub fn roster_digest(&self) -> [u8; 32] {
+        self.roster_digest
+    }
+
+    fn next_epoch(&self) -> DkgResult<u64> {
+        self.epoch
+            .checked_add(1)
+            .ok_or_else(|| Error::Initialization("The epoch is exhausted".to_string()))
+    }
+
+    fn compute_checksum(&self) -> [u8; 32] {
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Share Record");
+        transcript.append_message(b"ciphersuite", &ciphersuite_id::<G>());
+        transcript.append_u64(b"epoch", self.epoch);
+        transcript.append_message(b"key_id", &self.key_id.to_bytes());
+        transcript.append_u64(b"ordinal", self.ordinal as u64);
+        transcript.append_message(b"id", self.id.0.to_repr().as_ref());
+        transcript.append_u64(b"threshold", self.threshold as u64);
+        transcript.append_message(
+            b"message_generator",
+            self.message_generator.to_bytes().as_ref(),
+        );
+        transcript.append_message(
+            b"secret_share",
+            self.secret_share.value.0.to_repr().as_ref(),
+        );
+        transcript.append_message(b"public_key", self.public_key.0.to_bytes().as_ref());
+        transcript.append_u64(b"participants", self.participant_ids.len() as u64);
+        for (ordinal, id) in &self.participant_ids {
+            transcript.append_u64(b"participant_ordinal", *ordinal as u64);
+            transcript.append_message(b"participant_id", id.0.to_repr().as_ref());
+        }
+        transcript.append_u64(
+            b"verification_shares",
+            self.verification_shares.len() as u64,
+        );
+        for (ordinal, share) in &self.verification_shares {
+            transcript.append_u64(b"verification_ordinal", *ordinal as u64);
+            transcript.append_message(b"verification_share", share.0.to_bytes().as_ref());
+        }
+        transcript.append_message(b"roster_digest", &self.roster_digest);
+        let mut checksum = [0u8; 32];
+        transcript.challenge_bytes(b"checksum", &mut checksum);
+        checksum
+    }
+}
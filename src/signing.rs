@@ -0,0 +1,537 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve::zeroize::{DefaultIsZeroes, Zeroize};
+use elliptic_curve::{Field, PrimeField};
+use elliptic_curve_tools::{SumOfProducts, group, prime_field};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+use vsss_rs::IdentifierPrimeField;
+
+/// A secret scalar that is overwritten with zero when zeroized
+#[derive(Copy, Clone)]
+pub(crate) struct SecretScalar<F: PrimeField>(pub(crate) F);
+
+impl<F: PrimeField> Default for SecretScalar<F> {
+    fn default() -> Self {
+        Self(F::ZERO)
+    }
+}
+
+impl<F: PrimeField> DefaultIsZeroes for SecretScalar<F> {}
+
+/// The secret nonces of a signer for a single FROST signing session.
+///
+/// Reusing nonces across messages leaks the secret share, so
+/// [`DkgOutput::sign`] takes them by value. The nonces are zeroized on drop.
+#[derive(Clone)]
+pub struct SigningNonces<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    pub(crate) hiding: SecretScalar<G::Scalar>,
+    pub(crate) binding: SecretScalar<G::Scalar>,
+    pub(crate) commitments: SigningCommitments<G>,
+}
+
+impl<G> Drop for SigningNonces<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl<G> Debug for SigningNonces<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningNonces")
+            .field("commitments", &self.commitments)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G> SigningNonces<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The public commitments to these nonces
+    pub fn commitments(&self) -> SigningCommitments<G> {
+        self.commitments
+    }
+}
+
+/// The commitments to a signer's nonces sent to the coordinator
+/// in round 1 of FROST signing
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SigningCommitments<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The signer's ordinal index
+    pub(crate) ordinal: usize,
+    /// The signer's ID
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<G::Scalar>: Serialize",
+        deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>"
+    ))]
+    pub(crate) id: IdentifierPrimeField<G::Scalar>,
+    /// The commitment to the hiding nonce
+    #[serde(with = "group")]
+    pub(crate) hiding: G,
+    /// The commitment to the binding nonce
+    #[serde(with = "group")]
+    pub(crate) binding: G,
+}
+
+impl<G> SigningCommitments<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The signer's ordinal index
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The signer's ID
+    pub fn id(&self) -> IdentifierPrimeField<G::Scalar> {
+        self.id
+    }
+
+    /// The commitment to the hiding nonce
+    pub fn hiding(&self) -> G {
+        self.hiding
+    }
+
+    /// The commitment to the binding nonce
+    pub fn binding(&self) -> G {
+        self.binding
+    }
+}
+
+/// The message and signer commitments chosen by the coordinator
+/// and sent to every signer in round 2 of FROST signing
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SigningPackage<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The commitments of each signer by ordinal index
+    #[serde(bound(
+        serialize = "SigningCommitments<G>: Serialize",
+        deserialize = "SigningCommitments<G>: Deserialize<'de>"
+    ))]
+    pub(crate) commitments: BTreeMap<usize, SigningCommitments<G>>,
+    /// The message to sign
+    pub(crate) message: Vec<u8>,
+}
+
+impl<G> SigningPackage<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Create a new signing package from the commitments of every signer
+    pub fn new(commitments: &[SigningCommitments<G>], message: &[u8]) -> DkgResult<Self> {
+        let mut signers = BTreeMap::new();
+        for c in commitments {
+            if signers.insert(c.ordinal, *c).is_some() {
+                return Err(Error::Signature(format!(
+                    "Duplicate commitments for signer {}",
+                    c.ordinal
+                )));
+            }
+        }
+        Ok(Self {
+            commitments: signers,
+            message: message.to_vec(),
+        })
+    }
+
+    /// The commitments of each signer by ordinal index
+    pub fn commitments(&self) -> &BTreeMap<usize, SigningCommitments<G>> {
+        &self.commitments
+    }
+
+    /// The message to sign
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+}
+
+/// A signer's share of a threshold signature
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignatureShare<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The signer's ordinal index
+    pub(crate) ordinal: usize,
+    /// The share of the response
+    #[serde(with = "prime_field")]
+    pub(crate) share: G::Scalar,
+}
+
+impl<G> SignatureShare<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The signer's ordinal index
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The share of the response
+    pub fn share(&self) -> G::Scalar {
+        self.share
+    }
+}
+
+/// A Schnorr signature produced by FROST threshold signing
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ThresholdSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The group commitment
+    #[serde(with = "group")]
+    pub(crate) r: G,
+    /// The response
+    #[serde(with = "prime_field")]
+    pub(crate) z: G::Scalar,
+}
+
+impl<G> ThresholdSignature<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The group commitment
+    pub fn r(&self) -> G {
+        self.r
+    }
+
+    /// The response
+    pub fn z(&self) -> G::Scalar {
+        self.z
+    }
+
+    /// Verify the signature over `message` with the group key and
+    /// the generator used by the DKG
+    pub fn verify(&self, public_key: G, generator: G, message: &[u8]) -> DkgResult<()> {
+        let c = challenge(&self.r, &public_key, message);
+        if generator * self.z != self.r + public_key * c {
+            return Err(Error::Signature("Invalid threshold signature".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// The values every signer derives from the signing package
+struct SigningContext<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    binding_factors: BTreeMap<usize, G::Scalar>,
+    group_commitment: G,
+    challenge: G::Scalar,
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Round 1 of FROST signing.
+    ///
+    /// Generates fresh nonces for a single signature. The commitments
+    /// are sent to the coordinator and the nonces kept for [`DkgOutput::sign`].
+    pub fn commit(&self) -> (SigningNonces<G>, SigningCommitments<G>) {
+        let hiding = self.generate_nonce();
+        let binding = self.generate_nonce();
+        let commitments = SigningCommitments {
+            ordinal: self.ordinal,
+            id: self.id,
+            hiding: self.message_generator * hiding,
+            binding: self.message_generator * binding,
+        };
+        (
+            SigningNonces {
+                hiding: SecretScalar(hiding),
+                binding: SecretScalar(binding),
+                commitments,
+            },
+            commitments,
+        )
+    }
+
+    /// Round 2 of FROST signing.
+    ///
+    /// Computes this signer's share of the signature over the message
+    /// in `package` using the nonces from [`DkgOutput::commit`].
+    pub fn sign(
+        &self,
+        package: &SigningPackage<G>,
+        nonces: SigningNonces<G>,
+    ) -> DkgResult<SignatureShare<G>> {
+        let Some(commitments) = package.commitments.get(&self.ordinal) else {
+            return Err(Error::Signature(
+                "Signer is not part of the signing package".to_string(),
+            ));
+        };
+        if *commitments != nonces.commitments {
+            return Err(Error::Signature(
+                "Nonces do not match the commitments in the signing package".to_string(),
+            ));
+        }
+        let context = self.signing_context(package)?;
+        let lambda = lagrange(&self.id, package);
+        let share = nonces.hiding.0
+            + nonces.binding.0 * context.binding_factors[&self.ordinal]
+            + lambda * self.secret_share.value.0 * context.challenge;
+        Ok(SignatureShare {
+            ordinal: self.ordinal,
+            share,
+        })
+    }
+
+    /// Combine the signature shares of every signer in `package`.
+    ///
+    /// Each share is checked against the signer's verification share so
+    /// a misbehaving signer is identified by ordinal in the returned error.
+    pub fn aggregate(
+        &self,
+        package: &SigningPackage<G>,
+        shares: &[SignatureShare<G>],
+    ) -> DkgResult<ThresholdSignature<G>> {
+        let context = self.signing_context(package)?;
+        let shares = shares
+            .iter()
+            .map(|share| (share.ordinal, share.share))
+            .collect::<BTreeMap<_, _>>();
+        if shares.len() != package.commitments.len()
+            || !package.commitments.keys().all(|o| shares.contains_key(o))
+        {
+            return Err(Error::Signature(
+                "Signature shares do not match the signers in the signing package".to_string(),
+            ));
+        }
+
+        let mut z = G::Scalar::ZERO;
+        for (ordinal, commitments) in &package.commitments {
+            let share = shares[ordinal];
+            let verification_share = self.verification_shares[ordinal].0;
+            let lambda = lagrange(&commitments.id, package);
+            let expected = commitments.hiding
+                + commitments.binding * context.binding_factors[ordinal]
+                + verification_share * (lambda * context.challenge);
+            if self.message_generator * share != expected {
+                return Err(Error::Signature(format!(
+                    "Signature share from {ordinal} is invalid"
+                )));
+            }
+            z += share;
+        }
+
+        let signature = ThresholdSignature {
+            r: context.group_commitment,
+            z,
+        };
+        signature.verify(self.public_key.0, self.message_generator, &package.message)?;
+        Ok(signature)
+    }
+
+    /// Check a signature from [`prove_liveness`] over `canary` with this
+    /// output's group key
+    pub fn verify_liveness(
+        &self,
+        canary: &[u8],
+        signature: &ThresholdSignature<G>,
+    ) -> DkgResult<()> {
+        signature
+            .verify(
+                self.public_key.0,
+                self.message_generator,
+                &liveness_message(canary),
+            )
+            .map_err(|_| {
+                Error::Signature(format!(
+                    "Liveness signature does not verify for participant {}",
+                    self.ordinal
+                ))
+            })
+    }
+
+    fn generate_nonce(&self) -> G::Scalar {
+        let mut rng = rand::rng();
+        let random = G::Scalar::random(&mut rng);
+        let mut input = random.to_repr().as_ref().to_vec();
+        input.extend_from_slice(self.secret_share.value.0.to_repr().as_ref());
+        G::Scalar::hash_to_scalar_with_dst(&input, &signing_dst::<G>(b"nonce"))
+    }
+
+    fn signing_context(&self, package: &SigningPackage<G>) -> DkgResult<SigningContext<G>> {
+        if package.commitments.len() < self.threshold {
+            return Err(Error::Signature(format!(
+                "Signing package has {} signers but the threshold is {}",
+                package.commitments.len(),
+                self.threshold
+            )));
+        }
+        for (ordinal, c) in &package.commitments {
+            if c.ordinal != *ordinal
+                || self.participant_ids.get(ordinal) != Some(&c.id)
+                || !self.verification_shares.contains_key(ordinal)
+            {
+                return Err(Error::Signature(format!(
+                    "Signer {ordinal} does not hold a share of the group key"
+                )));
+            }
+            if bool::from(c.hiding.is_identity() | c.binding.is_identity()) {
+                return Err(Error::Signature(format!(
+                    "Signer {ordinal} committed to the identity element"
+                )));
+            }
+        }
+
+        let mut prefix = self.public_key.0.to_bytes().as_ref().to_vec();
+        prefix.extend_from_slice(&(package.message.len() as u64).to_be_bytes());
+        prefix.extend_from_slice(&package.message);
+        for c in package.commitments.values() {
+            prefix.extend_from_slice(c.id.0.to_repr().as_ref());
+            prefix.extend_from_slice(c.hiding.to_bytes().as_ref());
+            prefix.extend_from_slice(c.binding.to_bytes().as_ref());
+        }
+        let dst = signing_dst::<G>(b"rho");
+        let binding_factors = package
+            .commitments
+            .iter()
+            .map(|(ordinal, c)| {
+                let mut input = prefix.clone();
+                input.extend_from_slice(c.id.0.to_repr().as_ref());
+                (*ordinal, G::Scalar::hash_to_scalar_with_dst(&input, &dst))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let group_commitment = package
+            .commitments
+            .iter()
+            .fold(G::identity(), |acc, (ordinal, c)| {
+                acc + c.hiding + c.binding * binding_factors[ordinal]
+            });
+        let challenge = challenge(&group_commitment, &self.public_key.0, &package.message);
+        Ok(SigningContext {
+            binding_factors,
+            group_commitment,
+            challenge,
+        })
+    }
+}
+
+/// The message signed by [`prove_liveness`] for `canary`.
+///
+/// The canary is prefixed so a liveness signature is never a signature
+/// over an application message.
+pub fn liveness_message(canary: &[u8]) -> Vec<u8> {
+    const PREFIX: &[u8] = b"FROST-DKG-V1-LIVENESS";
+    let mut message = Vec::with_capacity(PREFIX.len() + canary.len());
+    message.extend_from_slice(PREFIX);
+    message.extend_from_slice(canary);
+    message
+}
+
+/// Confirm a new key works end to end before declaring it live.
+///
+/// The first threshold `outputs` sign [`liveness_message`] of `canary`
+/// with FROST and every output checks the signature against its own copy
+/// of the group key. Returns the signature so it can be kept as a record.
+pub fn prove_liveness<G>(
+    outputs: &[DkgOutput<G>],
+    canary: &[u8],
+) -> DkgResult<ThresholdSignature<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let Some(first) = outputs.first() else {
+        return Err(Error::Signature(
+            "No outputs to prove liveness with".to_string(),
+        ));
+    };
+    if outputs.len() < first.threshold {
+        return Err(Error::Signature(format!(
+            "Liveness needs {} signers but only {} outputs were given",
+            first.threshold,
+            outputs.len()
+        )));
+    }
+    let message = liveness_message(canary);
+    let signers = &outputs[..first.threshold];
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|o| o.commit()).unzip();
+    let package = SigningPackage::new(&commitments, &message)?;
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(output, nonces)| output.sign(&package, nonces))
+        .collect::<DkgResult<Vec<_>>>()?;
+    let signature = first.aggregate(&package, &shares)?;
+    for output in outputs {
+        output.verify_liveness(canary, &signature)?;
+    }
+    Ok(signature)
+}
+
+fn signing_dst<G>(purpose: &[u8]) -> Vec<u8>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut dst = group_dst::<G>();
+    dst.extend_from_slice(b"-FROST-");
+    dst.extend_from_slice(purpose);
+    dst
+}
+
+fn challenge<G>(r: &G, public_key: &G, message: &[u8]) -> G::Scalar
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut input = r.to_bytes().as_ref().to_vec();
+    input.extend_from_slice(public_key.to_bytes().as_ref());
+    input.extend_from_slice(message);
+    G::Scalar::hash_to_scalar_with_dst(&input, &signing_dst::<G>(b"chal"))
+}
+
+fn lagrange<G>(id: &IdentifierPrimeField<G::Scalar>, package: &SigningPackage<G>) -> G::Scalar
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut num = G::Scalar::ONE;
+    let mut den = G::Scalar::ONE;
+    for c in package.commitments.values() {
+        if c.id == *id {
+            continue;
+        }
+        num *= c.id.0;
+        den *= c.id.0 - id.0;
+    }
+    num * den.invert().expect("Signer ids are distinct")
+}
@@ -0,0 +1,74 @@
+use super::*;
+use curve25519_dalek::EdwardsPoint;
+use elliptic_curve::group::{Group, GroupEncoding};
+use serde::{Deserialize, Serialize};
+
+/// The ciphersuite name used in FROST key packages for ed25519
+const FROST_ED25519_CIPHERSUITE: &str = "FROST-ED25519-SHA512-v1";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct KeyPackage {
+    header: KeyPackageHeader,
+    identifier: String,
+    signing_share: String,
+    verifying_share: String,
+    verifying_key: String,
+    min_signers: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct KeyPackageHeader {
+    version: u8,
+    ciphersuite: String,
+}
+
+impl DkgOutput<EdwardsPoint> {
+    /// The group public key as a base58 Solana address
+    pub fn solana_public_key(&self) -> String {
+        DisplayPoint(self.public_key.0).to_base58()
+    }
+
+    /// The public key of this share as a base58 Solana address
+    pub fn solana_share_public_key(&self) -> DkgResult<String> {
+        Ok(DisplayPoint(self.share_public_key()?).to_base58())
+    }
+
+    /// Export this share as a FROST ed25519 key package in JSON.
+    ///
+    /// This is the format read by the `frost-ed25519` signer tooling used
+    /// for threshold signing of Solana transactions. A share is a scalar and
+    /// not an ed25519 seed so it cannot be written as a Solana keypair file.
+    pub fn solana_key_package(&self) -> DkgResult<String> {
+        if self.message_generator != EdwardsPoint::generator() {
+            return Err(Error::Encoding(
+                "Key packages need the ed25519 base point as the message generator".to_string(),
+            ));
+        }
+        let min_signers = u16::try_from(self.threshold).map_err(|_| {
+            Error::Encoding(format!(
+                "Threshold {} is too large for a key package",
+                self.threshold
+            ))
+        })?;
+        let package = KeyPackage {
+            header: KeyPackageHeader {
+                version: 0,
+                ciphersuite: FROST_ED25519_CIPHERSUITE.to_string(),
+            },
+            identifier: hex::encode(self.id.0.to_bytes()),
+            signing_share: hex::encode(self.secret_share.value.0.to_bytes()),
+            verifying_share: hex::encode(self.share_public_key()?.to_bytes()),
+            verifying_key: hex::encode(self.public_key.0.to_bytes()),
+            min_signers,
+        };
+        Ok(serde_json::to_string(&package)?)
+    }
+
+    fn share_public_key(&self) -> DkgResult<EdwardsPoint> {
+        self.verification_share(self.ordinal)
+            .map(|share| share.0)
+            .ok_or_else(|| {
+                Error::Encoding("Missing the verification share of this shareholder".to_string())
+            })
+    }
+}
@@ -0,0 +1,500 @@
+use super::*;
+use crate::locked::SecretStorage;
+use crate::serdes::{GroupElement, hex_bytes, hex_commitments, hex_identifier, hex_share};
+use crate::telemetry::RoundTimer;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::{SumOfProducts, group, prime_field};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use vsss_rs::{IdentifierPrimeField, ValuePrimeField};
+
+/// The version of the state snapshots written by [`Participant::export_state`]
+pub const STATE_VERSION: u16 = 16;
+
+/// Round 1 data as stored in snapshots.
+///
+/// Accepted round 1 data always echoes the participant's own threshold and
+/// limit so those are restored from the snapshot instead, keeping the layout
+/// of snapshots written before the echo was added.
+#[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "IdentifierPrimeField<G::Scalar>: Serialize, Signature<G>: Serialize",
+    deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>, Signature<G>: Deserialize<'de>"
+))]
+struct StoredRound1Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    sender_ordinal: usize,
+    sender_id: IdentifierPrimeField<G::Scalar>,
+    sender_type: ParticipantType,
+    #[serde(with = "hex_commitments")]
+    feldman_commitments: FeldmanCommitments<G>,
+    #[serde(with = "group")]
+    verifying_share: G,
+    signature: Signature<G>,
+}
+
+impl<G> StoredRound1Data<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn store(data: &Round1Data<G>) -> Self {
+        Self {
+            sender_ordinal: data.sender_ordinal,
+            sender_id: data.sender_id,
+            sender_type: data.sender_type,
+            feldman_commitments: data.feldman_commitments.clone(),
+            verifying_share: data.verifying_share,
+            signature: data.signature,
+        }
+    }
+
+    fn restore(self, threshold: usize, limit: usize) -> Round1Data<G> {
+        Round1Data {
+            sender_ordinal: self.sender_ordinal,
+            sender_id: self.sender_id,
+            sender_type: self.sender_type,
+            threshold,
+            limit,
+            feldman_commitments: self.feldman_commitments,
+            verifying_share: self.verifying_share,
+            signature: self.signature,
+        }
+    }
+}
+
+/// Round 2 data as stored in snapshots.
+///
+/// The signatures are stored after the version 1 layout so snapshots
+/// written before round 2 data was signed still restore.
+#[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "IdentifierPrimeField<F>: Serialize, SecretShare<F>: Serialize",
+    deserialize = "IdentifierPrimeField<F>: Deserialize<'de>, SecretShare<F>: Deserialize<'de>"
+))]
+struct StoredRound2Data<F: ScalarHash> {
+    sender_ordinal: usize,
+    #[serde(with = "hex_identifier")]
+    sender_id: IdentifierPrimeField<F>,
+    sender_type: ParticipantType,
+    #[serde(with = "hex_share")]
+    secret_share: SecretShare<F>,
+    #[serde(with = "hex_bytes")]
+    transcript_hash: Vec<u8>,
+}
+
+impl<F: ScalarHash> StoredRound2Data<F> {
+    fn store(data: &Round2Data<F>) -> Self {
+        Self {
+            sender_ordinal: data.sender_ordinal,
+            sender_id: data.sender_id,
+            sender_type: data.sender_type,
+            secret_share: data.secret_share,
+            transcript_hash: data.transcript_hash.clone(),
+        }
+    }
+
+    fn restore(self, signature: Option<ScalarSignature<F>>) -> Round2Data<F> {
+        Round2Data {
+            sender_ordinal: self.sender_ordinal,
+            sender_id: self.sender_id,
+            sender_type: self.sender_type,
+            secret_share: self.secret_share,
+            transcript_hash: self.transcript_hash,
+            signature: signature.unwrap_or_default(),
+        }
+    }
+}
+
+/// Dispute evidence as stored in snapshots.
+///
+/// The signed context is rebuilt from the participant on import.
+#[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "IdentifierPrimeField<G::Scalar>: Serialize, StoredRound1Data<G>: Serialize, Round2Data<G::Scalar>: Serialize",
+    deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>, StoredRound1Data<G>: Deserialize<'de>, Round2Data<G::Scalar>: Deserialize<'de>"
+))]
+struct StoredDisputeEvidence<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    accuser_ordinal: usize,
+    accuser_id: IdentifierPrimeField<G::Scalar>,
+    threshold: usize,
+    limit: usize,
+    #[serde(with = "group")]
+    message_generator: G,
+    participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    round1_data: StoredRound1Data<G>,
+    round2_data: Round2Data<G::Scalar>,
+}
+
+impl<G> StoredDisputeEvidence<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn store(evidence: &DisputeEvidence<G>) -> Self {
+        Self {
+            accuser_ordinal: evidence.accuser_ordinal,
+            accuser_id: evidence.accuser_id,
+            threshold: evidence.context.threshold,
+            limit: evidence.context.limit,
+            message_generator: evidence.context.message_generator,
+            participant_ids: evidence.context.participant_ids.clone(),
+            round1_data: StoredRound1Data::store(&evidence.round1_data),
+            round2_data: evidence.round2_data.clone(),
+        }
+    }
+
+    fn restore(self, context: &SignedContext<G>) -> DisputeEvidence<G> {
+        DisputeEvidence {
+            accuser_ordinal: self.accuser_ordinal,
+            accuser_id: self.accuser_id,
+            context: context.clone(),
+            round1_data: self.round1_data.restore(self.threshold, self.limit),
+            round2_data: self.round2_data,
+        }
+    }
+}
+
+/// The layout of version 1 snapshots.
+///
+/// Every later version appends fields after this layout, see
+/// [`Participant::export_state`]. A layout change only ever appends, so
+/// [`Participant::import_state`] reads the fields a snapshot has and uses
+/// the defaults for the ones added after it was written.
+#[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "IdentifierPrimeField<G::Scalar>: Serialize, ValuePrimeField<G::Scalar>: Serialize, SecretShare<G::Scalar>: Serialize, GroupElement<G>: Serialize, StoredRound1Data<G>: Serialize, StoredRound2Data<G::Scalar>: Serialize, StoredDisputeEvidence<G>: Serialize",
+    deserialize = "IdentifierPrimeField<G::Scalar>: Deserialize<'de>, ValuePrimeField<G::Scalar>: Deserialize<'de>, SecretShare<G::Scalar>: Deserialize<'de>, GroupElement<G>: Deserialize<'de>, StoredRound1Data<G>: Deserialize<'de>, StoredRound2Data<G::Scalar>: Deserialize<'de>, StoredDisputeEvidence<G>: Deserialize<'de>"
+))]
+struct ParticipantStateV1<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    participant_type: ParticipantType,
+    ordinal: usize,
+    id: IdentifierPrimeField<G::Scalar>,
+    threshold: usize,
+    limit: usize,
+    round: Round,
+    completed: bool,
+    secret_shares: BTreeMap<usize, SecretShare<G::Scalar>>,
+    feldman_verifiers: Vec<GroupElement<G>>,
+    #[serde(with = "prime_field")]
+    original_secret: G::Scalar,
+    #[serde(with = "group")]
+    verifying_share: G,
+    secret_share: SecretShare<G::Scalar>,
+    #[serde(with = "group")]
+    message_generator: G,
+    public_key: GroupElement<G>,
+    verification_shares: BTreeMap<usize, GroupElement<G>>,
+    powers_of_i: Vec<ValuePrimeField<G::Scalar>>,
+    received_round1_data: BTreeMap<usize, StoredRound1Data<G>>,
+    received_round2_data: BTreeMap<usize, StoredRound2Data<G::Scalar>>,
+    all_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    valid_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    invalid_participant_ids: BTreeMap<usize, IdentifierPrimeField<G::Scalar>>,
+    dispute_evidence: BTreeMap<usize, StoredDisputeEvidence<G>>,
+    transcript_label: Vec<u8>,
+    transcript_hash_size: TranscriptHashSize,
+}
+
+/// Append a field of the current layout to a snapshot
+fn append<T: Serialize + ?Sized>(bytes: Vec<u8>, value: &T) -> DkgResult<Vec<u8>> {
+    Ok(postcard::to_extend(value, bytes)?)
+}
+
+/// Read the next field of a snapshot written with `version`, or the
+/// default if the field was added in a later version than `since`
+fn take_since<'a, T>(version: u16, since: u16, bytes: &mut &'a [u8]) -> DkgResult<T>
+where
+    T: Deserialize<'a> + Default,
+{
+    if version < since {
+        return Ok(T::default());
+    }
+    let (value, rest) = postcard::take_from_bytes(bytes)?;
+    *bytes = rest;
+    Ok(value)
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// Serialize the full state of this participant so the protocol can
+    /// be resumed later with [`Participant::import_state`].
+    ///
+    /// The snapshot contains the secret share and the original secret
+    /// and must be stored as securely as the key itself.
+    pub fn export_state(&self) -> DkgResult<Vec<u8>> {
+        let state = ParticipantStateV1 {
+            participant_type: self.participant_impl.get_type(),
+            ordinal: self.ordinal,
+            id: self.id,
+            threshold: self.threshold,
+            limit: self.limit,
+            round: self.round,
+            completed: self.completed,
+            secret_shares: self.secret_shares.clone(),
+            feldman_verifiers: self
+                .feldman_verifiers
+                .iter()
+                .copied()
+                .map(GroupElement)
+                .collect(),
+            original_secret: self.original_secret,
+            verifying_share: self.verifying_share.0,
+            secret_share: *self.secret_share,
+            message_generator: self.message_generator,
+            public_key: GroupElement(self.public_key),
+            verification_shares: self
+                .verification_shares
+                .iter()
+                .map(|(ordinal, share)| (*ordinal, GroupElement(*share)))
+                .collect(),
+            powers_of_i: self
+                .powers_of_i
+                .iter()
+                .map(|power| IdentifierPrimeField(*power))
+                .collect(),
+            received_round1_data: self
+                .received_round1_data
+                .iter()
+                .map(|(ordinal, data)| (*ordinal, StoredRound1Data::store(data)))
+                .collect(),
+            received_round2_data: self
+                .received_round2_data
+                .iter()
+                .map(|(ordinal, data)| (*ordinal, StoredRound2Data::store(data)))
+                .collect(),
+            all_participant_ids: self.all_participant_ids.clone(),
+            valid_participant_ids: self.valid_participant_ids.clone(),
+            invalid_participant_ids: self.invalid_participant_ids.clone(),
+            dispute_evidence: self
+                .dispute_evidence
+                .iter()
+                .map(|(ordinal, evidence)| (*ordinal, StoredDisputeEvidence::store(evidence)))
+                .collect(),
+            transcript_label: self.transcript_label.clone(),
+            transcript_hash_size: self.transcript_hash_size,
+        };
+        let previous_verification_shares = self
+            .previous_verification_shares
+            .iter()
+            .map(|(ordinal, share)| (*ordinal, GroupElement(*share)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut bytes = STATE_VERSION.to_be_bytes().to_vec();
+        bytes = append(bytes, &state)?;
+        // Version 2: the cached final transcript hash kept by `gc`
+        bytes = append(bytes, &self.final_transcript_hash)?;
+        // Version 3: the roster metadata
+        bytes = append(bytes, &self.participant_metadata)?;
+        // Version 4: the participant identifiers
+        bytes = append(bytes, &self.participant_identifiers)?;
+        // Version 5: the verification shares a refresh starts from
+        bytes = append(bytes, &previous_verification_shares)?;
+        // Version 6: the blinder polynomial for pedersen commitments
+        bytes = append(bytes, &self.blinder_generator.map(GroupElement))?;
+        bytes = append(
+            bytes,
+            &self
+                .pedersen_commitments
+                .iter()
+                .copied()
+                .map(GroupElement)
+                .collect::<Vec<_>>(),
+        )?;
+        bytes = append(bytes, &self.blinder_shares)?;
+        // Version 7: the number of dealers handing off to a successor committee
+        bytes = append(bytes, &self.handoff_dealers)?;
+        // Version 8: the messages held from quarantined peers and the
+        // disqualified peers
+        bytes = append(bytes, &self.quarantined_messages)?;
+        bytes = append(bytes, &self.disqualified_participant_ids)?;
+        // Version 9: the signatures of the round 2 data
+        bytes = append(
+            bytes,
+            &self
+                .received_round2_data
+                .iter()
+                .map(|(ordinal, data)| (*ordinal, data.signature))
+                .collect::<BTreeMap<_, _>>(),
+        )?;
+        // Version 10: the acknowledgments of the shares this participant dealt
+        bytes = append(bytes, &self.share_acks)?;
+        // Version 11: the old share id of each participant resharing under a new id
+        bytes = append(bytes, &self.reshare_mapping)?;
+        // Version 12: the application data bound into the transcripts
+        bytes = append(bytes, &self.transcript_extensions)?;
+        // Version 13: the policy for missing round 2 shares
+        bytes = append(bytes, &self.missing_share_policy)?;
+        // Version 14: the number of invalid dealers disqualified automatically
+        bytes = append(bytes, &self.fault_tolerance)?;
+        // Version 15: the round 1 commitments
+        bytes = append(bytes, &self.commit_reveal)?;
+        bytes = append(bytes, &self.round1_commitments)?;
+        bytes = append(
+            bytes,
+            &self.committed_round1.as_ref().map(StoredRound1Data::store),
+        )?;
+        // Version 16: the openings of the pedersen commitments
+        bytes = append(
+            bytes,
+            &self
+                .round1_openings
+                .iter()
+                .map(|(ordinal, data)| (*ordinal, StoredRound1Data::store(data)))
+                .collect::<BTreeMap<_, _>>(),
+        )?;
+        Ok(bytes)
+    }
+
+    /// Restore a participant from a snapshot created by [`Participant::export_state`].
+    ///
+    /// Snapshots written by older versions of this crate are upgraded to
+    /// the current layout. Returns [`Error::UnsupportedStateVersion`] if the
+    /// snapshot was written with an unknown version.
+    pub fn import_state(bytes: &[u8]) -> DkgResult<Self> {
+        let Some((version, payload)) = bytes.split_first_chunk::<2>() else {
+            return Err(Error::Initialization(
+                "State snapshot is missing its version".to_string(),
+            ));
+        };
+        let version = u16::from_be_bytes(*version);
+        if version == 0 || version > STATE_VERSION {
+            return Err(Error::UnsupportedStateVersion(version));
+        }
+        let (state, mut payload) = postcard::take_from_bytes::<ParticipantStateV1<G>>(payload)?;
+        let final_transcript_hash = take_since(version, 2, &mut payload)?;
+        let participant_metadata = take_since(version, 3, &mut payload)?;
+        let participant_identifiers = take_since(version, 4, &mut payload)?;
+        let previous_verification_shares: BTreeMap<usize, GroupElement<G>> =
+            take_since(version, 5, &mut payload)?;
+        let blinder_generator: Option<GroupElement<G>> = take_since(version, 6, &mut payload)?;
+        let pedersen_commitments: Vec<GroupElement<G>> = take_since(version, 6, &mut payload)?;
+        let blinder_shares = take_since(version, 6, &mut payload)?;
+        let handoff_dealers = take_since(version, 7, &mut payload)?;
+        let quarantined_messages = take_since(version, 8, &mut payload)?;
+        let disqualified_participant_ids = take_since(version, 8, &mut payload)?;
+        let mut round2_signatures: BTreeMap<usize, ScalarSignature<G::Scalar>> =
+            take_since(version, 9, &mut payload)?;
+        let share_acks = take_since(version, 10, &mut payload)?;
+        let reshare_mapping = take_since(version, 11, &mut payload)?;
+        let transcript_extensions = take_since(version, 12, &mut payload)?;
+        let missing_share_policy = take_since(version, 13, &mut payload)?;
+        let fault_tolerance = take_since(version, 14, &mut payload)?;
+        let commit_reveal = take_since(version, 15, &mut payload)?;
+        let round1_commitments = take_since(version, 15, &mut payload)?;
+        let committed_round1: Option<StoredRound1Data<G>> = take_since(version, 15, &mut payload)?;
+        let round1_openings: BTreeMap<usize, StoredRound1Data<G>> =
+            take_since(version, 16, &mut payload)?;
+        if !payload.is_empty() {
+            return Err(Error::Initialization(
+                "State snapshot has trailing data".to_string(),
+            ));
+        }
+
+        let participant_impl = I::default();
+        if !matches!(
+            (participant_impl.get_type(), state.participant_type),
+            (ParticipantType::Secret, ParticipantType::Secret)
+                | (ParticipantType::Refresh, ParticipantType::Refresh)
+        ) {
+            return Err(Error::Initialization(format!(
+                "State snapshot is for a {:?} participant",
+                state.participant_type
+            )));
+        }
+        let verifying_share = NonIdentity::new(state.verifying_share).ok_or_else(|| {
+            Error::Initialization("Verifying share cannot be the identity".to_string())
+        })?;
+
+        let mut participant = Self {
+            ordinal: state.ordinal,
+            id: state.id,
+            threshold: state.threshold,
+            limit: state.limit,
+            round: state.round,
+            completed: state.completed,
+            secret_shares: state.secret_shares,
+            feldman_verifiers: state.feldman_verifiers.into_iter().map(|c| c.0).collect(),
+            original_secret: state.original_secret,
+            verifying_share,
+            secret_share: SecretStorage::new(state.secret_share),
+            message_generator: state.message_generator,
+            public_key: state.public_key.0,
+            verification_shares: state
+                .verification_shares
+                .into_iter()
+                .map(|(ordinal, share)| (ordinal, share.0))
+                .collect(),
+            powers_of_i: state.powers_of_i.into_iter().map(|power| power.0).collect(),
+            received_round1_data: state
+                .received_round1_data
+                .into_iter()
+                .map(|(ordinal, data)| (ordinal, data.restore(state.threshold, state.limit)))
+                .collect(),
+            received_round2_data: state
+                .received_round2_data
+                .into_iter()
+                .map(|(ordinal, data)| (ordinal, data.restore(round2_signatures.remove(&ordinal))))
+                .collect(),
+            round1_openings: round1_openings
+                .into_iter()
+                .map(|(ordinal, data)| (ordinal, data.restore(state.threshold, state.limit)))
+                .collect(),
+            all_participant_ids: state.all_participant_ids,
+            valid_participant_ids: state.valid_participant_ids,
+            invalid_participant_ids: state.invalid_participant_ids,
+            dispute_evidence: BTreeMap::new(),
+            transcript_label: state.transcript_label,
+            transcript_hash_size: state.transcript_hash_size,
+            final_transcript_hash,
+            participant_metadata,
+            participant_identifiers,
+            previous_verification_shares: previous_verification_shares
+                .into_iter()
+                .map(|(ordinal, share)| (ordinal, share.0))
+                .collect(),
+            blinder_generator: blinder_generator.map(|blinder| blinder.0),
+            pedersen_commitments: pedersen_commitments.into_iter().map(|c| c.0).collect(),
+            blinder_shares,
+            handoff_dealers,
+            reshare_mapping,
+            transcript_extensions,
+            quarantined_messages,
+            disqualified_participant_ids,
+            share_acks,
+            missing_share_policy,
+            fault_tolerance,
+            commit_reveal,
+            round1_commitments,
+            committed_round1: committed_round1
+                .map(|data| data.restore(state.threshold, state.limit)),
+            expected_senders: BTreeMap::new(),
+            policy: None,
+            round_timer: RoundTimer::default(),
+            participant_impl,
+        };
+        let context = participant.signed_context();
+        participant.dispute_evidence = state
+            .dispute_evidence
+            .into_iter()
+            .map(|(ordinal, evidence)| (ordinal, evidence.restore(&context)))
+            .collect();
+        Ok(participant)
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use futures::{Sink, Stream};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A [`Stream`] of the messages a participant needs to send for a round
+#[derive(Debug)]
+pub struct OutboundStream<F: ScalarHash> {
+    outputs: std::vec::IntoIter<ParticipantRoundOutput<F>>,
+}
+
+impl<G> From<&RoundOutputGenerator<G>> for OutboundStream<G::Scalar>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    fn from(generator: &RoundOutputGenerator<G>) -> Self {
+        Self {
+            outputs: generator.iter().collect::<Vec<_>>().into_iter(),
+        }
+    }
+}
+
+// The iterator is never pinned structurally
+impl<F: ScalarHash> Unpin for OutboundStream<F> {}
+
+impl<F: ScalarHash> Stream for OutboundStream<F> {
+    type Item = ParticipantRoundOutput<F>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().outputs.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.outputs.size_hint()
+    }
+}
+
+/// A [`Sink`] that delivers received bytes to a participant
+pub struct ParticipantSink<'a, G, P>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+{
+    participant: &'a mut P,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G, P> std::fmt::Debug for ParticipantSink<'_, G, P>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParticipantSink")
+            .field("participant", &self.participant)
+            .finish()
+    }
+}
+
+impl<'a, G, P> ParticipantSink<'a, G, P>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+{
+    /// Create a new sink for the participant
+    pub fn new(participant: &'a mut P) -> Self {
+        Self {
+            participant,
+            _group: PhantomData,
+        }
+    }
+
+    /// Run the next round of the participant and stream its output
+    pub fn run(&mut self) -> DkgResult<OutboundStream<G::Scalar>> {
+        let generator = self.participant.run()?;
+        Ok(OutboundStream::from(&generator))
+    }
+}
+
+impl<G, P> Sink<Vec<u8>> for ParticipantSink<'_, G, P>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+    P: AnyParticipant<G> + ?Sized,
+{
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<DkgResult<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Vec<u8>) -> DkgResult<()> {
+        self.get_mut().participant.receive(&item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<DkgResult<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<DkgResult<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+use elliptic_curve::Field;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::{SumOfProducts, group, prime_field};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A proof that a shareholder derived the same application subkey as everyone else.
+///
+/// The proof commits to the derived group key and proves knowledge of the
+/// derived secret share for the derived verification share, so a shareholder
+/// that used a different context or tweak fails verification.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SubkeyProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder
+    pub(crate) ordinal: usize,
+    /// The derived group key
+    #[serde(with = "group")]
+    pub(crate) public_key: G,
+    /// The schnorr commitment
+    #[serde(with = "group")]
+    pub(crate) r: G,
+    /// The schnorr response
+    #[serde(with = "prime_field")]
+    pub(crate) s: G::Scalar,
+}
+
+impl<G> SubkeyProof<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The ordinal index of the shareholder that created the proof
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The derived group key claimed by the shareholder
+    pub fn public_key(&self) -> G {
+        self.public_key
+    }
+}
+
+impl<G> DkgOutput<G>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// The tweak added to the group secret to derive the subkey for `context`
+    pub fn subkey_tweak(&self, context: &[u8]) -> G::Scalar {
+        let mut input = self.public_key.0.to_bytes().as_ref().to_vec();
+        input.extend_from_slice(&(context.len() as u64).to_be_bytes());
+        input.extend_from_slice(context);
+        G::Scalar::hash_to_scalar_with_dst(&input, &subkey_dst::<G>(b"tweak"))
+    }
+
+    /// Derive the application specific subkey for `context`.
+    ///
+    /// Every shareholder adds the same tweak to its secret share, so the
+    /// result is a sharing of the tweaked group secret with the same
+    /// threshold. The derived output can be used for signing like the original.
+    /// The dealer contributions are carried over from the parent key and don't
+    /// include the tweak.
+    pub fn derive_subkey(&self, context: &[u8]) -> DkgResult<Self> {
+        let tweak = self.subkey_tweak(context);
+        let offset = self.message_generator * tweak;
+        let public_key = NonIdentity::new(self.public_key.0 + offset)
+            .ok_or_else(|| Error::Signature("Derived group key is the identity".to_string()))?;
+        let verification_shares = self
+            .verification_shares
+            .iter()
+            .map(|(ordinal, share)| NonIdentity::new(share.0 + offset).map(|s| (*ordinal, s)))
+            .collect::<Option<BTreeMap<_, _>>>()
+            .ok_or_else(|| {
+                Error::Signature("Derived verification share is the identity".to_string())
+            })?;
+        let mut secret_share = self.secret_share;
+        secret_share.value.0 += tweak;
+
+        let mut transcript = merlin::Transcript::new(b"Frost DKG - Subkey");
+        transcript.append_message(b"parent transcript", &self.transcript_hash);
+        transcript.append_message(b"context", context);
+        let mut transcript_hash = vec![0u8; self.transcript_hash.len()];
+        transcript.challenge_bytes(b"subkey transcript", &mut transcript_hash);
+
+        Ok(Self {
+            ordinal: self.ordinal,
+            id: self.id,
+            threshold: self.threshold,
+            message_generator: self.message_generator,
+            secret_share,
+            public_key,
+            participant_ids: self.participant_ids.clone(),
+            verification_shares,
+            transcript_hash,
+            participant_identifiers: self.participant_identifiers.clone(),
+            contribution_public_keys: self.contribution_public_keys.clone(),
+        })
+    }
+
+    /// Prove to the other shareholders which subkey was derived for `context`
+    pub fn subkey_proof(&self, context: &[u8]) -> DkgResult<SubkeyProof<G>> {
+        let subkey = self.derive_subkey(context)?;
+        let k = G::Scalar::random(&mut rand::rng());
+        let r = self.message_generator * k;
+        let verification_share = subkey.message_generator * subkey.secret_share.value.0;
+        let c = subkey_challenge(
+            self.ordinal,
+            context,
+            &subkey.public_key.0,
+            &verification_share,
+            &r,
+        );
+        Ok(SubkeyProof {
+            ordinal: self.ordinal,
+            public_key: subkey.public_key.0,
+            r,
+            s: k + c * subkey.secret_share.value.0,
+        })
+    }
+
+    /// Check that another shareholder derived the same subkey for `context`
+    pub fn verify_subkey_proof(&self, context: &[u8], proof: &SubkeyProof<G>) -> DkgResult<()> {
+        let subkey = self.derive_subkey(context)?;
+        if proof.public_key != subkey.public_key.0 {
+            return Err(Error::Signature(format!(
+                "Shareholder {} derived a different subkey",
+                proof.ordinal
+            )));
+        }
+        let verification_share = subkey
+            .verification_share(proof.ordinal)
+            .ok_or_else(|| Error::Signature(format!("Unknown shareholder {}", proof.ordinal)))?;
+        let c = subkey_challenge(
+            proof.ordinal,
+            context,
+            &proof.public_key,
+            &verification_share.0,
+            &proof.r,
+        );
+        if self.message_generator * proof.s != proof.r + verification_share.0 * c {
+            return Err(Error::Signature(format!(
+                "Shareholder {} did not apply the same subkey tweak",
+                proof.ordinal
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn subkey_challenge<G>(
+    ordinal: usize,
+    context: &[u8],
+    public_key: &G,
+    verification_share: &G,
+    r: &G,
+) -> G::Scalar
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut input = (ordinal as u64).to_be_bytes().to_vec();
+    input.extend_from_slice(&(context.len() as u64).to_be_bytes());
+    input.extend_from_slice(context);
+    input.extend_from_slice(public_key.to_bytes().as_ref());
+    input.extend_from_slice(verification_share.to_bytes().as_ref());
+    input.extend_from_slice(r.to_bytes().as_ref());
+    G::Scalar::hash_to_scalar_with_dst(&input, &subkey_dst::<G>(b"proof"))
+}
+
+fn subkey_dst<G>(purpose: &[u8]) -> Vec<u8>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut dst = group_dst::<G>();
+    dst.extend_from_slice(b"-SUBKEY-");
+    dst.extend_from_slice(purpose);
+    dst
+}
@@ -0,0 +1,231 @@
+use super::*;
+use elliptic_curve::PrimeField;
+use elliptic_curve::group::{Group, GroupEncoding};
+use k256::{ProjectivePoint, Scalar};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const DESCRIPTOR_INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const DESCRIPTOR_CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The BIP341 breakdown of a taproot output key without a script tree
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct TaprootKey {
+    /// The x-only internal key
+    pub(crate) internal_key: [u8; 32],
+    /// The `TapTweak` tagged hash of the internal key
+    pub(crate) tweak: [u8; 32],
+    /// The x-only output key committed to in the scriptPubKey
+    pub(crate) output_key: [u8; 32],
+    /// Whether the y coordinate of the output key is odd
+    pub(crate) output_key_parity: bool,
+}
+
+impl TaprootKey {
+    /// Compute the output key for the x-only `internal_key`
+    pub fn new(internal_key: [u8; 32]) -> DkgResult<Self> {
+        let point = lift_x(&internal_key)?;
+        let tweak = tagged_hash(b"TapTweak", &internal_key);
+        let t = Option::<Scalar>::from(Scalar::from_repr(tweak.into())).ok_or_else(|| {
+            Error::Encoding("Taproot tweak is not less than the group order".to_string())
+        })?;
+        let output = point + ProjectivePoint::GENERATOR * t;
+        if output.is_identity().into() {
+            return Err(Error::Encoding(
+                "Taproot output key is the identity".to_string(),
+            ));
+        }
+        let (output_key, output_key_parity) = x_only(&output);
+        Ok(Self {
+            internal_key,
+            tweak,
+            output_key,
+            output_key_parity,
+        })
+    }
+
+    /// The x-only internal key
+    pub fn internal_key(&self) -> [u8; 32] {
+        self.internal_key
+    }
+
+    /// The tweak added to the internal key
+    pub fn tweak(&self) -> [u8; 32] {
+        self.tweak
+    }
+
+    /// The x-only output key
+    pub fn output_key(&self) -> [u8; 32] {
+        self.output_key
+    }
+
+    /// Whether the y coordinate of the output key is odd
+    pub fn output_key_parity(&self) -> bool {
+        self.output_key_parity
+    }
+
+    /// The segwit v1 scriptPubKey paying to the output key
+    pub fn script_pubkey(&self) -> [u8; 34] {
+        let mut script = [0u8; 34];
+        script[0] = 0x51;
+        script[1] = 0x20;
+        script[2..].copy_from_slice(&self.output_key);
+        script
+    }
+
+    /// The `tr(…)` output descriptor with its checksum
+    pub fn descriptor(&self) -> String {
+        let descriptor = format!("tr({})", hex::encode(self.internal_key));
+        let checksum =
+            descriptor_checksum(&descriptor).expect("hex descriptors only use valid characters");
+        format!("{descriptor}#{checksum}")
+    }
+}
+
+impl DkgOutput<ProjectivePoint> {
+    /// Whether the group key has an even y coordinate as BIP340 requires
+    pub fn has_even_y(&self) -> bool {
+        !x_only(&self.public_key.0).1
+    }
+
+    /// Normalize the output for BIP340 and BIP341.
+    ///
+    /// When the group key has an odd y coordinate the secret share, group key
+    /// and verification shares are all negated so the x-only key can be used
+    /// directly. Every shareholder must normalize to keep the shares consistent.
+    pub fn to_x_only(&self) -> Self {
+        let mut output = self.clone();
+        if output.has_even_y() {
+            return output;
+        }
+        output.secret_share.value.0 = -output.secret_share.value.0;
+        output.public_key = NonIdentity(-output.public_key.0);
+        for share in output.verification_shares.values_mut() {
+            *share = NonIdentity(-share.0);
+        }
+        output
+    }
+
+    /// The x-only group key
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        x_only(&self.public_key.0).0
+    }
+
+    /// The BIP341 breakdown of the key path only taproot output for the group key
+    pub fn taproot_key(&self) -> DkgResult<TaprootKey> {
+        TaprootKey::new(self.x_only_public_key())
+    }
+
+    /// The `tr(…)` output descriptor for the group key so wallets can watch
+    /// the address.
+    ///
+    /// Signers must use the output of [`DkgOutput::to_x_only`] and apply the
+    /// tweak from [`DkgOutput::taproot_key`] to spend from it.
+    pub fn taproot_descriptor(&self) -> DkgResult<String> {
+        Ok(self.taproot_key()?.descriptor())
+    }
+}
+
+/// The x coordinate and whether the y coordinate is odd
+fn x_only(point: &ProjectivePoint) -> ([u8; 32], bool) {
+    let bytes = point.to_bytes();
+    let mut x = [0u8; 32];
+    x.copy_from_slice(&bytes[1..]);
+    (x, bytes[0] == 0x03)
+}
+
+fn lift_x(x: &[u8; 32]) -> DkgResult<ProjectivePoint> {
+    let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+    repr[0] = 0x02;
+    repr[1..].copy_from_slice(x);
+    Option::<ProjectivePoint>::from(ProjectivePoint::from_bytes(&repr))
+        .ok_or_else(|| Error::Encoding("Invalid x-only key".to_string()))
+}
+
+fn tagged_hash(tag: &[u8], message: &[u8]) -> [u8; 32] {
+    let tag = Sha256::digest(tag);
+    let mut hasher = Sha256::new();
+    hasher.update(tag);
+    hasher.update(tag);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// The BIP380 checksum of an output descriptor
+pub(crate) fn descriptor_checksum(descriptor: &str) -> DkgResult<String> {
+    const GENERATOR: [u64; 5] = [
+        0xf5dee51989,
+        0xa9fdca3312,
+        0x1bab10e32d,
+        0x3706b1677a,
+        0x644d626ffd,
+    ];
+    let polymod = |checksum: u64, value: u64| {
+        let top = checksum >> 35;
+        let mut checksum = ((checksum & 0x7ffffffff) << 5) ^ value;
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= g;
+            }
+        }
+        checksum
+    };
+
+    let mut checksum = 1u64;
+    let mut groups = Vec::with_capacity(3);
+    for c in descriptor.chars() {
+        let value = DESCRIPTOR_INPUT_CHARSET
+            .find(c)
+            .ok_or_else(|| Error::Encoding(format!("Invalid character '{c}' in descriptor")))?
+            as u64;
+        checksum = polymod(checksum, value & 31);
+        groups.push(value >> 5);
+        if groups.len() == 3 {
+            checksum = polymod(checksum, groups[0] * 9 + groups[1] * 3 + groups[2]);
+            groups.clear();
+        }
+    }
+    match groups.as_slice() {
+        [a] => checksum = polymod(checksum, *a),
+        [a, b] => checksum = polymod(checksum, a * 3 + b),
+        _ => {}
+    }
+    for _ in 0..8 {
+        checksum = polymod(checksum, 0);
+    }
+    checksum ^= 1;
+    Ok((0..8)
+        .map(|i| DESCRIPTOR_CHECKSUM_CHARSET[((checksum >> (5 * (7 - i))) & 31) as usize] as char)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taproot_known_answer() {
+        // From the BIP341 wallet test vectors and BIP380
+        let internal_key =
+            hex::decode("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d")
+                .expect("valid hex");
+        let internal_key = <[u8; 32]>::try_from(internal_key.as_slice()).expect("32 bytes");
+        let key = TaprootKey::new(internal_key).expect("valid internal key");
+        assert_eq!(
+            hex::encode(key.tweak()),
+            "b86e7be8f39bab32a6f2c0443abbc210f0edac0e2c53d501b36b64437d9c6c70"
+        );
+        assert_eq!(
+            hex::encode(key.output_key()),
+            "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"
+        );
+        assert_eq!(
+            descriptor_checksum("raw(deadbeef)").expect("valid descriptor"),
+            "89f8spxm"
+        );
+        assert_eq!(
+            key.descriptor(),
+            "tr(d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d)#zd5eym6u"
+        );
+    }
+}
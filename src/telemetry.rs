@@ -0,0 +1,87 @@
+use crate::{Error, Round};
+
+// Reported through the `metrics` facade with the `metrics` feature and
+// no-ops otherwise so the protocol code can report unconditionally
+
+/// The number of round payloads received, labelled by `round`
+#[cfg(feature = "metrics")]
+pub const MESSAGES_RECEIVED: &str = "frost_dkg_messages_received_total";
+/// The number of payloads that failed validation, labelled by `round` and
+/// the `kind` of error
+#[cfg(feature = "metrics")]
+pub const VALIDATION_FAILURES: &str = "frost_dkg_validation_failures_total";
+/// The seconds between entering a round and running it, labelled by `round`
+#[cfg(feature = "metrics")]
+pub const ROUND_DURATION: &str = "frost_dkg_round_duration_seconds";
+
+/// When the current round started
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct RoundTimer(#[cfg(feature = "metrics")] std::time::Instant);
+
+// The instant has no default so this can only be derived without metrics
+#[allow(clippy::derivable_impls)]
+impl Default for RoundTimer {
+    fn default() -> Self {
+        Self(
+            #[cfg(feature = "metrics")]
+            std::time::Instant::now(),
+        )
+    }
+}
+
+pub(crate) fn message_received(round: Round) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(MESSAGES_RECEIVED, "round" => round.to_string()).increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = round;
+}
+
+pub(crate) fn validation_failed(round: Round, error: &Error) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(
+        VALIDATION_FAILURES,
+        "round" => round.to_string(),
+        "kind" => error_kind(error)
+    )
+    .increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = (round, error);
+}
+
+/// Record how long `round` took and restart the timer for the next one
+pub(crate) fn round_completed(round: Round, timer: &mut RoundTimer) {
+    #[cfg(feature = "metrics")]
+    {
+        metrics::histogram!(ROUND_DURATION, "round" => round.to_string())
+            .record(timer.0.elapsed().as_secs_f64());
+        *timer = RoundTimer::default();
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (round, timer);
+}
+
+#[cfg(feature = "metrics")]
+fn error_kind(error: &Error) -> &'static str {
+    match error {
+        Error::Fmt(_) => "fmt",
+        Error::Io(_) => "io",
+        Error::Vsss(_) => "vsss",
+        Error::Postcard(_) => "postcard",
+        Error::Initialization(_) => "initialization",
+        Error::Round(_) => "round",
+        Error::Pvss(_) => "pvss",
+        Error::Subgroup(_) => "subgroup",
+        Error::NonCanonical(_) => "non_canonical",
+        Error::UnsupportedStateVersion(_) => "unsupported_state_version",
+        Error::Encoding(_) => "encoding",
+        Error::ShareContinuity(_) => "share_continuity",
+        Error::Integrity(_) => "integrity",
+        Error::Signature(_) => "signature",
+        Error::Decode { .. } => "decode",
+        Error::Replay { .. } => "replay",
+        Error::PolicyRejected { .. } => "policy_rejected",
+        Error::ParameterMismatch { .. } => "parameter_mismatch",
+        #[cfg(feature = "json")]
+        Error::Json(_) => "json",
+    }
+}
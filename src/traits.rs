@@ -1,3 +1,5 @@
+#[cfg(feature = "arkworks")]
+mod tarkworks;
 #[cfg(feature = "bls12_381_plus")]
 mod tbls12_381_plus;
 #[cfg(feature = "blstrs_plus")]
@@ -13,10 +15,102 @@ mod tp256;
 #[cfg(feature = "p384")]
 mod tp384;
 
-use elliptic_curve::PrimeField;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::{Group, PrimeField};
 
 /// A trait for hashing a scalar
 pub trait ScalarHash: PrimeField {
+    /// Whether more than one group has this scalar field, like BLS12-381 G1
+    /// and G2, so challenges are also separated by group, see
+    /// [`hash_to_group_scalar`]
+    const SHARED_BY_GROUPS: bool = false;
+
     /// Hash a scalar
     fn hash_to_scalar(bytes: &[u8]) -> Self;
+
+    /// Hash a scalar with an explicit domain separation tag.
+    ///
+    /// The default prefixes the tag to the input of [`ScalarHash::hash_to_scalar`].
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        let mut input = Vec::with_capacity(2 + dst.len() + bytes.len());
+        input.extend_from_slice(&(dst.len() as u16).to_be_bytes());
+        input.extend_from_slice(dst);
+        input.extend_from_slice(bytes);
+        Self::hash_to_scalar(&input)
+    }
+}
+
+/// The domain separation tag for challenges computed over `G`.
+///
+/// The tag is derived from the encoding of the group's generator so groups
+/// that share a scalar field, like BLS12-381 G1 and G2, never produce
+/// cross-usable challenges.
+pub fn group_dst<G: Group + GroupEncoding>() -> Vec<u8> {
+    let mut transcript = merlin::Transcript::new(b"Frost DKG - Group DST");
+    transcript.append_message(b"generator", G::generator().to_bytes().as_ref());
+    let mut digest = [0u8; 16];
+    transcript.challenge_bytes(b"group", &mut digest);
+    let mut dst = b"FROST-DKG-V1-".to_vec();
+    dst.extend_from_slice(hex::encode(digest).as_bytes());
+    dst
+}
+
+/// The length of the ciphersuite id returned by [`ciphersuite_id`]
+pub const CIPHERSUITE_ID_LENGTH: usize = 8;
+
+/// The identifier of the ciphersuite over `G` carried in every message
+/// and bound into the round 1 signatures.
+///
+/// It commits to the curve through [`group_dst`] and to the scalar hash and
+/// its domain separation tag through the hash of a fixed input, so a message
+/// produced for another ciphersuite is refused before it is decoded.
+pub fn ciphersuite_id<G>() -> [u8; CIPHERSUITE_ID_LENGTH]
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+{
+    const PROBE: &[u8] = b"Frost DKG - Ciphersuite";
+    let mut transcript = merlin::Transcript::new(PROBE);
+    transcript.append_message(b"group", &group_dst::<G>());
+    transcript.append_message(b"hash", G::Scalar::hash_to_scalar(PROBE).to_repr().as_ref());
+    let mut id = [0u8; CIPHERSUITE_ID_LENGTH];
+    transcript.challenge_bytes(b"ciphersuite", &mut id);
+    id
+}
+
+/// A group whose points can be checked for membership of the prime order
+/// subgroup.
+///
+/// Edwards25519 and Edwards448 have small cofactors and their decoders accept
+/// points with a torsion component, so they check it explicitly. Every other
+/// supported group either has prime order or rejects such points when
+/// decoding and keeps the default.
+pub trait PrimeSubgroup: Group {
+    /// Whether the point is in the prime order subgroup
+    fn is_torsion_free(&self) -> bool {
+        true
+    }
+}
+
+/// Check a point is in the prime order subgroup, see [`PrimeSubgroup`]
+pub fn is_torsion_free<G: PrimeSubgroup>(point: &G) -> bool {
+    PrimeSubgroup::is_torsion_free(point)
+}
+
+/// Hash to a scalar for a challenge computed over `G`.
+///
+/// Scalar fields shared by more than one group use the domain separation
+/// tag for `G`. Every other ciphersuite hashes with
+/// [`ScalarHash::hash_to_scalar`] as before, so its challenges stay
+/// compatible with earlier releases.
+pub fn hash_to_group_scalar<G>(bytes: &[u8]) -> G::Scalar
+where
+    G: Group + GroupEncoding,
+    G::Scalar: ScalarHash,
+{
+    if G::Scalar::SHARED_BY_GROUPS {
+        G::Scalar::hash_to_scalar_with_dst(bytes, &group_dst::<G>())
+    } else {
+        G::Scalar::hash_to_scalar(bytes)
+    }
 }
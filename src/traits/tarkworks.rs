@@ -0,0 +1,16 @@
+use super::*;
+use crate::{ArkCurve, ArkGroup, ArkScalar, ArkScalarParams};
+use sha2::Digest;
+
+impl<F: ArkScalarParams> ScalarHash for ArkScalar<F> {
+    const SHARED_BY_GROUPS: bool = true;
+
+    fn hash_to_scalar(bytes: &[u8]) -> Self {
+        let mut hash = sha2::Sha512::new();
+        hash.update(bytes);
+        Self(F::from_le_bytes_mod_order(&hash.finalize()))
+    }
+}
+
+// The decoder rejects points outside the prime order subgroup
+impl<C: ArkCurve> PrimeSubgroup for ArkGroup<C> {}
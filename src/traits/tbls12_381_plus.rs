@@ -3,7 +3,18 @@ use bls12_381_plus::Scalar;
 use bls12_381_plus::elliptic_curve_013::hash2curve::ExpandMsgXmd;
 
 impl ScalarHash for Scalar {
+    const SHARED_BY_GROUPS: bool = true;
+
     fn hash_to_scalar(bytes: &[u8]) -> Self {
-        Scalar::hash::<ExpandMsgXmd<sha2_010::Sha256>>(bytes, b"BLS12381_XMD:SHA-256_RO_NUL_")
+        Self::hash_to_scalar_with_dst(bytes, b"BLS12381_XMD:SHA-256_RO_NUL_")
+    }
+
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        Scalar::hash::<ExpandMsgXmd<sha2_010::Sha256>>(bytes, dst)
     }
 }
+
+// The decoders reject points outside the prime order subgroup
+impl PrimeSubgroup for bls12_381_plus::G1Projective {}
+
+impl PrimeSubgroup for bls12_381_plus::G2Projective {}
@@ -9,3 +9,11 @@ impl ScalarHash for Scalar {
         Scalar::from_hash(hash)
     }
 }
+
+impl PrimeSubgroup for curve25519_dalek::EdwardsPoint {
+    fn is_torsion_free(&self) -> bool {
+        curve25519_dalek::EdwardsPoint::is_torsion_free(self)
+    }
+}
+
+impl PrimeSubgroup for curve25519_dalek::RistrettoPoint {}
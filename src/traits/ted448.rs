@@ -4,6 +4,16 @@ use hash2curve::ExpandMsgXof;
 
 impl ScalarHash for Scalar {
     fn hash_to_scalar(bytes: &[u8]) -> Self {
-        Scalar::hash::<ExpandMsgXof<shake::Shake256>>(bytes, b"edwards448_XOF:SHAKE256_RO_NUL_")
+        Self::hash_to_scalar_with_dst(bytes, b"edwards448_XOF:SHAKE256_RO_NUL_")
+    }
+
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        Scalar::hash::<ExpandMsgXof<shake::Shake256>>(bytes, dst)
+    }
+}
+
+impl PrimeSubgroup for ed448_goldilocks_plus::EdwardsPoint {
+    fn is_torsion_free(&self) -> bool {
+        bool::from(ed448_goldilocks_plus::EdwardsPoint::is_torsion_free(self))
     }
 }
@@ -6,7 +6,13 @@ use k256::{Scalar, Secp256k1};
 impl ScalarHash for Scalar {
     fn hash_to_scalar(bytes: &[u8]) -> Self {
         const DST: &[u8] = b"secp256k1_XMD:SHA-256_RO_NUL_";
-        hash2curve::hash_to_scalar::<Secp256k1, ExpandMsgXmd<sha2::Sha256>, U48>(&[bytes], &[DST])
+        Self::hash_to_scalar_with_dst(bytes, DST)
+    }
+
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        hash2curve::hash_to_scalar::<Secp256k1, ExpandMsgXmd<sha2::Sha256>, U48>(&[bytes], &[dst])
             .expect("hash_to_scalar failed")
     }
 }
+
+impl PrimeSubgroup for k256::ProjectivePoint {}
@@ -6,7 +6,13 @@ use p256::{NistP256, Scalar};
 impl ScalarHash for Scalar {
     fn hash_to_scalar(bytes: &[u8]) -> Self {
         const DST: &[u8] = b"P256_XMD:SHA-256_RO_NUL_";
-        hash2curve::hash_to_scalar::<NistP256, ExpandMsgXmd<sha2::Sha256>, U48>(&[bytes], &[DST])
+        Self::hash_to_scalar_with_dst(bytes, DST)
+    }
+
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        hash2curve::hash_to_scalar::<NistP256, ExpandMsgXmd<sha2::Sha256>, U48>(&[bytes], &[dst])
             .expect("hash_to_scalar failed")
     }
 }
+
+impl PrimeSubgroup for p256::ProjectivePoint {}
@@ -6,7 +6,13 @@ use p384::{NistP384, Scalar};
 impl ScalarHash for Scalar {
     fn hash_to_scalar(bytes: &[u8]) -> Self {
         const DST: &[u8] = b"P384_XMD:SHA-384_RO_NUL_";
-        hash2curve::hash_to_scalar::<NistP384, ExpandMsgXmd<sha2::Sha384>, U72>(&[bytes], &[DST])
+        Self::hash_to_scalar_with_dst(bytes, DST)
+    }
+
+    fn hash_to_scalar_with_dst(bytes: &[u8], dst: &[u8]) -> Self {
+        hash2curve::hash_to_scalar::<NistP384, ExpandMsgXmd<sha2::Sha384>, U72>(&[bytes], &[dst])
             .expect("hash_to_scalar failed")
     }
 }
+
+impl PrimeSubgroup for p384::ProjectivePoint {}
@@ -0,0 +1,271 @@
+use super::*;
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use vsss_rs::IdentifierPrimeField;
+
+/// A view of a participant's state containing only public information.
+///
+/// Safe to display in user interfaces and logs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ParticipantView<F: ScalarHash> {
+    /// The participant's ordinal index
+    pub ordinal: usize,
+    /// The participant's ID
+    #[serde(bound(
+        serialize = "IdentifierPrimeField<F>: Serialize",
+        deserialize = "IdentifierPrimeField<F>: Deserialize<'de>"
+    ))]
+    pub id: IdentifierPrimeField<F>,
+    /// The participant's type
+    pub participant_type: ParticipantType,
+    /// The threshold
+    pub threshold: usize,
+    /// The limit
+    pub limit: usize,
+    /// The current round
+    pub round: Round,
+    /// Whether the protocol has completed
+    pub completed: bool,
+    /// All participants that started the protocol
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<F>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<F>>: Deserialize<'de>"
+    ))]
+    pub all_participant_ids: BTreeMap<usize, IdentifierPrimeField<F>>,
+    /// The participants that are still valid
+    #[serde(bound(
+        serialize = "BTreeMap<usize, IdentifierPrimeField<F>>: Serialize",
+        deserialize = "BTreeMap<usize, IdentifierPrimeField<F>>: Deserialize<'de>"
+    ))]
+    pub valid_participant_ids: BTreeMap<usize, IdentifierPrimeField<F>>,
+    /// The hex encoded digest of each received set of feldman commitments
+    pub commitment_digests: BTreeMap<usize, String>,
+    /// The ordinals that sent round 2 data
+    pub round2_senders: Vec<usize>,
+}
+
+/// A snapshot of the progress of the protocol for a participant
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DkgStatus {
+    /// The current round
+    pub round: Round,
+    /// Whether the protocol has completed
+    pub completed: bool,
+    /// The number of round 1 payloads received including our own
+    pub round1_received: usize,
+    /// The number of round 2 payloads received including our own
+    pub round2_received: usize,
+    /// The ordinals of participants that are still valid
+    pub valid_participants: Vec<usize>,
+    /// The ordinals of participants that sent invalid data attributable to
+    /// them, see [`Participant::get_invalid_participant_ids`]
+    pub invalid_participants: Vec<usize>,
+    /// The ordinals of participants whose data for the current round has not arrived
+    pub missing_participants: Vec<usize>,
+    /// Whether [`Participant::run`] can be called to advance to the next round
+    pub ready: bool,
+}
+
+/// What a participant still needs before [`Participant::run`] succeeds
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RoundRequirements {
+    /// The round that [`Participant::run`] executes next
+    pub round: Round,
+    /// The round whose payloads are needed, `None` when none are
+    pub payload_round: Option<Round>,
+    /// How many more payloads are needed
+    pub needed: usize,
+    /// The ordinals that must send a payload regardless of the count,
+    /// like the secret participants of a roster mixed with refresh participants
+    pub required: Vec<usize>,
+    /// The ordinals that have not sent a payload yet and still can
+    pub candidates: Vec<usize>,
+}
+
+impl RoundRequirements {
+    /// Whether [`Participant::run`] can be called now
+    pub fn is_satisfied(&self) -> bool {
+        self.round != Round::Four && self.needed == 0 && self.required.is_empty()
+    }
+}
+
+impl<I, G> Participant<I, G>
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    /// A view of this participant without any secret values
+    pub fn public_view(&self) -> ParticipantView<G::Scalar> {
+        ParticipantView {
+            ordinal: self.ordinal,
+            id: self.id,
+            participant_type: self.participant_impl.get_type(),
+            threshold: self.threshold,
+            limit: self.limit,
+            round: self.round,
+            completed: self.completed,
+            all_participant_ids: self.all_participant_ids.clone(),
+            valid_participant_ids: self.valid_participant_ids.clone(),
+            commitment_digests: self
+                .received_round1_data
+                .iter()
+                .map(|(ordinal, data)| (*ordinal, hex::encode(data.commitments_digest())))
+                .collect(),
+            round2_senders: self.received_round2_data.keys().copied().collect(),
+        }
+    }
+
+    /// A snapshot of the progress of the protocol
+    pub fn status(&self) -> DkgStatus {
+        let is_missing = |ordinal: &&usize, received: bool| {
+            **ordinal != self.ordinal
+                && !received
+                && !self.invalid_participant_ids.contains_key(*ordinal)
+        };
+        let (missing_participants, ready) = match self.round {
+            Round::One | Round::Two => (
+                self.all_participant_ids
+                    .keys()
+                    .filter(|o| is_missing(o, self.received_round1_data.contains_key(*o)))
+                    .copied()
+                    .collect(),
+                self.round == Round::One || self.round2_ready(),
+            ),
+            Round::Three if self.is_handoff_dealer(self.ordinal) => (Vec::new(), true),
+            Round::Three if self.round1_openings.contains_key(&self.ordinal) => {
+                (self.missing_round1_openings(), self.round3_ready())
+            }
+            Round::Three => (
+                self.valid_participant_ids
+                    .keys()
+                    .filter(|o| is_missing(o, self.received_round2_data.contains_key(*o)))
+                    .copied()
+                    .collect(),
+                self.round3_ready(),
+            ),
+            Round::Four => (Vec::new(), false),
+        };
+        let valid_participants = if self.valid_participant_ids.is_empty() {
+            self.received_round1_data.keys().copied().collect()
+        } else {
+            self.valid_participant_ids
+                .keys()
+                .filter(|ordinal| !self.invalid_participant_ids.contains_key(ordinal))
+                .copied()
+                .collect()
+        };
+        DkgStatus {
+            round: self.round,
+            completed: self.completed,
+            round1_received: self.received_round1_data.len(),
+            round2_received: self.received_round2_data.len(),
+            valid_participants,
+            invalid_participants: self.invalid_participant_ids.keys().copied().collect(),
+            missing_participants,
+            ready,
+        }
+    }
+
+    /// Which payloads are still needed before [`Participant::run`] succeeds
+    pub fn round_requirements(&self) -> RoundRequirements {
+        let (payload_round, needed, required) = match self.round {
+            Round::One | Round::Four => (None, 0, Vec::new()),
+            Round::Three if self.is_handoff_dealer(self.ordinal) => (None, 0, Vec::new()),
+            Round::Two => (
+                Some(Round::One),
+                self.threshold
+                    .saturating_sub(self.received_round1_data.len()),
+                self.missing_round1_reveals(),
+            ),
+            Round::Three if self.round1_openings.contains_key(&self.ordinal) => {
+                (Some(Round::Three), 0, self.missing_round1_openings())
+            }
+            Round::Three => {
+                let (secret, refresh) = self.roster_counts();
+                let mut required = if secret > 0 && refresh > 0 {
+                    self.missing_secret_senders()
+                } else {
+                    Vec::new()
+                };
+                // Unless told to proceed every dealer's share is needed
+                if self.missing_share_policy != MissingSharePolicy::Proceed {
+                    required.extend(self.missing_shares());
+                    required.sort_unstable();
+                    required.dedup();
+                }
+                (
+                    Some(Round::Two),
+                    self.threshold
+                        .saturating_sub(self.received_round2_data.len()),
+                    required,
+                )
+            }
+        };
+        let candidates = match payload_round {
+            Some(_) => self.status().missing_participants,
+            None => Vec::new(),
+        };
+        RoundRequirements {
+            round: self.round,
+            payload_round,
+            needed,
+            required,
+            candidates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::participant::testing::{next_round, receive};
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn round_requirements() {
+        let parameters = Parameters::<k256::ProjectivePoint>::new(
+            NonZeroUsize::new(3).expect("threshold is non-zero"),
+            NonZeroUsize::new(3).expect("limit is non-zero"),
+            None,
+            None,
+        );
+        let mut participants = (1..=3)
+            .map(|id| {
+                let id = IdentifierPrimeField(k256::Scalar::from(id as u64));
+                SecretParticipant::<k256::ProjectivePoint>::new_secret(id, &parameters)
+                    .expect("create secret participant")
+            })
+            .collect::<Vec<_>>();
+
+        let requirements = participants[0].round_requirements();
+        assert_eq!(requirements.round, Round::One);
+        assert_eq!(requirements.payload_round, None);
+        assert!(requirements.is_satisfied());
+
+        let generators = next_round(&mut participants);
+        let requirements = participants[0].round_requirements();
+        assert_eq!(requirements.round, Round::Two);
+        assert_eq!(requirements.payload_round, Some(Round::One));
+        assert_eq!(requirements.needed, 2);
+        assert_eq!(requirements.candidates, vec![1, 2]);
+        assert!(!requirements.is_satisfied());
+        assert!(participants[0].run().is_err());
+
+        receive(&mut participants, generators);
+        assert!(participants[0].round_requirements().is_satisfied());
+
+        let generators = next_round(&mut participants);
+        let requirements = participants[0].round_requirements();
+        assert_eq!(requirements.payload_round, Some(Round::Two));
+        assert_eq!(requirements.needed, 2);
+        receive(&mut participants, generators);
+        assert!(participants[0].round_requirements().is_satisfied());
+
+        next_round(&mut participants);
+        assert!(!participants[0].round_requirements().is_satisfied());
+    }
+}
@@ -0,0 +1,80 @@
+use elliptic_curve::Field;
+use frost_dkg::*;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::Group};
+
+#[test]
+fn abort_certificate_countersigned() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let signing_keys = (0..3)
+        .map(|_| k256::Scalar::random(&mut rand::rng()))
+        .collect::<Vec<_>>();
+    let signers = signing_keys
+        .iter()
+        .enumerate()
+        .map(|(ordinal, key)| (ordinal, G::generator() * key))
+        .collect::<BTreeMap<_, _>>();
+
+    // Participant 2 never sends its round 1 payload
+    let generators = participants[..2]
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+
+    let mut certificate = participants[0]
+        .abort_certificate(AbortReason::Timeout(vec![2]), signing_keys[0])
+        .unwrap();
+    assert_eq!(certificate.round(), Round::Two);
+    assert!(certificate.verify(&signers, 2).is_err());
+
+    participants[1]
+        .countersign_abort(&mut certificate, signing_keys[1])
+        .unwrap();
+    certificate.verify(&signers, 2).unwrap();
+    assert!(certificate.verify(&signers, 3).is_err());
+
+    // A signature with a key other than the expected one
+    let mut wrong_keys = signers.clone();
+    wrong_keys.insert(1, G::generator() * signing_keys[2]);
+    assert!(certificate.verify(&wrong_keys, 2).is_err());
+
+    // Participants of another ceremony cannot countersign
+    let other = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        None,
+    );
+    let outsider =
+        SecretParticipant::<G>::new_secret(IdentifierPrimeField(k256::Scalar::from(3u64)), &other)
+            .unwrap();
+    assert!(
+        outsider
+            .countersign_abort(&mut certificate, signing_keys[2])
+            .is_err()
+    );
+}
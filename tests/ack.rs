@@ -0,0 +1,86 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn share_acks<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert_eq!(participants[0].unacknowledged_recipients(), vec![1, 2]);
+
+    // No share was received from itself
+    assert!(participants[1].acknowledge_share(1).is_err());
+
+    let ack = participants[1].acknowledge_share(0).unwrap();
+    assert_eq!(ack.dealer_ordinal(), 0);
+    assert_eq!(ack.recipient_ordinal(), 1);
+
+    // Only the dealer keeps the acknowledgment
+    assert!(participants[2].receive_share_ack(ack.clone()).is_err());
+
+    // An acknowledgment relabelled as another recipient's is refused
+    let mut bytes = postcard::to_stdvec(&participants[2].acknowledge_share(0).unwrap()).unwrap();
+    assert_eq!(bytes[1], 2);
+    bytes[1] = 1;
+    let forged: ShareAck<G> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(forged.recipient_ordinal(), 1);
+    assert!(participants[0].receive_share_ack(forged).is_err());
+
+    participants[0].receive_share_ack(ack).unwrap();
+    let ack = participants[2].acknowledge_share(0).unwrap();
+    participants[0].receive_share_ack(ack).unwrap();
+    assert!(participants[0].unacknowledged_recipients().is_empty());
+    assert_eq!(participants[0].get_share_acks().len(), 2);
+
+    // A relying party checks delivery with the public round 1 broadcasts
+    let context = participants[0].signed_context();
+    let round1 = participants[0].get_received_round1_data();
+    for ack in participants[0].get_share_acks().values() {
+        ack.verify(&context, &round1[&0], &round1[&ack.recipient_ordinal()])
+            .unwrap();
+    }
+    let ack = &participants[0].get_share_acks()[&1];
+    assert!(ack.verify(&context, &round1[&0], &round1[&2]).is_err());
+    assert!(ack.verify(&context, &round1[&1], &round1[&1]).is_err());
+
+    // Acknowledgments survive a snapshot
+    let state = participants[0].export_state().unwrap();
+    let restored = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(restored.get_share_acks().len(), 2);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "age")]
+
+use age::secrecy::ExposeSecret;
+use frost_dkg::*;
+use k256::{ProjectivePoint, Scalar};
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn age_backup_round_trip() {
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<ProjectivePoint>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let output = &outputs[0];
+
+    let operator = age::x25519::Identity::generate();
+    let escrow = age::x25519::Identity::generate();
+    let recipients = [
+        operator.to_public().to_string(),
+        escrow.to_public().to_string(),
+    ];
+    let recipients = recipients.iter().map(String::as_str).collect::<Vec<_>>();
+    let blob = output.to_age(&recipients).unwrap();
+    assert!(blob.starts_with(b"age-encryption.org/v1"));
+
+    for identity in [&operator, &escrow] {
+        let restored =
+            DkgOutput::<ProjectivePoint>::from_age(&blob, identity.to_string().expose_secret())
+                .unwrap();
+        assert_eq!(
+            restored.secret_share().value.0,
+            output.secret_share().value.0
+        );
+        assert_eq!(restored.public_key(), output.public_key());
+        assert_eq!(
+            restored.transcript_hash_bytes(),
+            output.transcript_hash_bytes()
+        );
+    }
+
+    let stranger = age::x25519::Identity::generate();
+    assert!(
+        DkgOutput::<ProjectivePoint>::from_age(&blob, stranger.to_string().expose_secret())
+            .is_err()
+    );
+    assert!(output.to_age(&["not a recipient"]).is_err());
+    assert!(output.to_age(&[]).is_err());
+}
+
+#[test]
+fn age_passphrase_round_trip() {
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<ProjectivePoint>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+
+    let blob = outputs[2].to_age_with_passphrase("correct horse").unwrap();
+    let restored =
+        DkgOutput::<ProjectivePoint>::from_age_with_passphrase(&blob, "correct horse").unwrap();
+    assert_eq!(
+        restored.secret_share().value.0,
+        outputs[2].secret_share().value.0
+    );
+}
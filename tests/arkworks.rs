@@ -0,0 +1,61 @@
+#![cfg(feature = "ark-bn254")]
+
+use elliptic_curve::{Field, PrimeField};
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+type G = ArkGroup<ark_bn254::G1Projective>;
+type S = ArkScalar<ark_bn254::Fr>;
+
+#[test]
+fn scalar_constants() {
+    assert_eq!(S::TWO_INV.double(), S::ONE);
+    assert_eq!(S::ROOT_OF_UNITY * S::ROOT_OF_UNITY_INV, S::ONE);
+    assert_eq!(
+        S::MULTIPLICATIVE_GENERATOR.pow_vartime([1u64 << S::S]),
+        S::DELTA
+    );
+    assert_eq!(S::ROOT_OF_UNITY.pow_vartime([1u64 << S::S]), S::ONE);
+
+    let scalar = S::random(&mut rand::rng());
+    assert_eq!(S::from_repr(scalar.to_repr()).unwrap(), scalar);
+    assert_eq!(scalar.square().sqrt().unwrap().square(), scalar.square());
+    assert!(bool::from(S::from_repr([0xff; 32]).is_none()));
+}
+
+#[test]
+fn bn254_dkg() {
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            ciphersuites::ArkBn254Secret::new_secret(IdentifierPrimeField(S::from(id)), &parameters)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "json")]
+
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn canonical_json_is_sorted_and_compact() {
+    let value = serde_json::json!({
+        "round": 2,
+        "completed": false,
+        "nested": { "b": [1, "two", null], "a": "\u{1f}\"" },
+    });
+    let bytes = to_canonical_json(&value).unwrap();
+    assert_eq!(
+        String::from_utf8(bytes).unwrap(),
+        r#"{"completed":false,"nested":{"a":"\u001f\"","b":[1,"two",null]},"round":2}"#
+    );
+    assert!(to_canonical_json(&serde_json::json!({ "x": 1.5 })).is_err());
+}
+
+#[test]
+fn detached_signature_over_report() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let output = participants[0].get_dkg_output().unwrap();
+    let report = participants[0].status();
+    let signature = DetachedSignature::sign(
+        &report,
+        output.secret_share().value.0,
+        output.message_generator(),
+    )
+    .unwrap();
+    assert_eq!(signature.signer(), *output.verification_share(0).unwrap());
+    assert!(
+        signature
+            .verify(&report, output.message_generator())
+            .is_ok()
+    );
+
+    let mut tampered = report.clone();
+    tampered.invalid_participants.push(1);
+    assert!(
+        signature
+            .verify(&tampered, output.message_generator())
+            .is_err()
+    );
+
+    let encoded = serde_json::to_string(&signature).unwrap();
+    let decoded: DetachedSignature<G> = serde_json::from_str(&encoded).unwrap();
+    assert!(decoded.verify(&report, output.message_generator()).is_ok());
+}
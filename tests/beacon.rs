@@ -0,0 +1,65 @@
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+
+#[test]
+fn beacon_seeds_the_ceremony() {
+    type G = k256::ProjectivePoint;
+
+    let mut beacons = (0..3)
+        .map(|ordinal| RandomnessBeacon::new(ordinal, 3, DEFAULT_TRANSCRIPT_LABEL).unwrap())
+        .collect::<Vec<_>>();
+    assert!(beacons[0].reveal().is_err());
+
+    let commitments = beacons.iter().map(|b| b.commitment()).collect::<Vec<_>>();
+    for beacon in beacons.iter_mut() {
+        for commitment in &commitments {
+            if commitment.sender_ordinal != beacon.commitment().sender_ordinal {
+                beacon.receive_commitment(commitment).unwrap();
+            }
+        }
+    }
+    let reveals = beacons
+        .iter()
+        .map(|b| b.reveal().unwrap())
+        .collect::<Vec<_>>();
+
+    // A seed that doesn't match the commitment
+    let mut forged = reveals[1];
+    forged.seed[0] ^= 1;
+    assert!(beacons[0].receive_reveal(&forged).is_err());
+
+    for beacon in beacons.iter_mut() {
+        for reveal in &reveals {
+            if reveal.sender_ordinal != beacon.commitment().sender_ordinal {
+                beacon.receive_reveal(reveal).unwrap();
+            }
+        }
+    }
+    let output = beacons[0].output().unwrap();
+    for beacon in &beacons {
+        assert_eq!(beacon.output().unwrap(), output);
+    }
+
+    let ids = output.participant_ids::<k256::Scalar>(3);
+    let parameters = OwnedParameters::<G>::new(NonZeroUsize::new(2).unwrap(), None, ids.clone())
+        .with_session_id(&output.session_id());
+    let parameters = parameters.as_parameters();
+    let mut participants = ids
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "bls12_381_plus")]
+
+use bls12_381_plus::{G1Projective, G2Projective, Scalar};
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn threshold_bls_signature() {
+    let ids = (1..=4u64)
+        .map(|id| IdentifierPrimeField(Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G1Projective>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let message = b"threshold bls";
+    let shares = outputs
+        .iter()
+        .map(|output| output.bls_sign_share(message))
+        .collect::<Vec<_>>();
+    for share in &shares {
+        outputs[0].bls_verify_share(message, share).unwrap();
+    }
+
+    let signature = outputs[1].bls_aggregate(message, &shares[1..]).unwrap();
+    assert_eq!(
+        outputs[0].bls_aggregate(message, &shares[..3]).unwrap(),
+        signature
+    );
+    let public_key = outputs[0].public_key().get();
+    signature
+        .verify(public_key, outputs[0].message_generator(), message)
+        .unwrap();
+    assert!(
+        signature
+            .verify(public_key, outputs[0].message_generator(), b"other")
+            .is_err()
+    );
+
+    // Fewer shares than the threshold cannot sign
+    assert!(outputs[0].bls_aggregate(message, &shares[..2]).is_err());
+
+    // Duplicates do not count toward the threshold
+    let duplicated = [shares[0], shares[0], shares[1]];
+    assert!(outputs[0].bls_aggregate(message, &duplicated).is_err());
+
+    // A share over another message is refused
+    let mut bad = shares[..3].to_vec();
+    bad[2] = outputs[2].bls_sign_share(b"other");
+    assert!(outputs[0].bls_verify_share(message, &bad[2]).is_err());
+    assert!(outputs[0].bls_aggregate(message, &bad).is_err());
+
+    let bytes = postcard::to_stdvec(&shares[0]).unwrap();
+    let share: BlsSignatureShare = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(share.ordinal(), 0);
+    assert_ne!(share.signature(), G2Projective::IDENTITY);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "x509")]
+
+use frost_dkg::*;
+use rstest::*;
+
+fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    assert!(content.len() < 0x80);
+    let mut out = vec![tag, content.len() as u8];
+    out.extend_from_slice(content);
+    out
+}
+
+fn certificate(spki: &[u8], with_version: bool) -> Vec<u8> {
+    let algorithm = tlv(
+        0x30,
+        &tlv(0x06, &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02]),
+    );
+    let name = tlv(0x30, &tlv(0x31, &tlv(0x30, &[])));
+    let mut tbs = Vec::new();
+    if with_version {
+        tbs.extend(tlv(0xa0, &tlv(0x02, &[0x02])));
+    }
+    tbs.extend(tlv(0x02, &[0x01]));
+    tbs.extend(&algorithm);
+    tbs.extend(&name);
+    tbs.extend(tlv(0x30, &[]));
+    tbs.extend(&name);
+    tbs.extend_from_slice(spki);
+    let mut cert = tlv(0x30, &tbs);
+    cert.extend(&algorithm);
+    cert.extend(tlv(0x03, &[0x00, 0x01]));
+    tlv(0x30, &cert)
+}
+
+#[rstest]
+#[case::k256(k256::Scalar::ZERO)]
+#[case::p256(p256::Scalar::ZERO)]
+#[case::ed448(ed448_goldilocks_plus::Scalar::ZERO)]
+fn certificate_identity<F: ScalarHash>(#[case] _f: F) {
+    let spki = tlv(0x30, &[0x03, 0x03, 0x00, 0x04, 0x01]);
+    let other_spki = tlv(0x30, &[0x03, 0x03, 0x00, 0x04, 0x02]);
+
+    let identity = CertificateIdentity::<F>::from_der(&certificate(&spki, true)).unwrap();
+    assert_eq!(
+        identity,
+        CertificateIdentity::<F>::from_spki(&spki).unwrap()
+    );
+    assert!(identity.matches(&certificate(&spki, false)));
+    assert!(!identity.matches(&certificate(&other_spki, true)));
+    assert!(CertificateIdentity::<F>::from_der(&spki).is_err());
+}
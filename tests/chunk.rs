@@ -0,0 +1,88 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::default())]
+#[case::p256(p256::ProjectivePoint::default())]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::default())]
+fn chunked_ceremony<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    const QR_CHUNK_SIZE: usize = 64;
+
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    let parameters = Parameters::<G>::new(threshold, limit, None, None);
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut assemblers = vec![ChunkAssembler::new(); 3];
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                let mut chunks = output.to_chunks(QR_CHUNK_SIZE).unwrap();
+                assert!(chunks.iter().all(|c| c.len() <= QR_CHUNK_SIZE));
+                // Scanned out of order and with a repeat
+                chunks.reverse();
+                chunks.push(chunks[0].clone());
+                let assembler = &mut assemblers[output.dst_ordinal];
+                let mut message = None;
+                for chunk in &chunks {
+                    if let Some(m) = assembler.receive(chunk).unwrap() {
+                        message = Some(m);
+                    }
+                }
+                assert_eq!(message.as_deref(), Some(output.data.as_slice()));
+                participants[output.dst_ordinal]
+                    .receive(&message.unwrap())
+                    .unwrap();
+            }
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+}
+
+#[test]
+fn chunk_errors() {
+    let message = (0..=255u8).collect::<Vec<_>>();
+    assert!(chunk_message(&message, CHUNK_OVERHEAD).is_err());
+    assert!(chunk_message(&[], 32).is_err());
+
+    let chunks = chunk_message(&message, 32).unwrap();
+    assert_eq!(chunks.len(), message.len().div_ceil(32 - CHUNK_OVERHEAD));
+
+    let mut corrupted = chunks[1].to_bytes();
+    corrupted[CHUNK_OVERHEAD] ^= 1;
+    assert!(MessageChunk::from_bytes(&corrupted).is_err());
+
+    let mut assembler = ChunkAssembler::new();
+    assert!(assembler.receive(&chunks[2].to_bytes()).unwrap().is_none());
+    let missing = assembler.missing_chunks(chunks[2].message_id()).unwrap();
+    assert_eq!(missing.len(), chunks.len() - 1);
+    assert!(!missing.contains(&2));
+
+    let mut reassembled = None;
+    for chunk in chunks {
+        if let Some(m) = assembler.receive_chunk(chunk).unwrap() {
+            reassembled = Some(m);
+        }
+    }
+    assert_eq!(reassembled, Some(message));
+}
@@ -0,0 +1,143 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField,
+    elliptic_curve::{Field, group::GroupEncoding},
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn commit_reveal<G>(#[case] _g: G, #[values(false, true)] blinded: bool)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    )
+    .with_commit_reveal();
+    if blinded {
+        parameters = parameters.with_blinder_generator(G::random(&mut rand::rng()));
+    }
+    let new_participant = |id: u64| {
+        SecretParticipant::<G>::new_secret(IdentifierPrimeField(G::Scalar::from(id)), &parameters)
+            .unwrap()
+    };
+    let mut participants = (1..=3u64).map(new_participant).collect::<Vec<_>>();
+    let signing_keys = (0..3)
+        .map(|_| G::Scalar::random(&mut rand::rng()))
+        .collect::<Vec<_>>();
+    let sender_keys = signing_keys
+        .iter()
+        .map(|key| G::generator() * key)
+        .collect::<Vec<_>>();
+
+    // Round 1 can't run before committing to it
+    assert!(participants[0].run().is_err());
+    let commitments = participants
+        .iter_mut()
+        .zip(&signing_keys)
+        .map(|(p, key)| p.commit_round1(*key).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        participants[0]
+            .commit_round1(signing_keys[0])
+            .unwrap()
+            .commitment,
+        commitments[0].commitment
+    );
+
+    // A commitment spoofed in another participant's name is not stored
+    let mut spoofed = participants[2].commit_round1(signing_keys[2]).unwrap();
+    spoofed.sender_ordinal = 1;
+    assert!(
+        participants[0]
+            .receive_round1_commitment(&spoofed, &sender_keys[1])
+            .is_err()
+    );
+    assert!(participants[0].missing_round1_commitments().contains(&1));
+
+    // Nor before every commitment was received
+    participants[0]
+        .receive_round1_commitment(&commitments[1], &sender_keys[1])
+        .unwrap();
+    assert_eq!(participants[0].missing_round1_commitments(), vec![2]);
+    assert!(participants[0].run().is_err());
+    for participant in participants.iter_mut() {
+        for commitment in &commitments {
+            if commitment.sender_ordinal != participant.get_ordinal() {
+                participant
+                    .receive_round1_commitment(commitment, &sender_keys[commitment.sender_ordinal])
+                    .unwrap();
+            }
+        }
+        assert!(participant.missing_round1_commitments().is_empty());
+    }
+    // The sender can't change its commitment
+    let mut changed = participants[1].commit_round1(signing_keys[1]).unwrap();
+    changed.commitment = [0u8; 32];
+    assert!(
+        participants[0]
+            .receive_round1_commitment(&changed, &sender_keys[1])
+            .is_err()
+    );
+
+    // The commitments survive a snapshot
+    let restored =
+        SecretParticipant::<G>::import_state(&participants[0].export_state().unwrap()).unwrap();
+    assert!(restored.missing_round1_commitments().is_empty());
+    participants[0] = restored;
+
+    // A participant revealing other data than it committed to is rejected
+    let mut impostor = new_participant(3);
+    impostor.commit_round1(signing_keys[2]).unwrap();
+    for commitment in &commitments[..2] {
+        impostor
+            .receive_round1_commitment(commitment, &sender_keys[commitment.sender_ordinal])
+            .unwrap();
+    }
+    let generator = impostor.run().unwrap();
+    let output = generator.iter().find(|o| o.dst_ordinal == 0).unwrap();
+    let mut checked = participants[0].clone();
+    assert!(checked.receive(&output.data).is_err());
+    // Anyone can send data under the ordinal so the sender is not blamed
+    assert!(checked.get_invalid_participant_ids().is_empty());
+
+    // Round 2 waits for every committed reveal, the threshold is not enough
+    let mut early = participants[..2].to_vec();
+    early[0].run().unwrap();
+    let generator = early[1].run().unwrap();
+    let output = generator.iter().find(|o| o.dst_ordinal == 0).unwrap();
+    early[0].receive(&output.data).unwrap();
+    assert_eq!(early[0].missing_round1_reveals(), vec![2]);
+    assert_eq!(early[0].round_requirements().required, vec![2]);
+    assert!(early[0].run().is_err());
+
+    // Pedersen commitments are opened in an extra run of round 3
+    while !participants.iter().all(SecretParticipant::completed) {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
@@ -0,0 +1,89 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn compact_round1<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(5).unwrap(),
+        NonZeroUsize::new(6).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=6u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for participant in participants.iter_mut() {
+        participant.run().unwrap();
+    }
+
+    // Broadcast the digests and keep the commitments on a relay
+    let relay = participants
+        .iter()
+        .map(|p| {
+            let full = p.get_received_round1_data()[&p.get_ordinal()].clone();
+            let compact = p.compact_round1().unwrap();
+            assert!(
+                postcard::to_stdvec(&compact).unwrap().len()
+                    < postcard::to_stdvec(&full).unwrap().len()
+            );
+            (compact, full.feldman_commitments().to_vec())
+        })
+        .collect::<Vec<_>>();
+
+    // Commitments that don't match the digest are refused
+    let mut tampered = relay[1].1.clone();
+    tampered.swap(1, 2);
+    assert!(relay[1].0.expand(&tampered).is_err());
+    assert!(
+        participants[0]
+            .receive_compact_round1(&relay[1].0, &tampered)
+            .is_err()
+    );
+    assert_eq!(participants[0].peer_status(1), Some(PeerStatus::Pending));
+
+    for participant in participants.iter_mut() {
+        for (compact, commitments) in &relay {
+            if compact.sender_ordinal() != participant.get_ordinal() {
+                participant
+                    .receive_compact_round1(compact, commitments)
+                    .unwrap();
+            }
+        }
+    }
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    for participant in participants.iter_mut() {
+        participant.run().unwrap();
+    }
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
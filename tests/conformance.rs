@@ -0,0 +1,102 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::conformance::{check_round1_bytes, check_round1_opening_bytes, check_round2_bytes};
+use frost_dkg::*;
+use rstest::*;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn conformance<G>(#[case] _g: G, #[values(false, true)] blinded: bool)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    if blinded {
+        parameters = parameters.with_blinder_generator(G::random(&mut rand::rng()));
+    }
+    let other = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let mut round1 = BTreeMap::new();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            let data = check_round1_bytes(&output.data, &parameters).unwrap();
+            assert_eq!(data.sender_ordinal(), sender);
+            assert!(check_round1_bytes(&output.data, &other).is_err());
+            // The signature is the last field
+            let mut tampered = output.data.clone();
+            *tampered.last_mut().unwrap() ^= 1;
+            assert!(check_round1_bytes(&tampered, &parameters).is_err());
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+            round1.insert(sender, data);
+        }
+    }
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            let share = check_round2_bytes(&output.data, &parameters, &round1[&sender]).unwrap();
+            assert_eq!(share.secret_share().identifier, output.dst_id);
+            // Checked against the commitments of another dealer
+            let wrong = &round1[&((sender + 1) % 3)];
+            assert!(check_round2_bytes(&output.data, &parameters, wrong).is_err());
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    if !blinded {
+        return;
+    }
+
+    // The pedersen commitments are opened in round 3
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            let opening =
+                check_round1_opening_bytes(&output.data, &parameters, &round1[&sender]).unwrap();
+            assert_eq!(opening.sender_ordinal(), sender);
+            let wrong = &round1[&((sender + 1) % 3)];
+            assert!(check_round1_opening_bytes(&output.data, &parameters, wrong).is_err());
+            let mut tampered = output.data.clone();
+            *tampered.last_mut().unwrap() ^= 1;
+            assert!(check_round1_opening_bytes(&tampered, &parameters, &round1[&sender]).is_err());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorType, elliptic_curve::group::GroupEncoding,
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn key_continuity<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    let ids = (1..=3u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rand::rng()).unwrap();
+    let previous_key = outputs[0].public_key();
+    let parameters = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+
+    // A refresh keeps the key
+    let mut refresh = outputs
+        .iter()
+        .map(|output| {
+            RefreshParticipant::<G>::new_refresh(
+                output.id(),
+                Some(output.secret_share().value.0),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    run(&mut refresh);
+    let proof = refresh[0]
+        .continuity_proof(previous_key, KeyContinuity::Preserved)
+        .unwrap();
+    assert_eq!(proof.public_key(), previous_key.get());
+    assert_eq!(proof.contributions().len(), 3);
+    assert_eq!(
+        proof.transcript_hash(),
+        refresh[0].get_final_transcript_hash_bytes()
+    );
+    assert!(
+        refresh[1]
+            .continuity_proof(previous_key, KeyContinuity::Fresh)
+            .is_err()
+    );
+
+    // Auditors check the exported proof on its own
+    let bytes = postcard::to_stdvec(&proof).unwrap();
+    let restored: ContinuityProof<G> = postcard::from_bytes(&bytes).unwrap();
+    restored.verify().unwrap();
+
+    // A fresh key generation replaces the key
+    let mut fresh = ids
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+    run(&mut fresh);
+    let proof = fresh[0]
+        .continuity_proof(previous_key, KeyContinuity::Fresh)
+        .unwrap();
+    assert_eq!(proof.public_key(), fresh[0].get_public_key().unwrap().get());
+    assert!(
+        fresh[0]
+            .continuity_proof(previous_key, KeyContinuity::Preserved)
+            .is_err()
+    );
+    let new_key = fresh[0].get_public_key().unwrap();
+    assert!(
+        fresh[1]
+            .continuity_proof(new_key, KeyContinuity::Fresh)
+            .is_err()
+    );
+}
+
+fn run<I, G>(participants: &mut [Participant<I, G>])
+where
+    I: ParticipantImpl<G> + Default,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+}
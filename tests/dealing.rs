@@ -0,0 +1,86 @@
+use elliptic_curve::{Field, subtle::ConditionallySelectable};
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rand_chacha::ChaCha8Rng;
+use rand_core::SeedableRng;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn precommitted_polynomials<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let mut rng = ChaCha8Rng::from_seed([5u8; 32]);
+
+    // Commit to the polynomials before the roster is known
+    let polynomials = (0..3)
+        .map(|_| DealerPolynomial::<G>::random(threshold, G::Scalar::random(&mut rng), &mut rng))
+        .collect::<Vec<_>>();
+    let commitments = polynomials
+        .iter()
+        .map(|polynomial| polynomial.commitments(G::generator()))
+        .collect::<Vec<_>>();
+
+    let parameters = Parameters::<G>::new(threshold, NonZeroUsize::new(3).unwrap(), None, None);
+    let ids = parameters.roster_ids().unwrap();
+    assert_eq!(locate_in_roster(ids[2], &ids).unwrap(), 2);
+    assert!(locate_in_roster(IdentifierPrimeField(G::Scalar::from(9u64)), &ids).is_err());
+    assert!(polynomials[0].deal(&[ids[0], ids[0]]).is_err());
+    assert_eq!(polynomials[0].deal(&ids).unwrap().len(), 3);
+
+    let mut participants = ids
+        .iter()
+        .zip(&polynomials)
+        .map(|(id, polynomial)| {
+            SecretParticipant::<G>::with_polynomial(*id, &parameters, polynomial).unwrap()
+        })
+        .collect::<Vec<_>>();
+    for (participant, commitments) in participants.iter().zip(&commitments) {
+        assert_eq!(participant.get_feldman_verifiers(), commitments.to_vec());
+    }
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    let public_key = commitments
+        .iter()
+        .fold(G::identity(), |acc, commitments| acc + commitments[0]);
+    for participant in &participants {
+        assert_eq!(participant.get_public_key().unwrap().get(), public_key);
+    }
+
+    // The degree must match the parameters and refreshes must share zero
+    let short = DealerPolynomial::<G>::from_coefficients(&[G::Scalar::ONE]);
+    assert!(SecretParticipant::<G>::with_polynomial(ids[0], &parameters, &short).is_err());
+    let refresh = DealerPolynomial::<G>::random(threshold, G::Scalar::ZERO, &mut rng);
+    assert!(
+        RefreshParticipant::<G>::new_refresh_with_polynomial(ids[0], None, &parameters, &refresh)
+            .is_ok()
+    );
+    assert!(
+        RefreshParticipant::<G>::new_refresh_with_polynomial(
+            ids[0],
+            None,
+            &parameters,
+            &polynomials[0]
+        )
+        .is_err()
+    );
+}
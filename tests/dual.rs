@@ -0,0 +1,101 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, ReadableShareSet, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn dual_output<G>(#[case] _g: G, #[values(false, true)] blinded: bool)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    if blinded {
+        parameters = parameters.with_blinder_generator(G::random(&mut rand::rng()));
+    }
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            DualParticipant::<G>::new(IdentifierPrimeField(G::Scalar::from(id)), &parameters)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Pedersen commitments are opened in an extra run of round 3
+    while !participants.iter().all(DualParticipant::completed) {
+        let round = participants[0].get_round();
+        let outputs = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        if round == Round::Two {
+            // The shares of one key are not accepted for the other
+            let output = &outputs[0][0];
+            let mut swapped = participants[output.dst_ordinal].clone();
+            let mut halves = signing_and_encryption(&output.data);
+            halves.reverse();
+            assert!(swapped.receive(&join(&halves)).is_err());
+            // Truncated messages are rejected
+            assert!(swapped.receive(&output.data[..3]).is_err());
+        }
+        for outputs in &outputs {
+            for output in outputs {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let outputs = participants
+        .iter()
+        .map(|p| {
+            assert!(p.completed());
+            p.get_dkg_output().unwrap()
+        })
+        .collect::<Vec<_>>();
+    let signing_key = outputs[0].signing().public_key();
+    let encryption_key = outputs[0].encryption().public_key();
+    assert_ne!(signing_key, encryption_key);
+    assert_ne!(
+        outputs[0].signing().key_id(),
+        outputs[0].encryption().key_id()
+    );
+    for output in &outputs {
+        assert_eq!(output.signing().public_key(), signing_key);
+        assert_eq!(output.encryption().public_key(), encryption_key);
+    }
+
+    let (signing, encryption): (Vec<_>, Vec<_>) = outputs
+        .into_iter()
+        .map(|output| {
+            let (signing, encryption) = output.into_parts();
+            (signing.secret_share(), encryption.secret_share())
+        })
+        .unzip();
+    let secret = signing[..2].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *signing_key);
+    let secret = encryption[1..].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *encryption_key);
+}
+
+fn signing_and_encryption(data: &[u8]) -> Vec<Vec<u8>> {
+    let length = u32::from_be_bytes(data[..4].try_into().unwrap()) as usize;
+    vec![data[4..4 + length].to_vec(), data[4 + length..].to_vec()]
+}
+
+fn join(halves: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = (halves[0].len() as u32).to_be_bytes().to_vec();
+    bytes.extend_from_slice(&halves[0]);
+    bytes.extend_from_slice(&halves[1]);
+    bytes
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "eth2")]
+
+use bls12_381_plus::{G1Affine, G1Projective};
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn eip2335_keystore_round_trip() {
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(bls12_381_plus::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G1Projective>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let output = &outputs[0];
+    assert_eq!(
+        output.eth2_public_key().to_vec(),
+        output.public_key_bytes(PointEncoding::Compressed).unwrap()
+    );
+    assert_eq!(
+        output.eth2_share_public_key().to_vec(),
+        G1Affine::from(output.verification_share(output.ordinal()).unwrap().get())
+            .to_compressed()
+            .to_vec()
+    );
+
+    let keystore = output.eip2335_keystore("correct horse", "").unwrap();
+    let json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+    assert_eq!(json["version"], 4);
+    assert_eq!(json["pubkey"], hex::encode(output.eth2_share_public_key()));
+
+    let secret = decrypt_eip2335_keystore(&keystore, "correct horse").unwrap();
+    assert_eq!(secret, output.secret_share().value.0);
+    assert!(decrypt_eip2335_keystore(&keystore, "battery staple").is_err());
+}
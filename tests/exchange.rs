@@ -0,0 +1,61 @@
+use frost_dkg::*;
+use rand_core::Rng;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn air_gapped_file_exchange() {
+    type G = k256::ProjectivePoint;
+
+    let mut suffix = [0u8; 8];
+    rand::rng().fill_bytes(&mut suffix);
+    let dir = std::env::temp_dir().join(format!("frost-dkg-exchange-{}", hex::encode(suffix)));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        for participant in participants.iter_mut() {
+            let generator = participant.run().unwrap();
+            let written = participant.write_outbox(&generator, &dir).unwrap();
+            assert_eq!(written.len(), generator.iter().count());
+        }
+        for participant in participants.iter_mut() {
+            let expected = if participant.completed() { 0 } else { 2 };
+            assert_eq!(participant.read_inbox(&dir).unwrap().len(), expected);
+            // Reading again skips messages that were already received
+            assert!(participant.read_inbox(&dir).unwrap().is_empty());
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+
+    // A tampered file is rejected
+    let path = dir.join("round1-from0-to1.frost");
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+    let mut participant = SecretParticipant::<G>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(2u64)),
+        &parameters,
+    )
+    .unwrap();
+    participant.run().unwrap();
+    assert!(participant.read_inbox(&dir).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,59 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn fixed_topology<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    type Fixed<G> = FixedSecretParticipant<G, 2, 3>;
+
+    let ids: [IdentifierPrimeField<G::Scalar>; 3] =
+        std::array::from_fn(|i| IdentifierPrimeField(G::Scalar::from(i as u64 + 1)));
+    let mut participants = ids.map(|id| Fixed::<G>::new_secret(id, &ids).unwrap());
+    assert_eq!(participants[0].participant_ids(), ids);
+    assert_eq!(Fixed::<G>::ROUND1_COMMITMENTS, 2);
+    assert_eq!(Fixed::<G>::ROUND2_RECIPIENTS, 2);
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let verification_shares = participants[0].verification_shares().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.feldman_commitments().len(), 2);
+        assert_eq!(
+            participant.verification_shares().unwrap(),
+            verification_shares
+        );
+    }
+
+    // A participant of another topology is refused
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let participant = SecretParticipant::<G>::new_secret(ids[0], &parameters).unwrap();
+    assert!(Fixed::<G>::try_from(participant).is_err());
+}
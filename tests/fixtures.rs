@@ -0,0 +1,116 @@
+#![cfg(feature = "fixtures")]
+
+use frost_dkg::*;
+use k256::elliptic_curve::group::GroupEncoding;
+use std::num::NonZeroUsize;
+
+#[test]
+fn interop_vectors_round_trip() {
+    let vectors =
+        InteropVectors::generate(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3).unwrap())
+            .unwrap();
+    assert_eq!(vectors.round1_messages.len(), 3);
+    assert_eq!(vectors.round2_messages.len(), 6);
+    assert!(vectors.verify().is_ok());
+
+    let bytes = postcard::to_stdvec(&vectors).unwrap();
+    let decoded: InteropVectors = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, vectors);
+    assert!(decoded.verify().is_ok());
+}
+
+#[test]
+fn interop_vectors_reject_tampering() {
+    let vectors =
+        InteropVectors::generate(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3).unwrap())
+            .unwrap();
+
+    let mut bad_result = vectors.clone();
+    bad_result.results.swap(0, 1);
+    assert!(bad_result.verify().is_err());
+
+    let mut bad_message = vectors.clone();
+    bad_message.round2_messages[0].data = bad_message.round2_messages[1].data.clone();
+    assert!(bad_message.verify().is_err());
+
+    let mut bad_key = vectors.clone();
+    bad_key.group_key = vectors.results[0].verification_share.clone();
+    assert!(bad_key.verify().is_err());
+}
+
+const VECTORS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors/secp256k1.json");
+
+fn checked_in_vectors() -> InteropVectors {
+    serde_json::from_str(include_str!("vectors/secp256k1.json")).unwrap()
+}
+
+/// Rewrites the checked in vectors, run with `--ignored` after a wire change
+#[test]
+#[ignore]
+fn write_interop_vectors() {
+    let vectors =
+        InteropVectors::generate(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3).unwrap())
+            .unwrap();
+    std::fs::write(
+        VECTORS_PATH,
+        serde_json::to_string_pretty(&vectors).unwrap() + "\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn interop_vectors_are_deterministic() {
+    let vectors = checked_in_vectors();
+    assert!(vectors.verify().is_ok());
+
+    let generated =
+        InteropVectors::generate(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3).unwrap())
+            .unwrap();
+    assert_eq!(generated.participant_ids, vectors.participant_ids);
+    assert_eq!(generated.results, vectors.results);
+    assert_eq!(generated.group_key, vectors.group_key);
+}
+
+#[test]
+fn interop_vectors_replay() {
+    let vectors = checked_in_vectors();
+    assert_eq!(vectors.round1_states.len(), vectors.limit);
+
+    let mut participants = vectors
+        .round1_states
+        .iter()
+        .map(|state| {
+            SecretParticipant::<k256::ProjectivePoint>::import_state(&hex::decode(state).unwrap())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for (ordinal, participant) in participants.iter_mut().enumerate() {
+        for (sender, message) in vectors.round1_messages.iter().enumerate() {
+            if sender != ordinal {
+                participant.receive(&hex::decode(message).unwrap()).unwrap();
+            }
+        }
+        // The shares this participant deals were recorded with the vectors
+        participant.run().unwrap();
+    }
+    for message in &vectors.round2_messages {
+        participants[message.receiver]
+            .receive(&hex::decode(&message.data).unwrap())
+            .unwrap();
+    }
+
+    for (participant, result) in participants.iter_mut().zip(&vectors.results) {
+        participant.run().unwrap();
+        assert!(participant.completed());
+        let share = participant.get_secret_share().unwrap();
+        assert_eq!(hex::encode(share.value.0.to_bytes()), result.secret_share);
+        assert_eq!(
+            hex::encode((k256::ProjectivePoint::GENERATOR * share.value.0).to_bytes()),
+            result.verification_share
+        );
+        assert_eq!(
+            hex::encode(participant.get_public_key().unwrap().to_bytes()),
+            vectors.group_key
+        );
+    }
+}
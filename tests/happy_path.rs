@@ -1,4 +1,7 @@
-use elliptic_curve::{Field, subtle::ConditionallySelectable};
+use elliptic_curve::{
+    Field,
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+};
 use elliptic_curve_tools::SumOfProducts;
 use frost_dkg::*;
 use rand_chacha::ChaCha8Rng;
@@ -19,7 +22,7 @@ use vsss_rs::{
 #[case::curve25519_dalek_edwards(curve25519_dalek::EdwardsPoint::default())]
 fn static_init_dkg<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let rng = ChaCha8Rng::from_seed([0u8; 32]);
@@ -32,7 +35,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
 fn static_add_participant_same_threshold<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 3;
@@ -45,7 +48,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
 fn static_add_participant_increase_threshold<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 5;
@@ -58,7 +61,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
 fn static_remove_participant_same_threshold<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 3;
@@ -71,7 +74,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::default())]
 fn static_remove_participant_decrease_threshold<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 2;
@@ -86,7 +89,7 @@ fn static_add_and_remove_participant_increase_participant<G>(
     #[case] _g: G,
     #[case] threshold: usize,
 ) where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     static_five_participants_add_and_remove_increase_participant::<G>(threshold);
@@ -100,7 +103,7 @@ fn static_add_and_remove_participant_decrease_participant<G>(
     #[case] _g: G,
     #[case] threshold: usize,
 ) where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     static_five_participants_add_and_remove_decrease_participant::<G>(threshold);
@@ -108,7 +111,7 @@ fn static_add_and_remove_participant_decrease_participant<G>(
 
 fn static_five_participants_add_participant<G>(threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const LIMIT: usize = 5;
@@ -206,7 +209,7 @@ where
 
 fn static_five_participants_remove_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const LIMIT: usize = 3;
@@ -284,7 +287,7 @@ where
 
 fn static_five_participants_add_and_remove_decrease_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const LIMIT: usize = 3;
@@ -364,7 +367,7 @@ where
 
 fn static_five_participants_add_and_remove_increase_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const LIMIT: usize = 3;
@@ -449,7 +452,7 @@ fn static_numbering_init_dkg<G>(
     mut rng: impl Rng,
 ) -> (Vec<Box<dyn AnyParticipant<G>>>, <G as Group>::Scalar)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 3;
@@ -511,7 +514,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
 fn init_dkg<G>(#[case] _g: G)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_init::<G>();
@@ -523,7 +526,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
 fn refresh<G>(#[case] _g: G)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 3;
@@ -585,11 +588,11 @@ where
         receive(&mut participants, &round_generators);
     }
 
-    for i in 1..participants.len() {
-        assert_eq!(
-            participants[i - 1].get_public_key().unwrap(),
-            participants[i].get_public_key().unwrap()
-        );
+    // A pure refresh aggregates to the identity so there is no public key
+    for participant in participants.iter() {
+        assert!(participant.completed());
+        assert!(participant.get_public_key().is_none());
+        assert!(participant.get_dkg_output().is_none());
     }
 
     let shares = participants
@@ -604,20 +607,339 @@ where
     assert_eq!(new_secret.0.is_zero().unwrap_u8(), 1);
 
     let actual_pk = G::generator() * *new_secret;
+    assert_eq!(actual_pk.is_identity().unwrap_u8(), 1u8);
 
-    assert_eq!(participants[0].get_public_key().unwrap(), actual_pk);
+    // Old shared secret remains unchanged
+    assert_eq!(secret + *new_secret, secret);
+}
 
-    assert_eq!(
-        participants[0]
-            .get_public_key()
-            .unwrap()
-            .is_identity()
-            .unwrap_u8(),
-        1u8
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn refresh_share_continuity<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(3).unwrap();
+    let limit = NonZeroUsize::new(4).unwrap();
+    let ids = (1..=4u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rand::rng()).unwrap();
+    let seq = vec![ParticipantIdGeneratorType::list(&ids)];
+    let parameters = Parameters::<G>::new(threshold, limit, None, Some(seq))
+        .with_previous_verification_shares(outputs[0].verification_shares());
+
+    // Starting from a share that is not the previous one is refused
+    let res = RefreshParticipant::<G>::new_refresh(
+        ids[1],
+        Some(outputs[0].secret_share().value.0),
+        &parameters,
     );
+    assert!(matches!(res, Err(Error::ShareContinuity(1))));
 
-    // Old shared secret remains unchanged
-    assert_eq!(secret + *new_secret, secret);
+    let mut participants = outputs
+        .iter()
+        .map(|output| {
+            Box::new(
+                RefreshParticipant::<G>::new_refresh(
+                    output.id(),
+                    Some(output.secret_share().value.0),
+                    &parameters,
+                )
+                .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    // The round 1 signatures prove the old shares without giving them away
+    let round_generators = next_round(&mut participants);
+    for (generator, output) in round_generators.iter().zip(&outputs) {
+        assert_share_hidden(&parameters, generator, output.secret_share().value.0);
+    }
+    receive(&mut participants, &round_generators);
+    for _ in [Round::Two, Round::Three] {
+        let round_generators = next_round(&mut participants);
+        receive(&mut participants, &round_generators);
+    }
+
+    let refreshed = participants[0].get_refreshed_verification_shares().unwrap();
+    for (participant, output) in participants.iter().zip(&outputs) {
+        assert_eq!(
+            participant.get_refreshed_verification_shares().as_ref(),
+            Some(&refreshed)
+        );
+        let share = *output.secret_share().value + *participant.get_secret_share().unwrap().value;
+        assert_eq!(
+            refreshed[&participant.get_ordinal()],
+            G::generator() * share
+        );
+    }
+
+    // Peers reject a participant that starts from the wrong share
+    let unchecked = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    let mut checked = RefreshParticipant::<G>::new_refresh(
+        ids[0],
+        Some(outputs[0].secret_share().value.0),
+        &parameters,
+    )
+    .unwrap();
+    let mut impostor = RefreshParticipant::<G>::new_refresh(
+        ids[1],
+        Some(G::Scalar::random(&mut rand::rng())),
+        &unchecked,
+    )
+    .unwrap();
+    let generator = impostor.run().unwrap();
+    let output = generator.iter().find(|o| o.dst_ordinal == 0).unwrap();
+    assert!(matches!(
+        checked.receive(&output.data),
+        Err(Error::ShareContinuity(1))
+    ));
+    // Round 1 data is not attributable so the real participant 1 is not blamed
+    assert!(checked.get_invalid_participant_ids().is_empty());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn same_roster_rotation<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(3).unwrap();
+    let limit = NonZeroUsize::new(4).unwrap();
+    let ids = (1..=4u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rand::rng()).unwrap();
+    let old_key = outputs[0].public_key();
+    let seq = vec![ParticipantIdGeneratorType::list(&ids)];
+    let parameters = Parameters::<G>::new(threshold, limit, None, Some(seq))
+        .with_previous_verification_shares(outputs[0].verification_shares());
+
+    // The share ids must be the roster
+    let res = RefreshParticipant::<G>::with_same_roster_share(
+        &outputs[0].secret_share(),
+        &parameters,
+        &ids[..3],
+    );
+    assert!(matches!(res, Err(Error::Initialization(_))));
+    // Continuity can't be checked without the previous verification shares
+    let unchecked = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    let res = RefreshParticipant::<G>::with_same_roster_share(
+        &outputs[0].secret_share(),
+        &unchecked,
+        &ids,
+    );
+    assert!(matches!(res, Err(Error::Initialization(_))));
+
+    let mut participants = outputs
+        .iter()
+        .map(|output| {
+            Box::new(
+                RefreshParticipant::<G>::with_same_roster_share(
+                    &output.secret_share(),
+                    &parameters,
+                    &ids,
+                )
+                .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    assert!(participants[0].get_refreshed_secret_share().is_none());
+    // Each shareholder signs round 1 with its old share without revealing it
+    let round_generators = next_round(&mut participants);
+    for (generator, output) in round_generators.iter().zip(&outputs) {
+        assert_share_hidden(&parameters, generator, output.secret_share().value.0);
+    }
+    receive(&mut participants, &round_generators);
+    for _ in [Round::Two, Round::Three] {
+        let round_generators = next_round(&mut participants);
+        receive(&mut participants, &round_generators);
+    }
+
+    let refreshed = participants[0].get_refreshed_verification_shares().unwrap();
+    let shares = participants
+        .iter()
+        .zip(&outputs)
+        .map(|(participant, output)| {
+            let share = participant.get_refreshed_secret_share().unwrap();
+            assert_eq!(share.identifier, output.id());
+            assert_ne!(share.value, output.secret_share().value);
+            assert_eq!(
+                refreshed[&participant.get_ordinal()],
+                G::generator() * *share.value
+            );
+            share
+        })
+        .collect::<Vec<_>>();
+    let secret = shares[1..].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *old_key);
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn handoff_to_successor_committee<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let dealer_ids = (1..=3u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &dealer_ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let successor_ids = (10..=13u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let threshold = NonZeroUsize::new(3).unwrap();
+    let parameters =
+        Parameters::<G>::handoff(threshold, None, &dealer_ids, &successor_ids).unwrap();
+    assert_eq!(parameters.handoff_dealers(), 3);
+
+    let mut participants = outputs
+        .iter()
+        .map(|output| {
+            Box::new(
+                SecretParticipant::<G>::handoff_dealer(&output.secret_share(), &parameters)
+                    .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .chain(successor_ids.iter().map(|id| {
+            Box::new(RefreshParticipant::<G>::handoff_successor(*id, &parameters).unwrap())
+                as Box<dyn AnyParticipant<G>>
+        }))
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let round_generators = next_round(&mut participants);
+        receive(&mut participants, &round_generators);
+    }
+
+    let old_key = outputs[0].public_key();
+    for dealer in &participants[..3] {
+        assert!(dealer.completed());
+        assert!(dealer.get_secret_share().is_none());
+        assert!(dealer.get_public_key().is_none());
+    }
+    let shares = participants[3..]
+        .iter()
+        .map(|successor| {
+            assert_eq!(successor.get_public_key().unwrap(), old_key);
+            let output = successor.get_dkg_output().unwrap();
+            assert_eq!(output.participant_ids().len(), 4);
+            assert_eq!(output.contribution_public_keys().len(), 7);
+            output.verify_contributions().unwrap();
+            successor.get_secret_share().unwrap()
+        })
+        .collect::<Vec<_>>();
+    let secret = shares[..3].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *old_key);
+
+    // The committees must not share an id
+    assert!(Parameters::<G>::handoff(threshold, None, &dealer_ids, &dealer_ids[..1]).is_err());
+    // A dealer share can't be used outside a handoff
+    let plain = Parameters::<G>::new(threshold, NonZeroUsize::new(3).unwrap(), None, None);
+    assert!(SecretParticipant::<G>::handoff_dealer(&outputs[0].secret_share(), &plain).is_err());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn reshare_with_changed_ids<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let id = |i: u64| IdentifierPrimeField(G::Scalar::from(i));
+    let old_ids = [id(1), id(2), id(3)];
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &old_ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+
+    // The holder of share 2 rejoins as 1, the holder of share 1 as 20 and 4 is new
+    let new_ids = [id(1), id(20), id(4)];
+    let mapping = [(id(2), id(1)), (id(1), id(20))];
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    let seq = vec![ParticipantIdGeneratorType::list(&new_ids)];
+    let parameters =
+        Parameters::<G>::new(threshold, limit, None, Some(seq)).with_reshare_mapping(&mapping);
+    assert!(parameters.validate().is_empty());
+    let context = parameters.signed_context().unwrap();
+    assert_eq!(context.reshare_mapping()[&0], id(2));
+    assert_eq!(context.reshare_mapping()[&1], id(1));
+
+    // Unmapped participants can't deal a share and mapped ones must
+    assert!(SecretParticipant::<G>::reshare(&outputs[2].secret_share(), &parameters).is_err());
+    assert!(RefreshParticipant::<G>::new_refresh(id(1), None, &parameters).is_err());
+
+    let mut participants: Vec<Box<dyn AnyParticipant<G>>> = vec![
+        Box::new(SecretParticipant::<G>::reshare(&outputs[1].secret_share(), &parameters).unwrap()),
+        Box::new(SecretParticipant::<G>::reshare(&outputs[0].secret_share(), &parameters).unwrap()),
+        Box::new(RefreshParticipant::<G>::new_refresh(id(4), None, &parameters).unwrap()),
+    ];
+    assert_eq!(participants[0].get_id(), id(1));
+    assert_eq!(participants[1].get_id(), id(20));
+    let reshared = run_local_ceremony(&mut participants).unwrap();
+    for output in &reshared {
+        assert_eq!(output.public_key(), outputs[0].public_key());
+    }
+
+    // Peers refuse a dealer that disagrees on the mapping
+    let swapped = [(id(1), id(1)), (id(2), id(20))];
+    let seq = vec![ParticipantIdGeneratorType::list(&new_ids)];
+    let other =
+        Parameters::<G>::new(threshold, limit, None, Some(seq)).with_reshare_mapping(&swapped);
+    let mut honest =
+        SecretParticipant::<G>::reshare(&outputs[1].secret_share(), &parameters).unwrap();
+    let mut dissenter =
+        SecretParticipant::<G>::reshare(&outputs[1].secret_share(), &other).unwrap();
+    honest.run().unwrap();
+    let generator = dissenter.run().unwrap();
+    let output = generator.iter().find(|o| o.dst_ordinal == 0).unwrap();
+    assert!(honest.receive(&output.data).is_err());
+
+    // Entries that repeat an id or map outside the roster are reported
+    let seq = vec![ParticipantIdGeneratorType::list(&new_ids)];
+    let invalid = Parameters::<G>::new(threshold, limit, None, Some(seq)).with_reshare_mapping(&[
+        (id(1), id(1)),
+        (id(1), id(20)),
+        (id(3), id(30)),
+    ]);
+    assert_eq!(
+        invalid.validate(),
+        vec![
+            ParameterDiagnostic::InvalidReshareMapping { index: 1 },
+            ParameterDiagnostic::InvalidReshareMapping { index: 2 },
+        ]
+    );
+    assert!(SecretParticipant::<G>::reshare(&outputs[0].secret_share(), &invalid).is_err());
 }
 
 #[rstest]
@@ -626,7 +948,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 3)]
 fn add_participant_same_threshold<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_add_participant::<G>(threshold);
@@ -639,7 +961,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 5)]
 fn add_participant_increase_threshold<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_add_participant::<G>(threshold);
@@ -652,7 +974,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 3)]
 fn remove_participant_same_threshold<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_remove_participant::<G>(threshold);
@@ -665,7 +987,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 2)]
 fn remove_participant_decrease_threshold<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_remove_participant::<G>(threshold);
@@ -677,7 +999,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 5)]
 fn add_and_remove_participant_increase_participant<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_add_and_remove_increase_participant::<G>(threshold);
@@ -689,7 +1011,7 @@ where
 #[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY, 3)]
 fn add_and_remove_participant_decrease_participant<G>(#[case] _g: G, #[case] threshold: usize)
 where
-    G: SumOfProducts + GroupEncoding + Default + ConditionallySelectable,
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     five_participants_add_and_remove_decrease_participant::<G>(threshold);
@@ -697,7 +1019,7 @@ where
 
 fn five_participants_init<G>() -> (Vec<Box<dyn AnyParticipant<G>>>, <G as Group>::Scalar)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     const THRESHOLD: usize = 3;
@@ -745,6 +1067,32 @@ where
         G::generator() * *secret
     );
 
+    // every participant agrees on the verification shares
+    let output = participants[0].get_dkg_output().unwrap();
+    for participant in participants.iter() {
+        let other = participant.get_dkg_output().unwrap();
+        assert_eq!(other.public_key(), output.public_key());
+        assert_eq!(other.verification_shares(), output.verification_shares());
+        assert_eq!(
+            other.verification_share(other.ordinal()).unwrap(),
+            G::generator() * other.secret_share().value.0
+        );
+        // and on what each dealer contributed to the group key
+        assert_eq!(
+            other.contribution_public_keys(),
+            output.contribution_public_keys()
+        );
+        assert_eq!(
+            other.contribution_public_key(other.ordinal()),
+            Some(participant.get_feldman_verifiers()[0])
+        );
+        assert_eq!(
+            participant.get_contribution_public_key(other.ordinal()),
+            other.contribution_public_key(other.ordinal())
+        );
+        other.verify_contributions().unwrap();
+    }
+
     // publicly verify secret sharing DKG result
     for participant in participants.iter() {
         let round1_data: Vec<Round1Data<G>> = participant
@@ -756,7 +1104,7 @@ where
             publicly_verify_dkg_results(
                 &round1_data,
                 &parameters,
-                participant.get_public_key().unwrap(),
+                *participant.get_public_key().unwrap(),
             )
             .is_ok()
         );
@@ -767,7 +1115,7 @@ where
 
 fn five_participants_add_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let (participants, secret) = five_participants_init::<G>();
@@ -884,7 +1232,7 @@ where
 
 fn five_participants_remove_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let (participants, secret) = five_participants_init::<G>();
@@ -963,7 +1311,7 @@ where
 
 fn five_participants_add_and_remove_decrease_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let (participants, secret) = five_participants_init::<G>();
@@ -1049,7 +1397,7 @@ where
 
 fn five_participants_add_and_remove_increase_participant<G>(threshold: usize)
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let (participants, secret) = five_participants_init::<G>();
@@ -1146,15 +1494,18 @@ where
     // Old shared secret remains unchanged
     assert_eq!(secret, *new_secret);
 
-    let transcript_hash = participants[0].get_final_transcript_hash();
+    let transcript_hash = participants[0].get_final_transcript_hash_bytes();
     for participant in participants.iter().skip(1) {
-        assert_eq!(participant.get_final_transcript_hash(), transcript_hash);
+        assert_eq!(
+            participant.get_final_transcript_hash_bytes(),
+            transcript_hash
+        );
     }
 }
 
 fn next_round<G>(participants: &mut [Box<dyn AnyParticipant<G>>]) -> Vec<RoundOutputGenerator<G>>
 where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     let mut round_generators = Vec::with_capacity(participants.len());
@@ -1169,7 +1520,7 @@ fn receive<G>(
     participants: &mut [Box<dyn AnyParticipant<G>>],
     round_generators: &[RoundOutputGenerator<G>],
 ) where
-    G: GroupEncoding + SumOfProducts + Default + ConditionallySelectable,
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
     G::Scalar: ScalarHash,
 {
     for round_generator in round_generators {
@@ -1189,3 +1540,1146 @@ fn receive<G>(
         }
     }
 }
+
+/// Check the round 1 broadcast in `generator` was signed without giving
+/// away the refresh participant's `share`
+fn assert_share_hidden<G>(
+    parameters: &Parameters<G>,
+    generator: &RoundOutputGenerator<G>,
+    share: G::Scalar,
+) where
+    G: GroupEncoding + SumOfProducts + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let output = generator.iter().next().unwrap();
+    let data =
+        postcard::from_bytes::<Round1Data<G>>(&output.data[MESSAGE_HEADER_LENGTH..]).unwrap();
+    let schnorr_bytes = parameters.signed_context().unwrap().schnorr_bytes(&data);
+    let c = hash_to_group_scalar::<G>(&schnorr_bytes);
+    assert_eq!(data.verifying_share(), G::generator() * share);
+    assert_ne!(data.signature().r(), G::identity());
+    assert_ne!(data.signature().s() * c.invert().unwrap(), share);
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+#[case::curve25519_dalek(curve25519_dalek::RistrettoPoint::default())]
+fn text_encodings<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let rng = ChaCha8Rng::from_seed([0u8; 32]);
+    let (participants, _) = static_numbering_init_dkg::<G>(rng);
+
+    let public_key = DisplayPoint::from(participants[0].get_public_key().unwrap());
+    let hex = public_key.to_string();
+    let base58 = format!("{public_key:#}");
+    assert_eq!(hex, public_key.to_hex());
+    assert_eq!(base58, public_key.to_base58());
+    assert_eq!(hex.parse::<DisplayPoint<G>>().unwrap(), public_key);
+    assert_eq!(DisplayPoint::<G>::from_base58(&base58).unwrap(), public_key);
+    assert!(DisplayPoint::<G>::from_hex(&hex[2..]).is_err());
+
+    let id = DisplayIdentifier(participants[1].get_id());
+    assert_eq!(
+        id.to_string()
+            .parse::<DisplayIdentifier<G::Scalar>>()
+            .unwrap(),
+        id
+    );
+    assert_eq!(
+        DisplayIdentifier::<G::Scalar>::from_base58(&id.to_base58()).unwrap(),
+        id
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY, 65)]
+#[case::p256(p256::ProjectivePoint::IDENTITY, 65)]
+#[case::p384(p384::ProjectivePoint::IDENTITY, 97)]
+fn uncompressed_encoding<G>(#[case] _g: G, #[case] len: usize)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let point = G::random(&mut ChaCha8Rng::from_seed([1u8; 32]));
+    let bytes = encode_point(&point, PointEncoding::Uncompressed).unwrap();
+    assert_eq!(bytes.len(), len);
+    assert_eq!(bytes[0], 4);
+    assert_eq!(decode_point::<G>(&bytes).unwrap(), point);
+
+    let hex = DisplayPoint(point)
+        .to_hex_with(PointEncoding::Uncompressed)
+        .unwrap();
+    assert_eq!(hex.parse::<DisplayPoint<G>>().unwrap().0, point);
+    assert_eq!(
+        DisplayPoint(point)
+            .to_bytes_with(PointEncoding::Compressed)
+            .unwrap(),
+        point.to_bytes().as_ref()
+    );
+}
+
+#[test]
+fn uncompressed_encoding_matches_sec1() {
+    use k256::elliptic_curve::sec1::{FromSec1Point, ToSec1Point};
+
+    let point = k256::ProjectivePoint::random(&mut ChaCha8Rng::from_seed([2u8; 32]));
+    let bytes = encode_point(&point, PointEncoding::Uncompressed).unwrap();
+    let sec1 = k256::AffinePoint::from(point).to_sec1_point(false);
+    assert_eq!(bytes, sec1.as_bytes());
+
+    let parsed = k256::Sec1Point::from_bytes(&bytes).unwrap();
+    let affine = k256::AffinePoint::from_sec1_point(&parsed).unwrap();
+    assert_eq!(k256::ProjectivePoint::from(affine), point);
+    assert_eq!(
+        decode_point::<k256::ProjectivePoint>(&bytes).unwrap(),
+        point
+    );
+
+    // A point that isn't on the curve is rejected
+    let mut tampered = bytes.clone();
+    tampered[64] ^= 1;
+    assert!(decode_point::<k256::ProjectivePoint>(&tampered).is_err());
+}
+
+#[test]
+fn uncompressed_encoding_unsupported() {
+    let point = ed448_goldilocks_plus::EdwardsPoint::generator();
+    assert!(encode_point(&point, PointEncoding::Uncompressed).is_err());
+    assert!(encode_point(&point, PointEncoding::Compressed).is_ok());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn owned_parameters<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let owned = OwnedParameters::try_from(&parameters).unwrap();
+    assert_eq!(owned.participant_ids().len(), 3);
+
+    let bytes = postcard::to_stdvec(&owned).unwrap();
+    // The threshold and the limit lead the encoding
+    assert_eq!(bytes[..2], [2, 3]);
+    for (index, value) in [(0, 0), (0, 4), (1, 2), (1, 4)] {
+        let mut tampered = bytes.clone();
+        tampered[index] = value;
+        assert!(postcard::from_bytes::<OwnedParameters<G>>(&tampered).is_err());
+    }
+    let owned: OwnedParameters<G> =
+        std::thread::spawn(move || postcard::from_bytes(&bytes).unwrap())
+            .join()
+            .unwrap();
+
+    let parameters = owned.as_parameters();
+    let mut participants = owned
+        .participant_ids()
+        .iter()
+        .map(|id| {
+            let p = Box::new(SecretParticipant::<G>::new_secret(*id, &parameters).unwrap());
+            p as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<Box<dyn AnyParticipant<G>>>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let round_generators = next_round(&mut participants);
+        receive(&mut participants, &round_generators);
+    }
+    for (ordinal, participant) in participants.iter().enumerate() {
+        assert_eq!(participant.get_ordinal(), ordinal);
+        assert_eq!(
+            participant.get_public_key(),
+            participants[0].get_public_key()
+        );
+    }
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn custom_transcript<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    )
+    .with_transcript_label(b"my application")
+    .with_transcript_hash_size(TranscriptHashSize::Bytes64);
+    let seq = parameters.participant_number_generator().to_vec();
+    let mut participants = ParticipantIdGeneratorCollection::from(&seq)
+        .iter()
+        .map(|id| {
+            let p = Box::new(SecretParticipant::<G>::new_secret(id, &parameters).unwrap());
+            p as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<Box<dyn AnyParticipant<G>>>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let round_generators = next_round(&mut participants);
+        receive(&mut participants, &round_generators);
+    }
+    let transcript_hash = participants[0].get_final_transcript_hash_bytes();
+    assert_eq!(transcript_hash.len(), 64);
+    for participant in &participants {
+        assert_eq!(
+            participant.get_final_transcript_hash_bytes(),
+            transcript_hash
+        );
+        assert_eq!(
+            participant
+                .get_dkg_output()
+                .unwrap()
+                .transcript_hash_bytes(),
+            transcript_hash.as_slice()
+        );
+        for data in participant.get_received_round2_data().values() {
+            assert_eq!(data.transcript_hash_bytes().len(), 64);
+        }
+        // The deprecated accessors keep returning the first 32 bytes
+        #[allow(deprecated)]
+        let legacy = participant.get_final_transcript_hash();
+        assert_eq!(legacy, transcript_hash[..32]);
+    }
+
+    // A participant using a different label is rejected in round 1 since
+    // the label is part of the signed context
+    let other_parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    )
+    .with_transcript_label(b"another application")
+    .with_transcript_hash_size(TranscriptHashSize::Bytes64);
+    let mut participants = ParticipantIdGeneratorCollection::from(&seq)
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let parameters = if i == 0 {
+                &other_parameters
+            } else {
+                &parameters
+            };
+            SecretParticipant::<G>::new_secret(id, parameters).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let round1 = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in round1.iter().enumerate() {
+        for output in generator.iter() {
+            let res = participants[output.dst_ordinal].receive(&output.data);
+            assert_eq!(res.is_err(), sender == 0 || output.dst_ordinal == 0);
+        }
+    }
+    assert_ne!(
+        participants[0].signed_context().parameters_digest(),
+        participants[1].signed_context().parameters_digest()
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn transcript_extensions<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let seq = parameters.participant_number_generator().to_vec();
+    let new_participants = || {
+        ParticipantIdGeneratorCollection::from(&seq)
+            .iter()
+            .map(|id| SecretParticipant::<G>::new_secret(id, &parameters).unwrap())
+            .collect::<Vec<_>>()
+    };
+    let run_round = |participants: &mut [SecretParticipant<G>]| {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        let mut results = Vec::new();
+        for generator in &generators {
+            for output in generator.iter() {
+                results.push(participants[output.dst_ordinal].receive(&output.data));
+            }
+        }
+        results
+    };
+
+    let agreement = [7u8; 32];
+    let mut participants = new_participants();
+    run_round(&mut participants);
+    for participant in participants.iter_mut() {
+        participant
+            .extend_transcript(b"agreement", &agreement)
+            .unwrap();
+    }
+    let state = participants[0].export_state().unwrap();
+    let restored = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(
+        restored.get_transcript_extensions(),
+        &[(b"agreement".to_vec(), agreement.to_vec())]
+    );
+    for _ in [Round::Two, Round::Three] {
+        assert!(run_round(&mut participants).iter().all(|r| r.is_ok()));
+    }
+    let transcript_hash = participants[0].get_final_transcript_hash_bytes();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(
+            participant.get_final_transcript_hash_bytes(),
+            transcript_hash
+        );
+    }
+    assert!(
+        participants[0]
+            .extend_transcript(b"agreement", &agreement)
+            .is_err()
+    );
+
+    // The same ceremony without the agreement has another transcript
+    let mut plain = new_participants();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        assert!(run_round(&mut plain).iter().all(|r| r.is_ok()));
+    }
+    assert_ne!(plain[0].get_final_transcript_hash_bytes(), transcript_hash);
+
+    // A participant binding a different agreement has its shares rejected
+    let mut participants = new_participants();
+    run_round(&mut participants);
+    for (i, participant) in participants.iter_mut().enumerate() {
+        let data = if i == 0 { [8u8; 32] } else { agreement };
+        participant.extend_transcript(b"agreement", &data).unwrap();
+    }
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            let res = participants[output.dst_ordinal].receive(&output.data);
+            assert_eq!(res.is_err(), sender == 0 || output.dst_ordinal == 0);
+        }
+    }
+}
+
+#[test]
+fn parameter_diagnostics() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    assert!(parameters.validate().is_empty());
+
+    let ids = [1u64, 0, 1]
+        .map(|id| IdentifierPrimeField(k256::Scalar::from(id)))
+        .to_vec();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(4).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        Some(G::IDENTITY),
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    )
+    .with_transcript_label(b"");
+    let diagnostics = parameters.validate();
+    assert_eq!(
+        diagnostics,
+        vec![
+            ParameterDiagnostic::IdentityGenerator,
+            ParameterDiagnostic::RosterTooSmall {
+                expected: 4,
+                actual: 3
+            },
+            ParameterDiagnostic::ZeroId { ordinal: 1 },
+            ParameterDiagnostic::DuplicateId {
+                first: 0,
+                duplicate: 2
+            },
+            ParameterDiagnostic::EmptyTranscriptLabel,
+        ]
+    );
+
+    let owned = OwnedParameters::<G>::new(NonZeroUsize::new(5).unwrap(), None, ids);
+    assert!(
+        owned
+            .validate()
+            .contains(&ParameterDiagnostic::ThresholdExceedsLimit {
+                threshold: 5,
+                limit: 3
+            })
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn presized_buffers_with_large_threshold<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = [2u64, 5, 9, 14, 20, 27, 35]
+        .iter()
+        .map(|id| IdentifierPrimeField(G::Scalar::from(*id)))
+        .collect::<Vec<_>>();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(5).unwrap(),
+        NonZeroUsize::new(7).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    let mut participants = ids
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+
+    for round in [Round::One, Round::Two] {
+        let mut inboxes = vec![Vec::new(); participants.len()];
+        for participant in participants.iter_mut() {
+            for output in participant.run().unwrap().iter() {
+                inboxes[output.dst_ordinal].push(output.data);
+            }
+        }
+        for (participant, inbox) in participants.iter_mut().zip(inboxes) {
+            // The shares are checked together in one batch
+            let results = match round {
+                Round::One => inbox.iter().map(|data| participant.receive(data)).collect(),
+                _ => participant.receive_batch(&inbox),
+            };
+            assert!(results.iter().all(|result| result.is_ok()));
+        }
+    }
+    let outputs = participants
+        .iter_mut()
+        .map(|participant| {
+            participant.run().unwrap();
+            participant.get_dkg_output().unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Every verification share is computed from a buffer reused per participant
+    for output in &outputs {
+        assert_eq!(
+            output.verification_shares(),
+            outputs[0].verification_shares()
+        );
+        assert_eq!(
+            output.verification_share(output.ordinal()).unwrap().get(),
+            G::generator() * *output.secret_share().value
+        );
+    }
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn lagrange_coefficients_interpolate_shares<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let outputs = sparse_ceremony::<G>(4, &[6, 13, 21, 40, 58, 77]);
+    let signers = [&outputs[5], &outputs[0], &outputs[3], &outputs[2]];
+    let ids = signers.iter().map(|o| o.id()).collect::<Vec<_>>();
+    let interpolate = |at: G::Scalar| {
+        lagrange_coefficients(&ids, at)
+            .unwrap()
+            .into_iter()
+            .zip(&signers)
+            .fold(G::Scalar::ZERO, |acc, (coefficient, output)| {
+                acc + coefficient * *output.secret_share().value
+            })
+    };
+
+    // The batched inverses recover the secret and every other share
+    assert_eq!(
+        G::generator() * interpolate(G::Scalar::ZERO),
+        outputs[0].public_key().get()
+    );
+    for output in [&outputs[1], &outputs[4]] {
+        assert_eq!(interpolate(output.id().0), *output.secret_share().value);
+    }
+
+    // At a signer's own id only its coefficient is left
+    let coefficients = lagrange_coefficients(&ids, ids[2].0).unwrap();
+    for (index, coefficient) in coefficients.iter().enumerate() {
+        let expected = if index == 2 {
+            G::Scalar::ONE
+        } else {
+            G::Scalar::ZERO
+        };
+        assert_eq!(*coefficient, expected);
+    }
+
+    assert!(lagrange_coefficients::<G::Scalar>(&[], G::Scalar::ZERO).is_err());
+    assert!(lagrange_coefficients(&[ids[0], ids[1], ids[0]], G::Scalar::ZERO).is_err());
+    assert!(
+        lagrange_coefficients(
+            &[ids[0], IdentifierPrimeField(G::Scalar::ZERO)],
+            G::Scalar::ZERO
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn roster_metadata() {
+    type G = k256::ProjectivePoint;
+
+    let metadata = |name: &str| ParticipantMetadata {
+        display_name: Some(name.to_string()),
+        endpoint: Some(format!("https://{name}.example.com")),
+        key_fingerprint: None,
+    };
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    )
+    .with_participant_metadata(0, metadata("alice"))
+    .with_participant_metadata(1, metadata("bob"))
+    .with_participant_metadata(2, metadata("carol"));
+    assert!(parameters.validate().is_empty());
+    let divergent = parameters
+        .clone()
+        .with_participant_metadata(2, metadata("mallory"));
+
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            let parameters = if id == 3 { &divergent } else { &parameters };
+            let p = Box::new(
+                SecretParticipant::<G>::new_secret(
+                    IdentifierPrimeField(k256::Scalar::from(id)),
+                    parameters,
+                )
+                .unwrap(),
+            );
+            p as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<Box<dyn AnyParticipant<G>>>>();
+    assert_eq!(
+        participants[0].get_participant_metadata()[&1].display_name,
+        Some("bob".to_string())
+    );
+
+    let round_generators = next_round(&mut participants);
+    receive(&mut participants, &round_generators);
+    let round_generators = next_round(&mut participants);
+    // The participant with a different roster computes a different transcript
+    let mut rejected = 0;
+    for generator in &round_generators {
+        for output in generator.iter() {
+            if participants[output.dst_ordinal]
+                .receive(&output.data)
+                .is_err()
+            {
+                rejected += 1;
+            }
+        }
+    }
+    assert_eq!(rejected, 4);
+}
+
+#[test]
+fn signed_context() {
+    type G = k256::ProjectivePoint;
+
+    let metadata = |fingerprint: u8| ParticipantMetadata {
+        key_fingerprint: Some(vec![fingerprint; 32]),
+        ..Default::default()
+    };
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    )
+    .with_participant_metadata(0, metadata(1))
+    .with_participant_metadata(1, metadata(2))
+    .with_participant_metadata(2, metadata(3));
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let context = parameters.signed_context().unwrap();
+    assert_eq!(participants[0].signed_context(), context);
+
+    for participant in participants.iter_mut() {
+        participant.run().unwrap();
+    }
+    let round1data = participants[1].get_received_round1_data()[&1].clone();
+
+    // A relying party can later check the dealer signed this roster
+    let bytes = postcard::to_stdvec(&context).unwrap();
+    let restored: SignedContext<G> = postcard::from_bytes(&bytes).unwrap();
+    assert!(restored.verify(&round1data).is_ok());
+
+    // A different identity key for any participant is a different roster
+    let swapped = parameters.clone().with_participant_metadata(2, metadata(4));
+    let other = swapped.signed_context().unwrap();
+    assert_ne!(other.roster_digest(), context.roster_digest());
+    assert_eq!(other.parameters_digest(), context.parameters_digest());
+    assert!(other.verify(&round1data).is_err());
+    assert!(participants[0].receive_round1(round1data.clone()).is_ok());
+    let mut outsider = SecretParticipant::<G>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(1u64)),
+        &swapped,
+    )
+    .unwrap();
+    outsider.run().unwrap();
+    assert!(outsider.receive_round1(round1data).is_err());
+}
+
+#[test]
+fn parameter_mismatch() {
+    type G = k256::ProjectivePoint;
+
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(k256::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let seq = vec![ParticipantIdGeneratorType::list(&ids)];
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        Some(seq.clone()),
+    );
+    let divergent = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        Some(seq),
+    );
+    let mut honest = SecretParticipant::<G>::new_secret(ids[0], &parameters).unwrap();
+    let mut other = SecretParticipant::<G>::new_secret(ids[1], &divergent).unwrap();
+    honest.run().unwrap();
+    let generator = other.run().unwrap();
+    let output = generator
+        .iter()
+        .find(|output| output.dst_ordinal == 0)
+        .unwrap();
+
+    let data = decode_round1_message::<G>(&output.data).unwrap();
+    assert_eq!((data.threshold(), data.limit()), (3, 3));
+    assert!(matches!(
+        honest.receive(&output.data),
+        Err(Error::ParameterMismatch { ordinal: 1 })
+    ));
+}
+
+#[test]
+fn mixed_roster_missing_secret_participant() {
+    type G = k256::ProjectivePoint;
+
+    let mut rng = ChaCha8Rng::from_seed([0u8; 32]);
+    let (old_participants, _) = static_numbering_init_dkg::<G>(&mut rng);
+
+    let share_ids = [
+        old_participants[0].get_id(),
+        old_participants[1].get_id(),
+        old_participants[2].get_id(),
+        IdentifierPrimeField(k256::Scalar::random(&mut rng)),
+    ];
+    let seq = vec![ParticipantIdGeneratorType::list(&share_ids)];
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(seq),
+    );
+
+    let mut participants = old_participants
+        .iter()
+        .take(3)
+        .map(|p| {
+            let p = Box::new(
+                SecretParticipant::<G>::with_secret(
+                    p.get_id(),
+                    &p.get_secret_share().unwrap(),
+                    &parameters,
+                    &share_ids[..3],
+                )
+                .unwrap(),
+            );
+            p as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<Box<dyn AnyParticipant<G>>>>();
+    participants.push(Box::new(
+        RefreshParticipant::<G>::new_refresh(share_ids[3], None, &parameters).unwrap(),
+    ));
+
+    let round_generators = next_round(&mut participants);
+    receive(&mut participants, &round_generators);
+    let round_generators = next_round(&mut participants);
+    // The refresh participant never hears from the first secret participant
+    for (sender, generator) in round_generators.iter().enumerate() {
+        for output in generator.iter() {
+            if sender == 0 && output.dst_ordinal == 3 {
+                continue;
+            }
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+
+    assert!(participants[0].run().is_ok());
+    // Even when told to proceed without the missing share
+    participants[3].set_missing_share_policy(MissingSharePolicy::Proceed);
+    let res = participants[3].run();
+    assert!(matches!(res, Err(Error::Round(msg)) if msg.contains("missing [0]")));
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn generate_shares_helper<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(3).unwrap();
+    let limit = NonZeroUsize::new(5).unwrap();
+    let ids = (1..=5u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id * 11)))
+        .collect::<Vec<_>>();
+
+    let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rng).unwrap();
+    assert_eq!(outputs.len(), limit.get());
+    for (output, id) in outputs.iter().zip(&ids) {
+        assert_eq!(output.id(), *id);
+        assert_eq!(output.public_key(), outputs[0].public_key());
+    }
+
+    let shares = outputs
+        .iter()
+        .map(|output| output.secret_share())
+        .collect::<Vec<_>>();
+    let secret = (&shares[..threshold.get()]).combine().unwrap();
+    assert_eq!(G::generator() * *secret, outputs[0].public_key().get());
+
+    // The secrets and polynomials only depend on the rng
+    let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+    let again = generate_shares::<G>(threshold, limit, &ids, &mut rng).unwrap();
+    assert_eq!(again[0].secret_share(), outputs[0].secret_share());
+
+    assert!(generate_shares::<G>(threshold, limit, &ids[1..], &mut rng).is_err());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn output_equality<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let mut rng = ChaCha8Rng::from_seed([3u8; 32]);
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rng).unwrap();
+    // The round 1 nonces are hedged with fresh randomness so a rerun has
+    // another transcript, compare decoded copies instead
+    let again = outputs
+        .iter()
+        .map(|output| {
+            let bytes = postcard::to_stdvec(output).unwrap();
+            postcard::from_bytes::<DkgOutput<G>>(&bytes).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(outputs, again);
+    assert_eq!(outputs[0].ct_eq(&again[0]).unwrap_u8(), 1);
+    assert_eq!(outputs[0].ct_eq(&outputs[1]).unwrap_u8(), 0);
+
+    // Duplicates collapse in hashed and ordered collections
+    let unique = outputs
+        .iter()
+        .chain(&again)
+        .cloned()
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(unique.len(), 3);
+    let keys = outputs
+        .iter()
+        .chain(&again)
+        .map(|output| output.public_key())
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(keys.len(), 1);
+    let mut shares = outputs[0]
+        .verification_shares()
+        .values()
+        .copied()
+        .collect::<Vec<_>>();
+    shares.sort();
+    assert!(
+        shares
+            .windows(2)
+            .all(|pair| pair[0].to_bytes().as_ref() < pair[1].to_bytes().as_ref())
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+fn caller_supplied_coefficients<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let polynomials = (1..=3u64)
+        .map(|i| [G::Scalar::from(i * 100), G::Scalar::from(i * 7 + 1)])
+        .collect::<Vec<_>>();
+    let mut participants = polynomials
+        .iter()
+        .enumerate()
+        .map(|(i, coefficients)| {
+            let id = IdentifierPrimeField(G::Scalar::from(i as u64 + 1));
+            SecretParticipant::<G>::with_coefficients(id, &parameters, coefficients).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // The commitments are exactly the committed coefficients
+    let verifiers = participants[0].get_feldman_verifiers();
+    assert_eq!(verifiers[0], G::generator() * polynomials[0][0]);
+    assert_eq!(verifiers[1], G::generator() * polynomials[0][1]);
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let secret = polynomials
+        .iter()
+        .fold(G::Scalar::ZERO, |acc, coefficients| acc + coefficients[0]);
+    for participant in &participants {
+        assert_eq!(
+            participant.get_public_key().unwrap().get(),
+            G::generator() * secret
+        );
+    }
+
+    let id = IdentifierPrimeField(G::Scalar::ONE);
+    // Wrong degree
+    assert!(SecretParticipant::<G>::with_coefficients(id, &parameters, &[G::Scalar::ONE]).is_err());
+    // Zero secret
+    assert!(
+        SecretParticipant::<G>::with_coefficients(
+            id,
+            &parameters,
+            &[G::Scalar::ZERO, G::Scalar::ONE]
+        )
+        .is_err()
+    );
+    // Refreshes must not change the key
+    assert!(
+        RefreshParticipant::<G>::new_refresh_with_coefficients(
+            id,
+            None,
+            &parameters,
+            &[G::Scalar::ONE, G::Scalar::ONE]
+        )
+        .is_err()
+    );
+    assert!(
+        RefreshParticipant::<G>::new_refresh_with_coefficients(
+            id,
+            None,
+            &parameters,
+            &[G::Scalar::ZERO, G::Scalar::ONE]
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn participant_identifiers() {
+    type G = k256::ProjectivePoint;
+
+    let identifiers = vec![
+        ParticipantIdentifier::from("alice"),
+        ParticipantIdentifier::from([7u8; 16]),
+        ParticipantIdentifier::from(42u64),
+    ];
+    let owned = OwnedParameters::<G>::from_identifiers(
+        NonZeroUsize::new(2).unwrap(),
+        None,
+        identifiers.clone(),
+    )
+    .unwrap();
+    let parameters = owned.as_parameters();
+    assert!(parameters.validate().is_empty());
+    assert_eq!(
+        identifiers[2].to_identifier::<k256::Scalar>().unwrap(),
+        IdentifierPrimeField(k256::Scalar::from(42u64))
+    );
+    assert_eq!(
+        identifiers[1].to_string(),
+        "07070707-0707-0707-0707-070707070707"
+    );
+
+    let mut participants = identifiers
+        .iter()
+        .map(|identifier| {
+            SecretParticipant::<G>::new_secret(identifier.to_identifier().unwrap(), &parameters)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    for participant in &participants {
+        assert_eq!(
+            participant.participant_identifier(participant.get_ordinal()),
+            Some(&identifiers[participant.get_ordinal()])
+        );
+        let output = participant.get_dkg_output().unwrap();
+        assert_eq!(output.participant_identifier(0), Some(&identifiers[0]));
+        assert_eq!(output.participant_identifiers().len(), 3);
+    }
+
+    let state = participants[0].export_state().unwrap();
+    let restored = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(
+        restored.get_participant_identifiers(),
+        participants[0].get_participant_identifiers()
+    );
+
+    let mismatched = owned.with_participant_identifier(1, "mallory");
+    let parameters = mismatched.as_parameters();
+    assert_eq!(
+        parameters.validate(),
+        vec![ParameterDiagnostic::IdentifierMismatch { ordinal: 1 }]
+    );
+    assert!(
+        SecretParticipant::<G>::new_secret(identifiers[0].to_identifier().unwrap(), &parameters)
+            .is_err()
+    );
+}
+
+/// Run a fresh DKG with the given `threshold` over the given sparse `ids`
+fn sparse_ceremony<G>(threshold: usize, ids: &[u64]) -> Vec<DkgOutput<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = ids
+        .iter()
+        .map(|id| IdentifierPrimeField(G::Scalar::from(*id)))
+        .collect::<Vec<_>>();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(threshold).unwrap(),
+        NonZeroUsize::new(ids.len()).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    let mut participants = ids
+        .iter()
+        .map(|id| {
+            Box::new(SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+                as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    run_local_ceremony(&mut participants).unwrap()
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn reshare_with_lagrange_table<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let outputs = sparse_ceremony::<G>(3, &[3, 7, 11, 19, 23]);
+    let holders = [&outputs[1], &outputs[2], &outputs[4]];
+    let old_ids = holders.iter().map(|o| o.id()).collect::<Vec<_>>();
+    let table = LagrangeTable::new(&old_ids).unwrap();
+    assert_eq!(table.len(), 3);
+
+    // Three holders keep their ids and a newcomer joins with a 3-of-4
+    let mut new_ids = old_ids.clone();
+    new_ids.push(IdentifierPrimeField(G::Scalar::from(42u64)));
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&new_ids)]),
+    );
+    let mut participants = holders
+        .iter()
+        .map(|output| {
+            Box::new(
+                SecretParticipant::<G>::with_lagrange_table(
+                    output.id(),
+                    &output.secret_share(),
+                    &parameters,
+                    &table,
+                )
+                .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    participants.push(Box::new(
+        RefreshParticipant::<G>::new_refresh(new_ids[3], None, &parameters).unwrap(),
+    ));
+    let reshared = run_local_ceremony(&mut participants).unwrap();
+    for output in &reshared {
+        assert_eq!(output.public_key(), outputs[0].public_key());
+    }
+
+    // A share outside the table is refused
+    assert!(
+        SecretParticipant::<G>::with_lagrange_table(
+            outputs[0].id(),
+            &outputs[0].secret_share(),
+            &parameters,
+            &table,
+        )
+        .is_err()
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn decode_failures_name_the_sender<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = [4u64, 8, 15, 16]
+        .iter()
+        .map(|id| IdentifierPrimeField(G::Scalar::from(*id)))
+        .collect::<Vec<_>>();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    let mut participants = ids
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let share = generators[3]
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+
+    let truncated = &share.data[..share.data.len() - 3];
+    let error = participants[1].receive(truncated).unwrap_err();
+    assert!(error.to_string().contains("participant 3"));
+    assert!(matches!(
+        error,
+        Error::Decode {
+            round: Round::Two,
+            sender: Some(3),
+            length,
+            ..
+        } if length == truncated.len() - MESSAGE_HEADER_LENGTH
+    ));
+
+    // Nothing after the header to read the sender from
+    let error = participants[1]
+        .receive(&share.data[..MESSAGE_HEADER_LENGTH])
+        .unwrap_err();
+    assert!(error.to_string().contains("an unknown sender"));
+    assert!(matches!(
+        error,
+        Error::Decode {
+            round: Round::Two,
+            length: 0,
+            sender: None,
+            ..
+        }
+    ));
+
+    // The intact share is still accepted
+    participants[1].receive(&share.data).unwrap();
+}
@@ -0,0 +1,57 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn share_health_check<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = (1..=4u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+
+    let challenge = b"health check 2026-10-16";
+    let proofs = outputs
+        .iter()
+        .map(|output| output.prove_share_possession(challenge).unwrap())
+        .collect::<Vec<_>>();
+    let report = outputs[0].health_report(challenge, &proofs);
+    assert!(report.is_healthy());
+    assert!(report.meets_threshold());
+    assert_eq!(report.healthy.len(), 4);
+
+    // A stale proof, a proof from a lost share and a missing proof
+    let stale = outputs[1].prove_share_possession(b"last week").unwrap();
+    let other = generate_shares::<G>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let corrupted = other[2].prove_share_possession(challenge).unwrap();
+    let report = outputs[3].health_report(challenge, &[proofs[0].clone(), stale, corrupted]);
+    assert!(!report.is_healthy());
+    assert!(!report.meets_threshold());
+    assert_eq!(report.healthy.iter().copied().collect::<Vec<_>>(), vec![0]);
+    assert_eq!(
+        report.invalid.keys().copied().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(report.missing.iter().copied().collect::<Vec<_>>(), vec![3]);
+}
@@ -0,0 +1,63 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn key_certificate_quorum<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = (1..=4u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let certificates = outputs
+        .iter()
+        .map(|output| output.key_certificate().unwrap())
+        .collect::<Vec<_>>();
+
+    let public_key = verify_key_certificates(&certificates[1..]).unwrap();
+    assert_eq!(public_key, outputs[0].public_key());
+    assert_eq!(
+        certificates[0].transcript_hash(),
+        outputs[0].transcript_hash_bytes()
+    );
+
+    // Below the threshold
+    assert!(verify_key_certificates(&certificates[..2]).is_err());
+    // The same shareholder twice
+    let duplicated = vec![
+        certificates[0].clone(),
+        certificates[1].clone(),
+        certificates[1].clone(),
+    ];
+    assert!(verify_key_certificates(&duplicated).is_err());
+    // A certificate from a different ceremony
+    let other = generate_shares::<G>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let mixed = vec![
+        certificates[0].clone(),
+        certificates[1].clone(),
+        other[2].key_certificate().unwrap(),
+    ];
+    assert!(mixed[2].verify().is_ok());
+    assert!(verify_key_certificates(&mixed).is_err());
+}
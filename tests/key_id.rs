@@ -0,0 +1,56 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn key_id<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = (1..=4u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+
+    let key_id = outputs[0].key_id();
+    for output in &outputs {
+        assert_eq!(output.key_id(), key_id);
+        assert_eq!(output.key_certificate().unwrap().key_id(), key_id);
+    }
+    assert_eq!(
+        key_id,
+        KeyId::compute(
+            &outputs[0].public_key().get(),
+            &shareholder_digest(outputs[0].participant_ids())
+        )
+    );
+    assert_eq!(outputs[0].health_report(b"challenge", &[]).key_id, key_id);
+
+    // The same key held by a different roster has another id
+    let mut roster = outputs[0].participant_ids().clone();
+    roster.remove(&3);
+    assert_ne!(
+        KeyId::compute(&outputs[0].public_key().get(), &shareholder_digest(&roster)),
+        key_id
+    );
+
+    let text = key_id.to_string();
+    assert_eq!(text.len(), 2 * KEY_ID_LENGTH);
+    assert_eq!(text.parse::<KeyId>().unwrap(), key_id);
+    assert!(text[2..].parse::<KeyId>().is_err());
+    let bytes = postcard::to_stdvec(&key_id).unwrap();
+    assert_eq!(postcard::from_bytes::<KeyId>(&bytes).unwrap(), key_id);
+}
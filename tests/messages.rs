@@ -0,0 +1,115 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn decode_messages_without_participant<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            assert_eq!(message_round(&output.data).unwrap(), Round::One);
+            let data = decode_round1_message::<G>(&output.data).unwrap();
+            let sent = &participants[data.sender_ordinal()].get_received_round1_data()
+                [&data.sender_ordinal()];
+            assert_eq!(data.commitments_digest(), sent.commitments_digest());
+            assert!(decode_round2_message::<G>(&output.data).is_err());
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            assert_eq!(message_round(&output.data).unwrap(), Round::Two);
+            let data = decode_round2_message::<G>(&output.data).unwrap();
+            assert_eq!(data.sender_ordinal(), sender);
+            assert_eq!(data.secret_share().identifier, output.dst_id);
+            assert!(decode_round1_message::<G>(&output.data).is_err());
+        }
+    }
+
+    assert!(message_round(&[]).is_err());
+    assert!(message_round(&[42]).is_err());
+}
+
+#[test]
+fn ciphersuite_mismatch() {
+    // Edwards25519 and Ristretto25519 share a scalar field and a hash
+    assert_ne!(
+        ciphersuite_id::<curve25519_dalek::EdwardsPoint>(),
+        ciphersuite_id::<curve25519_dalek::RistrettoPoint>()
+    );
+    assert_ne!(
+        ciphersuite_id::<k256::ProjectivePoint>(),
+        ciphersuite_id::<p256::ProjectivePoint>()
+    );
+
+    let k256_parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(2).unwrap(),
+        None,
+        None,
+    );
+    let p256_parameters = Parameters::<p256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(2).unwrap(),
+        None,
+        None,
+    );
+    let mut sender = SecretParticipant::<k256::ProjectivePoint>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(1u64)),
+        &k256_parameters,
+    )
+    .unwrap();
+    let mut receiver = SecretParticipant::<p256::ProjectivePoint>::new_secret(
+        IdentifierPrimeField(p256::Scalar::from(2u64)),
+        &p256_parameters,
+    )
+    .unwrap();
+    let generator = sender.run().unwrap();
+    let output = generator.iter().next().unwrap();
+    assert_eq!(
+        message_ciphersuite(&output.data).unwrap(),
+        ciphersuite_id::<k256::ProjectivePoint>()
+    );
+    assert!(matches!(
+        receiver.receive(&output.data),
+        Err(Error::Encoding(_))
+    ));
+    assert!(decode_round1_message::<p256::ProjectivePoint>(&output.data).is_err());
+    assert!(message_ciphersuite(&output.data[..4]).is_err());
+}
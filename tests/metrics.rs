@@ -0,0 +1,94 @@
+#![cfg(feature = "metrics")]
+
+use frost_dkg::*;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn reports_metrics() {
+    type G = k256::ProjectivePoint;
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        let parameters = Parameters::<G>::new(
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(3).unwrap(),
+            None,
+            None,
+        );
+        let mut participants = (1..=3u64)
+            .map(|id| {
+                SecretParticipant::<G>::new_secret(
+                    IdentifierPrimeField(k256::Scalar::from(id)),
+                    &parameters,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        // A share meant for participant 2 fails to verify for participant 1
+        let misdirected = generators[0]
+            .iter()
+            .find(|output| output.dst_ordinal == 2)
+            .unwrap();
+        assert!(participants[1].receive(&misdirected.data).is_err());
+
+        // A batch counts each message once, the misdirected one included
+        let batch = [(0, 2), (1, 0)]
+            .into_iter()
+            .map(|(sender, receiver)| {
+                generators[sender]
+                    .iter()
+                    .find(|output| output.dst_ordinal == receiver)
+                    .unwrap()
+                    .data
+            })
+            .collect::<Vec<_>>();
+        let results = participants[2].receive_batch(&batch);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    });
+
+    let snapshot = snapshotter.snapshot().into_vec();
+    let counter = |name: &str, round: &str| {
+        snapshot
+            .iter()
+            .filter(|(key, _, _, _)| {
+                key.key().name() == name
+                    && key
+                        .key()
+                        .labels()
+                        .any(|label| label.key() == "round" && label.value() == round)
+            })
+            .map(|(_, _, _, value)| match value {
+                DebugValue::Counter(count) => *count,
+                _ => 0,
+            })
+            .sum::<u64>()
+    };
+    assert_eq!(counter(MESSAGES_RECEIVED, "1"), 6);
+    assert_eq!(counter(MESSAGES_RECEIVED, "2"), 3);
+    assert_eq!(counter(VALIDATION_FAILURES, "2"), 2);
+    assert!(
+        snapshot
+            .iter()
+            .any(|(key, _, _, value)| key.key().name() == ROUND_DURATION
+                && matches!(value, DebugValue::Histogram(_)))
+    );
+}
@@ -0,0 +1,149 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn missing_share_policy<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut withheld = None;
+    for round in [Round::One, Round::Two] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for (sender, generator) in generators.iter().enumerate() {
+            for output in generator.iter() {
+                // The share from 2 to 0 never arrives
+                if round == Round::Two && sender == 2 && output.dst_ordinal == 0 {
+                    withheld = Some(output.data);
+                    continue;
+                }
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    let withheld = withheld.unwrap();
+    assert_eq!(participants[0].missing_shares(), vec![2]);
+    assert_eq!(
+        participants[0].get_missing_share_policy(),
+        MissingSharePolicy::Abort
+    );
+
+    // Proceeding only needs the threshold
+    let mut proceed = participants[0].clone();
+    proceed.set_missing_share_policy(MissingSharePolicy::Proceed);
+    proceed.run().unwrap();
+    assert!(proceed.completed());
+
+    // Waiting for a resend
+    let mut resend = participants[0].clone();
+    resend.set_missing_share_policy(MissingSharePolicy::RequestResend);
+    assert!(!resend.status().ready);
+    assert!(resend.run().is_err());
+    assert_eq!(resend.get_round(), Round::Three);
+    resend.receive(&withheld).unwrap();
+    assert!(resend.missing_shares().is_empty());
+    resend.run().unwrap();
+    assert!(resend.completed());
+
+    // Aborting blames the dealer, even after a snapshot
+    let abort = participants[0].clone();
+    let state = abort.export_state().unwrap();
+    let mut abort = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(abort.get_missing_share_policy(), MissingSharePolicy::Abort);
+    assert!(abort.run().is_err());
+    assert!(!abort.completed());
+    assert!(abort.get_invalid_participant_ids().contains_key(&2));
+    assert_eq!(abort.peer_status(2), Some(PeerStatus::Quarantined));
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn receivers_missing_different_dealers<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=4u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for round in [Round::One, Round::Two] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for (sender, generator) in generators.iter().enumerate() {
+            for output in generator.iter() {
+                // 0 never gets the share of 2 and 1 never gets the share of 3
+                if round == Round::Two && matches!((sender, output.dst_ordinal), (2, 0) | (3, 1)) {
+                    continue;
+                }
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert_eq!(participants[0].missing_shares(), vec![2]);
+    assert_eq!(participants[1].missing_shares(), vec![3]);
+    assert_eq!(participants[0].round_requirements().required, vec![2]);
+
+    // By default neither derives a key from a different set of dealers
+    for participant in &participants[..2] {
+        let mut participant = participant.clone();
+        assert!(participant.run().is_err());
+        assert!(!participant.completed());
+    }
+
+    // Proceeding anyway leaves them with different keys
+    let keys = participants[..2]
+        .iter()
+        .map(|participant| {
+            let mut participant = participant.clone();
+            participant.set_missing_share_policy(MissingSharePolicy::Proceed);
+            participant.run().unwrap();
+            participant.get_public_key().unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_ne!(keys[0], keys[1]);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "mlock")]
+
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn locked_secret_share() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let share = participants[0].get_secret_share().unwrap();
+    let cloned = participants[0].clone();
+    assert_eq!(cloned.get_secret_share().unwrap(), share);
+    drop(participants);
+
+    let state = cloned.export_state().unwrap();
+    let restored = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(restored.get_secret_share().unwrap(), share);
+}
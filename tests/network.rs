@@ -0,0 +1,228 @@
+#![cfg(feature = "tokio")]
+
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+#[case::curve25519_dalek(curve25519_dalek::RistrettoPoint::default())]
+#[tokio::test]
+async fn local_network_dkg<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    const THRESHOLD: usize = 3;
+    const LIMIT: usize = 5;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(THRESHOLD).unwrap(),
+        NonZeroUsize::new(LIMIT).unwrap(),
+        None,
+        None,
+    );
+    let participants = LocalNetwork::run(&parameters).await.unwrap();
+    assert_eq!(participants.len(), LIMIT);
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for (ordinal, participant) in participants.iter().enumerate() {
+        assert!(participant.completed());
+        assert_eq!(participant.get_ordinal(), ordinal);
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+        assert!(participant.status().invalid_participants.is_empty());
+    }
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[tokio::test]
+async fn actor_dkg<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    const THRESHOLD: usize = 2;
+    const LIMIT: usize = 3;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(THRESHOLD).unwrap(),
+        NonZeroUsize::new(LIMIT).unwrap(),
+        None,
+        None,
+    );
+    let (handles, join_handles): (Vec<_>, Vec<_>) = (1..=LIMIT)
+        .map(|id| {
+            let id = IdentifierPrimeField(G::Scalar::from(id as u64));
+            let participant = SecretParticipant::<G>::new_secret(id, &parameters).unwrap();
+            DkgActor::<G>::spawn(participant)
+        })
+        .unzip();
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let mut outputs = Vec::new();
+        for handle in &handles {
+            outputs.extend(handle.run().await.unwrap());
+        }
+        for output in outputs {
+            handles[output.dst_ordinal]
+                .deliver(output.data)
+                .await
+                .unwrap();
+        }
+    }
+    for handle in &handles {
+        let status = handle.status().await.unwrap();
+        assert!(status.completed);
+        assert_eq!(status.round, Round::Four);
+    }
+    drop(handles);
+
+    let mut public_keys = Vec::with_capacity(LIMIT);
+    for join_handle in join_handles {
+        let participant = join_handle.await.unwrap();
+        public_keys.push(participant.get_public_key().unwrap());
+    }
+    assert!(public_keys.windows(2).all(|keys| keys[0] == keys[1]));
+}
+
+/// Fails the first `failures` sends with a dropped connection
+struct FlakyTransport {
+    inner: ChannelTransport,
+    failures: usize,
+}
+
+impl<F: ScalarHash> Transport<F> for FlakyTransport {
+    async fn send(&mut self, output: ParticipantRoundOutput<F>) -> DkgResult<()> {
+        if self.failures > 0 {
+            self.failures -= 1;
+            return Err(Error::Io(std::io::Error::from(
+                std::io::ErrorKind::ConnectionReset,
+            )));
+        }
+        self.inner.send(output).await
+    }
+
+    async fn recv(&mut self) -> DkgResult<Vec<u8>> {
+        <ChannelTransport as Transport<F>>::recv(&mut self.inner).await
+    }
+}
+
+#[tokio::test]
+async fn drive_retries_transient_failures() {
+    type G = k256::ProjectivePoint;
+    const LIMIT: usize = 3;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(LIMIT).unwrap(),
+        None,
+        None,
+    );
+    let policy = RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(1),
+        ..RetryPolicy::default()
+    };
+    assert_eq!(policy.backoff(0), Duration::from_millis(1));
+    assert_eq!(policy.backoff(2), Duration::from_millis(4));
+    assert_eq!(policy.backoff(64), policy.max_backoff);
+
+    let handles = ChannelTransport::connect(LIMIT)
+        .into_iter()
+        .enumerate()
+        .map(|(ordinal, inner)| {
+            let id = IdentifierPrimeField(k256::Scalar::from(ordinal as u64 + 1));
+            let mut participant = SecretParticipant::<G>::new_secret(id, &parameters).unwrap();
+            let mut transport = FlakyTransport { inner, failures: 2 };
+            tokio::spawn(async move {
+                drive_with_retry(&mut participant, &mut transport, &policy).await?;
+                Ok::<_, Error>(participant)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut public_keys = Vec::with_capacity(LIMIT);
+    for handle in handles {
+        let participant = handle.await.unwrap().unwrap();
+        assert!(participant.completed());
+        public_keys.push(participant.get_public_key().unwrap());
+    }
+    assert!(public_keys.windows(2).all(|keys| keys[0] == keys[1]));
+
+    let mut participant = SecretParticipant::<G>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(1u64)),
+        &parameters,
+    )
+    .unwrap();
+    let mut transport = FlakyTransport {
+        inner: ChannelTransport::connect(LIMIT).remove(0),
+        failures: 1,
+    };
+    assert!(matches!(
+        drive(&mut participant, &mut transport).await,
+        Err(Error::Io(_))
+    ));
+}
+
+#[tokio::test]
+async fn ceremony_with_local_participants() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut transports = ChannelTransport::connect_groups(&[vec![0, 1], vec![2]]);
+
+    // The wallet holds two of the three shares
+    let mut remote = participants.pop().unwrap();
+    let mut remote_transport = transports.pop().unwrap();
+    let handle = tokio::spawn(async move {
+        drive(&mut remote, &mut remote_transport).await?;
+        Ok::<_, Error>(remote)
+    });
+
+    let mut ceremony = Ceremony::<G>::new();
+    for participant in participants {
+        ceremony.add(participant).unwrap();
+    }
+    assert!(
+        ceremony
+            .add(
+                SecretParticipant::<G>::new_secret(
+                    IdentifierPrimeField(k256::Scalar::from(1u64)),
+                    &parameters
+                )
+                .unwrap()
+            )
+            .is_err()
+    );
+    ceremony.drive(&mut transports[0]).await.unwrap();
+    assert!(ceremony.completed());
+
+    let remote = handle.await.unwrap().unwrap();
+    let public_key = remote.get_public_key().unwrap();
+    for ordinal in [0, 1] {
+        let participant = ceremony.participant(ordinal).unwrap();
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
@@ -0,0 +1,185 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorType,
+    elliptic_curve::{PrimeField, group::GroupEncoding},
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn receive_before_run<G>(#[case] _g: G, #[values(false, true)] blinded: bool)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    if blinded {
+        parameters = parameters.with_blinder_generator(G::random(&mut rand::rng()));
+    }
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // The peers run round 1 before participant 0 does
+    let generators = participants[1..]
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    assert_eq!(participants[0].get_round(), Round::One);
+    assert_eq!(participants[0].get_received_round1_data().len(), 2);
+
+    let generator = participants[0].run().unwrap();
+    assert!(participants[0].round_requirements().is_satisfied());
+    for output in generator.iter() {
+        participants[output.dst_ordinal]
+            .receive(&output.data)
+            .unwrap();
+    }
+
+    // Shares that arrive before round 2 ran are rejected without blame
+    let generators = participants[1..]
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let mut early = Vec::new();
+    for generator in &generators {
+        for output in generator.iter() {
+            if output.dst_ordinal == 0 {
+                assert!(participants[0].receive(&output.data).is_err());
+                early.push(output.data);
+                continue;
+            }
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    assert!(participants[0].get_invalid_participant_ids().is_empty());
+
+    let generator = participants[0].run().unwrap();
+    for data in &early {
+        participants[0].receive(data).unwrap();
+    }
+    for output in generator.iter() {
+        participants[output.dst_ordinal]
+            .receive(&output.data)
+            .unwrap();
+    }
+
+    // Pedersen commitments are opened in an extra run of round 3
+    while !participants.iter().all(SecretParticipant::completed) {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn canonical_roster_encoding<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    let ids = (1..=3u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let metadata = |i: usize| ParticipantMetadata {
+        display_name: Some(format!("participant {i}")),
+        ..Default::default()
+    };
+
+    // The same roster supplied as a list with the metadata in order and as
+    // owned parameters with the metadata in reverse
+    let mut listed = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    );
+    for i in 0..3 {
+        listed = listed.with_participant_metadata(i, metadata(i));
+    }
+    let mut owned = OwnedParameters::<G>::new(threshold, None, ids.clone());
+    for i in (0..3).rev() {
+        owned = owned.with_participant_metadata(i, metadata(i));
+    }
+    let context = listed.signed_context().unwrap();
+    assert_eq!(owned.as_parameters().signed_context().unwrap(), context);
+
+    let mut participant = SecretParticipant::<G>::new_secret(ids[0], &listed).unwrap();
+    let generator = participant.run().unwrap();
+    let output = generator.iter().next().unwrap();
+    let data = conformance::check_round1_bytes(&output.data, &owned.as_parameters()).unwrap();
+    let bytes = context.schnorr_bytes(&data);
+
+    // The challenge can be reproduced from the exposed bytes
+    let challenge = hash_to_group_scalar::<G>(&bytes);
+    let signature = data.signature();
+    assert_eq!(
+        G::generator() * signature.s() - data.verifying_share() * challenge,
+        signature.r()
+    );
+
+    // The roster is encoded with its length and ordinals
+    let scalar_len = ids[0].0.to_repr().as_ref().len();
+    let point_len = G::generator().to_bytes().as_ref().len();
+    assert_eq!(
+        bytes.len(),
+        CIPHERSUITE_ID_LENGTH + scalar_len * 4 + 2 * 8 + point_len * 3 + 3 * 32
+    );
+
+    // Another order of the same ids is another roster
+    let reordered = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&[
+            ids[1], ids[0], ids[2],
+        ])]),
+    )
+    .signed_context()
+    .unwrap();
+    assert_ne!(reordered.schnorr_bytes(&data), bytes);
+    assert!(reordered.verify(&data).is_err());
+}
@@ -0,0 +1,259 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorType, ReadableShareSet,
+    elliptic_curve::group::GroupEncoding,
+};
+
+fn pedersen_participants<G>() -> Vec<SecretParticipant<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = (1..=4u64)
+        .map(|id| IdentifierPrimeField(G::Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    )
+    .with_blinder_generator(G::random(&mut rand::rng()));
+    ids.iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect()
+}
+
+fn run_and_deliver<G>(participants: &mut [SecretParticipant<G>]) -> Vec<RoundOutputGenerator<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    generators
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn pedersen_dkg<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut participants = pedersen_participants::<G>();
+
+    let generators = run_and_deliver(&mut participants);
+    // The broadcast hides the contribution behind the blinder and is signed
+    let broadcast = generators[0].iter().next().unwrap();
+    let round1 =
+        postcard::from_bytes::<Round1Data<G>>(&broadcast.data[MESSAGE_HEADER_LENGTH..]).unwrap();
+    assert_ne!(
+        round1.verifying_share(),
+        *participants[0].get_verifying_share()
+    );
+    assert!(participants[1].signed_context().verify(&round1).is_ok());
+    run_and_deliver(&mut participants);
+    // Nothing is known about the contributions before the openings
+    assert!(participants[1].get_contribution_public_key(0).is_none());
+
+    // The first run of round 3 fixes the dealers and opens the commitments
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    assert!(participants.iter().all(|p| !p.completed()));
+    assert_eq!(participants[0].missing_round1_openings(), vec![1, 2, 3]);
+    assert!(participants[0].run().is_err());
+    let bytes = participants[0].export_state().unwrap();
+    participants[0] = SecretParticipant::<G>::import_state(&bytes).unwrap();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    assert!(participants[0].missing_round1_openings().is_empty());
+    run_and_deliver(&mut participants);
+
+    let public_key = participants[0].get_public_key().unwrap();
+    let contributions = participants
+        .iter()
+        .fold(G::identity(), |acc, p| acc + *p.get_verifying_share());
+    assert_eq!(*public_key, contributions);
+    // The opened feldman commitments are kept next to the signed broadcast
+    let opened = &participants[1].get_round1_openings()[&0];
+    assert_eq!(
+        opened.verifying_share(),
+        *participants[0].get_verifying_share()
+    );
+    assert_eq!(
+        participants[1].get_received_round1_data()[&0].commitments_digest(),
+        round1.commitments_digest()
+    );
+    assert_eq!(
+        participants[1].get_contribution_public_key(0),
+        Some(*participants[0].get_verifying_share())
+    );
+
+    let shares = participants
+        .iter()
+        .map(|p| p.get_secret_share().unwrap())
+        .collect::<Vec<_>>();
+    let secret = shares[..3].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *public_key);
+    for participant in &participants {
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+        assert_eq!(
+            participant.get_final_transcript_hash_bytes(),
+            participants[0].get_final_transcript_hash_bytes()
+        );
+    }
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn pedersen_rejects_invalid_share<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut participants = pedersen_participants::<G>();
+    run_and_deliver(&mut participants);
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+
+    // The share and blinder meant for participant 2 don't open for
+    // participant 1, anyone could replay them so the sender is not blamed
+    let misdirected = generators[0]
+        .iter()
+        .find(|output| output.dst_ordinal == 2)
+        .unwrap();
+    assert!(participants[1].receive(&misdirected.data).is_err());
+    assert!(participants[1].get_invalid_participant_ids().is_empty());
+
+    // Unblinded round 2 data is refused
+    let data =
+        postcard::from_bytes::<BlindedRound2Data<G>>(&misdirected.data[MESSAGE_HEADER_LENGTH..])
+            .unwrap();
+    assert!(
+        participants[3]
+            .receive_round2(data.round2_data().clone())
+            .is_err()
+    );
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn pedersen_authenticates_messages<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let mut participants = pedersen_participants::<G>();
+    let mut impostors = pedersen_participants::<G>();
+
+    // The signature is the last field of the round 1 broadcast
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let mut tampered = generators[0].iter().next().unwrap().data;
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(participants[1].receive(&tampered).is_err());
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    run_and_deliver(&mut impostors);
+
+    // A share from another run under the same ordinal isn't signed with the
+    // key of the sender's broadcast, so the sender is not blamed for it
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let forged = impostors[0].run().unwrap();
+    let forged = forged
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+    assert!(participants[1].receive(&forged.data).is_err());
+    assert!(participants[1].get_invalid_participant_ids().is_empty());
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+
+    // Openings are only accepted once the receiver fixed its dealers
+    let opening = participants[0].run().unwrap();
+    let opening = opening
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+    assert!(participants[1].receive(&opening.data).is_err());
+    participants[1].run().unwrap();
+    participants[1].receive(&opening.data).unwrap();
+    assert_eq!(participants[1].missing_round1_openings(), vec![2, 3]);
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn pedersen_blinder_generator_checks<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(2).unwrap();
+    let limit = NonZeroUsize::new(3).unwrap();
+    for blinder in [G::identity(), G::generator()] {
+        let parameters =
+            Parameters::<G>::new(threshold, limit, None, None).with_blinder_generator(blinder);
+        assert_eq!(
+            parameters.validate(),
+            vec![ParameterDiagnostic::InvalidBlinderGenerator]
+        );
+        assert!(
+            SecretParticipant::<G>::new_secret(IdentifierPrimeField::ONE, &parameters).is_err()
+        );
+    }
+
+    let parameters = Parameters::<G>::new(threshold, limit, None, None)
+        .with_blinder_generator(G::random(&mut rand::rng()));
+    let owned = OwnedParameters::try_from(&parameters).unwrap();
+    assert_eq!(owned.blinder_generator(), parameters.blinder_generator());
+    assert_eq!(
+        owned.as_parameters().blinder_generator(),
+        parameters.blinder_generator()
+    );
+}
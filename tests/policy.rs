@@ -0,0 +1,85 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn policy_veto<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Participant 2 is outside its window until the flag is set
+    let open = Arc::new(AtomicBool::new(false));
+    let window = open.clone();
+    participants[0].set_policy(Arc::new(move |message: &PolicyMessage<'_>| {
+        if message.sender == 2 && !window.load(Ordering::SeqCst) {
+            return Err("outside the signing window".to_string());
+        }
+        Ok(())
+    }));
+    assert!(participants[0].has_policy());
+
+    for round in [Round::One, Round::Two] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        let mut vetoed = None;
+        for (sender, generator) in generators.iter().enumerate() {
+            for output in generator.iter() {
+                if output.dst_ordinal == 0 && sender == 2 {
+                    let result = participants[0].receive(&output.data);
+                    assert!(matches!(
+                        result,
+                        Err(Error::PolicyRejected { sender: 2, round: r, .. }) if r == round
+                    ));
+                    vetoed = Some(output.data);
+                    continue;
+                }
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+        // The vetoed sender is not blamed and its message is accepted later
+        assert!(participants[0].get_invalid_participant_ids().is_empty());
+        open.store(true, Ordering::SeqCst);
+        participants[0].receive(&vetoed.unwrap()).unwrap();
+        open.store(false, Ordering::SeqCst);
+    }
+
+    participants[0].clear_policy();
+    assert!(!participants[0].has_policy());
+    for participant in participants.iter_mut() {
+        participant.run().unwrap();
+    }
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
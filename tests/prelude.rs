@@ -0,0 +1,77 @@
+use frost_dkg::ciphersuites::{Ed448Secret, Secp256k1Secret};
+use frost_dkg::prelude::*;
+use std::num::NonZeroUsize;
+
+#[test]
+fn ciphersuite_aliases_with_prelude() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            let id = IdentifierPrimeField(k256::Scalar::from(id));
+            Box::new(Secp256k1Secret::new_secret(id, &parameters).unwrap())
+                as Box<dyn AnyParticipant<k256::ProjectivePoint>>
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    let shares = participants
+        .iter()
+        .map(|p| p.get_secret_share().unwrap())
+        .collect::<Vec<_>>();
+    let secret = shares.combine().unwrap();
+    assert_eq!(
+        participants[0].get_public_key().unwrap(),
+        k256::ProjectivePoint::GENERATOR * secret.0
+    );
+
+    let parameters = Parameters::<ed448_goldilocks_plus::EdwardsPoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let id = IdentifierPrimeField(ed448_goldilocks_plus::Scalar::from(1u64));
+    assert!(Ed448Secret::new_secret(id, &parameters).is_ok());
+}
+
+#[test]
+fn local_ceremony_with_prelude() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            let id = IdentifierPrimeField(k256::Scalar::from(id));
+            Box::new(Secp256k1Secret::new_secret(id, &parameters).unwrap())
+                as Box<dyn AnyParticipant<k256::ProjectivePoint>>
+        })
+        .collect::<Vec<_>>();
+
+    participants.swap(0, 1);
+    assert!(run_local_ceremony(&mut participants).is_err());
+    participants.swap(0, 1);
+    let outputs: Vec<DkgOutput<_>> = run_local_ceremony(&mut participants).unwrap();
+    assert_eq!(outputs.len(), 3);
+    for output in &outputs {
+        assert_eq!(output.public_key(), outputs[0].public_key());
+    }
+}
@@ -0,0 +1,198 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn quarantine_and_release<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(participants[1].peer_status(0), Some(PeerStatus::Pending));
+    assert_eq!(participants[1].peer_status(1), None);
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for generator in &generators {
+        for output in generator.iter() {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    assert_eq!(participants[1].peer_status(0), Some(PeerStatus::Validated));
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+
+    // Anyone can replay a share meant for someone else so it is not held
+    // against the sender
+    let misdirected = generators[0]
+        .iter()
+        .find(|output| output.dst_ordinal == 2)
+        .unwrap();
+    assert!(participants[1].receive(&misdirected.data).is_err());
+    assert_eq!(participants[1].peer_status(0), Some(PeerStatus::Validated));
+
+    // A share that never arrives quarantines the sender when aborting
+    let share = generators[2]
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+    participants[1].receive(&share.data).unwrap();
+    participants[1].set_missing_share_policy(MissingSharePolicy::Abort);
+    assert!(participants[1].run().is_err());
+    assert_eq!(
+        participants[1].peer_status(0),
+        Some(PeerStatus::Quarantined)
+    );
+
+    // Later messages are held instead of rejected
+    let share = generators[0]
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+    participants[1].receive(&share.data).unwrap();
+    assert_eq!(participants[1].quarantined_messages(0), &[share.data]);
+    assert!(!participants[1].get_received_round2_data().contains_key(&0));
+
+    let results = participants[1].release_peer(0).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_ok());
+    assert_eq!(participants[1].peer_status(0), Some(PeerStatus::Validated));
+    assert!(participants[1].quarantined_messages(0).is_empty());
+    assert!(participants[1].get_received_round2_data().contains_key(&0));
+    assert!(participants[1].release_peer(0).is_err());
+
+    // Disqualified peers are refused
+    participants[1].disqualify_peer(2).unwrap();
+    let share = generators[2]
+        .iter()
+        .find(|output| output.dst_ordinal == 1)
+        .unwrap();
+    assert!(participants[1].receive(&share.data).is_err());
+    assert_eq!(
+        participants[1].peer_statuses(),
+        [(0, PeerStatus::Validated), (2, PeerStatus::Disqualified)]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+    );
+    assert!(participants[1].disqualify_peer(1).is_err());
+
+    // Statuses survive a state snapshot
+    let restored =
+        SecretParticipant::<G>::import_state(&participants[1].export_state().unwrap()).unwrap();
+    assert_eq!(restored.peer_statuses(), participants[1].peer_statuses());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn regenerate_after_disqualification<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(5).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=5u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(participants[0].regenerate_round_output().is_err());
+    let destinations = |generator: &RoundOutputGenerator<G>| {
+        generator
+            .iter()
+            .map(|output| output.dst_ordinal)
+            .collect::<Vec<_>>()
+    };
+
+    // The last participant is disqualified after broadcasting
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for participant in participants.iter_mut().take(4) {
+        participant.disqualify_peer(4).unwrap();
+    }
+    assert_eq!(destinations(&generators[0]), vec![1, 2, 3, 4]);
+    let regenerated = participants[0].regenerate_round_output().unwrap();
+    assert_eq!(destinations(&regenerated), vec![1, 2, 3]);
+    for generator in &generators[..4] {
+        for output in generator.iter().filter(|output| output.dst_ordinal < 4) {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+
+    // The fourth is disqualified after the shares were dealt
+    let generators = participants
+        .iter_mut()
+        .take(4)
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for participant in participants.iter_mut().take(3) {
+        participant.disqualify_peer(3).unwrap();
+    }
+    assert_eq!(destinations(&generators[1]), vec![0, 2, 3]);
+    let regenerated = participants[1].regenerate_round_output().unwrap();
+    assert_eq!(destinations(&regenerated), vec![0, 2]);
+    for output in regenerated.iter() {
+        participants[output.dst_ordinal]
+            .receive(&output.data)
+            .unwrap();
+    }
+    for sender in [0, 2] {
+        for output in generators[sender]
+            .iter()
+            .filter(|output| output.dst_ordinal < 3)
+        {
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    for participant in participants.iter_mut().take(3) {
+        participant.run().unwrap();
+    }
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in participants.iter().take(3) {
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
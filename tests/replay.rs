@@ -0,0 +1,89 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn replay_cache<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut caches = vec![ReplayCache::new(Duration::from_secs(60)); 3];
+    let mut first = None;
+    for round in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        if round == Round::Three {
+            break;
+        }
+        for (sender, generator) in generators.iter().enumerate() {
+            for output in generator.iter() {
+                assert_eq!(message_sender(&output.data).unwrap(), sender);
+                participants[output.dst_ordinal]
+                    .receive_with_replay_cache(&output.data, &mut caches[output.dst_ordinal], 100)
+                    .unwrap();
+                if first.is_none() && output.dst_ordinal == 0 {
+                    first = Some(output.data);
+                }
+            }
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+    assert_eq!(caches[0].len(), 4);
+
+    // A replayed round 1 message is reported as such
+    let first = first.unwrap();
+    let sender = message_sender(&first).unwrap();
+    assert!(matches!(
+        participants[0].receive_with_replay_cache(&first, &mut caches[0], 130),
+        Err(Error::Replay {
+            sender: s,
+            round: Round::One,
+            first_seen: 100
+        }) if s == sender
+    ));
+
+    // A first time invalid message is not a replay, but repeating it is
+    let mut invalid = first.clone();
+    let last = invalid.len() - 1;
+    invalid[last] ^= 1;
+    let result = participants[0].receive_with_replay_cache(&invalid, &mut caches[0], 130);
+    assert!(result.is_err());
+    assert!(!matches!(result, Err(Error::Replay { .. })));
+    assert!(matches!(
+        participants[0].receive_with_replay_cache(&invalid, &mut caches[0], 131),
+        Err(Error::Replay { .. })
+    ));
+
+    // Once the time to live passes the message is forgotten
+    caches[0].prune(160);
+    assert_eq!(caches[0].len(), 1);
+    assert!(!matches!(
+        participants[0].receive_with_replay_cache(&first, &mut caches[0], 160),
+        Err(Error::Replay { .. })
+    ));
+}
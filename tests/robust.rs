@@ -0,0 +1,85 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn tolerate_invalid_dealer<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let limit = NonZeroUsize::new(5).unwrap();
+    let parameters = Parameters::<G>::new(NonZeroUsize::new(3).unwrap(), limit, None, None);
+    // The last dealer runs with a different threshold
+    let mismatched = Parameters::<G>::new(NonZeroUsize::new(2).unwrap(), limit, None, None);
+    let mut participants = (1..=5u64)
+        .map(|id| {
+            let parameters = if id == 5 { &mismatched } else { &parameters };
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(participants[0].set_fault_tolerance(3).is_err());
+    for participant in &mut participants[..4] {
+        participant.set_fault_tolerance(1).unwrap();
+        assert_eq!(participant.get_fault_tolerance(), 1);
+    }
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            let result = participants[output.dst_ordinal].receive(&output.data);
+            if sender == 4 {
+                assert!(matches!(
+                    result,
+                    Err(Error::ParameterMismatch { ordinal: 4 })
+                ));
+            }
+        }
+    }
+    // Anyone can send round 1 data under the ordinal so the dealer is not
+    // disqualified for it, it is just left out
+    for participant in &participants[..4] {
+        assert_eq!(participant.peer_status(4), Some(PeerStatus::Pending));
+    }
+
+    let honest = &mut participants[..4];
+    for _ in [Round::Two, Round::Three] {
+        let generators = honest
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                honest[output.dst_ordinal].receive(&output.data).unwrap();
+            }
+        }
+    }
+
+    let public_key = honest[0].get_public_key().unwrap();
+    for participant in honest.iter() {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+        assert_eq!(
+            participant
+                .get_dkg_output()
+                .unwrap()
+                .participant_ids()
+                .len(),
+            4
+        );
+    }
+}
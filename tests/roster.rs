@@ -0,0 +1,82 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorType, elliptic_curve::group::GroupEncoding,
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn remap_roster<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let id = |i: u64| IdentifierPrimeField(G::Scalar::from(i));
+    let old_ids = (1..=4).map(id).collect::<Vec<_>>();
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&old_ids)]),
+    );
+    let mut participants = old_ids[..3]
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(*id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+
+    // Participant 4 leaves, 5 joins and 1 moves to the end
+    let new_ids = vec![id(2), id(3), id(5), id(1)];
+    let mapping = remap_ordinals(participants[0].get_all_participant_ids(), &new_ids).unwrap();
+    assert_eq!(
+        mapping.into_iter().collect::<Vec<_>>(),
+        vec![(0, 3), (1, 0), (2, 1)]
+    );
+    assert!(remap_ordinals(participants[0].get_all_participant_ids(), &[id(1), id(1)]).is_err());
+    assert!(participants[2].remap_roster(&[id(1), id(2)]).is_err());
+
+    let commitments = participants[0].get_feldman_verifiers();
+    for participant in participants.iter_mut() {
+        participant.remap_roster(&new_ids).unwrap();
+    }
+    assert_eq!(participants[0].get_ordinal(), 3);
+    assert_eq!(participants[0].get_limit(), 4);
+    assert_eq!(participants[0].get_feldman_verifiers(), commitments);
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&new_ids)]),
+    );
+    participants.push(SecretParticipant::<G>::new_secret(id(5), &parameters).unwrap());
+    participants.sort_by_key(|p| p.get_ordinal());
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+        assert_eq!(participant.get_all_participant_ids().len(), 4);
+    }
+
+    // The roster is fixed once round 1 ran
+    assert!(participants[0].remap_roster(&new_ids[..3]).is_err());
+}
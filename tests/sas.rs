@@ -0,0 +1,55 @@
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn sas_codes_match_across_participants() {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for round in [Round::One, Round::Two, Round::Three] {
+        if round == Round::Three {
+            let code = participants[0].sas_code().unwrap();
+            for participant in &participants {
+                assert_eq!(participant.sas_code().unwrap(), code);
+            }
+            assert_eq!(code.words().len(), SAS_LENGTH);
+            assert_eq!(code.to_string(), code.words().join(" "));
+            assert_eq!(format!("{code:#}"), code.emoji().join(" "));
+        } else {
+            assert!(participants[0].sas_code().is_err());
+        }
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+
+    assert_ne!(
+        SasCode::from_transcript_hash(b"ceremony a"),
+        SasCode::from_transcript_hash(b"ceremony b")
+    );
+}
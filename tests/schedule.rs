@@ -0,0 +1,109 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn refresh_schedule<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let ids = (1..=4u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids[..3],
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let policy = RefreshPolicy {
+        interval: Some(Duration::from_secs(30 * DAY)),
+        on_member_change: true,
+    };
+    let mut schedule = RefreshSchedule::new(&outputs[0], policy, 0);
+    assert_eq!(schedule.epoch(), 0);
+    assert_eq!(schedule.due(DAY, &ids[..3]), None);
+    assert_eq!(
+        schedule.due(30 * DAY, &ids[..3]),
+        Some(RefreshReason::Interval)
+    );
+
+    // Same committee, new shares of the same key
+    let parameters = schedule.next_parameters(&ids[..3]).unwrap();
+    let mut participants = outputs
+        .iter()
+        .map(|output| {
+            schedule
+                .participant(output.id(), Some(&output.secret_share()), &parameters)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let refreshed = run_local_ceremony(&mut participants).unwrap();
+    for (old, new) in outputs.iter().zip(&refreshed) {
+        assert_eq!(new.public_key(), old.public_key());
+        assert_ne!(new.secret_share().value, old.secret_share().value);
+    }
+    schedule.advance(&refreshed[0], 30 * DAY).unwrap();
+    assert_eq!(schedule.epoch(), 1);
+    assert_eq!(schedule.due(31 * DAY, &ids[..3]), None);
+
+    // The third shareholder leaves and a newcomer joins
+    let members = [ids[0], ids[1], ids[3]];
+    assert_eq!(
+        schedule.due(31 * DAY, &members),
+        Some(RefreshReason::MemberChange)
+    );
+    let parameters = schedule.next_parameters(&members).unwrap();
+    assert!(
+        schedule
+            .participant(ids[2], Some(&refreshed[2].secret_share()), &parameters)
+            .is_err()
+    );
+    assert!(schedule.participant(ids[0], None, &parameters).is_err());
+    let mut participants = vec![
+        schedule
+            .participant(ids[0], Some(&refreshed[0].secret_share()), &parameters)
+            .unwrap(),
+        schedule
+            .participant(ids[1], Some(&refreshed[1].secret_share()), &parameters)
+            .unwrap(),
+        schedule.participant(ids[3], None, &parameters).unwrap(),
+    ];
+    let reshared = run_local_ceremony(&mut participants).unwrap();
+    assert_eq!(reshared[2].public_key(), outputs[0].public_key());
+    schedule.advance(&reshared[2], 31 * DAY).unwrap();
+    assert_eq!(schedule.epoch(), 2);
+    assert_eq!(schedule.due(32 * DAY, &members), None);
+
+    // Too few shareholders stay to reshare the key
+    let strangers = (10..=12u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    assert!(
+        schedule
+            .next_parameters(&[ids[0], strangers[0], strangers[1]])
+            .is_err()
+    );
+
+    // Outputs of an unrelated key don't advance the schedule
+    let other = generate_shares::<G>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &strangers,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    assert!(schedule.advance(&other[0], 32 * DAY).is_err());
+    assert_eq!(schedule.epoch(), 2);
+}
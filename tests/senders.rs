@@ -0,0 +1,88 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn expected_senders<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(participants[0].expect_senders(Round::Three, &[1]).is_err());
+    assert!(participants[0].expect_senders(Round::One, &[0]).is_err());
+    assert!(participants[0].expect_senders(Round::One, &[3]).is_err());
+    participants[0].expect_senders(Round::One, &[1]).unwrap();
+    assert_eq!(
+        participants[0]
+            .get_expected_senders(Round::One)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert!(participants[0].get_expected_senders(Round::Two).is_none());
+
+    let generators = participants
+        .iter_mut()
+        .map(|p| p.run().unwrap())
+        .collect::<Vec<_>>();
+    let mut unexpected = None;
+    for (sender, generator) in generators.iter().enumerate() {
+        for output in generator.iter() {
+            if output.dst_ordinal == 0 && sender == 2 {
+                assert!(matches!(
+                    participants[0].receive(&output.data),
+                    Err(Error::Round(_))
+                ));
+                unexpected = Some(output.data);
+                continue;
+            }
+            participants[output.dst_ordinal]
+                .receive(&output.data)
+                .unwrap();
+        }
+    }
+    // The rejected peer is not blamed
+    assert!(participants[0].get_invalid_participant_ids().is_empty());
+
+    participants[0].clear_expected_senders(Round::One);
+    participants[0].receive(&unexpected.unwrap()).unwrap();
+
+    for _ in [Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert!(participants.iter().all(|p| p.completed()));
+}
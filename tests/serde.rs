@@ -0,0 +1,127 @@
+#![cfg(feature = "json")]
+
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+type G = k256::ProjectivePoint;
+
+#[test]
+fn round_data_is_hex_when_human_readable() {
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let round1_data = &participants[0].get_received_round1_data()[&1];
+    let json = serde_json::to_value(round1_data).unwrap();
+    assert_eq!(
+        json["sender_id"],
+        serde_json::json!(hex::encode(k256::Scalar::from(2u64).to_bytes()))
+    );
+    assert!(json["verifying_share"].is_string());
+    assert!(json["signature"]["r"].is_string());
+    assert!(json["signature"]["s"].is_string());
+    assert!(
+        json["feldman_commitments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|c| c.is_string())
+    );
+    let decoded: Round1Data<G> = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        postcard::to_stdvec(&decoded).unwrap(),
+        postcard::to_stdvec(round1_data).unwrap()
+    );
+
+    let round2_data = &participants[0].get_received_round2_data()[&2];
+    let json = serde_json::to_value(round2_data).unwrap();
+    assert!(json["transcript_hash"].is_string());
+    assert!(json["secret_share"]["identifier"].is_string());
+    assert!(json["secret_share"]["value"].is_string());
+    let decoded: Round2Data<k256::Scalar> = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        postcard::to_stdvec(&decoded).unwrap(),
+        postcard::to_stdvec(round2_data).unwrap()
+    );
+}
+
+#[test]
+fn typed_receive_over_json() {
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for round in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                // Simulate a transport that carries JSON messages
+                let participant = &mut participants[output.dst_ordinal];
+                match round {
+                    Round::One => {
+                        let data: Round1Data<G> =
+                            postcard::from_bytes(&output.data[MESSAGE_HEADER_LENGTH..]).unwrap();
+                        let json = serde_json::to_string(&data).unwrap();
+                        participant
+                            .receive_round1(serde_json::from_str(&json).unwrap())
+                            .unwrap();
+                    }
+                    _ => {
+                        let data: Round2Data<k256::Scalar> =
+                            postcard::from_bytes(&output.data[MESSAGE_HEADER_LENGTH..]).unwrap();
+                        let json = serde_json::to_string(&data).unwrap();
+                        participant
+                            .receive_round2(serde_json::from_str(&json).unwrap())
+                            .unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
@@ -0,0 +1,98 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorType, ReadableShareSet,
+    elliptic_curve::group::GroupEncoding,
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn share_record_epochs<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let threshold = NonZeroUsize::new(3).unwrap();
+    let limit = NonZeroUsize::new(4).unwrap();
+    let ids = (1..=4u64)
+        .map(|i| IdentifierPrimeField(G::Scalar::from(i)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<G>(threshold, limit, &ids, &mut rand::rng()).unwrap();
+    let records = outputs
+        .iter()
+        .map(|output| ShareRecord::from_output(output, 7).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(records[0].key_id(), outputs[0].key_id());
+
+    // Stored records are checked when loaded
+    let mut bytes = records[0].to_bytes().unwrap();
+    let loaded = ShareRecord::<G>::from_bytes(&bytes).unwrap();
+    assert_eq!(loaded.epoch(), 7);
+    assert_eq!(loaded.secret_share(), records[0].secret_share());
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    assert!(matches!(
+        ShareRecord::<G>::from_bytes(&bytes),
+        Err(Error::Integrity(_))
+    ));
+
+    // Roll the records forward with a refresh
+    let parameters = Parameters::<G>::new(
+        threshold,
+        limit,
+        None,
+        Some(vec![ParticipantIdGeneratorType::list(&ids)]),
+    )
+    .with_previous_verification_shares(records[0].verification_shares());
+    let mut participants = records
+        .iter()
+        .map(|record| {
+            RefreshParticipant::<G>::with_same_roster_share(
+                &record.secret_share(),
+                &parameters,
+                &ids,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    assert!(records[0].refresh(&participants[1]).is_err());
+    let refreshed = records
+        .iter()
+        .zip(&participants)
+        .map(|(record, participant)| record.refresh(participant).unwrap())
+        .collect::<Vec<_>>();
+    for (record, old) in refreshed.iter().zip(&records) {
+        assert_eq!(record.epoch(), 8);
+        assert_eq!(record.key_id(), old.key_id());
+        assert_ne!(record.secret_share(), old.secret_share());
+        ShareRecord::<G>::from_bytes(&record.to_bytes().unwrap()).unwrap();
+    }
+    let shares = refreshed
+        .iter()
+        .map(|record| record.secret_share())
+        .collect::<Vec<_>>();
+    let secret = shares[1..].to_vec().combine().unwrap();
+    assert_eq!(G::generator() * *secret, *outputs[0].public_key());
+
+    // A reshare must keep the group key
+    let other = generate_shares::<G>(threshold, limit, &ids, &mut rand::rng()).unwrap();
+    assert!(refreshed[0].reshare(&other[0]).is_err());
+}
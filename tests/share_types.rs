@@ -0,0 +1,57 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    DefaultShare, IdentifierPrimeField, ReadableShareSet, ValuePrimeField,
+    elliptic_curve::group::GroupEncoding,
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn convert_share_types<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            Box::new(
+                SecretParticipant::<G>::new_secret(
+                    IdentifierPrimeField(G::Scalar::from(id)),
+                    &parameters,
+                )
+                .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    let outputs = run_local_ceremony(&mut participants).unwrap();
+
+    for (output, id) in outputs.iter().zip(1..=3u64) {
+        assert_eq!(output.integer_id(), Some(id));
+    }
+
+    let shares = outputs
+        .iter()
+        .map(|output| {
+            output.secret_share_as::<DefaultShare<
+                IdentifierPrimeField<G::Scalar>,
+                ValuePrimeField<G::Scalar>,
+            >>()
+        })
+        .collect::<Vec<_>>();
+    let secret = shares.combine().unwrap();
+    assert_eq!(
+        outputs[0].message_generator() * secret.0,
+        outputs[0].public_key().get()
+    );
+}
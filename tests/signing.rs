@@ -0,0 +1,251 @@
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, elliptic_curve::group::GroupEncoding};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::p384(p384::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+#[case::curve25519_dalek(curve25519_dalek::RistrettoPoint::default())]
+#[case::curve25519_dalek_edwards(curve25519_dalek::EdwardsPoint::default())]
+#[case::bls12_381_g1(bls12_381_plus::G1Projective::default())]
+#[case::bls12_381_g2(bls12_381_plus::G2Projective::default())]
+fn threshold_signing<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let outputs = run_dkg::<G>(3, 5);
+    let message = b"frost threshold signing";
+
+    let signers = &outputs[1..4];
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|o| o.commit()).unzip();
+    let package = SigningPackage::new(&commitments, message).unwrap();
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(output, nonces)| output.sign(&package, nonces).unwrap())
+        .collect::<Vec<_>>();
+
+    let signature = outputs[0].aggregate(&package, &shares).unwrap();
+    let public_key = outputs[0].public_key().get();
+    assert!(
+        signature
+            .verify(public_key, G::generator(), message)
+            .is_ok()
+    );
+    assert!(
+        signature
+            .verify(public_key, G::generator(), b"another message")
+            .is_err()
+    );
+
+    // A share over a different message is attributed to its signer
+    let (other_nonces, other_commitments) = signers[0].commit();
+    let other_package = SigningPackage::new(
+        &[other_commitments, commitments[1], commitments[2]],
+        b"another message",
+    )
+    .unwrap();
+    let mut bad_shares = shares.clone();
+    bad_shares[0] = signers[0].sign(&other_package, other_nonces).unwrap();
+    match outputs[0].aggregate(&package, &bad_shares) {
+        Err(Error::Signature(e)) => assert!(e.contains(&signers[0].ordinal().to_string())),
+        _ => panic!("expected an invalid signature share"),
+    }
+
+    // Every signer in the package must contribute a share
+    assert!(outputs[0].aggregate(&package, &shares[..2]).is_err());
+
+    // Fewer than threshold signers cannot produce a signature
+    let package = SigningPackage::new(&commitments[..2], message).unwrap();
+    assert!(outputs[0].aggregate(&package, &shares[..2]).is_err());
+}
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn liveness<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            Box::new(
+                SecretParticipant::<G>::new_secret(
+                    IdentifierPrimeField(G::Scalar::from(id)),
+                    &parameters,
+                )
+                .unwrap(),
+            ) as Box<dyn AnyParticipant<G>>
+        })
+        .collect::<Vec<_>>();
+    let (outputs, signature) =
+        run_local_ceremony_with_liveness(&mut participants, b"ceremony 42").unwrap();
+    for output in &outputs {
+        output.verify_liveness(b"ceremony 42", &signature).unwrap();
+        assert!(output.verify_liveness(b"ceremony 43", &signature).is_err());
+    }
+
+    // The canary is never signed as is
+    let public_key = outputs[0].public_key().get();
+    assert!(
+        signature
+            .verify(
+                public_key,
+                G::generator(),
+                &liveness_message(b"ceremony 42")
+            )
+            .is_ok()
+    );
+    assert!(
+        signature
+            .verify(public_key, G::generator(), b"ceremony 42")
+            .is_err()
+    );
+
+    assert!(prove_liveness(&outputs[..1], b"ceremony 42").is_err());
+    assert!(prove_liveness::<G>(&[], b"ceremony 42").is_err());
+    let other = run_dkg::<G>(2, 3);
+    let mixed = [outputs[0].clone(), outputs[1].clone(), other[2].clone()];
+    assert!(prove_liveness(&mixed, b"ceremony 42").is_err());
+}
+
+#[test]
+fn preprocessed_signing() {
+    type G = k256::ProjectivePoint;
+
+    let outputs = run_dkg::<G>(2, 3);
+    let message = b"one round online";
+
+    let mut stores = outputs.iter().map(NonceStore::new).collect::<Vec<_>>();
+    let published = outputs
+        .iter()
+        .zip(stores.iter_mut())
+        .map(|(output, store)| store.generate(output, 4).unwrap())
+        .collect::<Vec<_>>();
+    assert!(stores.iter().all(|store| store.remaining() == 4));
+    assert!(stores[0].generate(&outputs[1], 1).is_err());
+
+    let package = SigningPackage::new(&[published[0][0], published[2][0]], message).unwrap();
+    let shares = [0, 2]
+        .into_iter()
+        .map(|i| {
+            outputs[i]
+                .sign_with_store(&package, &mut stores[i])
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let signature = outputs[1].aggregate(&package, &shares).unwrap();
+    assert!(
+        signature
+            .verify(outputs[1].public_key().get(), G::GENERATOR, message)
+            .is_ok()
+    );
+    assert_eq!(stores[0].remaining(), 3);
+
+    // Nonces are single use
+    assert!(
+        outputs[0]
+            .sign_with_store(&package, &mut stores[0])
+            .is_err()
+    );
+}
+
+#[test]
+fn subkey_derivation() {
+    type G = k256::ProjectivePoint;
+
+    let outputs = run_dkg::<G>(2, 3);
+    let context = b"wallet/0";
+
+    let proofs = outputs
+        .iter()
+        .map(|output| output.subkey_proof(context).unwrap())
+        .collect::<Vec<_>>();
+    for output in &outputs {
+        for proof in &proofs {
+            assert!(output.verify_subkey_proof(context, proof).is_ok());
+        }
+    }
+    // A shareholder that derived for another context is detected
+    let divergent = outputs[2].subkey_proof(b"wallet/1").unwrap();
+    assert!(outputs[0].verify_subkey_proof(context, &divergent).is_err());
+
+    let subkeys = outputs
+        .iter()
+        .map(|output| output.derive_subkey(context).unwrap())
+        .collect::<Vec<_>>();
+    assert_ne!(subkeys[0].public_key(), outputs[0].public_key());
+    assert_eq!(
+        subkeys[0].public_key().get(),
+        outputs[0].public_key().get() + G::GENERATOR * outputs[0].subkey_tweak(context)
+    );
+
+    let message = b"signed with a subkey";
+    let signers = &subkeys[..2];
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|o| o.commit()).unzip();
+    let package = SigningPackage::new(&commitments, message).unwrap();
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(output, nonces)| output.sign(&package, nonces).unwrap())
+        .collect::<Vec<_>>();
+    let signature = subkeys[2].aggregate(&package, &shares).unwrap();
+    assert!(
+        signature
+            .verify(subkeys[2].public_key().get(), G::GENERATOR, message)
+            .is_ok()
+    );
+}
+
+fn run_dkg<G>(threshold: usize, limit: usize) -> Vec<DkgOutput<G>>
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(threshold).unwrap(),
+        NonZeroUsize::new(limit).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=limit as u64)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(G::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+    participants
+        .iter()
+        .map(|p| p.get_dkg_output().unwrap())
+        .collect()
+}
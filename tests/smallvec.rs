@@ -0,0 +1,57 @@
+#![cfg(feature = "smallvec")]
+
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[rstest]
+#[case::inline(3)]
+#[case::spilled(INLINE_COMMITMENTS + 1)]
+fn smallvec_dkg(#[case] threshold: usize) {
+    type G = k256::ProjectivePoint;
+
+    let parameters = Parameters::<G>::new(
+        NonZeroUsize::new(threshold).unwrap(),
+        NonZeroUsize::new(threshold + 1).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=threshold as u64 + 1)
+        .map(|id| {
+            SecretParticipant::<G>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(participants[0].get_feldman_verifiers().len(), threshold);
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+
+    let state = participants[0].export_state().unwrap();
+    let restored = SecretParticipant::<G>::import_state(&state).unwrap();
+    assert_eq!(
+        restored.get_feldman_verifiers(),
+        participants[0].get_feldman_verifiers()
+    );
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "solana")]
+
+use curve25519_dalek::{EdwardsPoint, Scalar};
+use frost_dkg::*;
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn solana_key_package() {
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<EdwardsPoint>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+    let output = &outputs[1];
+
+    let public_key = output.solana_public_key();
+    assert_eq!(
+        bs58::decode(&public_key).into_vec().unwrap(),
+        output.public_key().compress().to_bytes().to_vec()
+    );
+    assert_eq!(
+        DisplayPoint::<EdwardsPoint>::from_base58(&public_key)
+            .unwrap()
+            .0,
+        output.public_key().get()
+    );
+
+    let package = output.solana_key_package().unwrap();
+    let json: serde_json::Value = serde_json::from_str(&package).unwrap();
+    assert_eq!(json["header"]["ciphersuite"], "FROST-ED25519-SHA512-v1");
+    assert_eq!(json["min_signers"], 2);
+    assert_eq!(
+        json["identifier"],
+        hex::encode(Scalar::from(2u64).to_bytes())
+    );
+    assert_eq!(
+        json["signing_share"],
+        hex::encode(output.secret_share().value.0.to_bytes())
+    );
+    assert_eq!(
+        json["verifying_key"],
+        hex::encode(bs58::decode(&public_key).into_vec().unwrap())
+    );
+    assert_eq!(
+        json["verifying_share"],
+        hex::encode(
+            bs58::decode(output.solana_share_public_key().unwrap())
+                .into_vec()
+                .unwrap()
+        )
+    );
+}
@@ -0,0 +1,228 @@
+use frost_dkg::*;
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{IdentifierPrimeField, ReadableShareSet};
+
+#[rstest]
+#[case::round_one(Round::One)]
+#[case::round_two(Round::Two)]
+#[case::round_three(Round::Three)]
+fn resume_from_snapshot(#[case] resume_at: Round) {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<k256::ProjectivePoint>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for round in [Round::One, Round::Two, Round::Three] {
+        if round == resume_at {
+            let state = participants[0].export_state().unwrap();
+            participants[0] =
+                SecretParticipant::<k256::ProjectivePoint>::import_state(&state).unwrap();
+        }
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let shares = participants
+        .iter()
+        .map(|p| p.get_secret_share().unwrap())
+        .collect::<Vec<_>>();
+    let secret = shares.combine().unwrap();
+    assert_eq!(
+        participants[0].get_public_key().unwrap(),
+        k256::ProjectivePoint::GENERATOR * secret.0
+    );
+}
+
+#[test]
+fn upgrades_older_snapshots() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let participant = SecretParticipant::<k256::ProjectivePoint>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(1u64)),
+        &parameters,
+    )
+    .unwrap();
+    let state = participant.export_state().unwrap();
+
+    // Version 15 snapshots end before the pedersen openings and version 14
+    // snapshots before the round 1 commitment fields, which are all empty here
+    for (version, empty_fields) in [(15u16, 1), (14, 4)] {
+        assert_eq!(state[state.len() - empty_fields..], vec![0u8; empty_fields]);
+        let mut older = state[..state.len() - empty_fields].to_vec();
+        older[..2].copy_from_slice(&version.to_be_bytes());
+        let upgraded = SecretParticipant::<k256::ProjectivePoint>::import_state(&older).unwrap();
+        assert_eq!(upgraded.export_state().unwrap(), state);
+    }
+
+    let mut trailing = state.clone();
+    trailing.push(0);
+    assert!(SecretParticipant::<k256::ProjectivePoint>::import_state(&trailing).is_err());
+}
+
+#[test]
+fn rejects_unknown_snapshots() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let participant = SecretParticipant::<k256::ProjectivePoint>::new_secret(
+        IdentifierPrimeField(k256::Scalar::from(1u64)),
+        &parameters,
+    )
+    .unwrap();
+    let mut state = participant.export_state().unwrap();
+    assert_eq!(state[..2], STATE_VERSION.to_be_bytes());
+
+    assert!(RefreshParticipant::<k256::ProjectivePoint>::import_state(&state).is_err());
+
+    state[..2].copy_from_slice(&(STATE_VERSION + 1).to_be_bytes());
+    assert!(matches!(
+        SecretParticipant::<k256::ProjectivePoint>::import_state(&state),
+        Err(Error::UnsupportedStateVersion(v)) if v == STATE_VERSION + 1
+    ));
+}
+
+#[test]
+fn gc_after_completion() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<k256::ProjectivePoint>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(participants[0].gc().is_err());
+
+    for _ in [Round::One, Round::Two, Round::Three] {
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+    }
+
+    let transcript_hash = participants[0].get_final_transcript_hash_bytes();
+    let output = participants[0].get_dkg_output().unwrap();
+    let footprint = participants[0].memory_footprint();
+
+    participants[0].gc().unwrap();
+    assert!(participants[0].memory_footprint() < footprint);
+    assert!(participants[0].get_received_round1_data().is_empty());
+    assert!(participants[0].get_received_round2_data().is_empty());
+    assert_eq!(
+        participants[0].get_final_transcript_hash_bytes(),
+        transcript_hash
+    );
+    let pruned = participants[0].get_dkg_output().unwrap();
+    assert_eq!(pruned.public_key(), output.public_key());
+    assert_eq!(pruned.secret_share(), output.secret_share());
+    assert_eq!(pruned.verification_shares(), output.verification_shares());
+    assert_eq!(
+        pruned.transcript_hash_bytes(),
+        output.transcript_hash_bytes()
+    );
+
+    let state = participants[0].export_state().unwrap();
+    let restored = SecretParticipant::<k256::ProjectivePoint>::import_state(&state).unwrap();
+    assert_eq!(restored.get_final_transcript_hash_bytes(), transcript_hash);
+}
+
+#[test]
+fn replace_crashed_participant() {
+    let parameters = Parameters::<k256::ProjectivePoint>::new(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        None,
+        None,
+    );
+    let mut participants = (1..=3u64)
+        .map(|id| {
+            SecretParticipant::<k256::ProjectivePoint>::new_secret(
+                IdentifierPrimeField(k256::Scalar::from(id)),
+                &parameters,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(participants[0].accepts_replacement(2));
+
+    let mut snapshot = Vec::new();
+    for round in [Round::One, Round::Two, Round::Three] {
+        if round == Round::Three {
+            // Participant 2 crashed after round 2 and is replaced from its snapshot
+            let other = Parameters::<k256::ProjectivePoint>::new(
+                NonZeroUsize::new(3).unwrap(),
+                NonZeroUsize::new(3).unwrap(),
+                None,
+                None,
+            );
+            assert!(SecretParticipant::<k256::ProjectivePoint>::resume(&snapshot, &other).is_err());
+            participants[2] =
+                SecretParticipant::<k256::ProjectivePoint>::resume(&snapshot, &parameters).unwrap();
+            assert_eq!(participants[2].get_round(), Round::Three);
+        }
+        let generators = participants
+            .iter_mut()
+            .map(|p| p.run().unwrap())
+            .collect::<Vec<_>>();
+        for generator in &generators {
+            for output in generator.iter() {
+                participants[output.dst_ordinal]
+                    .receive(&output.data)
+                    .unwrap();
+            }
+        }
+        if round == Round::Two {
+            snapshot = participants[2].export_state().unwrap();
+        }
+    }
+    // The dealer of a round 1 broadcast cannot start over
+    assert!(!participants[0].accepts_replacement(2));
+
+    let public_key = participants[0].get_public_key().unwrap();
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(participant.get_public_key().unwrap(), public_key);
+    }
+}
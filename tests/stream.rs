@@ -0,0 +1,60 @@
+#![cfg(feature = "futures")]
+
+use elliptic_curve::subtle::ConditionallySelectable;
+use elliptic_curve_tools::SumOfProducts;
+use frost_dkg::*;
+use futures::{SinkExt, StreamExt, executor::block_on};
+use rstest::*;
+use std::num::NonZeroUsize;
+use vsss_rs::{
+    IdentifierPrimeField, ParticipantIdGeneratorCollection, ParticipantIdGeneratorType,
+    elliptic_curve::group::GroupEncoding,
+};
+
+#[rstest]
+#[case::k256(k256::ProjectivePoint::IDENTITY)]
+#[case::p256(p256::ProjectivePoint::IDENTITY)]
+#[case::ed448(ed448_goldilocks_plus::EdwardsPoint::IDENTITY)]
+fn stream_sink_dkg<G>(#[case] _g: G)
+where
+    G: SumOfProducts + GroupEncoding + ConditionallySelectable + PrimeSubgroup,
+    G::Scalar: ScalarHash,
+{
+    const THRESHOLD: usize = 2;
+    const LIMIT: usize = 3;
+
+    let threshold = NonZeroUsize::new(THRESHOLD).unwrap();
+    let limit = NonZeroUsize::new(LIMIT).unwrap();
+    let seq = vec![
+        ParticipantIdGeneratorType::<IdentifierPrimeField<G::Scalar>>::sequential(
+            None, None, limit,
+        ),
+    ];
+    let parameters = Parameters::<G>::new(threshold, limit, None, Some(seq.clone()));
+    let mut participants = ParticipantIdGeneratorCollection::from(&seq)
+        .iter()
+        .map(|id| SecretParticipant::<G>::new_secret(id, &parameters).unwrap())
+        .collect::<Vec<_>>();
+
+    block_on(async {
+        for _ in [Round::One, Round::Two, Round::Three] {
+            let mut outbound = Vec::with_capacity(LIMIT);
+            for participant in participants.iter_mut() {
+                let stream = ParticipantSink::new(participant).run().unwrap();
+                outbound.push(stream.collect::<Vec<_>>().await);
+            }
+            for output in outbound.into_iter().flatten() {
+                let mut sink = ParticipantSink::new(&mut participants[output.dst_ordinal]);
+                sink.send(output.data).await.unwrap();
+            }
+        }
+    });
+
+    for participant in &participants {
+        assert!(participant.completed());
+        assert_eq!(
+            participant.get_public_key(),
+            participants[0].get_public_key()
+        );
+    }
+}
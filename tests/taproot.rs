@@ -0,0 +1,63 @@
+#![cfg(feature = "k256")]
+
+use elliptic_curve::PrimeField;
+use elliptic_curve::group::GroupEncoding;
+use frost_dkg::*;
+use k256::{ProjectivePoint, Scalar};
+use std::num::NonZeroUsize;
+use vsss_rs::IdentifierPrimeField;
+
+#[test]
+fn taproot_descriptor() {
+    let ids = (1..=3u64)
+        .map(|id| IdentifierPrimeField(Scalar::from(id)))
+        .collect::<Vec<_>>();
+    let outputs = generate_shares::<ProjectivePoint>(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        &ids,
+        &mut rand::rng(),
+    )
+    .unwrap();
+
+    let normalized = outputs.iter().map(|o| o.to_x_only()).collect::<Vec<_>>();
+    for (output, normalized) in outputs.iter().zip(&normalized) {
+        assert!(normalized.has_even_y());
+        assert_eq!(output.x_only_public_key(), normalized.x_only_public_key());
+        assert_eq!(
+            output.taproot_descriptor().unwrap(),
+            normalized.taproot_descriptor().unwrap()
+        );
+        let verification_share = normalized.verification_share(normalized.ordinal()).unwrap();
+        assert_eq!(
+            ProjectivePoint::GENERATOR * normalized.secret_share().value.0,
+            verification_share.get()
+        );
+    }
+
+    let key = normalized[0].taproot_key().unwrap();
+    assert_eq!(key.internal_key(), normalized[0].x_only_public_key());
+    assert_eq!(&key.script_pubkey()[..2], &[0x51, 0x20]);
+    assert_eq!(&key.script_pubkey()[2..], &key.output_key());
+
+    // The tweaked key is spendable by tweaking the interpolated secret
+    let secret = combine_shares(&normalized[..2]);
+    let tweak = Option::<Scalar>::from(Scalar::from_repr(key.tweak().into())).unwrap();
+    let output_key = ProjectivePoint::GENERATOR * (secret + tweak);
+    assert_eq!(&output_key.to_bytes()[1..], &key.output_key());
+    assert_eq!(output_key.to_bytes()[0] == 0x03, key.output_key_parity());
+
+    let descriptor = normalized[0].taproot_descriptor().unwrap();
+    assert!(descriptor.starts_with(&format!("tr({})#", hex::encode(key.internal_key()))));
+    assert_eq!(descriptor.len(), "tr()#".len() + 64 + 8);
+}
+
+fn combine_shares(outputs: &[DkgOutput<ProjectivePoint>]) -> Scalar {
+    let ids = outputs.iter().map(|o| o.id()).collect::<Vec<_>>();
+    lagrange_coefficients(&ids, Scalar::ZERO)
+        .unwrap()
+        .into_iter()
+        .zip(outputs)
+        .map(|(l, o)| l * o.secret_share().value.0)
+        .sum()
+}